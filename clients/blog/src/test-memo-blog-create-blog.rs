@@ -381,8 +381,9 @@ fn create_create_blog_instruction(
     let result = hasher.finalize();
     let mut instruction_data = result[..8].to_vec();
     
-    // Add parameters: burn_amount (u64)
+    // Add parameters: burn_amount (u64), min_mint_interval (i64)
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0i64.to_le_bytes()); // min_mint_interval (0 = no throttle)
 
     let accounts = vec![
         AccountMeta::new(*creator, true),
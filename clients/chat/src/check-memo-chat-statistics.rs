@@ -129,9 +129,36 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         println!("   🏷️  Tags: {}", group_info.tags.join(", "));
                     }
 
+                    if !group_info.lang.is_empty() {
+                        println!("   🌐 Language: {}", group_info.lang);
+                    }
+
+                    if !group_info.pinned_message.is_empty() {
+                        println!("   📌 Pinned: \"{}\"", group_info.pinned_message);
+                    }
+
                     println!("   💬 Memo count: {}", group_info.memo_count);
                     println!("   🔥 Burned tokens: {} MEMO", group_info.burned_amount / 1_000_000);
                     println!("   ⏱️  Min memo interval: {} seconds", group_info.min_memo_interval);
+                    if group_info.verified {
+                        println!("   ✅ Verified");
+                    }
+
+                    if group_info.archived {
+                        println!("   🗄️  Archived");
+                    }
+
+                    if group_info.store_messages {
+                        println!("   💾 Messages stored on-chain");
+                    }
+
+                    if group_info.reward_scaling_enabled {
+                        println!("   📏 Mint reward scales with message length");
+                    }
+
+                    if group_info.message_burn > 0 {
+                        println!("   💸 Message burn: {} MEMO per message", group_info.message_burn / 1_000_000);
+                    }
 
                     if group_info.last_memo_time > 0 {
                         let dt = DateTime::<Utc>::from_timestamp(group_info.last_memo_time, 0);
@@ -193,6 +220,16 @@ struct ChatGroupInfo {
     pub burned_amount: u64,
     pub min_memo_interval: i64,
     pub last_memo_time: i64,
+    pub message_burn: u64,
+    pub verified: bool,
+    pub archived: bool,
+    pub store_messages: bool,
+    pub reward_scaling_enabled: bool,
+    pub lang: String,
+    pub pinned_message: String,
+    pub name_lower: String,
+    pub require_fresh_messages: bool,
+    pub last_announcement: i64,
     pub bump: u8,
 }
 
@@ -270,6 +307,67 @@ fn parse_chat_group_data(data: &[u8]) -> Result<ChatGroupInfo, Box<dyn std::erro
     let last_memo_time = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
     offset += 8;
 
+    // Read message_burn (u64)
+    if data.len() < offset + 8 {
+        return Err("Data too short for message_burn".into());
+    }
+    let message_burn = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
+    // Read verified (bool)
+    if data.len() < offset + 1 {
+        return Err("Data too short for verified".into());
+    }
+    let verified = data[offset] != 0;
+    offset += 1;
+
+    // Read archived (bool)
+    if data.len() < offset + 1 {
+        return Err("Data too short for archived".into());
+    }
+    let archived = data[offset] != 0;
+    offset += 1;
+
+    // Read store_messages (bool)
+    if data.len() < offset + 1 {
+        return Err("Data too short for store_messages".into());
+    }
+    let store_messages = data[offset] != 0;
+    offset += 1;
+
+    // Read reward_scaling_enabled (bool)
+    if data.len() < offset + 1 {
+        return Err("Data too short for reward_scaling_enabled".into());
+    }
+    let reward_scaling_enabled = data[offset] != 0;
+    offset += 1;
+
+    // Read lang (String)
+    let (lang, new_offset) = read_string(data, offset)?;
+    offset = new_offset;
+
+    // Read pinned_message (String)
+    let (pinned_message, new_offset) = read_string(data, offset)?;
+    offset = new_offset;
+
+    // Read name_lower (String)
+    let (name_lower, new_offset) = read_string(data, offset)?;
+    offset = new_offset;
+
+    // Read require_fresh_messages (bool)
+    if data.len() < offset + 1 {
+        return Err("Data too short for require_fresh_messages".into());
+    }
+    let require_fresh_messages = data[offset] != 0;
+    offset += 1;
+
+    // Read last_announcement (i64)
+    if data.len() < offset + 8 {
+        return Err("Data too short for last_announcement".into());
+    }
+    let last_announcement = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
     // Read bump (u8)
     if data.len() < offset + 1 {
         return Err("Data too short for bump".into());
@@ -288,6 +386,16 @@ fn parse_chat_group_data(data: &[u8]) -> Result<ChatGroupInfo, Box<dyn std::erro
         burned_amount,
         min_memo_interval,
         last_memo_time,
+        message_burn,
+        verified,
+        archived,
+        store_messages,
+        reward_scaling_enabled,
+        lang,
+        pinned_message,
+        name_lower,
+        require_fresh_messages,
+        last_announcement,
         bump,
     })
 }
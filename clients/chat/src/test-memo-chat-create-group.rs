@@ -188,7 +188,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000, // Increased to meet minimum requirement
             name: "Basic Test Group".to_string(),
             description: "A basic test group".to_string(),
-            image: "avatar_001.png".to_string(),
+            image: "https://example.com/avatar_001.png".to_string(),
             tags: vec!["test".to_string(), "basic".to_string()],
             min_memo_interval: Some(60),
             should_succeed: true,
@@ -198,7 +198,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000,
             name: "".to_string(),  // Empty name
             description: "Testing empty name".to_string(),
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["test".to_string()],
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -208,7 +208,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000,
             name: "x".repeat(65),  // Name too long (>64 chars)
             description: "Testing long name".to_string(),
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["test".to_string()],
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -218,7 +218,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000,
             name: "Test Group".to_string(),
             description: "x".repeat(129),  // Description too long (>128 chars)
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["test".to_string()],
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -238,7 +238,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000,
             name: "Test Group".to_string(),
             description: "Testing too many tags".to_string(),
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["tag1".to_string(), "tag2".to_string(), "tag3".to_string(), "tag4".to_string(), "tag5".to_string()], // 5 tags (>4)
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -248,7 +248,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000,
             name: "Test Group".to_string(),
             description: "Testing long tag".to_string(),
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["x".repeat(33)], // Tag too long (>32 chars)
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -258,7 +258,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 10000,  // Less than required 42069 tokens
             name: "Test Group".to_string(),
             description: "Testing small burn amount".to_string(),
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["test".to_string()],
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -322,7 +322,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000,
             name: "Test Group".to_string(),
             description: "Testing invalid category".to_string(),
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["test".to_string()],
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -332,7 +332,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             burn_amount: 50000,
             name: "Test Group".to_string(),
             description: "Testing invalid operation".to_string(),
-            image: "test.png".to_string(),
+            image: "https://example.com/test.png".to_string(),
             tags: vec!["test".to_string()],
             min_memo_interval: Some(60),
             should_succeed: false,
@@ -756,6 +756,9 @@ fn create_chat_group_instruction(
     
     instruction_data.extend_from_slice(&expected_group_id.to_le_bytes());
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0u64.to_le_bytes()); // message_burn (0 = free group)
+    instruction_data.push(0); // store_messages (false)
+    instruction_data.push(0); // reward_scaling_enabled (false)
 
     let accounts = vec![
         AccountMeta::new(*creator, true),
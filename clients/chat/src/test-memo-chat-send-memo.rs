@@ -10,6 +10,7 @@ use solana_sdk::{
     compute_budget::ComputeBudgetInstruction,
     commitment_config::CommitmentConfig,
 };
+use solana_system_interface::program as system_program;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use std::str::FromStr;
 use sha2::{Sha256, Digest};
@@ -652,6 +653,12 @@ fn send_memo_to_group_instruction(
     
     instruction_data.extend_from_slice(&group_id.to_le_bytes());
 
+    // Per-user daily mint quota (created on the sender's first message)
+    let (daily_mint_quota, _) = Pubkey::find_program_address(
+        &[b"quota", sender.as_ref()],
+        program_id,
+    );
+
     let accounts = vec![
         AccountMeta::new(*sender, true),                        // sender (user as signer)
         AccountMeta::new(*chat_group, false),                   // chat_group
@@ -664,6 +671,11 @@ fn send_memo_to_group_instruction(
             solana_sdk::sysvar::instructions::id(),
             false
         ), // instructions
+        AccountMeta::new(daily_mint_quota, false),              // daily_mint_quota
+        AccountMeta::new_readonly(*program_id, false),          // user_global_burn_stats (not provided)
+        AccountMeta::new_readonly(*program_id, false),          // memo_burn_program (not provided)
+        AccountMeta::new_readonly(*program_id, false),          // stored_message (not provided)
+        AccountMeta::new_readonly(system_program::id(), false), // system_program
     ];
 
     Instruction::new_with_bytes(*program_id, &instruction_data, accounts)
@@ -11,6 +11,7 @@ use solana_sdk::{
     commitment_config::CommitmentConfig,
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
+use solana_system_interface::program as system_program;
 use sha2::{Sha256, Digest};
 use borsh::{BorshSerialize, BorshDeserialize};
 use base64::{Engine as _, engine::general_purpose};
@@ -308,9 +309,10 @@ fn create_burn_for_post_instruction(
     let result = hasher.finalize();
     let mut instruction_data = result[..8].to_vec();
     
-    // Add parameters: post_id (u64), amount (u64)
+    // Add parameters: post_id (u64), amount (u64), receipt_nonce (Option<u64>, None)
     instruction_data.extend_from_slice(&post_id.to_le_bytes());
     instruction_data.extend_from_slice(&amount.to_le_bytes());
+    instruction_data.push(0);
 
     let accounts = vec![
         AccountMeta::new(*user, true),
@@ -324,6 +326,7 @@ fn create_burn_for_post_instruction(
             solana_sdk::sysvar::instructions::id(),
             false
         ),
+        AccountMeta::new_readonly(system_program::id(), false),
     ];
 
     Instruction::new_with_bytes(*program_id, &instruction_data, accounts)
@@ -399,9 +399,11 @@ fn create_create_post_instruction(
     let result = hasher.finalize();
     let mut instruction_data = result[..8].to_vec();
     
-    // Add parameters: expected_post_id (u64), burn_amount (u64)
+    // Add parameters: expected_post_id (u64), burn_amount (u64), min_mint_interval (i64), min_holding (u64)
     instruction_data.extend_from_slice(&expected_post_id.to_le_bytes());
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0i64.to_le_bytes()); // min_mint_interval (0 = no throttle)
+    instruction_data.extend_from_slice(&0u64.to_le_bytes()); // min_holding (0 = anyone can reply)
 
     let accounts = vec![
         AccountMeta::new(*creator, true),
@@ -102,8 +102,12 @@ struct BurnLeaderboard {
 struct LeaderboardEntry {
     pub project_id: u64,
     pub burned_amount: u64,
+    pub pinned: bool,
 }
 
+// Serialized entry size: project_id (8) + burned_amount (8)
+const LEADERBOARD_ENTRY_SIZE: usize = 16;
+
 // Parse BurnLeaderboard account data
 fn parse_burn_leaderboard_data(data: &[u8]) -> Result<BurnLeaderboard, Box<dyn std::error::Error>> {
     if data.len() < 12 { // 8 discriminator + 4 vec_length
@@ -117,7 +121,7 @@ fn parse_burn_leaderboard_data(data: &[u8]) -> Result<BurnLeaderboard, Box<dyn s
     offset += 4;
 
     // Verify remaining data length
-    let expected_data_length = offset + (vec_length as usize * 16);
+    let expected_data_length = offset + (vec_length as usize * LEADERBOARD_ENTRY_SIZE);
     if data.len() < expected_data_length {
         return Err(format!("Data too short: expected {} bytes, got {} bytes", 
                           expected_data_length, data.len()).into());
@@ -126,7 +130,7 @@ fn parse_burn_leaderboard_data(data: &[u8]) -> Result<BurnLeaderboard, Box<dyn s
     // Read entries
     let mut entries = Vec::new();
     for i in 0..vec_length {
-        let entry_offset = offset + (i as usize * 16);
+        let entry_offset = offset + (i as usize * LEADERBOARD_ENTRY_SIZE);
         
         let project_id = u64::from_le_bytes(
             data[entry_offset..entry_offset + 8].try_into().unwrap()
@@ -134,13 +138,29 @@ fn parse_burn_leaderboard_data(data: &[u8]) -> Result<BurnLeaderboard, Box<dyn s
         let burned_amount = u64::from_le_bytes(
             data[entry_offset + 8..entry_offset + 16].try_into().unwrap()
         );
-
         entries.push(LeaderboardEntry {
             project_id,
             burned_amount,
+            pinned: false,
         });
     }
 
+    // Pinned project ids follow capacity (u16); older accounts have zeros here (no pins)
+    let pinned_offset = expected_data_length + 2;
+    if data.len() >= pinned_offset + 4 {
+        let pinned_length = u32::from_le_bytes(data[pinned_offset..pinned_offset + 4].try_into().unwrap()) as usize;
+        for i in 0..pinned_length {
+            let id_offset = pinned_offset + 4 + i * 8;
+            if data.len() < id_offset + 8 {
+                break;
+            }
+            let pinned_id = u64::from_le_bytes(data[id_offset..id_offset + 8].try_into().unwrap());
+            for entry in entries.iter_mut().filter(|entry| entry.project_id == pinned_id) {
+                entry.pinned = true;
+            }
+        }
+    }
+
     Ok(BurnLeaderboard {
         entries,
     })
@@ -202,9 +222,10 @@ fn display_leaderboard_rankings(leaderboard: &BurnLeaderboard) {
             .position(|e| e.project_id == entry.project_id && e.burned_amount == entry.burned_amount)
             .unwrap_or(999);
 
-        println!("   {} Rank {:3}: Project {:5} - {:>10} MEMO ({:>15} units) [was Storage[{}]]", 
+        println!("   {} Rank {:3}: Project {:5} - {:>10} MEMO ({:>15} units) [was Storage[{}]]{}", 
                 medal, rank_display, entry.project_id, 
-                format_number(tokens), format_number(entry.burned_amount), original_index);
+                format_number(tokens), format_number(entry.burned_amount), original_index,
+                if entry.pinned { " 📌 pinned" } else { "" });
     }
     
     // Show position change summary
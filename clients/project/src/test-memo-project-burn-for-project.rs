@@ -11,6 +11,7 @@ use solana_sdk::{
     commitment_config::CommitmentConfig,
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
+use solana_system_interface::program as system_program;
 use std::str::FromStr;
 use sha2::{Sha256, Digest};
 use borsh::{BorshSerialize, BorshDeserialize};
@@ -587,7 +588,7 @@ fn analyze_unexpected_error(error_msg: &str) {
     println!("💡 Error analysis:");
     if error_msg.contains("MemoRequired") {
         println!("   Missing memo instruction");
-    } else if error_msg.contains("InvalidProjectBurnDataFormat") {
+    } else if error_msg.contains("ProjectBurnMemoInvalid") {
         println!("   Invalid memo format, Base64 decoding, or Borsh parsing failed");
     } else if error_msg.contains("UnsupportedMemoVersion") {
         println!("   Unsupported memo version");
@@ -629,6 +630,7 @@ fn burn_for_project_instruction(
     
     instruction_data.extend_from_slice(&project_id.to_le_bytes());
     instruction_data.extend_from_slice(&amount.to_le_bytes());
+    instruction_data.push(0); // receipt_nonce: None
 
     let accounts = vec![
         AccountMeta::new(*burner, true),
@@ -643,6 +645,7 @@ fn burn_for_project_instruction(
             solana_sdk::sysvar::instructions::id(),
             false
         ),
+        AccountMeta::new_readonly(system_program::id(), false),
     ];
 
     Instruction::new_with_bytes(*program_id, &instruction_data, accounts)
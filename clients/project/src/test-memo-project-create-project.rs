@@ -281,11 +281,33 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &memo_project_program_id,
     );
 
+    let (creator_cooldown_pda, _) = Pubkey::find_program_address(
+        &[b"creator_cooldown", user.pubkey().as_ref()],
+        &memo_project_program_id,
+    );
+
+    // One TagStats PDA per tag (counts tag usage across projects)
+    let tag_stats_pdas: Vec<Pubkey> = project_data.tags.iter()
+        .map(|tag| Pubkey::find_program_address(&[b"tag", tag.as_bytes()], &memo_project_program_id).0)
+        .collect();
+
+    // One (last page, next page) TagProjectList pair per tag (indexes the project by tag)
+    let tag_list_pdas: Vec<(Pubkey, Pubkey)> = project_data.tags.iter()
+        .map(|tag| find_tag_list_tail(&client, tag, &memo_project_program_id))
+        .collect();
+
     println!("PDAs:");
     println!("  Global counter: {}", global_counter_pda);
     println!("  Project: {}", project_pda);
+    println!("  Creator cooldown: {}", creator_cooldown_pda);
     println!("  Burn leaderboard: {}", burn_leaderboard_pda);
     println!("  User global burn stats: {}", user_global_burn_stats_pda);
+    for (tag, pda) in project_data.tags.iter().zip(&tag_stats_pdas) {
+        println!("  Tag stats ({}): {}", tag, pda);
+    }
+    for (tag, (tail, next)) in project_data.tags.iter().zip(&tag_list_pdas) {
+        println!("  Tag project list ({}): {} (next: {})", tag, tail, next);
+    }
     println!();
 
     // Get latest blockhash
@@ -307,10 +329,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &user.pubkey(),
         &global_counter_pda,
         &project_pda,
+        &creator_cooldown_pda,
         &burn_leaderboard_pda,
         &mint_address,
         &user_token_account,
         &user_global_burn_stats_pda,
+        &tag_stats_pdas,
+        &tag_list_pdas,
         next_project_id,
         burn_amount,
     );
@@ -408,7 +433,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("💡 The burn amount is too small. Minimum required: {} tokens", MIN_PROJECT_CREATION_BURN_TOKENS);
             } else if error_msg.contains("insufficient funds") || error_msg.contains("0x1") {
                 println!("💡 Insufficient token balance or SOL balance for transaction fees.");
-            } else if error_msg.contains("InvalidProjectDataFormat") {
+            } else if error_msg.contains("ProjectCreateMemoInvalid") {
                 println!("💡 Invalid project data format in memo. Check the project data structure.");
             } else if error_msg.contains("ProjectIdMismatch") {
                 println!("💡 Project ID mismatch. Expected: {}", next_project_id);
@@ -423,16 +448,44 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn tag_list_pda(tag: &str, page: u32, program_id: &Pubkey) -> Pubkey {
+    if page == 0 {
+        Pubkey::find_program_address(&[b"tag_list", tag.as_bytes()], program_id).0
+    } else {
+        Pubkey::find_program_address(&[b"tag_list", tag.as_bytes(), &page.to_le_bytes()], program_id).0
+    }
+}
+
+/// Follow a tag's TagProjectList chain from page 0 and return (last page, next page) PDAs
+fn find_tag_list_tail(client: &RpcClient, tag: &str, program_id: &Pubkey) -> (Pubkey, Pubkey) {
+    let mut page = 0u32;
+    loop {
+        let pda = tag_list_pda(tag, page, program_id);
+        // Layout: discriminator (8) + tag (4 + len) + page (4) + next (Option<Pubkey>)
+        let next_offset = 8 + 4 + tag.len() + 4;
+        let has_next = client.get_account(&pda)
+            .map(|account| account.data.get(next_offset) == Some(&1))
+            .unwrap_or(false);
+        if !has_next {
+            return (pda, tag_list_pda(tag, page + 1, program_id));
+        }
+        page += 1;
+    }
+}
+
 fn create_create_project_instruction(
     program_id: &Pubkey,
     memo_burn_program_id: &Pubkey,
     creator: &Pubkey,
     global_counter: &Pubkey,
     project: &Pubkey,
+    creator_cooldown: &Pubkey,
     burn_leaderboard: &Pubkey,
     mint: &Pubkey,
     creator_token_account: &Pubkey,
     user_global_burn_stats: &Pubkey,
+    tag_stats: &[Pubkey],
+    tag_lists: &[(Pubkey, Pubkey)],
     expected_project_id: u64,
     burn_amount: u64,
 ) -> Instruction {
@@ -442,14 +495,18 @@ fn create_create_project_instruction(
     let result = hasher.finalize();
     let mut instruction_data = result[..8].to_vec();
     
-    // Add parameters: expected_project_id (u64) + burn_amount (u64)
+    // Add parameters: expected_project_id (u64) + burn_amount (u64) + burn_cap (u64, 0 = unlimited)
+    // + idempotency_key (Option<[u8; 16]>, None)
     instruction_data.extend_from_slice(&expected_project_id.to_le_bytes());
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0u64.to_le_bytes());
+    instruction_data.push(0);
 
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*creator, true),
         AccountMeta::new(*global_counter, false),
         AccountMeta::new(*project, false),
+        AccountMeta::new(*creator_cooldown, false),
         AccountMeta::new(*burn_leaderboard, false),
         AccountMeta::new(*mint, false),
         AccountMeta::new(*creator_token_account, false),
@@ -463,5 +520,19 @@ fn create_create_project_instruction(
         ),
     ];
 
+    // Tag stats PDAs follow the optional economy_config / program_stats /
+    // rate_limit_exemption / idempotency_marker / parent_project accounts
+    // (passing the program id means "not provided")
+    if !tag_stats.is_empty() {
+        for _ in 0..5 {
+            accounts.push(AccountMeta::new_readonly(*program_id, false));
+        }
+        accounts.extend(tag_stats.iter().map(|pda| AccountMeta::new(*pda, false)));
+        for (tail, next) in tag_lists {
+            accounts.push(AccountMeta::new(*tail, false));
+            accounts.push(AccountMeta::new(*next, false));
+        }
+    }
+
     Instruction::new_with_bytes(*program_id, &instruction_data, accounts)
 }
@@ -77,6 +77,11 @@ pub struct Blog {
     pub memo_count: u64,
     pub burned_amount: u64,
     pub last_memo_time: i64,
+    pub min_mint_interval: i64,
+    pub content_flags: u8,
+    pub lang: String,
+    pub comment_count: u64,
+    pub name_lower: String,
     pub bump: u8,
 }
 
@@ -665,6 +670,7 @@ fn create_blog(
     let result = hasher.finalize();
     let mut instruction_data = result[..8].to_vec();
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0i64.to_le_bytes()); // min_mint_interval (0 = no throttle)
     
     let create_blog_instruction = Instruction::new_with_bytes(
         *blog_program_id,
@@ -74,6 +74,16 @@ pub struct ChatGroup {
     pub burned_amount: u64,
     pub min_memo_interval: i64,
     pub last_memo_time: i64,
+    pub message_burn: u64,
+    pub verified: bool,
+    pub archived: bool,
+    pub store_messages: bool,
+    pub reward_scaling_enabled: bool,
+    pub lang: String,
+    pub pinned_message: String,
+    pub name_lower: String,
+    pub require_fresh_messages: bool,
+    pub last_announcement: i64,
     pub bump: u8,
 }
 
@@ -350,6 +360,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut instruction_data = result[..8].to_vec();
     instruction_data.extend_from_slice(&next_group_id.to_le_bytes());
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0u64.to_le_bytes()); // message_burn (0 = free group)
+    instruction_data.push(0); // store_messages (false)
+    instruction_data.push(0); // reward_scaling_enabled (false)
 
     let create_group_ix = Instruction::new_with_bytes(
         chat_program_id,
@@ -497,6 +510,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut send_instruction_data = result[..8].to_vec();
     send_instruction_data.extend_from_slice(&next_group_id.to_le_bytes());
 
+    // Per-user daily mint quota (created on the sender's first message)
+    let (daily_mint_quota_pda, _) = Pubkey::find_program_address(
+        &[b"quota", payer.pubkey().as_ref()],
+        &chat_program_id,
+    );
+
     let send_memo_instruction = Instruction::new_with_bytes(
         chat_program_id,
         &send_instruction_data,
@@ -509,6 +528,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             AccountMeta::new_readonly(token_2022_id(), false),
             AccountMeta::new_readonly(mint_program_id, false),
             AccountMeta::new_readonly(solana_sdk::sysvar::instructions::id(), false),
+            AccountMeta::new(daily_mint_quota_pda, false),
+            AccountMeta::new_readonly(chat_program_id, false), // user_global_burn_stats (not provided)
+            AccountMeta::new_readonly(chat_program_id, false), // memo_burn_program (not provided)
+            AccountMeta::new_readonly(chat_program_id, false), // stored_message (not provided)
+            AccountMeta::new_readonly(system_program::ID, false),
         ],
     );
 
@@ -70,6 +70,11 @@ pub struct Post {
     pub reply_count: u64,
     pub burned_amount: u64,
     pub last_reply_time: i64,
+    pub min_mint_interval: i64,
+    pub author_commitment: [u8; 32],
+    pub content_flags: u8,
+    pub lang: String,
+    pub min_holding: u64,
     pub bump: u8,
 }
 
@@ -647,6 +652,8 @@ fn create_post(
     let mut instruction_data = result[..8].to_vec();
     instruction_data.extend_from_slice(&expected_post_id.to_le_bytes());
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0i64.to_le_bytes()); // min_mint_interval (0 = no throttle)
+    instruction_data.extend_from_slice(&0u64.to_le_bytes()); // min_holding (0 = anyone can reply)
     
     let create_post_instruction = Instruction::new_with_bytes(
         *forum_program_id,
@@ -737,6 +744,7 @@ fn burn_for_post(
     let mut instruction_data = result[..8].to_vec();
     instruction_data.extend_from_slice(&post_id.to_le_bytes());
     instruction_data.extend_from_slice(&amount.to_le_bytes());
+    instruction_data.push(0); // receipt_nonce: None
     
     let burn_for_post_instruction = Instruction::new_with_bytes(
         *forum_program_id,
@@ -750,6 +758,7 @@ fn burn_for_post(
             AccountMeta::new_readonly(token_2022_id(), false),
             AccountMeta::new_readonly(*burn_program_id, false),
             AccountMeta::new_readonly(solana_sdk::sysvar::instructions::id(), false),
+            AccountMeta::new_readonly(system_program::id(), false),
         ],
     );
     
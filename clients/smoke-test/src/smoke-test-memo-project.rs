@@ -503,11 +503,20 @@ fn create_project(
     let mut instruction_data = result[..8].to_vec();
     instruction_data.extend_from_slice(&project_id.to_le_bytes());
     instruction_data.extend_from_slice(&burn_amount.to_le_bytes());
+    instruction_data.extend_from_slice(&0u64.to_le_bytes()); // burn_cap (0 = unlimited)
+    instruction_data.push(0); // idempotency_key (None)
+    
+    // Per-creator cooldown PDA (created on first project)
+    let (creator_cooldown_pda, _) = Pubkey::find_program_address(
+        &[b"creator_cooldown", payer.pubkey().as_ref()],
+        project_program_id,
+    );
     
     // Account order must match CreateProject struct in lib.rs:
-    // 1. creator, 2. global_counter, 3. project, 4. burn_leaderboard,
-    // 5. mint, 6. creator_token_account, 7. user_global_burn_stats,
-    // 8. token_program, 9. memo_burn_program, 10. system_program, 11. instructions
+    // 1. creator, 2. global_counter, 3. project, 4. creator_cooldown,
+    // 5. burn_leaderboard, 6. mint, 7. creator_token_account,
+    // 8. user_global_burn_stats, 9. token_program, 10. memo_burn_program,
+    // 11. system_program, 12. instructions
     let create_project_instruction = Instruction::new_with_bytes(
         *project_program_id,
         &instruction_data,
@@ -515,14 +524,15 @@ fn create_project(
             AccountMeta::new(payer.pubkey(), true),              // 1. creator
             AccountMeta::new(*global_counter_pda, false),        // 2. global_counter
             AccountMeta::new(*project_pda, false),               // 3. project
-            AccountMeta::new(*burn_leaderboard_pda, false),      // 4. burn_leaderboard
-            AccountMeta::new(*mint, false),                      // 5. mint
-            AccountMeta::new(*token_account, false),             // 6. creator_token_account
-            AccountMeta::new(*user_global_burn_stats_pda, false),// 7. user_global_burn_stats
-            AccountMeta::new_readonly(token_2022_id(), false),   // 8. token_program
-            AccountMeta::new_readonly(*burn_program_id, false),  // 9. memo_burn_program
-            AccountMeta::new_readonly(system_program::id(), false), // 10. system_program
-            AccountMeta::new_readonly(solana_sdk::sysvar::instructions::id(), false), // 11. instructions
+            AccountMeta::new(creator_cooldown_pda, false),       // 4. creator_cooldown
+            AccountMeta::new(*burn_leaderboard_pda, false),      // 5. burn_leaderboard
+            AccountMeta::new(*mint, false),                      // 6. mint
+            AccountMeta::new(*token_account, false),             // 7. creator_token_account
+            AccountMeta::new(*user_global_burn_stats_pda, false),// 8. user_global_burn_stats
+            AccountMeta::new_readonly(token_2022_id(), false),   // 9. token_program
+            AccountMeta::new_readonly(*burn_program_id, false),  // 10. memo_burn_program
+            AccountMeta::new_readonly(system_program::id(), false), // 11. system_program
+            AccountMeta::new_readonly(solana_sdk::sysvar::instructions::id(), false), // 12. instructions
         ],
     );
     
@@ -81,7 +81,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         ],
                         &program_id,
                     );
-                    initialize_burn_history(&client, &payer, &program_id, user_profile_pda, burn_history_pda)?;
+                    initialize_burn_history(&client, &payer, &program_id, user_profile_pda, burn_history_pda, None)?;
                 },
                 Some(current_index) => {
                     // get current burn history PDA
@@ -122,7 +122,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                     ],
                                     &program_id,
                                 );
-                                initialize_burn_history(&client, &payer, &program_id, user_profile_pda, new_burn_history_pda)?;
+                                initialize_burn_history(&client, &payer, &program_id, user_profile_pda, new_burn_history_pda, Some(current_burn_history_pda))?;
                             } else {
                                 // if not full, no need to create a new one
                                 println!("Current burn history is not full ({}/{} signatures). No need to create a new one.",
@@ -142,7 +142,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 ],
                                 &program_id,
                             );
-                            initialize_burn_history(&client, &payer, &program_id, user_profile_pda, burn_history_pda)?;
+                            initialize_burn_history(&client, &payer, &program_id, user_profile_pda, burn_history_pda, None)?;
                         }
                     }
                 }
@@ -165,21 +165,29 @@ fn initialize_burn_history(
     program_id: &Pubkey,
     user_profile_pda: Pubkey,
     burn_history_pda: Pubkey,
+    previous_burn_history_pda: Option<Pubkey>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("Initializing burn history at: {}", burn_history_pda);
     
     // construct instruction data: only discriminator
     let instruction_data = INIT_BURN_HISTORY_DISCRIMINATOR.to_vec();
 
+    let mut accounts = vec![
+        AccountMeta::new(payer.pubkey(), true),         // user (signer, writable)
+        AccountMeta::new(user_profile_pda, false),      // user_profile (NOT writable)
+        AccountMeta::new(burn_history_pda, false),      // burn_history (NOT writable)
+        AccountMeta::new_readonly(system_program::id(), false), // system_program
+    ];
+
+    // link the full burn history to the new one
+    if let Some(previous_burn_history_pda) = previous_burn_history_pda {
+        accounts.push(AccountMeta::new(previous_burn_history_pda, false)); // previous_burn_history (writable)
+    }
+
     // create instruction
     let ix = Instruction {
         program_id: *program_id,
-        accounts: vec![
-            AccountMeta::new(payer.pubkey(), true),         // user (signer, writable)
-            AccountMeta::new(user_profile_pda, false),      // user_profile (NOT writable)
-            AccountMeta::new(burn_history_pda, false),      // burn_history (NOT writable)
-            AccountMeta::new_readonly(system_program::id(), false), // system_program
-        ],
+        accounts,
         data: instruction_data,
     };
 
@@ -13,6 +13,14 @@ pub const ADMIN_PUBKEY: &str = "Gkxz6ogojD7Ni58N4SnJXy6xDxSvH5kPFCz92sTZWBVn"; /
 // authorized mint
 pub const AUTHORIZED_MINT: &str = "MEM69mjnKAMxgqwosg5apfYNk2rMuV26FR9THDfT3Q7";
 
+// max signatures per burn history account
+pub const MAX_BURN_HISTORY_SIGNATURES: usize = 100;
+
+// burn streak bonus: +1 token per full 7-day streak, capped
+pub const SECONDS_PER_DAY: i64 = 86_400;
+pub const STREAK_BONUS_DAYS: u32 = 7;
+pub const MAX_STREAK_BONUS_TOKENS: u64 = 4;
+
 // individual burn record
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct BurnRecord {
@@ -102,6 +110,90 @@ pub struct UserProfile {
     pub created_at: i64,          // 8 bytes - create timestamp
     pub last_updated: i64,        // 8 bytes - last updated timestamp
     pub burn_history_index: Option<u64>, // 9 bytes (1 byte for Option + 8 bytes for u64)
+    pub current_streak: u32,      // 4 bytes - consecutive UTC days with a burn
+    pub last_burn_day: i64,       // 8 bytes - UTC day (unix_timestamp / 86400) of the last burn
+    pub reputation: u64,          // 8 bytes - reputation_score(total_minted, total_burned)
+}
+
+impl UserProfile {
+    // size before current_streak and last_burn_day were added
+    pub const LEGACY_SPACE: usize = 8 +    // discriminator
+        32 +    // pubkey
+        8 +     // total_minted
+        8 +     // total_burned
+        8 +     // mint_count
+        8 +     // burn_count
+        8 +     // created_at
+        8 +     // last_updated
+        9;      // burn_history_index (Option<u64>)
+
+    // size before reputation was added
+    pub const STREAK_SPACE: usize = Self::LEGACY_SPACE +
+        4 +     // current_streak
+        8;      // last_burn_day
+
+    pub const SPACE: usize = Self::STREAK_SPACE +
+        8;      // reputation
+
+    // whether a user_profile account needs migrate_user_profile: true for a profile of
+    // an older size, false if it is already current, error for anything else
+    pub fn needs_migration(data: &[u8]) -> Result<bool> {
+        if !data.starts_with(UserProfile::DISCRIMINATOR) {
+            return Err(ErrorCode::InvalidUserProfile.into());
+        }
+        match data.len() {
+            Self::SPACE => Ok(false),
+            Self::LEGACY_SPACE | Self::STREAK_SPACE => Ok(true),
+            _ => Err(ErrorCode::InvalidUserProfile.into()),
+        }
+    }
+
+    // recompute reputation from the current mint/burn totals
+    pub fn update_reputation(&mut self) {
+        self.reputation = reputation_score(self.total_minted, self.total_burned);
+    }
+
+    // update the burn streak for a burn at `timestamp`
+    // same day: unchanged, next day: +1, otherwise: restart at 1
+    pub fn record_burn_day(&mut self, timestamp: i64) {
+        let day = timestamp.div_euclid(SECONDS_PER_DAY);
+        if self.current_streak > 0 && day == self.last_burn_day {
+            return;
+        }
+        if self.current_streak > 0 && day == self.last_burn_day.saturating_add(1) {
+            self.current_streak = self.current_streak.saturating_add(1);
+        } else {
+            self.current_streak = 1;
+        }
+        self.last_burn_day = day;
+    }
+
+    // streak still counts if the last burn was today or yesterday
+    pub fn active_streak(&self, timestamp: i64) -> u32 {
+        let day = timestamp.div_euclid(SECONDS_PER_DAY);
+        if day.saturating_sub(self.last_burn_day) <= 1 {
+            self.current_streak
+        } else {
+            0
+        }
+    }
+}
+
+// reputation favors net contributors: burns count double, mints count against it, floored at 0
+pub fn reputation_score(total_minted: u64, total_burned: u64) -> u64 {
+    total_burned.saturating_mul(2).saturating_sub(total_minted)
+}
+
+// bonus tokens for a streak: +1 per full STREAK_BONUS_DAYS, capped at MAX_STREAK_BONUS_TOKENS
+pub fn streak_bonus_tokens(streak: u32) -> u64 {
+    ((streak / STREAK_BONUS_DAYS) as u64).min(MAX_STREAK_BONUS_TOKENS)
+}
+
+// streak bonus config (admin controlled)
+#[account]
+#[derive(Default)]
+pub struct StreakConfig {
+    pub streak_bonus_enabled: bool, // 1 byte - mint streak bonus in process_transfer
 }
 
 #[account]
@@ -110,6 +202,25 @@ pub struct UserBurnHistory {
     pub owner: Pubkey,           // 32 bytes - user pubkey
     pub index: u64,              // 8 bytes - history index
     pub signatures: Vec<String>, // 4 + (92 * 100) bytes - max 100 signatures
+    pub next_index: Option<u64>, // 9 bytes - newer history account, if any
+    pub prev_index: Option<u64>, // 9 bytes - older history account, if any
+}
+
+impl UserBurnHistory {
+    // append a burn signature, client must roll over to a new account when full
+    pub fn push_signature(&mut self, signature: String) -> Result<()> {
+        if self.signatures.len() >= MAX_BURN_HISTORY_SIGNATURES {
+            return Err(ErrorCode::BurnHistoryFull.into());
+        }
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    // link this account to the newer history account that follows it
+    pub fn link_next(&mut self, next: &mut UserBurnHistory) {
+        self.next_index = Some(next.index);
+        next.prev_index = Some(self.index);
+    }
 }
 
 
@@ -147,11 +258,51 @@ pub mod memo_token {
         user_profile.created_at = clock.unix_timestamp;
         user_profile.last_updated = clock.unix_timestamp;
         user_profile.burn_history_index = None;
+        user_profile.current_streak = 0;
+        user_profile.last_burn_day = 0;
+        user_profile.reputation = 0;
         
         msg!("User profile initialized for user: {}", ctx.accounts.user.key());
         Ok(())
     }
 
+    // grow a user profile created before the streak or reputation fields to UserProfile::SPACE;
+    // the streak starts zeroed and reputation is computed from the totals. The user pays the extra rent
+    pub fn migrate_user_profile(ctx: Context<MigrateUserProfile>) -> Result<()> {
+        let user_profile = ctx.accounts.user_profile.to_account_info();
+        if user_profile.owner != ctx.program_id {
+            return Err(ErrorCode::InvalidUserProfile.into());
+        }
+        if !UserProfile::needs_migration(&user_profile.try_borrow_data()?)? {
+            msg!("User profile for {} is already migrated", ctx.accounts.user.key());
+            return Ok(());
+        }
+
+        let required = Rent::get()?.minimum_balance(UserProfile::SPACE);
+        let top_up = required.saturating_sub(user_profile.lamports());
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.user.to_account_info(),
+                        to: user_profile.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        user_profile.resize(UserProfile::SPACE)?;
+
+        let mut data = user_profile.try_borrow_mut_data()?;
+        let mut profile = UserProfile::try_deserialize(&mut &data[..])?;
+        profile.update_reputation();
+        profile.try_serialize(&mut &mut data[..])?;
+
+        msg!("User profile migrated for user: {}", ctx.accounts.user.key());
+        Ok(())
+    }
+
     pub fn process_transfer(ctx: Context<ProcessTransfer>) -> Result<()> {
         // check user profile authority
         if let Some(user_profile) = &ctx.accounts.user_profile {
@@ -174,8 +325,22 @@ pub mod memo_token {
             return Err(ErrorCode::MemoTooLong.into());
         }
         
-        // Fixed token count - always mint 1 token regardless of memo length
-        let token_count = 1u64;
+        // get current clock information
+        let clock = Clock::get()?;
+        
+        // Base mint is 1 token regardless of memo length, plus the burn streak bonus if enabled
+        let bonus_enabled = ctx.accounts.streak_config
+            .as_ref()
+            .map(|config| config.streak_bonus_enabled)
+            .unwrap_or(false);
+        let bonus_tokens = match &ctx.accounts.user_profile {
+            Some(user_profile) if bonus_enabled => streak_bonus_tokens(user_profile.active_streak(clock.unix_timestamp)),
+            _ => 0,
+        };
+        let token_count = 1u64 + bonus_tokens;
+        if bonus_tokens > 0 {
+            msg!("Burn streak bonus: {} tokens", bonus_tokens);
+        }
         
         // get PDA and bump
         let (mint_authority, bump) = Pubkey::find_program_address(
@@ -188,9 +353,6 @@ pub mod memo_token {
             return Err(ProgramError::InvalidSeeds.into());
         }
         
-        // get current clock information
-        let clock = Clock::get()?;
-        
         // calculate mint amount (1 token = 10^9 units)
         let amount = token_count * 1_000_000_000;
         
@@ -235,6 +397,14 @@ pub mod memo_token {
             // Update last_updated timestamp
             user_profile.last_updated = clock.unix_timestamp;
             
+            user_profile.update_reputation();
+            emit!(ReputationUpdatedEvent {
+                user: ctx.accounts.user.key(),
+                total_minted: user_profile.total_minted,
+                total_burned: user_profile.total_burned,
+                reputation: user_profile.reputation,
+            });
+            
             msg!("Updated user profile stats for mint operation");
         }
         
@@ -350,9 +520,20 @@ pub mod memo_token {
                 user_profile.burn_count = u64::MAX;
             }
             
+            // Update burn streak
+            user_profile.record_burn_day(clock.unix_timestamp);
+            
             // Update last_updated timestamp
             user_profile.last_updated = clock.unix_timestamp;
             
+            user_profile.update_reputation();
+            emit!(ReputationUpdatedEvent {
+                user: ctx.accounts.user.key(),
+                total_minted: user_profile.total_minted,
+                total_burned: user_profile.total_burned,
+                reputation: user_profile.reputation,
+            });
+            
             msg!("Updated user profile stats for burn operation");
         }
         
@@ -418,6 +599,22 @@ pub mod memo_token {
         Ok(())
     }
 
+    // initialize streak config
+    pub fn initialize_streak_config(ctx: Context<InitializeStreakConfig>, streak_bonus_enabled: bool) -> Result<()> {
+        ctx.accounts.streak_config.streak_bonus_enabled = streak_bonus_enabled;
+        
+        msg!("Streak config initialized, bonus enabled: {}", streak_bonus_enabled);
+        Ok(())
+    }
+
+    // enable or disable the streak bonus
+    pub fn set_streak_bonus_enabled(ctx: Context<SetStreakBonusEnabled>, streak_bonus_enabled: bool) -> Result<()> {
+        ctx.accounts.streak_config.streak_bonus_enabled = streak_bonus_enabled;
+        
+        msg!("Streak bonus enabled: {}", streak_bonus_enabled);
+        Ok(())
+    }
+
     // Close latest burn shard account
     pub fn close_latest_burn_shard(ctx: Context<CloseLatestBurnShard>) -> Result<()> {
         // check if caller is admin
@@ -563,6 +760,17 @@ pub mod memo_token {
         ctx.accounts.burn_history.owner = ctx.accounts.user.key();
         ctx.accounts.burn_history.index = new_index;
         ctx.accounts.burn_history.signatures = Vec::new();
+        ctx.accounts.burn_history.next_index = None;
+        ctx.accounts.burn_history.prev_index = None;
+        
+        // link to the previous (latest) burn history so clients can walk all burns in order
+        if let Some(previous) = ctx.accounts.previous_burn_history.as_mut() {
+            if ctx.accounts.user_profile.burn_history_index != Some(previous.index) {
+                return Err(ErrorCode::InvalidBurnHistoryIndex.into());
+            }
+            previous.link_next(&mut ctx.accounts.burn_history);
+            msg!("Linked burn history {} -> {}", previous.index, new_index);
+        }
         
         // update user profile
         ctx.accounts.user_profile.burn_history_index = Some(new_index);
@@ -590,6 +798,14 @@ pub mod memo_token {
                 return Err(ErrorCode::InvalidBurnHistoryIndex.into());
             }
 
+            // unlink the previous burn history, it becomes the latest again
+            if let Some(previous) = ctx.accounts.previous_burn_history.as_mut() {
+                if current_index == 0 || previous.index != current_index - 1 {
+                    return Err(ErrorCode::InvalidBurnHistoryIndex.into());
+                }
+                previous.next_index = None;
+            }
+
             // if current index is 0, set burn_history_index to None
             if current_index == 0 {
                 ctx.accounts.user_profile.burn_history_index = None;
@@ -705,9 +921,20 @@ pub mod memo_token {
                 user_profile.burn_count = u64::MAX;
             }
             
+            // Update burn streak
+            user_profile.record_burn_day(clock.unix_timestamp);
+            
             // Update last_updated timestamp
             user_profile.last_updated = clock.unix_timestamp;
             
+            user_profile.update_reputation();
+            emit!(ReputationUpdatedEvent {
+                user: ctx.accounts.user.key(),
+                total_minted: user_profile.total_minted,
+                total_burned: user_profile.total_burned,
+                reputation: user_profile.reputation,
+            });
+            
             msg!("Updated user profile stats for burn operation");
         }
         
@@ -778,14 +1005,9 @@ pub mod memo_token {
             return Err(ErrorCode::UnauthorizedUser.into());
         }
 
-        // check if burn history account is full
-        if burn_history.signatures.len() >= 100 {
-            // if full, return error, client needs to create new burn history account
-            return Err(ErrorCode::BurnHistoryFull.into());
-        }
-
         // add signature to history
-        burn_history.signatures.push(signature);
+        // if full, return error, client needs to create new burn history account
+        burn_history.push_signature(signature)?;
         msg!("Added burn signature to history index: {}", burn_history.index);
 
         Ok(())
@@ -936,6 +1158,13 @@ pub struct ProcessTransfer<'info> {
         bump,
     )]
     pub user_profile: Option<Account<'info, UserProfile>>,
+    
+    // streak config (optional, no bonus when omitted)
+    #[account(
+        seeds = [b"streak_config"],
+        bump,
+    )]
+    pub streak_config: Option<Account<'info, StreakConfig>>,
 }
 
 // add account structure for burning instruction
@@ -1040,6 +1269,37 @@ pub struct ProcessBurnWithHistory<'info> {
     pub burn_history: Account<'info, UserBurnHistory>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeStreakConfig<'info> {
+    #[account(mut, constraint = payer.key().to_string() == ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + // discriminator
+               1,  // streak_bonus_enabled
+        seeds = [b"streak_config"],
+        bump
+    )]
+    pub streak_config: Account<'info, StreakConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetStreakBonusEnabled<'info> {
+    #[account(constraint = admin.key().to_string() == ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"streak_config"],
+        bump
+    )]
+    pub streak_config: Account<'info, StreakConfig>,
+}
+
 #[derive(Accounts)]
 pub struct InitializeLatestBurnShard<'info> {
     #[account(mut, constraint = payer.key().to_string() == ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin)]
@@ -1140,15 +1400,7 @@ pub struct InitializeUserProfile<'info> {
     #[account(
         init,
         payer = user,
-        space = 8 +    // discriminator
-               32 +    // pubkey
-               8 +     // total_minted
-               8 +     // total_burned
-               8 +     // mint_count
-               8 +     // burn_count
-               8 +     // created_at
-               8 +     // last_updated
-               9,      // burn_history_index (Option<u64>)
+        space = UserProfile::SPACE,
         seeds = [b"user_profile", user.key().as_ref()],
         bump
     )]
@@ -1157,6 +1409,23 @@ pub struct InitializeUserProfile<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateUserProfile<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    /// CHECK: legacy-sized profiles cannot be deserialized as UserProfile; owner,
+    /// discriminator and size are checked in migrate_user_profile
+    #[account(
+        mut,
+        seeds = [b"user_profile", user.key().as_ref()],
+        bump
+    )]
+    pub user_profile: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct CloseUserProfile<'info> {
     #[account(mut)]
@@ -1192,7 +1461,9 @@ pub struct InitializeUserBurnHistory<'info> {
         space = 8 +    // discriminator
                32 +    // owner
                8 +     // index
-               4 + (92 * 100), // Vec<String> for signatures (100 signatures max)
+               4 + (92 * 100) + // Vec<String> for signatures (100 signatures max)
+               9 +     // next_index (Option<u64>)
+               9,      // prev_index (Option<u64>)
         seeds = [
             b"burn_history",
             user.key().as_ref(),
@@ -1203,6 +1474,19 @@ pub struct InitializeUserBurnHistory<'info> {
     pub burn_history: Account<'info, UserBurnHistory>,
     
     pub system_program: Program<'info, System>,
+    
+    // current latest burn history (optional), linked to the new one
+    #[account(
+        mut,
+        seeds = [
+            b"burn_history",
+            user.key().as_ref(),
+            &user_profile.burn_history_index.unwrap_or(0).to_le_bytes()
+        ],
+        bump,
+        constraint = previous_burn_history.owner == user.key() @ ErrorCode::UnauthorizedUser
+    )]
+    pub previous_burn_history: Option<Account<'info, UserBurnHistory>>,
 }
 
 #[derive(Accounts)]
@@ -1232,6 +1516,28 @@ pub struct CloseUserBurnHistory<'info> {
     pub burn_history: Account<'info, UserBurnHistory>,
     
     pub system_program: Program<'info, System>,
+    
+    // burn history before the closed one (optional), its next_index is cleared
+    #[account(
+        mut,
+        seeds = [
+            b"burn_history",
+            user.key().as_ref(),
+            &user_profile.burn_history_index.unwrap_or(0).saturating_sub(1).to_le_bytes()
+        ],
+        bump,
+        constraint = previous_burn_history.owner == user.key() @ ErrorCode::UnauthorizedUser
+    )]
+    pub previous_burn_history: Option<Account<'info, UserBurnHistory>>,
+}
+
+// emitted whenever a mint or burn changes a user's reputation inputs
+#[event]
+pub struct ReputationUpdatedEvent {
+    pub user: Pubkey,
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub reputation: u64,
 }
 
 #[error_code]
@@ -1286,4 +1592,10 @@ pub enum ErrorCode {
 
     #[msg("Invalid signature format. Must be a valid base58 string.")]
     InvalidSignatureFormat,
-}
\ No newline at end of file
+
+    #[msg("Invalid user profile account: not a current or legacy-sized user profile.")]
+    InvalidUserProfile,
+}
+
+#[cfg(test)]
+mod tests;
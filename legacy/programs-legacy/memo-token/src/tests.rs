@@ -0,0 +1,243 @@
+// This crate is not a workspace member, so the workspace `cargo test` does not run
+// these tests. Run them from a standalone copy of the crate (e.g. with an empty
+// `[workspace]` table added to its Cargo.toml).
+
+use super::*;
+
+fn create_user_profile() -> UserProfile {
+    UserProfile {
+        pubkey: Pubkey::new_unique(),
+        ..Default::default()
+    }
+}
+
+// timestamp at `hour` o'clock UTC on `day` days after the epoch
+fn at(day: i64, hour: i64) -> i64 {
+    day * SECONDS_PER_DAY + hour * 3600
+}
+
+#[test]
+fn test_streak_starts_at_first_burn() {
+    let mut profile = create_user_profile();
+    profile.record_burn_day(at(20_000, 12));
+
+    assert_eq!(profile.current_streak, 1);
+    assert_eq!(profile.last_burn_day, 20_000);
+}
+
+#[test]
+fn test_streak_same_day_unchanged() {
+    let mut profile = create_user_profile();
+    profile.record_burn_day(at(20_000, 0));
+    profile.record_burn_day(at(20_000, 23));
+
+    assert_eq!(profile.current_streak, 1);
+}
+
+#[test]
+fn test_streak_increments_across_day_boundary() {
+    let mut profile = create_user_profile();
+
+    // 23:59:59 and 00:00:00 the next UTC day are consecutive days
+    profile.record_burn_day(at(20_001, 0) - 1);
+    profile.record_burn_day(at(20_001, 0));
+    assert_eq!(profile.current_streak, 2);
+
+    profile.record_burn_day(at(20_002, 18));
+    assert_eq!(profile.current_streak, 3);
+    assert_eq!(profile.last_burn_day, 20_002);
+}
+
+#[test]
+fn test_streak_resets_after_gap() {
+    let mut profile = create_user_profile();
+    for day in 20_000..20_010 {
+        profile.record_burn_day(at(day, 8));
+    }
+    assert_eq!(profile.current_streak, 10);
+
+    // skipping a day restarts the streak
+    profile.record_burn_day(at(20_011, 8));
+    assert_eq!(profile.current_streak, 1);
+    assert_eq!(profile.last_burn_day, 20_011);
+}
+
+#[test]
+fn test_active_streak_expires_after_missed_day() {
+    let mut profile = create_user_profile();
+    for day in 20_000..20_007 {
+        profile.record_burn_day(at(day, 8));
+    }
+
+    assert_eq!(profile.active_streak(at(20_006, 20)), 7);
+    assert_eq!(profile.active_streak(at(20_007, 20)), 7);
+    assert_eq!(profile.active_streak(at(20_008, 0)), 0);
+}
+
+#[test]
+fn test_streak_bonus_computation() {
+    assert_eq!(streak_bonus_tokens(0), 0);
+    assert_eq!(streak_bonus_tokens(6), 0);
+    assert_eq!(streak_bonus_tokens(7), 1);
+    assert_eq!(streak_bonus_tokens(13), 1);
+    assert_eq!(streak_bonus_tokens(14), 2);
+
+    // capped
+    assert_eq!(streak_bonus_tokens(7 * MAX_STREAK_BONUS_TOKENS as u32), MAX_STREAK_BONUS_TOKENS);
+    assert_eq!(streak_bonus_tokens(u32::MAX), MAX_STREAK_BONUS_TOKENS);
+}
+
+fn create_burn_history(owner: Pubkey, index: u64) -> UserBurnHistory {
+    UserBurnHistory {
+        owner,
+        index,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_burn_history_full() {
+    let mut history = create_burn_history(Pubkey::new_unique(), 0);
+    for i in 0..MAX_BURN_HISTORY_SIGNATURES {
+        history.push_signature(format!("sig{}", i)).unwrap();
+    }
+
+    let err = history.push_signature("overflow".to_string()).unwrap_err();
+    assert_eq!(err, ErrorCode::BurnHistoryFull.into());
+    assert_eq!(history.signatures.len(), MAX_BURN_HISTORY_SIGNATURES);
+}
+
+#[test]
+fn test_burn_history_rollover_links_accounts() {
+    let owner = Pubkey::new_unique();
+    let mut histories = vec![create_burn_history(owner, 0)];
+
+    // 150 burns, rolling over to a new linked account when the latest is full
+    for i in 0..150 {
+        let signature = format!("sig{}", i);
+        let latest = histories.last_mut().unwrap();
+        if latest.push_signature(signature.clone()).is_err() {
+            let mut next = create_burn_history(owner, latest.index + 1);
+            latest.link_next(&mut next);
+            next.push_signature(signature).unwrap();
+            histories.push(next);
+        }
+    }
+
+    assert_eq!(histories.len(), 2);
+    assert_eq!(histories[0].signatures.len(), 100);
+    assert_eq!(histories[1].signatures.len(), 50);
+
+    assert_eq!(histories[0].prev_index, None);
+    assert_eq!(histories[0].next_index, Some(1));
+    assert_eq!(histories[1].prev_index, Some(0));
+    assert_eq!(histories[1].next_index, None);
+
+    // walking the list from the first account yields every burn in order
+    let mut walked = Vec::new();
+    let mut cursor = Some(0u64);
+    while let Some(index) = cursor {
+        let history = &histories[index as usize];
+        walked.extend(history.signatures.iter().cloned());
+        cursor = history.next_index;
+    }
+    let expected: Vec<String> = (0..150).map(|i| format!("sig{}", i)).collect();
+    assert_eq!(walked, expected);
+}
+
+fn profile_data(profile: &UserProfile) -> Vec<u8> {
+    let mut data = Vec::new();
+    profile.try_serialize(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn test_user_profile_space_matches_layout() {
+    let profile = UserProfile { burn_history_index: Some(1), ..create_user_profile() };
+    assert_eq!(profile_data(&profile).len(), UserProfile::SPACE);
+    assert_eq!(UserProfile::STREAK_SPACE - UserProfile::LEGACY_SPACE, 12);
+    assert_eq!(UserProfile::SPACE - UserProfile::STREAK_SPACE, 8);
+}
+
+#[test]
+fn test_legacy_profile_needs_migration() {
+    let profile = UserProfile {
+        total_burned: 42,
+        burn_history_index: Some(3),
+        ..create_user_profile()
+    };
+    let mut data = profile_data(&profile);
+    assert!(!UserProfile::needs_migration(&data).unwrap());
+
+    // a legacy profile is the same bytes without the streak fields
+    data.truncate(UserProfile::LEGACY_SPACE);
+    assert!(UserProfile::needs_migration(&data).unwrap());
+    assert!(UserProfile::try_deserialize(&mut &data[..]).is_err());
+
+    // after the zero-filled realloc it decodes with no streak
+    data.resize(UserProfile::SPACE, 0);
+    let migrated = UserProfile::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!(migrated.total_burned, 42);
+    assert_eq!(migrated.burn_history_index, Some(3));
+    assert_eq!(migrated.current_streak, 0);
+    assert_eq!(migrated.last_burn_day, 0);
+}
+
+#[test]
+fn test_migration_rejects_other_accounts() {
+    let mut data = profile_data(&create_user_profile());
+    data.truncate(UserProfile::LEGACY_SPACE - 1);
+    assert!(UserProfile::needs_migration(&data).is_err());
+
+    let mut data = profile_data(&create_user_profile());
+    data[0] ^= 0xff;
+    assert!(UserProfile::needs_migration(&data).is_err());
+}
+
+#[test]
+fn test_reputation_formula() {
+    assert_eq!(reputation_score(0, 0), 0);
+    assert_eq!(reputation_score(0, 5), 10);
+    assert_eq!(reputation_score(3, 5), 7);
+    assert_eq!(reputation_score(10, 5), 0);
+    // clamped at zero rather than wrapping
+    assert_eq!(reputation_score(u64::MAX, 1), 0);
+    assert_eq!(reputation_score(0, u64::MAX), u64::MAX);
+}
+
+#[test]
+fn test_reputation_across_mints_and_burns() {
+    let mut profile = create_user_profile();
+    let mut step = |minted: u64, burned: u64| {
+        profile.total_minted += minted;
+        profile.total_burned += burned;
+        profile.update_reputation();
+        profile.reputation
+    };
+
+    assert_eq!(step(4, 0), 0);  // only mints: clamped at 0
+    assert_eq!(step(0, 1), 0);  // 2 - 4
+    assert_eq!(step(0, 2), 2);  // 6 - 4
+    assert_eq!(step(1, 0), 1);  // 6 - 5
+    assert_eq!(step(0, 10), 21); // 26 - 5
+    assert_eq!(step(30, 0), 0); // 26 - 35
+}
+
+#[test]
+fn test_migration_from_streak_sized_profile() {
+    let profile = UserProfile {
+        total_minted: 3,
+        total_burned: 5,
+        current_streak: 2,
+        ..create_user_profile()
+    };
+    let mut data = profile_data(&profile);
+    data.truncate(UserProfile::STREAK_SPACE);
+    assert!(UserProfile::needs_migration(&data).unwrap());
+
+    data.resize(UserProfile::SPACE, 0);
+    let mut migrated = UserProfile::try_deserialize(&mut &data[..]).unwrap();
+    assert_eq!(migrated.current_streak, 2);
+    migrated.update_reputation();
+    assert_eq!(migrated.reputation, 7);
+}
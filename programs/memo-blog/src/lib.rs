@@ -12,6 +12,7 @@ use memo_burn::cpi::accounts::ProcessBurn;
 use memo_mint::program::MemoMint;
 use memo_mint::cpi::accounts::ProcessMint;
 use anchor_lang::solana_program::sysvar::instructions::{ID as INSTRUCTIONS_ID};
+use solana_sha256_hasher::hash;
 use spl_memo::ID as MEMO_PROGRAM_ID;
 use base64::{Engine as _, engine::general_purpose};
 use std::str::FromStr;
@@ -31,10 +32,18 @@ pub const AUTHORIZED_MINT_PUBKEY: Pubkey = pubkey!("memoX1sJsBY6od7CfQ58XooRALwn
 #[cfg(not(feature = "mainnet"))]
 pub const AUTHORIZED_MINT_PUBKEY: Pubkey = pubkey!("HLCoc7wNDavNMfWWw2Bwd7U7A24cesuhBSNkxZgvZm1");
 
+// Authorized admin key - different for testnet and mainnet
+#[cfg(feature = "mainnet")]
+pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("FVvewrVHqg2TPWXkesc3CJ7xxWnPtAkzN9nCpvr6UCtQ");
+
+#[cfg(not(feature = "mainnet"))]
+pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("Gkxz6ogojD7Ni58N4SnJXy6xDxSvH5kPFCz92sTZWBVn");
+
 // ===== BUSINESS LOGIC CONSTANTS =====
 
 // Token economics
 pub const DECIMAL_FACTOR: u64 = 1_000_000; // Token decimals (6)
+pub const MINT_DECIMALS: u8 = 6; // Decimals the authorized mint must have (DECIMAL_FACTOR = 10^MINT_DECIMALS)
 
 // Blog creation/update/burn constants - all require at least 1 MEMO token
 pub const MIN_BLOG_BURN_TOKENS: u64 = 1; // Minimum tokens to burn for any blog operation
@@ -43,6 +52,9 @@ pub const MIN_BLOG_BURN_AMOUNT: u64 = MIN_BLOG_BURN_TOKENS * DECIMAL_FACTOR;
 // Maximum burn per transaction (consistent with memo-burn)
 pub const MAX_BURN_PER_TX: u64 = 1_000_000_000_000 * DECIMAL_FACTOR; // 1 trillion tokens
 
+// Maximum per-user mint cooldown a blog can set (0 = no throttle)
+pub const MAX_MINT_INTERVAL_SECONDS: i64 = 3600; // 1 hour
+
 // ===== STRING LENGTH CONSTRAINTS =====
 
 // Blog metadata limits (no website, no tags - simpler than project)
@@ -69,8 +81,8 @@ pub const MAX_BORSH_DATA_SIZE: usize = MEMO_MAX_LENGTH;
 // Current version of BurnMemo structure (consistent with memo-burn)
 pub const BURN_MEMO_VERSION: u8 = 1;
 
-// Current version of BlogCreationData structure
-pub const BLOG_CREATION_DATA_VERSION: u8 = 1;
+// Current version of BlogCreationData structure (v2 adds content_flags, v3 adds lang; v1 is still accepted)
+pub const BLOG_CREATION_DATA_VERSION: u8 = 3;
 
 // Current version of BlogUpdateData structure  
 pub const BLOG_UPDATE_DATA_VERSION: u8 = 1;
@@ -81,6 +93,18 @@ pub const BLOG_BURN_DATA_VERSION: u8 = 1;
 // Current version of BlogMintData structure
 pub const BLOG_MINT_DATA_VERSION: u8 = 1;
 
+// Current version of BlogCommentData structure
+pub const BLOG_COMMENT_DATA_VERSION: u8 = 1;
+
+// Content flags (bitfield, opt-in): front ends can blur or hide flagged content
+pub const CONTENT_FLAG_NSFW: u8 = 1 << 0;
+pub const CONTENT_FLAG_SPOILER: u8 = 1 << 1;
+pub const CONTENT_FLAG_SENSITIVE: u8 = 1 << 2;
+pub const CONTENT_FLAGS_MASK: u8 = CONTENT_FLAG_NSFW | CONTENT_FLAG_SPOILER | CONTENT_FLAG_SENSITIVE;
+
+// Language tag (BCP-47 style, e.g. "en", "zh-CN"), optional
+pub const MAX_LANG_LENGTH: usize = 8;
+
 // Expected category for memo-blog contract
 pub const EXPECTED_CATEGORY: &str = "blog";
 
@@ -99,6 +123,12 @@ pub const EXPECTED_BURN_FOR_BLOG_OPERATION: &str = "burn_for_blog";
 // expected operation for blog mint
 pub const EXPECTED_MINT_FOR_BLOG_OPERATION: &str = "mint_for_blog";
 
+// expected operation for burn_and_comment_blog
+pub const EXPECTED_BURN_AND_COMMENT_OPERATION: &str = "burn_and_comment";
+
+// Comment board: comments are stored in full, so they are kept short
+pub const MAX_COMMENT_LENGTH: usize = 128;
+
 /// BurnMemo structure (compatible with memo-burn contract)
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BurnMemo {
@@ -113,7 +143,6 @@ pub struct BurnMemo {
 }
 
 /// Blog creation data structure (stored in BurnMemo.payload)
-#[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BlogCreationData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -135,18 +164,80 @@ pub struct BlogCreationData {
     
     /// Blog image info (optional, max 256 characters)
     pub image: String,
+    
+    /// Content flags: nsfw / spoiler / sensitive bits (v2+, default 0)
+    pub content_flags: u8,
+    
+    /// Language tag, e.g. "en" or "zh-CN" (v3+, optional, max 8 characters)
+    pub lang: String,
+}
+
+impl AnchorSerialize for BlogCreationData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.creator.serialize(writer)?;
+        self.name.serialize(writer)?;
+        self.description.serialize(writer)?;
+        self.image.serialize(writer)?;
+        if self.version >= 2 {
+            self.content_flags.serialize(writer)?;
+        }
+        if self.version >= 3 {
+            self.lang.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for BlogCreationData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let creator = String::deserialize_reader(reader)?;
+        let name = String::deserialize_reader(reader)?;
+        let description = String::deserialize_reader(reader)?;
+        let image = String::deserialize_reader(reader)?;
+        let content_flags = if version >= 2 {
+            u8::deserialize_reader(reader)?
+        } else {
+            0
+        };
+        let lang = if version >= 3 {
+            String::deserialize_reader(reader)?
+        } else {
+            String::new()
+        };
+        Ok(Self { version, category, operation, creator, name, description, image, content_flags, lang })
+    }
 }
 
 impl BlogCreationData {
     /// Validate the structure fields
     pub fn validate(&self, expected_creator: Pubkey) -> Result<()> {
-        // Validate version
-        if self.version != BLOG_CREATION_DATA_VERSION {
-            msg!("Unsupported blog creation data version: {} (expected: {})", 
+        // Validate version (1-3)
+        if self.version == 0 || self.version > BLOG_CREATION_DATA_VERSION {
+            msg!("Unsupported blog creation data version: {} (expected: 1-{})", 
                  self.version, BLOG_CREATION_DATA_VERSION);
             return Err(ErrorCode::UnsupportedBlogDataVersion.into());
         }
         
+        // content_flags cannot be carried by a v1 payload
+        if self.version < 2 && self.content_flags != 0 {
+            msg!("content_flags requires blog creation data version 2");
+            return Err(ErrorCode::UnsupportedBlogDataVersion.into());
+        }
+        validate_content_flags(self.content_flags)?;
+        
+        // lang cannot be carried by a v1/v2 payload
+        if self.version < 3 && !self.lang.is_empty() {
+            msg!("lang requires blog creation data version 3");
+            return Err(ErrorCode::UnsupportedBlogDataVersion.into());
+        }
+        validate_lang(&self.lang)?;
+        
         // Validate category (must be exactly "blog")
         if self.category != EXPECTED_CATEGORY {
             msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
@@ -204,6 +295,7 @@ impl BlogCreationData {
                  self.image.len(), MAX_BLOG_IMAGE_LENGTH);
             return Err(ErrorCode::InvalidBlogImage.into());
         }
+        validate_image_reference(&self.image)?;
         
         msg!("Blog creation data validation passed: category={}, operation={}, creator={}, name={}", 
              self.category, self.operation, self.creator, self.name);
@@ -305,6 +397,7 @@ impl BlogUpdateData {
                      new_image.len(), MAX_BLOG_IMAGE_LENGTH);
                 return Err(ErrorCode::InvalidBlogImage.into());
             }
+            validate_image_reference(new_image)?;
         }
         
         msg!("Blog update data validation passed: category={}, operation={}, creator={}", 
@@ -477,16 +570,168 @@ impl BlogMintData {
     }
 }
 
+/// Blog comment data structure (stored in BurnMemo.payload for burn_and_comment_blog)
+/// Note: Anyone can comment on a blog; the burn amount travels in BurnMemo.burn_amount
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BlogCommentData {
+    /// Version of this structure (for future compatibility)
+    pub version: u8,
+    
+    /// Category of the request (must be "blog" for memo-blog contract)
+    pub category: String,
+    
+    /// Operation type (must be "burn_and_comment")
+    pub operation: String,
+    
+    /// Commenter pubkey as string (must match the transaction signer)
+    pub commenter: String,
+    
+    /// Comment text (required, max 128 characters)
+    pub comment: String,
+}
+
+impl BlogCommentData {
+    /// Validate the structure fields
+    pub fn validate(&self, expected_commenter: Pubkey) -> Result<()> {
+        // Validate version
+        if self.version != BLOG_COMMENT_DATA_VERSION {
+            msg!("Unsupported blog comment data version: {} (expected: {})", 
+                 self.version, BLOG_COMMENT_DATA_VERSION);
+            return Err(ErrorCode::UnsupportedBlogCommentDataVersion.into());
+        }
+        
+        // Validate category (must be exactly "blog")
+        if self.category != EXPECTED_CATEGORY {
+            msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
+            return Err(ErrorCode::InvalidCategory.into());
+        }
+        
+        // Validate operation (must be exactly "burn_and_comment")
+        if self.operation != EXPECTED_BURN_AND_COMMENT_OPERATION {
+            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, EXPECTED_BURN_AND_COMMENT_OPERATION);
+            return Err(ErrorCode::InvalidOperation.into());
+        }
+        
+        // Validate commenter pubkey matches transaction signer
+        let parsed_pubkey = Pubkey::from_str(&self.commenter)
+            .map_err(|_| {
+                msg!("Invalid commenter pubkey format: {}", self.commenter);
+                ErrorCode::InvalidBurnerPubkeyFormat
+            })?;
+        
+        if parsed_pubkey != expected_commenter {
+            msg!("Commenter pubkey mismatch: memo {} vs expected {}", parsed_pubkey, expected_commenter);
+            return Err(ErrorCode::BurnerPubkeyMismatch.into());
+        }
+        
+        // Validate comment (required, max MAX_COMMENT_LENGTH characters)
+        if self.comment.trim().is_empty() {
+            msg!("Comment cannot be empty");
+            return Err(ErrorCode::EmptyComment.into());
+        }
+        
+        if self.comment.len() > MAX_COMMENT_LENGTH {
+            msg!("Comment too long: {} characters (max: {})", self.comment.len(), MAX_COMMENT_LENGTH);
+            return Err(ErrorCode::CommentTooLong.into());
+        }
+        
+        msg!("Blog comment data validation passed: category={}, operation={}, commenter={}", 
+             self.category, self.operation, self.commenter);
+        
+        Ok(())
+    }
+}
+
+/// Validate a content_flags bitfield (reserved bits must be zero)
+pub fn validate_content_flags(content_flags: u8) -> Result<()> {
+    if content_flags & !CONTENT_FLAGS_MASK != 0 {
+        msg!("Invalid content flags: {:#010b} (allowed bits: {:#010b})", content_flags, CONTENT_FLAGS_MASK);
+        return Err(ErrorCode::InvalidContentFlags.into());
+    }
+    Ok(())
+}
+
+/// Validate a BCP-47 style language tag: empty, or ASCII letters with at most one hyphen
+pub fn validate_lang(lang: &str) -> Result<()> {
+    if lang.is_empty() {
+        return Ok(());
+    }
+    let valid = lang.len() <= MAX_LANG_LENGTH
+        && lang.matches('-').count() <= 1
+        && lang.split('-').all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphabetic()));
+    if !valid {
+        msg!("Invalid language tag: '{}' (expected ASCII letters with at most one hyphen, max {} characters)", lang, MAX_LANG_LENGTH);
+        return Err(ErrorCode::InvalidLanguageTag.into());
+    }
+    Ok(())
+}
+
+/// Lowercase mirror of a name for case-insensitive search by indexers. Falls back to ASCII-only
+/// lowercasing if full Unicode lowercasing would grow the name, so the mirror never needs more
+/// space than the name itself
+pub fn lowercase_name(name: &str) -> String {
+    let lower = name.to_lowercase();
+    if lower.len() <= name.len() {
+        lower
+    } else {
+        name.to_ascii_lowercase()
+    }
+}
+
+/// Image references must use one of these schemes (or be a bare CIDv0)
+pub const IMAGE_REFERENCE_PREFIXES: [&str; 3] = ["ipfs://", "ar://", "https://"];
+
+/// Length of a bare CIDv0 IPFS hash ("Qm" + 44 base58 characters)
+pub const CID_V0_LENGTH: usize = 46;
+
+/// Validate an image field: empty, an ipfs:// / ar:// / https:// reference, or a bare CIDv0
+pub fn validate_image_reference(image: &str) -> Result<()> {
+    if image.is_empty()
+        || IMAGE_REFERENCE_PREFIXES.iter().any(|prefix| image.starts_with(prefix))
+        || is_cid_v0(image)
+    {
+        return Ok(());
+    }
+
+    msg!("Invalid image reference: {} (expected ipfs://, ar://, https:// or a CIDv0)", image);
+    Err(ErrorCode::InvalidImageReference.into())
+}
+
+/// Whether `value` looks like a CIDv0 ("Qm..." in base58, 46 characters)
+fn is_cid_v0(value: &str) -> bool {
+    const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    value.len() == CID_V0_LENGTH
+        && value.starts_with("Qm")
+        && value.chars().all(|c| BASE58_ALPHABET.contains(c))
+}
+
 #[program]
 pub mod memo_blog {
     use super::*;
 
+    /// Initialize the program-wide burn/mint statistics (one-time setup, admin only;
+    /// burn and mint instructions fail until it exists)
+    pub fn initialize_program_stats(ctx: Context<InitializeProgramStats>) -> Result<()> {
+        let program_stats = &mut ctx.accounts.program_stats;
+        program_stats.total_burned = 0;
+        program_stats.total_minted = 0;
+        program_stats.event_seq = 0;
+        program_stats.bump = ctx.bumps.program_stats;
+
+        msg!("Program stats initialized by admin {}", ctx.accounts.admin.key());
+        Ok(())
+    }
+
     /// Create a new blog (requires burning at least 1 MEMO token)
     /// Each user can only create one unique blog, bound to their pubkey
     pub fn create_blog(
         ctx: Context<CreateBlog>,
         burn_amount: u64,
+        min_mint_interval: i64, // Per-user cooldown for mint_for_blog (0 = no throttle)
     ) -> Result<()> {
+        // Validate the mint cooldown before doing any work
+        Blog::validate_min_mint_interval(min_mint_interval)?;
+
         // Validate burn amount - require at least 1 token for blog creation
         if burn_amount < MIN_BLOG_BURN_AMOUNT {
             return Err(ErrorCode::BurnAmountTooSmall.into());
@@ -509,8 +754,13 @@ pub mod memo_blog {
 
         // Parse and validate Borsh memo data for blog creation
         let blog_data = parse_blog_creation_borsh_memo(&memo_data, ctx.accounts.creator.key(), burn_amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
         
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.creator_token_account.amount;
+
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.creator.to_account_info(),
@@ -523,6 +773,11 @@ pub mod memo_blog {
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.creator_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.creator_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
         
         // Get current timestamp once for consistency and efficiency
         let timestamp = Clock::get()?.unix_timestamp;
@@ -538,16 +793,25 @@ pub mod memo_blog {
         blog.memo_count = 0; // Initialize memo_count (tracks burn_for_blog and mint_for_blog operations)
         blog.burned_amount = burn_amount;
         blog.last_memo_time = 0; // Set to 0 initially (no burn/mint_for_blog memos yet)
+        blog.min_mint_interval = min_mint_interval;
+        blog.content_flags = blog_data.content_flags;
+        blog.lang = blog_data.lang.clone();
+        blog.name_lower = lowercase_name(&blog_data.name);
         blog.bump = ctx.bumps.blog;
 
         // Emit blog creation event
         emit!(BlogCreatedEvent {
             creator: ctx.accounts.creator.key(),
+            name_lower: lowercase_name(&blog_data.name),
             name: blog_data.name,
             description: blog_data.description,
             image: blog_data.image,
+            content_flags: blog_data.content_flags,
+            lang: blog_data.lang,
             burn_amount,
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         msg!("Blog created successfully by {} with {} tokens burned", 
@@ -582,8 +846,13 @@ pub mod memo_blog {
 
         // Parse and validate Borsh memo data for blog update
         let update_data = parse_blog_update_borsh_memo(&memo_data, ctx.accounts.updater.key(), burn_amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
         
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.updater_token_account.amount;
+
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.updater.to_account_info(),
@@ -596,6 +865,11 @@ pub mod memo_blog {
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.updater_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.updater_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
 
         // Get current timestamp once for consistency and efficiency
         let timestamp = Clock::get()?.unix_timestamp;
@@ -604,6 +878,7 @@ pub mod memo_blog {
         
         // Update fields if provided in memo data
         if let Some(new_name) = update_data.name {
+            blog.name_lower = lowercase_name(&new_name);
             blog.name = new_name;
         }
         
@@ -624,11 +899,14 @@ pub mod memo_blog {
         emit!(BlogUpdatedEvent {
             creator: ctx.accounts.updater.key(),
             name: blog.name.clone(),
+            name_lower: blog.name_lower.clone(),
             description: blog.description.clone(),
             image: blog.image.clone(),
             burn_amount,
             total_burned: blog.burned_amount,
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         msg!("Blog updated successfully by {} with {} tokens burned (total: {})", 
@@ -664,8 +942,13 @@ pub mod memo_blog {
 
         // Parse and validate Borsh memo content for burn operation
         parse_blog_burn_borsh_memo(&memo_data, amount, ctx.accounts.burner.key())?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.burner_token_account.amount;
 
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.burner.to_account_info(),
@@ -680,6 +963,11 @@ pub mod memo_blog {
         
         // Call memo-burn's process_burn instruction
         memo_burn::cpi::process_burn(cpi_ctx, amount)?;
+        ctx.accounts.burner_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.burner_token_account.amount, amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(amount);
         
         // Get current timestamp once for consistency and efficiency
         let timestamp = Clock::get()?.unix_timestamp;
@@ -707,6 +995,8 @@ pub mod memo_blog {
             amount,
             total_burned: blog.burned_amount,
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         Ok(())
@@ -724,6 +1014,21 @@ pub mod memo_blog {
 
         // Parse and validate Borsh memo content for mint operation
         parse_blog_mint_borsh_memo(&memo_data, ctx.accounts.minter.key())?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Enforce the blog's per-user mint cooldown
+        let min_mint_interval = ctx.accounts.blog.min_mint_interval;
+        match ctx.accounts.mint_cooldown.as_mut() {
+            Some(mint_cooldown) => {
+                mint_cooldown.bump = ctx.bumps.mint_cooldown.unwrap_or_default();
+                mint_cooldown.check_and_record(min_mint_interval, Clock::get()?.unix_timestamp)?;
+            }
+            None if min_mint_interval > 0 => return Err(ErrorCode::MintCooldownAccountRequired.into()),
+            None => {}
+        }
+
+        // Balance before minting, used to derive the minted amount for program stats
+        let balance_before_mint = ctx.accounts.minter_token_account.amount;
 
         // Call memo-mint contract to mint tokens
         // Using process_mint which mints to the caller's own account
@@ -741,6 +1046,10 @@ pub mod memo_blog {
         
         // Call memo-mint's process_mint instruction
         memo_mint::cpi::process_mint(cpi_ctx)?;
+
+        // Track program-wide mint total (process_mint picks the amount from the supply tier)
+        ctx.accounts.minter_token_account.reload()?;
+        ctx.accounts.program_stats.record_mint(ctx.accounts.minter_token_account.amount.saturating_sub(balance_before_mint));
         
         // Get current timestamp once for consistency and efficiency
         let timestamp = Clock::get()?.unix_timestamp;
@@ -760,10 +1069,145 @@ pub mod memo_blog {
         emit!(TokensMintedForBlogEvent {
             creator: ctx.accounts.minter.key(),
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        Ok(())
+    }
+
+    /// Create the comment board of a blog (anyone can pay for it)
+    pub fn initialize_comment_board(ctx: Context<InitializeCommentBoard>, blog_creator: Pubkey) -> Result<()> {
+        let board = &mut ctx.accounts.comment_board;
+        board.blog_creator = blog_creator;
+        board.current_index = 0;
+        board.comments = Vec::new();
+        board.bump = ctx.bumps.comment_board;
+
+        msg!("Comment board initialized for blog {} by {}", blog_creator, ctx.accounts.payer.key());
+        Ok(())
+    }
+
+    /// Burn tokens for a blog and leave a comment on its comment board (anyone can comment).
+    /// The memo carries both the burn amount and the comment text.
+    pub fn burn_and_comment_blog(
+        ctx: Context<BurnAndCommentBlog>,
+        blog_creator: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        // Validate burn amount - require at least 1 token
+        if amount < MIN_BLOG_BURN_AMOUNT {
+            return Err(ErrorCode::BurnAmountTooSmall.into());
+        }
+        
+        // Check burn amount limit
+        if amount > MAX_BURN_PER_TX {
+            return Err(ErrorCode::BurnAmountTooLarge.into());
+        }
+        
+        if amount % DECIMAL_FACTOR != 0 {
+            return Err(ErrorCode::InvalidBurnAmount.into());
+        }
+
+        // Check memo instruction with enhanced validation
+        let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
+        if !memo_found {
+            return Err(ErrorCode::MemoRequired.into());
+        }
+
+        // Parse and validate Borsh memo content for the comment
+        let comment_data = parse_blog_comment_borsh_memo(&memo_data, amount, ctx.accounts.commenter.key())?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.commenter_token_account.amount;
+
+        // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
+        let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
+        let cpi_accounts = ProcessBurn {
+            user: ctx.accounts.commenter.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            token_account: ctx.accounts.commenter_token_account.to_account_info(),
+            user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            instructions: ctx.accounts.instructions.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        memo_burn::cpi::process_burn(cpi_ctx, amount)?;
+        ctx.accounts.commenter_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.commenter_token_account.amount, amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(amount);
+        
+        // Get current timestamp once for consistency and efficiency
+        let timestamp = Clock::get()?.unix_timestamp;
+        
+        // Update the blog's comment and burn counters, then append the comment
+        let blog = &mut ctx.accounts.blog;
+        blog.record_comment(amount);
+        ctx.accounts.comment_board.add_comment(BlogComment {
+            commenter: ctx.accounts.commenter.key(),
+            amount,
+            comment: comment_data.comment.clone(),
+            timestamp,
+        });
+        
+        msg!("Comment with {} tokens burned added to blog {} by {}", 
+             amount / DECIMAL_FACTOR, blog_creator, ctx.accounts.commenter.key());
+        
+        emit!(BlogCommentedEvent {
+            blog_creator,
+            commenter: ctx.accounts.commenter.key(),
+            amount,
+            comment: comment_data.comment,
+            comment_count: blog.comment_count,
+            total_burned: blog.burned_amount,
+            timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         Ok(())
     }
+
+    /// Convert a blog created before min_mint_interval and the later fields were appended:
+    /// grow it to Blog::calculate_space_max (payer covers the extra rent) and rewrite it with
+    /// the appended fields initialized (see Blog::from_legacy). Anyone may migrate any blog;
+    /// a blog that is already current is left untouched
+    pub fn migrate_blog(ctx: Context<MigrateBlog>, blog_creator: Pubkey) -> Result<()> {
+        let blog_account = ctx.accounts.blog.to_account_info();
+        if blog_account.owner != ctx.program_id {
+            return Err(ErrorCode::InvalidBlogAccount.into());
+        }
+        if !Blog::needs_migration(&blog_account.try_borrow_data()?)? {
+            msg!("Blog of {} is already migrated", blog_creator);
+            return Ok(());
+        }
+        let blog = Blog::from_legacy(&blog_account.try_borrow_data()?)?;
+
+        let space = Blog::calculate_space_max();
+        let top_up = Rent::get()?.minimum_balance(space).saturating_sub(blog_account.lamports());
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: blog_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        blog_account.resize(space)?;
+        blog.try_serialize(&mut &mut blog_account.try_borrow_mut_data()?[..])?;
+
+        msg!("Blog of {} migrated to the current layout ({} bytes)", blog_creator, space);
+        Ok(())
+    }
 }
 
 /// Parse and validate Borsh-formatted memo data for blog creation (with Base64 decoding)
@@ -976,6 +1420,68 @@ fn parse_blog_burn_borsh_memo(memo_data: &[u8], expected_amount: u64, expected_b
     Ok(())
 }
 
+/// Parse and validate Borsh-formatted memo data for burn_and_comment_blog (with Base64 decoding)
+fn parse_blog_comment_borsh_memo(memo_data: &[u8], expected_amount: u64, expected_commenter: Pubkey) -> Result<BlogCommentData> {
+    // First, decode the Base64-encoded memo data
+    let base64_str = std::str::from_utf8(memo_data)
+        .map_err(|_| {
+            msg!("Invalid UTF-8 in memo data");
+            ErrorCode::InvalidMemoFormat
+        })?;
+    
+    let decoded_data = general_purpose::STANDARD.decode(base64_str)
+        .map_err(|_| {
+            msg!("Invalid Base64 encoding in memo");
+            ErrorCode::InvalidMemoFormat
+        })?;
+
+    // Check decoded borsh data size
+    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
+        msg!("Decoded data too large: {} bytes (max: {})", decoded_data.len(), MAX_BORSH_DATA_SIZE);
+        return Err(ErrorCode::InvalidMemoFormat.into());
+    }
+    
+    // Deserialize Borsh data from decoded bytes (following memo-burn pattern)
+    let burn_memo = BurnMemo::try_from_slice(&decoded_data)
+        .map_err(|_| {
+            msg!("Invalid Borsh format after Base64 decoding");
+            ErrorCode::InvalidMemoFormat
+        })?;
+    
+    // Validate version compatibility
+    if burn_memo.version != BURN_MEMO_VERSION {
+        msg!("Unsupported memo version: {} (expected: {})", 
+             burn_memo.version, BURN_MEMO_VERSION);
+        return Err(ErrorCode::UnsupportedMemoVersion.into());
+    }
+    
+    // Validate burn amount matches
+    if burn_memo.burn_amount != expected_amount {
+        msg!("Burn amount mismatch: memo {} vs expected {}", 
+             burn_memo.burn_amount, expected_amount);
+        return Err(ErrorCode::BurnAmountMismatch.into());
+    }
+    
+    // Validate payload length does not exceed maximum allowed value
+    if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
+        msg!("Payload too long: {} bytes (max: {})", 
+             burn_memo.payload.len(), MAX_PAYLOAD_LENGTH);
+        return Err(ErrorCode::PayloadTooLong.into());
+    }
+    
+    // Deserialize blog comment data from payload
+    let comment_data = BlogCommentData::try_from_slice(&burn_memo.payload)
+        .map_err(|_| {
+            msg!("Invalid blog comment data format in payload");
+            ErrorCode::InvalidBlogCommentDataFormat
+        })?;
+    
+    // Validate blog comment data
+    comment_data.validate(expected_commenter)?;
+    
+    Ok(comment_data)
+}
+
 /// Parse and validate Borsh-formatted memo data for blog mint (with Base64 decoding)
 /// Note: For mint operations, the burn_amount in BurnMemo should be 0
 fn parse_blog_mint_borsh_memo(memo_data: &[u8], expected_minter: Pubkey) -> Result<()> {
@@ -1043,6 +1549,11 @@ fn parse_blog_mint_borsh_memo(memo_data: &[u8], expected_minter: Pubkey) -> Resu
     Ok(())
 }
 
+/// Whether a mint has the decimals DECIMAL_FACTOR assumes (guards against a recreated mint)
+pub fn mint_decimals_valid(decimals: u8) -> bool {
+    decimals == MINT_DECIMALS
+}
+
 /// Check for memo instruction at REQUIRED index 0
 /// 
 /// IMPORTANT: This contract enforces memo at index 0:
@@ -1059,7 +1570,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     // to leave index 0 available for memo
     if current_index < 1 {
         msg!("memo-blog instruction must be at index 1 or later, but current instruction is at index {}", current_index);
-        return Ok((false, vec![]));
+        return memo_not_at_index_zero(instructions);
     }
     
     // Check that index 0 contains the memo instruction
@@ -1070,7 +1581,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
                 validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
             } else {
                 msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
-                Ok((false, vec![]))
+                memo_not_at_index_zero(instructions)
             }
         },
         Err(e) => {
@@ -1080,6 +1591,28 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     }
 }
 
+/// Called when index 0 holds no memo: if a memo exists elsewhere in the transaction,
+/// fail with MemoWrongIndex instead of letting the caller report MemoRequired
+fn memo_not_at_index_zero(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    let program_ids: Vec<Pubkey> = (0..)
+        .map_while(|i| anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(i, instructions).ok())
+        .map(|ix| ix.program_id)
+        .collect();
+    check_memo_placement(&program_ids)?;
+    Ok((false, vec![]))
+}
+
+/// Reject a transaction whose SPL Memo instruction is present but not at index 0
+fn check_memo_placement(program_ids: &[Pubkey]) -> Result<()> {
+    if let Some(index) = program_ids.iter().position(|id| *id == MEMO_PROGRAM_ID) {
+        if index > 0 {
+            msg!("Memo instruction found at index {}, but it must be placed at index 0", index);
+            return Err(ErrorCode::MemoWrongIndex.into());
+        }
+    }
+    Ok(())
+}
+
 /// Validate memo data length and return result
 fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize) -> Result<(bool, Vec<u8>)> {
     let memo_length = memo_data.len();
@@ -1107,9 +1640,95 @@ fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize)
     Ok((true, memo_data.to_vec()))
 }
 
+/// Compute the SHA-256 hash of the raw memo instruction data
+/// (emitted in events so indexers can match them to the on-chain memo)
+fn compute_memo_hash(memo_data: &[u8]) -> [u8; 32] {
+    hash(memo_data).to_bytes()
+}
+
+/// Check that a memo-burn process_burn CPI reduced the burner's token balance by
+/// exactly `amount` (guards against crediting burns that were not fully applied)
+fn check_burn_reflected(balance_before: u64, balance_after: u64, amount: u64) -> Result<()> {
+    if balance_before.checked_sub(balance_after) != Some(amount) {
+        msg!("Burn not reflected in balance: {} -> {} (expected {} burned)", 
+             balance_before, balance_after, amount);
+        return Err(ErrorCode::BurnAmountNotReflected.into());
+    }
+    Ok(())
+}
+
+/// Check that the account handed to memo-burn as `user_global_burn_stats` is owned by
+/// memo-burn and carries the UserGlobalBurnStats discriminator (guards against a
+/// spoofed account reaching the burn CPI)
+fn verify_burn_stats_account(account: &AccountInfo) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    if account.owner != &memo_burn::ID
+        || !data.starts_with(memo_burn::UserGlobalBurnStats::DISCRIMINATOR)
+    {
+        msg!("Invalid burn stats account: {} (owner: {})", account.key(), account.owner);
+        return Err(ErrorCode::InvalidBurnStatsAccount.into());
+    }
+    Ok(())
+}
+
+/// Program-wide burn/mint totals (singleton PDA, initialized by admin)
+#[account]
+pub struct ProgramStats {
+    pub total_burned: u128,     // Total units burned through this program
+    pub total_minted: u128,     // Total units minted through this program
+    pub event_seq: u64,         // Sequence number of the last emitted event (0 = none yet)
+    pub bump: u8,               // PDA bump
+}
+
+impl ProgramStats {
+    pub const SPACE: usize = 8 + // discriminator
+        16 + // total_burned (u128)
+        16 + // total_minted (u128)
+        8 +  // event_seq (u64)
+        1;   // bump (u8)
+
+    /// Add a burn to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_burn(&mut self, amount: u64) {
+        self.total_burned = self.total_burned.saturating_add(u128::from(amount));
+    }
+
+    /// Add a mint to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_mint(&mut self, amount: u64) {
+        self.total_minted = self.total_minted.saturating_add(u128::from(amount));
+    }
+
+    /// Advance the event sequence and return the new value. The first event gets 1,
+    /// so indexers can detect dropped events by looking for gaps in the sequence.
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.saturating_add(1);
+        self.event_seq
+    }
+}
+
+/// Account structure for initializing the program stats (admin only)
+#[derive(Accounts)]
+pub struct InitializeProgramStats<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramStats::SPACE,
+        seeds = [b"stats"],
+        bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// Account structure for creating a blog
 #[derive(Accounts)]
-#[instruction(burn_amount: u64)]
+#[instruction(burn_amount: u64, min_mint_interval: i64)]
 pub struct CreateBlog<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -1125,7 +1744,8 @@ pub struct CreateBlog<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1155,6 +1775,14 @@ pub struct CreateBlog<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for updating a blog
@@ -1168,13 +1796,15 @@ pub struct UpdateBlog<'info> {
         mut,
         seeds = [b"blog", updater.key().as_ref()],
         bump = blog.bump,
+        constraint = Blog::is_migrated(blog.to_account_info().data_len()) @ ErrorCode::BlogNotMigrated,
         constraint = blog.creator == updater.key() @ ErrorCode::UnauthorizedBlogAccess
     )]
     pub blog: Account<'info, Blog>,
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1202,6 +1832,14 @@ pub struct UpdateBlog<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for burning tokens for a blog
@@ -1215,13 +1853,15 @@ pub struct BurnForBlog<'info> {
         mut,
         seeds = [b"blog", burner.key().as_ref()],
         bump = blog.bump,
+        constraint = Blog::is_migrated(blog.to_account_info().data_len()) @ ErrorCode::BlogNotMigrated,
         constraint = blog.creator == burner.key() @ ErrorCode::UnauthorizedBlogAccess
     )]
     pub blog: Account<'info, Blog>,
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1249,6 +1889,14 @@ pub struct BurnForBlog<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for minting tokens for a blog
@@ -1261,13 +1909,15 @@ pub struct MintForBlog<'info> {
         mut,
         seeds = [b"blog", minter.key().as_ref()],
         bump = blog.bump,
+        constraint = Blog::is_migrated(blog.to_account_info().data_len()) @ ErrorCode::BlogNotMigrated,
         constraint = blog.creator == minter.key() @ ErrorCode::UnauthorizedBlogAccess
     )]
     pub blog: Account<'info, Blog>,
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1294,6 +1944,162 @@ pub struct MintForBlog<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Per-user mint cooldown (required when the blog sets min_mint_interval)
+    #[account(
+        init_if_needed,
+        payer = minter,
+        space = MintCooldown::SPACE,
+        seeds = [b"mint_cooldown", blog.key().as_ref(), minter.key().as_ref()],
+        bump
+    )]
+    pub mint_cooldown: Option<Account<'info, MintCooldown>>,
+
+    /// System program (only required together with mint_cooldown)
+    pub system_program: Option<Program<'info, System>>,
+}
+
+/// Account structure for migrating a legacy-sized blog (permissionless, payer covers rent)
+#[derive(Accounts)]
+#[instruction(blog_creator: Pubkey)]
+pub struct MigrateBlog<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: legacy blogs are not decoded as Blog; owner, discriminator and size are
+    /// checked in migrate_blog
+    #[account(
+        mut,
+        seeds = [b"blog", blog_creator.as_ref()],
+        bump
+    )]
+    pub blog: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for creating a blog's comment board
+#[derive(Accounts)]
+#[instruction(blog_creator: Pubkey)]
+pub struct InitializeCommentBoard<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"blog", blog_creator.as_ref()],
+        bump = blog.bump,
+        constraint = Blog::is_migrated(blog.to_account_info().data_len()) @ ErrorCode::BlogNotMigrated
+    )]
+    pub blog: Account<'info, Blog>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = BlogCommentBoard::SPACE,
+        seeds = [b"comment_board", blog_creator.as_ref()],
+        bump
+    )]
+    pub comment_board: Account<'info, BlogCommentBoard>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for burning tokens and commenting on a blog (anyone can comment)
+#[derive(Accounts)]
+#[instruction(blog_creator: Pubkey, amount: u64)]
+pub struct BurnAndCommentBlog<'info> {
+    #[account(mut)]
+    pub commenter: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"blog", blog_creator.as_ref()],
+        bump = blog.bump,
+        constraint = Blog::is_migrated(blog.to_account_info().data_len()) @ ErrorCode::BlogNotMigrated
+    )]
+    pub blog: Account<'info, Blog>,
+    
+    #[account(
+        mut,
+        seeds = [b"comment_board", blog_creator.as_ref()],
+        bump = comment_board.bump
+    )]
+    pub comment_board: Account<'info, BlogCommentBoard>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = commenter_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = commenter_token_account.owner == commenter.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub commenter_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User global burn statistics tracking account (now required)
+    #[account(
+        mut,
+        seeds = [b"user_global_burn_stats", commenter.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-burn program
+    pub memo_burn_program: Program<'info, MemoBurn>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Per-user mint cooldown state for one blog, seeded by (blog, user)
+#[account]
+pub struct MintCooldown {
+    pub last_mint_time: i64,          // Last mint_for_blog by this user (0 if never)
+    pub bump: u8,                     // PDA bump
+}
+
+impl MintCooldown {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // last_mint_time (i64)
+        1;  // bump (u8)
+
+    /// Enforce min_mint_interval since this user's last mint, then record current_time
+    /// (an interval of 0 never throttles)
+    pub fn check_and_record(&mut self, min_mint_interval: i64, current_time: i64) -> Result<()> {
+        if min_mint_interval > 0 && self.last_mint_time > 0 {
+            let elapsed = current_time.saturating_sub(self.last_mint_time);
+            if elapsed < min_mint_interval {
+                msg!("Mint too frequent: {}s since last mint (minimum: {}s)", elapsed, min_mint_interval);
+                return Err(ErrorCode::MintTooFrequent.into());
+            }
+        }
+        self.last_mint_time = current_time;
+        Ok(())
+    }
 }
 
 /// Blog data structure (simpler than Project - no website, no tags)
@@ -1310,9 +2116,40 @@ pub struct Blog {
     pub burned_amount: u64,           // Total burned tokens for this blog
     pub last_memo_time: i64,          // Last burn/mint_for_blog operation timestamp (0 if never)
     pub bump: u8,                     // PDA bump
+    // Fields below were appended after the original layout; blogs created before
+    // them are converted by migrate_blog (see Blog::LEGACY_SPACE)
+    pub min_mint_interval: i64,       // Per-user cooldown for mint_for_blog in seconds (0 = no throttle)
+    pub content_flags: u8,            // Content flags from the creation memo (CONTENT_FLAG_*)
+    pub lang: String,                 // Language tag (optional, max 8 chars)
+    pub comment_count: u64,           // Number of burn_and_comment_blog operations
+    pub name_lower: String,           // Lowercased name for case-insensitive search (kept in sync with name)
+}
+
+/// Blog layout written by program versions before min_mint_interval (the prefix of Blog)
+#[derive(AnchorDeserialize)]
+struct LegacyBlog {
+    creator: Pubkey,
+    created_at: i64,
+    last_updated: i64,
+    name: String,
+    description: String,
+    image: String,
+    memo_count: u64,
+    burned_amount: u64,
+    last_memo_time: i64,
+    bump: u8,
 }
 
 impl Blog {
+    /// Validate a min_mint_interval (0..=MAX_MINT_INTERVAL_SECONDS)
+    pub fn validate_min_mint_interval(min_mint_interval: i64) -> Result<()> {
+        if !(0..=MAX_MINT_INTERVAL_SECONDS).contains(&min_mint_interval) {
+            msg!("Invalid min_mint_interval: {} (must be 0-{} seconds)", min_mint_interval, MAX_MINT_INTERVAL_SECONDS);
+            return Err(ErrorCode::InvalidMintInterval.into());
+        }
+        Ok(())
+    }
+
     /// Calculate maximum space for the account (conservative estimate)
     pub fn calculate_space_max() -> usize {
         8 + // discriminator
@@ -1323,11 +2160,130 @@ impl Blog {
         8 + // burned_amount
         8 + // last_memo_time
         1 + // bump
+        8 + // min_mint_interval
+        8 + // comment_count
+        1 + // content_flags
+        4 + 8 + // lang (max 8 chars)
+        4 + 64 + // name_lower (never longer than name)
         4 + 64 + // name (max 64 chars)
         4 + 256 + // description (max 256 chars)
         4 + 256 + // image (max 256 chars)
         128 // safety buffer
     }
+
+    /// Size of blogs created before min_mint_interval and the later fields were appended
+    /// (the calculate_space_max of that layout, which every such blog was allocated with)
+    pub const LEGACY_SPACE: usize = 8 + // discriminator
+        32 + // creator
+        8 + // created_at
+        8 + // last_updated
+        8 + // memo_count
+        8 + // burned_amount
+        8 + // last_memo_time
+        1 + // bump
+        4 + 64 + // name
+        4 + 256 + // description
+        4 + 256 + // image
+        128; // safety buffer
+
+    /// Whether a blog account of `data_len` bytes has the current layout. A legacy-sized
+    /// blog may decode, but its appended fields come from whatever bytes followed its old
+    /// data (zeros or stale string bytes), so instructions reject it until migrated
+    pub fn is_migrated(data_len: usize) -> bool {
+        data_len != Self::LEGACY_SPACE
+    }
+
+    /// Whether a blog account needs migrate_blog: true for a legacy-sized blog, false if
+    /// it is already current, error for anything else
+    pub fn needs_migration(data: &[u8]) -> Result<bool> {
+        if !data.starts_with(Blog::DISCRIMINATOR) {
+            return Err(ErrorCode::InvalidBlogAccount.into());
+        }
+        match data.len() {
+            Self::LEGACY_SPACE => Ok(true),
+            len if len == Self::calculate_space_max() => Ok(false),
+            _ => Err(ErrorCode::InvalidBlogAccount.into()),
+        }
+    }
+
+    /// Decode a legacy-sized blog (discriminator included). The appended fields start
+    /// empty, except name_lower (derived from name)
+    pub fn from_legacy(data: &[u8]) -> Result<Self> {
+        if !Self::needs_migration(data)? {
+            return Err(ErrorCode::InvalidBlogAccount.into());
+        }
+        let legacy = LegacyBlog::deserialize(&mut &data[8..])
+            .map_err(|_| ErrorCode::InvalidBlogAccount)?;
+
+        Ok(Self {
+            creator: legacy.creator,
+            created_at: legacy.created_at,
+            last_updated: legacy.last_updated,
+            name_lower: lowercase_name(&legacy.name),
+            name: legacy.name,
+            description: legacy.description,
+            image: legacy.image,
+            memo_count: legacy.memo_count,
+            burned_amount: legacy.burned_amount,
+            last_memo_time: legacy.last_memo_time,
+            bump: legacy.bump,
+            min_mint_interval: 0,
+            content_flags: 0,
+            lang: String::new(),
+            comment_count: 0,
+        })
+    }
+
+    /// Count a burn_and_comment_blog operation and its burn
+    pub fn record_comment(&mut self, amount: u64) {
+        self.burned_amount = self.burned_amount.saturating_add(amount);
+        self.comment_count = self.comment_count.saturating_add(1);
+    }
+}
+
+/// One comment on a blog's comment board
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BlogComment {
+    pub commenter: Pubkey,
+    pub amount: u64,
+    pub comment: String, // max MAX_COMMENT_LENGTH bytes
+    pub timestamp: i64,
+}
+
+impl BlogComment {
+    pub const SPACE: usize = 32 + // commenter
+        8 + // amount
+        4 + MAX_COMMENT_LENGTH + // comment
+        8; // timestamp
+}
+
+/// Comment board of a blog: ring buffer of the latest 50 comments
+#[account]
+pub struct BlogCommentBoard {
+    pub blog_creator: Pubkey,         // Blog this board belongs to
+    pub current_index: u8,            // Next slot to overwrite once the board is full
+    pub comments: Vec<BlogComment>,   // Latest comments (max MAX_COMMENTS)
+    pub bump: u8,                     // PDA bump
+}
+
+impl BlogCommentBoard {
+    pub const MAX_COMMENTS: usize = 50;
+
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // blog_creator
+        1 + // current_index
+        4 + Self::MAX_COMMENTS * BlogComment::SPACE + // comments
+        1; // bump
+
+    /// Append a comment, overwriting the oldest one once MAX_COMMENTS is reached
+    pub fn add_comment(&mut self, comment: BlogComment) {
+        if self.comments.len() < Self::MAX_COMMENTS {
+            self.comments.push(comment);
+        } else {
+            self.comments[self.current_index as usize] = comment;
+        }
+        self.current_index = ((self.current_index as usize + 1) % Self::MAX_COMMENTS) as u8;
+    }
 }
 
 /// Event emitted when a blog is created
@@ -1335,10 +2291,15 @@ impl Blog {
 pub struct BlogCreatedEvent {
     pub creator: Pubkey,
     pub name: String,
+    pub name_lower: String,
     pub description: String,
     pub image: String,
+    pub content_flags: u8,
+    pub lang: String,
     pub burn_amount: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Event emitted when a blog is updated
@@ -1346,11 +2307,14 @@ pub struct BlogCreatedEvent {
 pub struct BlogUpdatedEvent {
     pub creator: Pubkey,
     pub name: String,
+    pub name_lower: String,
     pub description: String,
     pub image: String,
     pub burn_amount: u64,
     pub total_burned: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Event emitted when tokens are burned for a blog
@@ -1360,6 +2324,8 @@ pub struct TokensBurnedForBlogEvent {
     pub amount: u64,
     pub total_burned: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Event emitted when tokens are minted for a blog
@@ -1367,6 +2333,22 @@ pub struct TokensBurnedForBlogEvent {
 pub struct TokensMintedForBlogEvent {
     pub creator: Pubkey,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a reader burns tokens and comments on a blog
+#[event]
+pub struct BlogCommentedEvent {
+    pub blog_creator: Pubkey,
+    pub commenter: Pubkey,
+    pub amount: u64,
+    pub comment: String,
+    pub comment_count: u64,
+    pub total_burned: u64,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Error code definitions
@@ -1494,4 +2476,55 @@ pub enum ErrorCode {
     
     #[msg("Message too long: Message must be at most 696 characters.")]
     MessageTooLong,
+
+    #[msg("Memo at wrong index: The SPL Memo instruction must be placed at index 0 of the transaction.")]
+    MemoWrongIndex,
+
+    #[msg("Unauthorized: Only the authorized admin can perform this operation")]
+    UnauthorizedAdmin,
+
+    #[msg("Invalid mint interval: min_mint_interval must be between 0 and 3600 seconds.")]
+    InvalidMintInterval,
+
+    #[msg("Mint too frequent: Wait for the blog's min_mint_interval before minting again.")]
+    MintTooFrequent,
+
+    #[msg("Mint cooldown account required: This blog has a min_mint_interval, so mint_cooldown must be provided.")]
+    MintCooldownAccountRequired,
+
+    #[msg("Invalid image reference: Image must be empty, an ipfs://, ar:// or https:// reference, or a CIDv0 hash.")]
+    InvalidImageReference,
+
+    #[msg("Invalid content flags: Only the nsfw, spoiler and sensitive bits may be set.")]
+    InvalidContentFlags,
+
+    #[msg("Burn not reflected: The token balance did not drop by the burned amount.")]
+    BurnAmountNotReflected,
+
+    #[msg("Invalid language tag: Use ASCII letters with at most one hyphen (max 8 characters), e.g. \"en\" or \"zh-CN\".")]
+    InvalidLanguageTag,
+
+    #[msg("Invalid blog comment data format. Must be valid Borsh-serialized data.")]
+    InvalidBlogCommentDataFormat,
+
+    #[msg("Unsupported blog comment data version. Please use the correct structure version.")]
+    UnsupportedBlogCommentDataVersion,
+
+    #[msg("Empty comment: A comment must contain text.")]
+    EmptyComment,
+
+    #[msg("Comment too long: Comment must be at most 128 characters.")]
+    CommentTooLong,
+
+    #[msg("Invalid burn stats account: user_global_burn_stats must be a memo-burn UserGlobalBurnStats account.")]
+    InvalidBurnStatsAccount,
+
+    #[msg("Unexpected mint decimals: The authorized mint must have 6 decimals.")]
+    UnexpectedMintDecimals,
+
+    #[msg("Invalid blog account: Not a current or legacy-sized blog.")]
+    InvalidBlogAccount,
+
+    #[msg("Blog not migrated: Call migrate_blog to convert this blog to the current layout.")]
+    BlogNotMigrated,
 }
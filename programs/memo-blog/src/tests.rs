@@ -36,7 +36,7 @@ mod tests {
     #[test]
     fn test_version_constants() {
         assert_eq!(BURN_MEMO_VERSION, 1);
-        assert_eq!(BLOG_CREATION_DATA_VERSION, 1);
+        assert_eq!(BLOG_CREATION_DATA_VERSION, 3);
         assert_eq!(BLOG_UPDATE_DATA_VERSION, 1);
         assert_eq!(BLOG_BURN_DATA_VERSION, 1);
         assert_eq!(BLOG_MINT_DATA_VERSION, 1);
@@ -65,6 +65,8 @@ mod tests {
             name: "Test Blog".to_string(),
             description: "Test blog description".to_string(),
             image: "https://example.com/blog-image.png".to_string(),
+            content_flags: 0,
+            lang: String::new(),
         }
     }
 
@@ -86,6 +88,8 @@ mod tests {
             name: "A".to_string(), // minimum 1 char
             description: String::new(),
             image: String::new(),
+            content_flags: 0,
+            lang: String::new(),
         };
         assert!(data.validate(creator).is_ok());
     }
@@ -100,7 +104,9 @@ mod tests {
             creator: creator.to_string(),
             name: "A".repeat(MAX_BLOG_NAME_LENGTH),
             description: "D".repeat(MAX_BLOG_DESCRIPTION_LENGTH),
-            image: "I".repeat(MAX_BLOG_IMAGE_LENGTH),
+            image: format!("https://{}", "I".repeat(MAX_BLOG_IMAGE_LENGTH - "https://".len())),
+            content_flags: 0,
+            lang: String::new(),
         };
         assert!(data.validate(creator).is_ok());
     }
@@ -488,7 +494,12 @@ mod tests {
             8 + // memo_count
             8 + // burned_amount
             8 + // last_memo_time
+            8 + // min_mint_interval
+            8 + // comment_count
+            1 + // content_flags
             1 + // bump
+            4 + 8 + // lang
+            4 + 64 + // name_lower
             4 + 64 + // name
             4 + 256 + // description
             4 + 256 + // image
@@ -502,7 +513,7 @@ mod tests {
         let space = Blog::calculate_space_max();
         
         // Minimum required (without buffer) - no blog_id anymore, no minted_amount
-        let minimum = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 
+        let minimum = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + (4 + 8) + (4 + 64) +
                      (4 + 64) + (4 + 256) + (4 + 256);
         
         // Space should be greater than minimum due to buffer
@@ -837,6 +848,8 @@ mod tests {
             name: name.to_string(),
             description: description.to_string(),
             image: image.to_string(),
+            content_flags: 0,
+            lang: String::new(),
         };
         
         let payload = blog_data.try_to_vec().unwrap();
@@ -1292,4 +1305,802 @@ mod tests {
         let result = parse_blog_mint_borsh_memo(&memo_data, minter);
         assert!(result.is_err(), "Mint memo with non-zero burn_amount should fail");
     }
+
+    // ============================================================================
+    // Memo Hash Tests
+    // ============================================================================
+
+    #[test]
+    fn test_compute_memo_hash_known_vector() {
+        // SHA-256("abc")
+        let expected: [u8; 32] = [
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22, 0x23,
+            0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad,
+        ];
+        assert_eq!(compute_memo_hash(b"abc"), expected);
+    }
+
+    #[test]
+    fn test_compute_memo_hash_matches_mint_memo_bytes() {
+        let minter = Pubkey::new_unique();
+        let memo_data = create_blog_mint_memo(minter, "Minting for my blog");
+
+        assert!(parse_blog_mint_borsh_memo(&memo_data, minter).is_ok());
+        assert_eq!(compute_memo_hash(&memo_data), hash(&memo_data).to_bytes());
+
+        let other_data = create_blog_mint_memo(minter, "Another mint message");
+        assert_ne!(compute_memo_hash(&memo_data), compute_memo_hash(&other_data));
+    }
+
+    // ============================================================================
+    // Memo Placement Tests
+    // ============================================================================
+
+    #[test]
+    fn test_memo_placement_no_memo_anywhere() {
+        // No memo at all: placement check passes so the caller reports MemoRequired
+        assert!(check_memo_placement(&[]).is_ok());
+        assert!(check_memo_placement(&[Pubkey::new_unique(), crate::ID]).is_ok());
+    }
+
+    #[test]
+    fn test_memo_placement_memo_at_wrong_index() {
+        let program_ids = [Pubkey::new_unique(), crate::ID, MEMO_PROGRAM_ID];
+        let err = check_memo_placement(&program_ids).unwrap_err();
+        assert_eq!(err, ErrorCode::MemoWrongIndex.into());
+
+        // A memo at index 0 is the valid layout
+        assert!(check_memo_placement(&[MEMO_PROGRAM_ID, crate::ID]).is_ok());
+    }
+
+    // ============================================================================
+    // Program Stats Tests
+    // ============================================================================
+
+    fn create_program_stats() -> ProgramStats {
+        ProgramStats {
+            total_burned: 0,
+            total_minted: 0,
+            event_seq: 0,
+            bump: 255,
+        }
+    }
+
+    // Handler-level tests: drive instructions through the program entrypoint with
+    // fabricated accounts (CPIs are unavailable off-chain, so burns stop short of the CPI)
+
+    use anchor_lang::solana_program::entrypoint::ProgramResult;
+    use anchor_lang::solana_program::program_error::ProgramError;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_lang::InstructionData;
+    use anchor_spl::token_2022::spl_token_2022::state::{
+        Account as TokenAccountState, AccountState, Mint as MintState,
+    };
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        is_signer: bool,
+        executable: bool,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+            Self { key, owner, lamports: 1_000_000_000, data, is_signer: false, executable: false }
+        }
+
+        fn signer(key: Pubkey) -> Self {
+            Self { is_signer: true, ..Self::new(key, anchor_lang::system_program::ID, Vec::new()) }
+        }
+
+        fn program(key: Pubkey) -> Self {
+            Self { executable: true, ..Self::new(key, anchor_lang::solana_program::bpf_loader_upgradeable::ID, Vec::new()) }
+        }
+
+        fn serialized<T: AccountSerialize>(key: Pubkey, owner: Pubkey, account: &T) -> Self {
+            let mut data = Vec::new();
+            account.try_serialize(&mut data).unwrap();
+            Self::new(key, owner, data)
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(&self.key, self.is_signer, true, &mut self.lamports, &mut self.data, &self.owner, self.executable, 0)
+        }
+    }
+
+    fn run_instruction(accounts: &mut [TestAccount], data: Vec<u8>) -> ProgramResult {
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        crate::entry(&crate::ID, &infos, &data)
+    }
+
+    fn anchor_error(code: anchor_lang::error::ErrorCode) -> ProgramError {
+        anchor_lang::error::Error::from(code).into()
+    }
+
+    fn mint_account() -> TestAccount {
+        let mut data = vec![0u8; MintState::LEN];
+        let mint = MintState { supply: 1_000_000 * DECIMAL_FACTOR, decimals: MINT_DECIMALS, is_initialized: true, ..Default::default() };
+        MintState::pack(mint, &mut data).unwrap();
+        TestAccount::new(AUTHORIZED_MINT_PUBKEY, anchor_spl::token_2022::ID, data)
+    }
+
+    fn token_account(owner: Pubkey) -> TestAccount {
+        let mut data = vec![0u8; TokenAccountState::LEN];
+        let state = TokenAccountState {
+            mint: AUTHORIZED_MINT_PUBKEY,
+            owner,
+            amount: 1_000 * DECIMAL_FACTOR,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        TokenAccountState::pack(state, &mut data).unwrap();
+        TestAccount::new(Pubkey::new_unique(), anchor_spl::token_2022::ID, data)
+    }
+
+    fn program_stats_account(key: Pubkey) -> TestAccount {
+        let (_, bump) = Pubkey::find_program_address(&[b"stats"], &crate::ID);
+        TestAccount::serialized(key, crate::ID, &ProgramStats { bump, ..create_program_stats() })
+    }
+
+    fn burn_for_blog_accounts(burner: Pubkey) -> Vec<TestAccount> {
+        let (blog_key, blog_bump) = Pubkey::find_program_address(&[b"blog", burner.as_ref()], &crate::ID);
+        let (burn_stats_key, burn_stats_bump) = Pubkey::find_program_address(&[b"user_global_burn_stats", burner.as_ref()], &memo_burn::ID);
+        let burn_stats = memo_burn::UserGlobalBurnStats { user: burner, total_burned: 0, burn_count: 0, last_burn_time: 0, bump: burn_stats_bump };
+        vec![
+            TestAccount::signer(burner),
+            TestAccount::serialized(blog_key, crate::ID, &Blog { bump: blog_bump, ..create_blog(burner) }),
+            mint_account(),
+            token_account(burner),
+            TestAccount::serialized(burn_stats_key, memo_burn::ID, &burn_stats),
+            TestAccount::program(anchor_spl::token_2022::ID),
+            TestAccount::program(memo_burn::ID),
+            TestAccount::new(INSTRUCTIONS_ID, anchor_lang::solana_program::sysvar::ID, Vec::new()),
+            program_stats_account(Pubkey::find_program_address(&[b"stats"], &crate::ID).0),
+        ]
+    }
+
+    fn burn_for_blog(accounts: &mut [TestAccount], amount: u64) -> ProgramResult {
+        run_instruction(accounts, crate::instruction::BurnForBlog { amount }.data())
+    }
+
+    #[test]
+    fn test_burn_for_blog_accepts_program_stats_pda() {
+        let mut accounts = burn_for_blog_accounts(Pubkey::new_unique());
+
+        // Account validation passes and the handler body runs (rejecting the amount)
+        let err = burn_for_blog(&mut accounts, MIN_BLOG_BURN_AMOUNT - 1).unwrap_err();
+        assert_eq!(err, anchor_lang::error::Error::from(ErrorCode::BurnAmountTooSmall).into());
+    }
+
+    #[test]
+    fn test_burn_for_blog_requires_program_stats() {
+        // Omitted the way optional accounts are (program id placeholder)
+        let mut accounts = burn_for_blog_accounts(Pubkey::new_unique());
+        *accounts.last_mut().unwrap() = TestAccount::program(crate::ID);
+        let err = burn_for_blog(&mut accounts, MIN_BLOG_BURN_AMOUNT).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram));
+
+        // Left off the end of the account list
+        let mut accounts = burn_for_blog_accounts(Pubkey::new_unique());
+        accounts.pop();
+        let err = burn_for_blog(&mut accounts, MIN_BLOG_BURN_AMOUNT).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::AccountNotEnoughKeys));
+    }
+
+    #[test]
+    fn test_burn_for_blog_rejects_substituted_program_stats() {
+        let mut accounts = burn_for_blog_accounts(Pubkey::new_unique());
+        *accounts.last_mut().unwrap() = program_stats_account(Pubkey::new_unique());
+
+        let err = burn_for_blog(&mut accounts, MIN_BLOG_BURN_AMOUNT).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::ConstraintSeeds));
+    }
+
+    #[test]
+    fn test_unmigrated_blog_rejected_by_instructions() {
+        let mut accounts = burn_for_blog_accounts(Pubkey::new_unique());
+        accounts[1].data.resize(Blog::LEGACY_SPACE, 0);
+
+        let err = burn_for_blog(&mut accounts, MIN_BLOG_BURN_AMOUNT).unwrap_err();
+        assert_eq!(err, anchor_lang::error::Error::from(ErrorCode::BlogNotMigrated).into());
+    }
+
+    #[test]
+    fn test_mint_for_blog_requires_program_stats() {
+        let minter = Pubkey::new_unique();
+        let (blog_key, blog_bump) = Pubkey::find_program_address(&[b"blog", minter.as_ref()], &crate::ID);
+        let (mint_authority, _) = Pubkey::find_program_address(&[b"mint_authority"], &memo_mint::ID);
+        let mut accounts = vec![
+            TestAccount::signer(minter),
+            TestAccount::serialized(blog_key, crate::ID, &Blog { bump: blog_bump, ..create_blog(minter) }),
+            mint_account(),
+            TestAccount::new(mint_authority, anchor_lang::system_program::ID, Vec::new()),
+            token_account(minter),
+            TestAccount::program(anchor_spl::token_2022::ID),
+            TestAccount::program(memo_mint::ID),
+            TestAccount::new(INSTRUCTIONS_ID, anchor_lang::solana_program::sysvar::ID, Vec::new()),
+            // program_stats, mint_cooldown and system_program all omitted with the placeholder
+            TestAccount::program(crate::ID),
+            TestAccount::program(crate::ID),
+            TestAccount::program(crate::ID),
+        ];
+
+        let err = run_instruction(&mut accounts, crate::instruction::MintForBlog {}.data()).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram));
+    }
+
+    // ============================================================================
+    // Mint Cooldown Tests
+    // ============================================================================
+
+    fn create_mint_cooldown() -> MintCooldown {
+        MintCooldown {
+            last_mint_time: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_min_mint_interval_bounds() {
+        assert!(Blog::validate_min_mint_interval(0).is_ok());
+        assert!(Blog::validate_min_mint_interval(MAX_MINT_INTERVAL_SECONDS).is_ok());
+
+        let err = Blog::validate_min_mint_interval(MAX_MINT_INTERVAL_SECONDS + 1).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidMintInterval.into());
+        let err = Blog::validate_min_mint_interval(-1).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidMintInterval.into());
+    }
+
+    #[test]
+    fn test_mint_cooldown_boundary() {
+        let mut cooldown = create_mint_cooldown();
+
+        // First mint is never throttled
+        assert!(cooldown.check_and_record(60, 1_000).is_ok());
+
+        // One second short of the interval is rejected and leaves the state untouched
+        let err = cooldown.check_and_record(60, 1_059).unwrap_err();
+        assert_eq!(err, ErrorCode::MintTooFrequent.into());
+        assert_eq!(cooldown.last_mint_time, 1_000);
+
+        // Exactly the interval is allowed
+        assert!(cooldown.check_and_record(60, 1_060).is_ok());
+        assert_eq!(cooldown.last_mint_time, 1_060);
+    }
+
+    #[test]
+    fn test_mint_cooldown_zero_interval_never_throttles() {
+        let mut cooldown = create_mint_cooldown();
+
+        assert!(cooldown.check_and_record(0, 1_000).is_ok());
+        assert!(cooldown.check_and_record(0, 1_000).is_ok());
+        assert!(cooldown.check_and_record(0, 1_001).is_ok());
+    }
+
+    #[test]
+    fn test_mint_cooldown_is_independent_per_user() {
+        let creator = Pubkey::new_unique();
+        let blog_key = Pubkey::find_program_address(&[b"blog", creator.as_ref()], &crate::ID).0;
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+
+        // Each user gets their own cooldown PDA for the same blog
+        let cooldown_pda = |user: &Pubkey| {
+            Pubkey::find_program_address(&[b"mint_cooldown", blog_key.as_ref(), user.as_ref()], &crate::ID).0
+        };
+        assert_ne!(cooldown_pda(&alice), cooldown_pda(&bob));
+
+        let mut alice_cooldown = create_mint_cooldown();
+        let mut bob_cooldown = create_mint_cooldown();
+
+        assert!(alice_cooldown.check_and_record(300, 1_000).is_ok());
+        // Alice is throttled, Bob is not
+        assert!(alice_cooldown.check_and_record(300, 1_100).is_err());
+        assert!(bob_cooldown.check_and_record(300, 1_100).is_ok());
+        assert!(bob_cooldown.check_and_record(300, 1_200).is_err());
+        assert!(alice_cooldown.check_and_record(300, 1_300).is_ok());
+    }
+
+    // ============================================================================
+    // Image Reference Tests
+    // ============================================================================
+
+    const TEST_CID_V0: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+    #[test]
+    fn test_image_reference_accepted_schemes() {
+        assert!(validate_image_reference("").is_ok());
+        assert!(validate_image_reference("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").is_ok());
+        assert!(validate_image_reference("ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U").is_ok());
+        assert!(validate_image_reference("https://example.com/image.png").is_ok());
+
+        assert_eq!(TEST_CID_V0.len(), CID_V0_LENGTH);
+        assert!(validate_image_reference(TEST_CID_V0).is_ok());
+    }
+
+    #[test]
+    fn test_image_reference_rejects_bare_filename() {
+        let err = validate_image_reference("image.png").unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidImageReference.into());
+
+        // Plain http and CID look-alikes are rejected too
+        assert!(validate_image_reference("http://example.com/image.png").is_err());
+        assert!(validate_image_reference(&TEST_CID_V0[..CID_V0_LENGTH - 1]).is_err());
+        assert!(validate_image_reference(&TEST_CID_V0.replace('Y', "0")).is_err());
+    }
+
+    #[test]
+    fn test_creation_data_rejects_invalid_image_reference() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_blog_creation_data(creator);
+        data.image = "image.png".to_string();
+        let err = data.validate(creator).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidImageReference.into());
+
+        data.image = TEST_CID_V0.to_string();
+        assert!(data.validate(creator).is_ok());
+    }
+
+    #[test]
+    fn test_update_data_rejects_invalid_image_reference() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_blog_update_data(creator);
+        data.image = Some("image.png".to_string());
+        let err = data.validate(creator).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidImageReference.into());
+
+        data.image = Some(TEST_CID_V0.to_string());
+        assert!(data.validate(creator).is_ok());
+    }
+
+    // ============================================================================
+    // BlogCreationData v2 (content_flags) Tests
+    // ============================================================================
+
+    /// Legacy v1 layout, as serialized by existing clients
+    #[derive(AnchorSerialize)]
+    struct BlogCreationDataV1 {
+        version: u8,
+        category: String,
+        operation: String,
+        creator: String,
+        name: String,
+        description: String,
+        image: String,
+    }
+
+    #[test]
+    fn test_blog_creation_data_v1_payload_decodes() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        let legacy = BlogCreationDataV1 {
+            version: 1,
+            category: EXPECTED_CATEGORY.to_string(),
+            operation: EXPECTED_OPERATION.to_string(),
+            creator: creator.to_string(),
+            name: "Old Blog".to_string(),
+            description: String::new(),
+            image: String::new(),
+        };
+        let bytes = legacy.try_to_vec().unwrap();
+
+        let decoded = BlogCreationData::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded.content_flags, 0);
+        assert!(decoded.validate(creator).is_ok());
+        assert_eq!(decoded.try_to_vec().unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_blog_creation_data_nsfw_flag() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_blog_creation_data(creator);
+        data.content_flags = CONTENT_FLAG_NSFW;
+
+        let decoded = BlogCreationData::try_from_slice(&data.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.content_flags, CONTENT_FLAG_NSFW);
+        assert!(decoded.validate(creator).is_ok());
+    }
+
+    #[test]
+    fn test_blog_creation_data_rejects_reserved_flag_bits() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_blog_creation_data(creator);
+        data.content_flags = 0b1111_1000;
+        let err = data.validate(creator).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidContentFlags.into());
+
+        // Flags cannot be carried by a v1 payload
+        data.version = 1;
+        data.content_flags = CONTENT_FLAG_NSFW;
+        let err = data.validate(creator).unwrap_err();
+        assert_eq!(err, ErrorCode::UnsupportedBlogDataVersion.into());
+    }
+
+    // ============================================================================
+    // Language Tag Tests
+    // ============================================================================
+
+    #[test]
+    fn test_blog_creation_data_valid_lang() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        for lang in ["en", "zh-CN", "pt-BR", "fil"] {
+            let mut data = create_valid_blog_creation_data(creator);
+            data.lang = lang.to_string();
+
+            let decoded = BlogCreationData::try_from_slice(&data.try_to_vec().unwrap()).unwrap();
+            assert_eq!(decoded.lang, lang);
+            assert!(decoded.validate(creator).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_blog_creation_data_invalid_lang() {
+        let creator = Pubkey::new_unique();
+        for lang in ["en1", "zh-CN-x", "-en", "en-", "en_US", "abcdefghi"] {
+            let mut data = create_valid_blog_creation_data(creator);
+            data.lang = lang.to_string();
+            let err = data.validate(creator).unwrap_err();
+            assert_eq!(err, ErrorCode::InvalidLanguageTag.into());
+        }
+    }
+
+    #[test]
+    fn test_blog_creation_data_default_empty_lang() {
+        let creator = Pubkey::new_unique();
+        let data = create_valid_blog_creation_data(creator);
+        assert!(data.lang.is_empty());
+        assert!(data.validate(creator).is_ok());
+
+        // A v2 payload cannot carry a language tag
+        let mut data = create_valid_blog_creation_data(creator);
+        data.version = 2;
+        data.lang = "en".to_string();
+        assert_eq!(data.validate(creator).unwrap_err(), ErrorCode::UnsupportedBlogDataVersion.into());
+    }
+
+    // ============================================================================
+    // Burn Balance Check Tests
+    // ============================================================================
+
+    #[test]
+    fn test_burn_reflected_exact_amount() {
+        assert!(check_burn_reflected(5_000_000, 4_000_000, 1_000_000).is_ok());
+        assert!(check_burn_reflected(1_000_000, 0, 1_000_000).is_ok());
+    }
+
+    #[test]
+    fn test_burn_not_reflected_rejected() {
+        // A burn program that under-burns (or burns nothing) is rejected
+        let err = check_burn_reflected(5_000_000, 4_500_000, 1_000_000).unwrap_err();
+        assert_eq!(err, ErrorCode::BurnAmountNotReflected.into());
+        assert!(check_burn_reflected(5_000_000, 5_000_000, 1_000_000).is_err());
+
+        // So is a balance that grew or dropped by more than the amount
+        assert!(check_burn_reflected(5_000_000, 6_000_000, 1_000_000).is_err());
+        assert!(check_burn_reflected(5_000_000, 3_000_000, 1_000_000).is_err());
+    }
+
+    // ============================================================================
+    // Burn and Comment Tests
+    // ============================================================================
+
+    fn create_valid_blog_comment_data(commenter: Pubkey) -> BlogCommentData {
+        BlogCommentData {
+            version: BLOG_COMMENT_DATA_VERSION,
+            category: EXPECTED_CATEGORY.to_string(),
+            operation: EXPECTED_BURN_AND_COMMENT_OPERATION.to_string(),
+            commenter: commenter.to_string(),
+            comment: "Great post, thanks!".to_string(),
+        }
+    }
+
+    fn create_blog_comment_memo(burn_amount: u64, commenter: Pubkey, comment: &str) -> Vec<u8> {
+        use borsh::BorshSerialize;
+
+        let mut comment_data = create_valid_blog_comment_data(commenter);
+        comment_data.comment = comment.to_string();
+
+        let burn_memo = BurnMemo {
+            version: BURN_MEMO_VERSION,
+            burn_amount,
+            payload: comment_data.try_to_vec().unwrap(),
+        };
+        general_purpose::STANDARD.encode(burn_memo.try_to_vec().unwrap()).into_bytes()
+    }
+
+    fn create_blog(creator: Pubkey) -> Blog {
+        Blog {
+            creator,
+            created_at: 0,
+            last_updated: 0,
+            name: "Test Blog".to_string(),
+            description: String::new(),
+            image: String::new(),
+            memo_count: 0,
+            burned_amount: MIN_BLOG_BURN_AMOUNT,
+            last_memo_time: 0,
+            bump: 255,
+            min_mint_interval: 0,
+            content_flags: 0,
+            lang: String::new(),
+            comment_count: 0,
+            name_lower: "test blog".to_string(),
+        }
+    }
+
+    fn create_comment(comment: &str, timestamp: i64) -> BlogComment {
+        BlogComment {
+            commenter: Pubkey::new_unique(),
+            amount: MIN_BLOG_BURN_AMOUNT,
+            comment: comment.to_string(),
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_blog_comment_data_validation() {
+        let commenter = Pubkey::new_unique();
+        assert!(create_valid_blog_comment_data(commenter).validate(commenter).is_ok());
+
+        let mut data = create_valid_blog_comment_data(commenter);
+        data.comment = "C".repeat(MAX_COMMENT_LENGTH);
+        assert!(data.validate(commenter).is_ok());
+
+        data.comment = "C".repeat(MAX_COMMENT_LENGTH + 1);
+        assert_eq!(data.validate(commenter).unwrap_err(), ErrorCode::CommentTooLong.into());
+
+        data.comment = "   ".to_string();
+        assert_eq!(data.validate(commenter).unwrap_err(), ErrorCode::EmptyComment.into());
+
+        let mut data = create_valid_blog_comment_data(commenter);
+        data.operation = EXPECTED_BURN_FOR_BLOG_OPERATION.to_string();
+        assert_eq!(data.validate(commenter).unwrap_err(), ErrorCode::InvalidOperation.into());
+
+        let data = create_valid_blog_comment_data(commenter);
+        let err = data.validate(Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, ErrorCode::BurnerPubkeyMismatch.into());
+    }
+
+    #[test]
+    fn test_parse_blog_comment_memo() {
+        let commenter = Pubkey::new_unique();
+        let amount = 5 * DECIMAL_FACTOR;
+        let memo_data = create_blog_comment_memo(amount, commenter, "Loved this one");
+
+        // The memo carries both the burn amount and the comment text
+        let comment_data = parse_blog_comment_borsh_memo(&memo_data, amount, commenter).unwrap();
+        assert_eq!(comment_data.comment, "Loved this one");
+
+        let err = parse_blog_comment_borsh_memo(&memo_data, amount + DECIMAL_FACTOR, commenter).err().unwrap();
+        assert_eq!(err, ErrorCode::BurnAmountMismatch.into());
+
+        // A plain burn_for_blog memo is not a comment
+        let burn_memo = create_blog_burn_memo(amount, commenter, "Loved this one");
+        assert!(parse_blog_comment_borsh_memo(&burn_memo, amount, commenter).is_err());
+    }
+
+    #[test]
+    fn test_record_comment_updates_counters() {
+        let mut blog = create_blog(Pubkey::new_unique());
+
+        blog.record_comment(3 * DECIMAL_FACTOR);
+        blog.record_comment(2 * DECIMAL_FACTOR);
+
+        assert_eq!(blog.comment_count, 2);
+        assert_eq!(blog.burned_amount, MIN_BLOG_BURN_AMOUNT + 5 * DECIMAL_FACTOR);
+        // Comments are not burn_for_blog/mint_for_blog memos
+        assert_eq!(blog.memo_count, 0);
+        assert_eq!(blog.last_memo_time, 0);
+    }
+
+    #[test]
+    fn test_comment_board_insertion_and_wraparound() {
+        let mut board = BlogCommentBoard {
+            blog_creator: Pubkey::new_unique(),
+            current_index: 0,
+            comments: Vec::new(),
+            bump: 255,
+        };
+
+        board.add_comment(create_comment("first", 1));
+        assert_eq!(board.comments.len(), 1);
+        assert_eq!(board.comments[0].comment, "first");
+        assert_eq!(board.current_index, 1);
+
+        for i in 1..BlogCommentBoard::MAX_COMMENTS as i64 {
+            board.add_comment(create_comment("filler", i + 1));
+        }
+        assert_eq!(board.comments.len(), BlogCommentBoard::MAX_COMMENTS);
+        assert_eq!(board.current_index, 0);
+
+        // Once full, the oldest comment is overwritten
+        board.add_comment(create_comment("newest", 1_000));
+        assert_eq!(board.comments.len(), BlogCommentBoard::MAX_COMMENTS);
+        assert_eq!(board.comments[0].comment, "newest");
+        assert_eq!(board.current_index, 1);
+    }
+
+    #[test]
+    fn test_comment_board_space() {
+        // Created with init, so it must fit the 10 KiB CPI account limit
+        let space = BlogCommentBoard::SPACE;
+        assert!(space <= 10_240);
+
+        use borsh::BorshSerialize;
+        let board = BlogCommentBoard {
+            blog_creator: Pubkey::new_unique(),
+            current_index: 0,
+            comments: vec![create_comment(&"C".repeat(MAX_COMMENT_LENGTH), i64::MAX); BlogCommentBoard::MAX_COMMENTS],
+            bump: 255,
+        };
+        assert!(8 + board.try_to_vec().unwrap().len() <= space);
+    }
+
+    // ============================================================================
+    // Blog Layout Migration Tests
+    // ============================================================================
+
+    /// Blog layout before min_mint_interval, as written by earlier program versions
+    #[derive(AnchorSerialize)]
+    struct BlogV1 {
+        creator: Pubkey,
+        created_at: i64,
+        last_updated: i64,
+        name: String,
+        description: String,
+        image: String,
+        memo_count: u64,
+        burned_amount: u64,
+        last_memo_time: i64,
+        bump: u8,
+    }
+
+    fn legacy_blog() -> BlogV1 {
+        BlogV1 {
+            creator: Pubkey::new_unique(),
+            created_at: 1_600_000_000,
+            last_updated: 1_650_000_000,
+            name: "Old Blog".to_string(),
+            description: "Written before mint cooldowns".to_string(),
+            image: String::new(),
+            memo_count: 5,
+            burned_amount: MIN_BLOG_BURN_AMOUNT,
+            last_memo_time: 1_660_000_000,
+            bump: 254,
+        }
+    }
+
+    /// A legacy-sized account holding `legacy`, followed by stale bytes from longer
+    /// strings written before an update shrank them
+    fn legacy_blog_data(legacy: &BlogV1) -> Vec<u8> {
+        let mut data = vec![b'x'; Blog::LEGACY_SPACE];
+        let encoded = legacy.try_to_vec().unwrap();
+        data[..8].copy_from_slice(Blog::DISCRIMINATOR);
+        data[8..8 + encoded.len()].copy_from_slice(&encoded);
+        data
+    }
+
+    #[test]
+    fn test_blog_layout_extends_legacy_layout() {
+        let legacy = legacy_blog();
+        let blog = Blog::from_legacy(&legacy_blog_data(&legacy)).unwrap();
+
+        // The current layout starts with the legacy fields, bump included, in the same order
+        let legacy_bytes = legacy.try_to_vec().unwrap();
+        let current_bytes = blog.try_to_vec().unwrap();
+        assert_eq!(&current_bytes[..legacy_bytes.len()], legacy_bytes.as_slice());
+        assert!(Blog::calculate_space_max() > Blog::LEGACY_SPACE);
+    }
+
+    #[test]
+    fn test_blog_from_legacy_ignores_stale_bytes() {
+        let legacy = legacy_blog();
+        let blog = Blog::from_legacy(&legacy_blog_data(&legacy)).unwrap();
+
+        assert_eq!(blog.creator, legacy.creator);
+        assert_eq!(blog.description, legacy.description);
+        assert_eq!(blog.memo_count, 5);
+        assert_eq!(blog.bump, 254);
+
+        // Appended fields start empty rather than decoding the stale tail
+        assert_eq!(blog.name_lower, "old blog");
+        assert_eq!(blog.min_mint_interval, 0);
+        assert_eq!(blog.lang, "");
+        assert_eq!(blog.comment_count, 0);
+    }
+
+    #[test]
+    fn test_blog_needs_migration() {
+        let legacy_data = legacy_blog_data(&legacy_blog());
+        assert!(Blog::needs_migration(&legacy_data).unwrap());
+
+        let mut current = Vec::new();
+        create_blog(Pubkey::new_unique()).try_serialize(&mut current).unwrap();
+        current.resize(Blog::calculate_space_max(), 0);
+        assert!(!Blog::needs_migration(&current).unwrap());
+        let err = Blog::from_legacy(&current).err().unwrap();
+        assert_eq!(err, ErrorCode::InvalidBlogAccount.into());
+
+        // Anything else is not a blog
+        let err = Blog::needs_migration(&legacy_data[..legacy_data.len() - 1]).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBlogAccount.into());
+        let mut foreign = legacy_data.clone();
+        foreign[0] ^= 1;
+        let err = Blog::needs_migration(&foreign).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBlogAccount.into());
+    }
+
+    // ============================================================================
+    // Lowercase Name Mirror Tests
+    // ============================================================================
+
+    #[test]
+    fn test_lowercase_name() {
+        assert_eq!(lowercase_name("MyProject"), "myproject");
+        assert_eq!(lowercase_name("Dev Notes 2026"), "dev notes 2026");
+        // Never longer than the original, so it fits the name's space budget
+        let name = "İ".repeat(MAX_BLOG_NAME_LENGTH / 2);
+        assert!(lowercase_name(&name).len() <= name.len());
+    }
+
+    // ============================================================================
+    // Burn Stats Account Verification Tests
+    // ============================================================================
+
+    /// Run verify_burn_stats_account against an account with the given owner and data
+    fn verify_burn_stats_with(owner: Pubkey, mut data: Vec<u8>) -> Result<()> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1_000_000u64;
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        verify_burn_stats_account(&account)
+    }
+
+    fn burn_stats_data() -> Vec<u8> {
+        let mut data = memo_burn::UserGlobalBurnStats::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0u8; 64]);
+        data
+    }
+
+    #[test]
+    fn test_verify_burn_stats_account_accepts_memo_burn_account() {
+        assert!(verify_burn_stats_with(memo_burn::ID, burn_stats_data()).is_ok());
+    }
+
+    #[test]
+    fn test_verify_burn_stats_account_rejects_wrong_owner() {
+        let err = verify_burn_stats_with(Pubkey::new_unique(), burn_stats_data()).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBurnStatsAccount.into());
+    }
+
+    #[test]
+    fn test_verify_burn_stats_account_rejects_wrong_discriminator() {
+        let err = verify_burn_stats_with(memo_burn::ID, vec![0u8; 72]).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBurnStatsAccount.into());
+
+        let err = verify_burn_stats_with(memo_burn::ID, Vec::new()).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBurnStatsAccount.into());
+    }
+
+    // ============================================================================
+    // Mint Decimals Tests
+    // ============================================================================
+
+    #[test]
+    fn test_six_decimal_mint_accepted() {
+        assert!(mint_decimals_valid(6));
+        assert_eq!(10u64.pow(MINT_DECIMALS as u32), DECIMAL_FACTOR);
+    }
+
+    #[test]
+    fn test_nine_decimal_mint_rejected() {
+        assert!(!mint_decimals_valid(9));
+        assert!(!mint_decimals_valid(0));
+    }
 }
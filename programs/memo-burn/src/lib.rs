@@ -40,6 +40,7 @@ pub const MAX_BORSH_DATA_SIZE: usize = MEMO_MAX_LENGTH;
 
 // Token decimal factor (decimal=6 means 1 token = 1,000,000 units)
 pub const DECIMAL_FACTOR: u64 = 1_000_000;
+pub const MINT_DECIMALS: u8 = 6; // Decimals the authorized mint must have (DECIMAL_FACTOR = 10^MINT_DECIMALS)
 
 // Minimum burn requirement (1 token)  
 pub const MIN_BURN_TOKENS: u64 = 1;
@@ -58,6 +59,9 @@ pub const BURN_MEMO_VERSION: u8 = 1;
 // 4. This higher limit ensures active users' contributions are fully tracked
 pub const MAX_USER_GLOBAL_BURN_AMOUNT: u64 = 18_000_000_000_000 * DECIMAL_FACTOR; // Reserve space for safety
 
+// Cumulative burn milestones (in tokens) that emit a BurnMilestoneEvent when first crossed
+pub const BURN_MILESTONES_TOKENS: [u64; 4] = [1_000, 10_000, 100_000, 1_000_000];
+
 /// User global burn statistics tracking account
 #[account]
 pub struct UserGlobalBurnStats {
@@ -77,6 +81,14 @@ impl UserGlobalBurnStats {
         1;   // bump (u8)
 }
 
+/// Event emitted when a burn pushes a user's cumulative total past a milestone
+#[event]
+pub struct BurnMilestoneEvent {
+    pub user: Pubkey,
+    pub milestone: u64,     // Milestone crossed (in tokens)
+    pub total_burned: u64,  // User's cumulative total after the burn (in units)
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BurnMemo {
     /// version of the BurnMemo structure (for future compatibility)
@@ -108,74 +120,120 @@ pub mod memo_burn {
 
     /// Process burn operation with Borsh memo validation
     pub fn process_burn(ctx: Context<ProcessBurn>, amount: u64) -> Result<()> {
-        // Check burn amount is at least 1 token and is a multiple of DECIMAL_FACTOR (decimal=6)
-        if amount < DECIMAL_FACTOR * MIN_BURN_TOKENS {
-            return Err(ErrorCode::BurnAmountTooSmall.into());
-        }
-        
-        // Check burn amount upper limit (prevent excessive burns)
-        if amount > MAX_BURN_PER_TX {
-            return Err(ErrorCode::BurnAmountTooLarge.into());
-        }
-        
-        // Check burn amount is a multiple of DECIMAL_FACTOR (decimal=6)
-        if amount % DECIMAL_FACTOR != 0 {
-            return Err(ErrorCode::InvalidBurnAmount.into());
-        }
+        execute_burn(ctx, amount)
+    }
 
-        // Check memo instruction with length validation
-        let (memo_found, memo_data) = check_memo_instruction(ctx.accounts.instructions.as_ref())?;
-        if !memo_found {
-            return Err(ErrorCode::MemoRequired.into());
-        }
+    /// Process burn operation with the amount given in whole tokens.
+    /// The amount is converted to units before validation, so the memo's
+    /// burn_amount must still be declared in units.
+    pub fn process_burn_tokens(ctx: Context<ProcessBurn>, token_amount: u64) -> Result<()> {
+        let amount = whole_tokens_to_units(token_amount)?;
+        execute_burn(ctx, amount)
+    }
+}
 
-        // Validate Borsh memo contains correct amount matching the burn amount
-        validate_memo_amount(&memo_data, amount)?;
+/// Convert a whole-token amount to units (decimal=6)
+pub fn whole_tokens_to_units(token_amount: u64) -> Result<u64> {
+    token_amount
+        .checked_mul(DECIMAL_FACTOR)
+        .ok_or_else(|| ErrorCode::BurnAmountTooLarge.into())
+}
 
-        let token_count = amount / DECIMAL_FACTOR;
+/// Milestones (in tokens) crossed when a user's cumulative total goes from `old_total` to
+/// `new_total` units. total_burned never decreases, so each milestone is crossed exactly once
+/// and the previous total is enough to tell a first crossing from a later burn.
+pub fn crossed_milestones(old_total: u64, new_total: u64) -> Vec<u64> {
+    BURN_MILESTONES_TOKENS
+        .iter()
+        .copied()
+        .filter(|milestone| {
+            let threshold = milestone * DECIMAL_FACTOR;
+            old_total < threshold && new_total >= threshold
+        })
+        .collect()
+}
 
-        token_2022::burn(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                token_2022::Burn {
-                    mint: ctx.accounts.mint.to_account_info(),
-                    from: ctx.accounts.token_account.to_account_info(),
-                    authority: ctx.accounts.user.to_account_info(),
-                },
-            ),
-            amount,
-        )?;
+/// Burn `amount` units after validating it and the memo (shared by both burn instructions)
+fn execute_burn(ctx: Context<ProcessBurn>, amount: u64) -> Result<()> {
+    // Check burn amount is at least 1 token and is a multiple of DECIMAL_FACTOR (decimal=6)
+    if amount < DECIMAL_FACTOR * MIN_BURN_TOKENS {
+        return Err(ErrorCode::BurnAmountTooSmall.into());
+    }
+    
+    // Check burn amount upper limit (prevent excessive burns)
+    if amount > MAX_BURN_PER_TX {
+        return Err(ErrorCode::BurnAmountTooLarge.into());
+    }
+    
+    // Check burn amount is a multiple of DECIMAL_FACTOR (decimal=6)
+    if amount % DECIMAL_FACTOR != 0 {
+        return Err(ErrorCode::InvalidBurnAmount.into());
+    }
 
-        // Update user global burn statistics tracking (now required)
-        let user_burn_stats = &mut ctx.accounts.user_global_burn_stats;
-        
-        // Check for overflow before adding
-        let new_total = user_burn_stats.total_burned.saturating_add(amount);
-        
-        // Apply maximum limit
-        if new_total > MAX_USER_GLOBAL_BURN_AMOUNT {
-            user_burn_stats.total_burned = MAX_USER_GLOBAL_BURN_AMOUNT;
-            msg!("User global burn amount reached maximum limit: {}", MAX_USER_GLOBAL_BURN_AMOUNT);
-        } else {
-            user_burn_stats.total_burned = new_total;
-        }
-        
-        // Update burn count with overflow protection
-        user_burn_stats.burn_count = user_burn_stats.burn_count.saturating_add(1);
-        
-        // Update last burn time
-        user_burn_stats.last_burn_time = Clock::get()?.unix_timestamp;
-        
-        msg!("Updated user global burn stats: total_burned={} units ({} tokens), burn_count={}", 
-             user_burn_stats.total_burned, 
-             user_burn_stats.total_burned / DECIMAL_FACTOR,
-             user_burn_stats.burn_count);
+    // Check memo instruction with length validation
+    let (memo_found, memo_data) = check_memo_instruction(ctx.accounts.instructions.as_ref())?;
+    if !memo_found {
+        return Err(ErrorCode::MemoRequired.into());
+    }
 
-        msg!("Successfully burned {} tokens ({} units) with Borsh+Base64 memo validation", 
-             token_count, amount);
-        
-        Ok(())
+    // Validate Borsh memo contains correct amount matching the burn amount
+    validate_memo_amount(&memo_data, amount)?;
+
+    let token_count = amount / DECIMAL_FACTOR;
+
+    // Burn removes exactly `amount`: Token-2022 transfer fees apply to transfer_checked only,
+    // so a fee-bearing mint needs no adjustment to the accounting below
+    token_2022::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_2022::Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // Update user global burn statistics tracking (now required)
+    let user_burn_stats = &mut ctx.accounts.user_global_burn_stats;
+    
+    // Check for overflow before adding
+    let old_total = user_burn_stats.total_burned;
+    let new_total = user_burn_stats.total_burned.saturating_add(amount);
+    
+    // Apply maximum limit
+    if new_total > MAX_USER_GLOBAL_BURN_AMOUNT {
+        user_burn_stats.total_burned = MAX_USER_GLOBAL_BURN_AMOUNT;
+        msg!("User global burn amount reached maximum limit: {}", MAX_USER_GLOBAL_BURN_AMOUNT);
+    } else {
+        user_burn_stats.total_burned = new_total;
     }
+    
+    // Update burn count with overflow protection
+    user_burn_stats.burn_count = user_burn_stats.burn_count.saturating_add(1);
+    
+    // Update last burn time
+    user_burn_stats.last_burn_time = Clock::get()?.unix_timestamp;
+    
+    msg!("Updated user global burn stats: total_burned={} units ({} tokens), burn_count={}", 
+         user_burn_stats.total_burned, 
+         user_burn_stats.total_burned / DECIMAL_FACTOR,
+         user_burn_stats.burn_count);
+
+    for milestone in crossed_milestones(old_total, user_burn_stats.total_burned) {
+        emit!(BurnMilestoneEvent {
+            user: ctx.accounts.user.key(),
+            milestone,
+            total_burned: user_burn_stats.total_burned,
+        });
+        msg!("User {} crossed the {} token burn milestone", ctx.accounts.user.key(), milestone);
+    }
+
+    msg!("Successfully burned {} tokens ({} units) with Borsh+Base64 memo validation", 
+         token_count, amount);
+    
+    Ok(())
 }
 
 /// validate Borsh-formatted memo data (with Base64 decoding)
@@ -271,6 +329,11 @@ fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize)
     Ok((true, memo_data.to_vec()))
 }
 
+/// Whether a mint has the decimals DECIMAL_FACTOR assumes (guards against a recreated mint)
+pub fn mint_decimals_valid(decimals: u8) -> bool {
+    decimals == MINT_DECIMALS
+}
+
 /// Check for memo instruction at REQUIRED index 0
 /// 
 /// IMPORTANT: This contract enforces memo at index 0:
@@ -287,7 +350,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     // to leave index 0 available for memo
     if current_index < 1 {
         msg!("process_burn must be at index 1 or later, but current instruction is at index {}", current_index);
-        return Ok((false, vec![]));
+        return memo_not_at_index_zero(instructions);
     }
     
     // Check that index 0 contains the memo instruction
@@ -298,7 +361,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
                 validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
             } else {
                 msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
-                Ok((false, vec![]))
+                memo_not_at_index_zero(instructions)
             }
         },
         Err(e) => {
@@ -308,6 +371,28 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     }
 }
 
+/// Called when index 0 holds no memo: if a memo exists elsewhere in the transaction,
+/// fail with MemoWrongIndex instead of letting the caller report MemoRequired
+fn memo_not_at_index_zero(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    let program_ids: Vec<Pubkey> = (0..)
+        .map_while(|i| anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(i, instructions).ok())
+        .map(|ix| ix.program_id)
+        .collect();
+    check_memo_placement(&program_ids)?;
+    Ok((false, vec![]))
+}
+
+/// Reject a transaction whose SPL Memo instruction is present but not at index 0
+fn check_memo_placement(program_ids: &[Pubkey]) -> Result<()> {
+    if let Some(index) = program_ids.iter().position(|id| *id == MEMO_PROGRAM_ID) {
+        if index > 0 {
+            msg!("Memo instruction found at index {}, but it must be placed at index 0", index);
+            return Err(ErrorCode::MemoWrongIndex.into());
+        }
+    }
+    Ok(())
+}
+
 /// Account structure for initializing user global burn statistics
 #[derive(Accounts)]
 pub struct InitializeUserGlobalBurnStats<'info> {
@@ -333,7 +418,8 @@ pub struct ProcessBurn<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -403,6 +489,16 @@ pub enum ErrorCode {
 
     #[msg("Unauthorized user. User mismatch in global burn statistics account.")]
     UnauthorizedUser,
+
+    #[msg("Memo at wrong index: The SPL Memo instruction must be placed at index 0 of the transaction.")]
+    MemoWrongIndex,
+
+    // No longer returned (burns are exempt from transfer fees); kept so later error codes keep their numbers
+    #[msg("Transfer fee not supported: The mint's transfer-fee extension would change the burned amount.")]
+    TransferFeeNotSupported,
+
+    #[msg("Unexpected mint decimals: The authorized mint must have 6 decimals.")]
+    UnexpectedMintDecimals,
 }
 
 // ============================================================================
@@ -3,6 +3,8 @@
 //! This test suite provides comprehensive coverage of all core functions:
 //! - validate_memo_amount: Borsh+Base64 memo validation with burn amount verification
 //! - validate_memo_length: Memo length validation (69-800 bytes)
+//! - whole_tokens_to_units: Whole-token burn amount conversion
+//! - Token-2022 burns: a transfer-fee mint still burns the full amount
 //! - BurnMemo structure: Serialization and deserialization
 //! - Constants: Verify all constant values and relationships
 
@@ -1060,6 +1062,235 @@ mod integration_tests {
     }
 }
 
+// ============================================================================
+// Tests for check_memo_placement()
+// ============================================================================
+
+#[cfg(test)]
+mod memo_placement_tests {
+    use super::*;
+
+    #[test]
+    fn test_memo_placement_no_memo_anywhere() {
+        // No memo at all: placement check passes so the caller reports MemoRequired
+        assert!(check_memo_placement(&[]).is_ok());
+        assert!(check_memo_placement(&[Pubkey::new_unique(), crate::ID]).is_ok());
+    }
+
+    #[test]
+    fn test_memo_placement_memo_at_wrong_index() {
+        let program_ids = [Pubkey::new_unique(), crate::ID, MEMO_PROGRAM_ID];
+        let err = check_memo_placement(&program_ids).unwrap_err();
+        assert_eq!(err, ErrorCode::MemoWrongIndex.into());
+
+        // A memo at index 0 is the valid layout
+        assert!(check_memo_placement(&[MEMO_PROGRAM_ID, crate::ID]).is_ok());
+    }
+}
+
+// ============================================================================
+// Tests for whole_tokens_to_units()
+// ============================================================================
+
+#[cfg(test)]
+mod whole_token_tests {
+    use super::*;
+
+    fn create_valid_memo(burn_amount: u64) -> Vec<u8> {
+        let memo = BurnMemo {
+            version: BURN_MEMO_VERSION,
+            burn_amount,
+            payload: b"whole token burn".to_vec(),
+        };
+        general_purpose::STANDARD.encode(borsh::to_vec(&memo).unwrap()).into_bytes()
+    }
+
+    #[test]
+    fn test_whole_tokens_converted_to_units() {
+        assert_eq!(whole_tokens_to_units(420).unwrap(), 420 * DECIMAL_FACTOR);
+        assert_eq!(whole_tokens_to_units(1).unwrap(), DECIMAL_FACTOR);
+        assert_eq!(whole_tokens_to_units(0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_both_modes_burn_identical_amount() {
+        // 420 whole tokens and 420 * DECIMAL_FACTOR units burn the same amount
+        let units = 420 * DECIMAL_FACTOR;
+        let converted = whole_tokens_to_units(420).unwrap();
+        assert_eq!(converted, units);
+
+        // The memo declares units in both modes
+        let memo_data = create_valid_memo(units);
+        assert!(validate_memo_amount(&memo_data, units).is_ok());
+        assert!(validate_memo_amount(&memo_data, converted).is_ok());
+
+        // A memo declaring whole tokens does not match the converted amount
+        let token_memo = create_valid_memo(420);
+        let err = validate_memo_amount(&token_memo, converted).unwrap_err();
+        assert_eq!(err, ErrorCode::BurnAmountMismatch.into());
+    }
+
+    #[test]
+    fn test_whole_tokens_overflow() {
+        let err = whole_tokens_to_units(u64::MAX / DECIMAL_FACTOR + 1).unwrap_err();
+        assert_eq!(err, ErrorCode::BurnAmountTooLarge.into());
+
+        // The largest allowed burn converts exactly
+        let max_tokens = MAX_BURN_PER_TX / DECIMAL_FACTOR;
+        assert_eq!(whole_tokens_to_units(max_tokens).unwrap(), MAX_BURN_PER_TX);
+    }
+}
+
+// ============================================================================
+// Token-2022 transfer-fee burns
+// ============================================================================
+
+#[cfg(test)]
+mod transfer_fee_tests {
+    use super::*;
+    use anchor_spl::token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::{TransferFee, TransferFeeConfig},
+            BaseStateWithExtensions, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensions,
+            StateWithExtensionsMut,
+        },
+        processor::Processor,
+        state::{Account as TokenAccountState, AccountState, Mint as MintState},
+    };
+
+    const SUPPLY: u64 = 1_000_000 * DECIMAL_FACTOR;
+    const BALANCE: u64 = 1_000 * DECIMAL_FACTOR;
+
+    /// Mint with a 1% transfer fee (capped at 5 tokens)
+    fn fee_mint_data() -> Vec<u8> {
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&[ExtensionType::TransferFeeConfig]).unwrap();
+        let mut data = vec![0u8; space];
+        let mut state = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        let fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: (5 * DECIMAL_FACTOR).into(),
+            transfer_fee_basis_points: 100.into(),
+        };
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = fee;
+        config.newer_transfer_fee = fee;
+        state.base = MintState {
+            supply: SUPPLY,
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    fn token_account_data(mint: Pubkey, owner: Pubkey) -> Vec<u8> {
+        let space = ExtensionType::try_calculate_account_len::<TokenAccountState>(&[ExtensionType::TransferFeeAmount]).unwrap();
+        let mut data = vec![0u8; space];
+        let mut state = StateWithExtensionsMut::<TokenAccountState>::unpack_uninitialized(&mut data).unwrap();
+        state.init_extension::<spl_token_2022::extension::transfer_fee::TransferFeeAmount>(true).unwrap();
+        state.base = TokenAccountState {
+            mint,
+            owner,
+            amount: BALANCE,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+
+    #[test]
+    fn test_fee_bearing_mint_burns_full_amount() {
+        let (mint_key, account_key, owner_key) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (mut mint_lamports, mut account_lamports, mut owner_lamports) = (1_000_000u64, 1_000_000u64, 0u64);
+        let mut mint_data = fee_mint_data();
+        let mut account_data = token_account_data(mint_key, owner_key);
+        let mut owner_data = Vec::new();
+        let token_program = spl_token_2022::ID;
+        let system_program = anchor_lang::solana_program::system_program::ID;
+
+        // The fee would apply to a transfer of this amount
+        let amount = 420 * DECIMAL_FACTOR;
+        {
+            let mint = StateWithExtensions::<MintState>::unpack(&mint_data).unwrap();
+            let fee = mint.get_extension::<TransferFeeConfig>().unwrap().calculate_epoch_fee(0, amount).unwrap();
+            assert_eq!(fee, 42 * DECIMAL_FACTOR / 10);
+        }
+
+        let accounts = [
+            AccountInfo::new(&account_key, false, true, &mut account_lamports, &mut account_data, &token_program, false, 0),
+            AccountInfo::new(&mint_key, false, true, &mut mint_lamports, &mut mint_data, &token_program, false, 0),
+            AccountInfo::new(&owner_key, true, false, &mut owner_lamports, &mut owner_data, &system_program, false, 0),
+        ];
+        let instruction = spl_token_2022::instruction::burn(&token_program, &account_key, &mint_key, &owner_key, &[], amount).unwrap();
+        Processor::process(&token_program, &accounts, &instruction.data).unwrap();
+        drop(accounts);
+
+        // ...but a burn removes exactly `amount` from the balance and the supply
+        let account = StateWithExtensions::<TokenAccountState>::unpack(&account_data).unwrap();
+        assert_eq!(account.base.amount, BALANCE - amount);
+        let mint = StateWithExtensions::<MintState>::unpack(&mint_data).unwrap();
+        assert_eq!(mint.base.supply, SUPPLY - amount);
+    }
+}
+
+// ============================================================================
+// Tests for mint_decimals_valid()
+// ============================================================================
+
+#[cfg(test)]
+mod mint_decimals_tests {
+    use super::*;
+
+    #[test]
+    fn test_six_decimal_mint_accepted() {
+        assert!(mint_decimals_valid(6));
+        assert_eq!(10u64.pow(MINT_DECIMALS as u32), DECIMAL_FACTOR);
+    }
+
+    #[test]
+    fn test_nine_decimal_mint_rejected() {
+        assert!(!mint_decimals_valid(9));
+        assert!(!mint_decimals_valid(0));
+    }
+}
+
+// ============================================================================
+// Tests for crossed_milestones()
+// ============================================================================
+
+#[cfg(test)]
+mod burn_milestone_tests {
+    use super::*;
+
+    #[test]
+    fn test_single_burn_crosses_one_milestone() {
+        let old_total = 900 * DECIMAL_FACTOR;
+        let new_total = old_total + 200 * DECIMAL_FACTOR;
+        assert_eq!(crossed_milestones(old_total, new_total), vec![1_000]);
+
+        // Landing exactly on the milestone counts as crossing it
+        assert_eq!(crossed_milestones(old_total, 1_000 * DECIMAL_FACTOR), vec![1_000]);
+    }
+
+    #[test]
+    fn test_milestone_not_reemitted_on_later_burns() {
+        let after_first = 1_100 * DECIMAL_FACTOR;
+        assert!(crossed_milestones(after_first, after_first + 500 * DECIMAL_FACTOR).is_empty());
+        assert!(crossed_milestones(1_000 * DECIMAL_FACTOR, 2_000 * DECIMAL_FACTOR).is_empty());
+    }
+
+    #[test]
+    fn test_large_burn_crosses_several_milestones() {
+        assert_eq!(crossed_milestones(0, 150_000 * DECIMAL_FACTOR), vec![1_000, 10_000, 100_000]);
+        assert_eq!(crossed_milestones(0, MAX_USER_GLOBAL_BURN_AMOUNT), BURN_MILESTONES_TOKENS.to_vec());
+    }
+}
+
 // ============================================================================
 // Comprehensive Test Summary
 // ============================================================================
@@ -12,6 +12,8 @@ use memo_mint::cpi::accounts::ProcessMint;
 use memo_burn::cpi::accounts::ProcessBurn;
 use memo_burn::program::MemoBurn;
 use anchor_lang::solana_program::sysvar::instructions::{ID as INSTRUCTIONS_ID};
+use anchor_lang::solana_program::program::set_return_data;
+use solana_sha256_hasher::hash;
 use std::str::FromStr;
 use spl_memo::ID as MEMO_PROGRAM_ID;
 use base64::{Engine as _, engine::general_purpose};
@@ -41,6 +43,7 @@ pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("Gkxz6ogojD7Ni58N4SnJXy6xDxS
 
 // Token economics
 pub const DECIMAL_FACTOR: u64 = 1_000_000; // Token decimals (6)
+pub const MINT_DECIMALS: u8 = 6; // Decimals the authorized mint must have (DECIMAL_FACTOR = 10^MINT_DECIMALS)
 pub const MIN_GROUP_CREATION_BURN_TOKENS: u64 = 42_069; // Minimum tokens to burn for group creation
 pub const MIN_GROUP_CREATION_BURN_AMOUNT: u64 = MIN_GROUP_CREATION_BURN_TOKENS * DECIMAL_FACTOR;
 pub const MIN_BURN_AMOUNT: u64 = 1 * DECIMAL_FACTOR; // Minimum burn amount (1 token)
@@ -48,9 +51,16 @@ pub const MIN_BURN_AMOUNT: u64 = 1 * DECIMAL_FACTOR; // Minimum burn amount (1 t
 // Maximum burn per transaction (consistent with memo-burn)
 pub const MAX_BURN_PER_TX: u64 = 1_000_000_000_000 * DECIMAL_FACTOR; // 1 trillion tokens
 
+// Maximum amount a single user can mint through chat messages per UTC day (across all groups)
+pub const MAX_DAILY_MINT: u64 = 1_000 * DECIMAL_FACTOR; // 1,000 tokens
+
+// Maximum number of groups (creation is rejected well before the u64 counter overflows)
+pub const MAX_ENTITIES: u64 = u64::MAX / 2;
+
 // Time limits  
 pub const DEFAULT_MEMO_INTERVAL_SECONDS: i64 = 60; // Default memo interval (1 minute)
 pub const MAX_MEMO_INTERVAL_SECONDS: i64 = 86400; // Maximum memo interval (24 hours)
+pub const MAX_CLOCK_SKEW_SECONDS: u64 = 300; // Allowed client_ts drift in groups requiring fresh messages (5 minutes)
 
 // ===== STRING LENGTH CONSTRAINTS =====
 
@@ -64,6 +74,7 @@ pub const MAX_TAG_LENGTH: usize = 32;
 // Message limits
 pub const MAX_MESSAGE_LENGTH: usize = 512;
 pub const MAX_BURN_MESSAGE_LENGTH: usize = 512;
+pub const MAX_PINNED_MESSAGE_LENGTH: usize = 512;
 
 // Signature format
 pub const SIGNATURE_LENGTH_BYTES: usize = 64;
@@ -87,8 +98,17 @@ pub const MAX_BORSH_DATA_SIZE: usize = MEMO_MAX_LENGTH;
 // Current version of BurnMemo structure (consistent with memo-burn)
 pub const BURN_MEMO_VERSION: u8 = 1;
 
-// Current version of ChatGroupCreationData structure
-pub const CHAT_GROUP_CREATION_DATA_VERSION: u8 = 1;
+// Current version of ChatGroupCreationData structure (v2 adds lang, v3 adds pinned_message; older versions are still accepted)
+pub const CHAT_GROUP_CREATION_DATA_VERSION: u8 = 3;
+
+// Current version of ChatMessageData structure (v2 adds the encryption marker, v3 adds client_ts; older versions are still accepted)
+pub const CHAT_MESSAGE_DATA_VERSION: u8 = 3;
+
+// Current version of ChatGroupBurnData structure
+pub const CHAT_GROUP_BURN_DATA_VERSION: u8 = 1;
+
+// Language tag (BCP-47 style, e.g. "en", "zh-CN"), optional
+pub const MAX_LANG_LENGTH: usize = 8;
 
 // Expected category for memo-chat contract
 pub const EXPECTED_CATEGORY: &str = "chat";
@@ -99,6 +119,9 @@ pub const EXPECTED_OPERATION: &str = "create_group";
 // Expected operation for sending messages
 pub const EXPECTED_SEND_MESSAGE_OPERATION: &str = "send_message";
 
+// Expected operation for creator announcements (same payload as send_message)
+pub const EXPECTED_ANNOUNCE_OPERATION: &str = "announce";
+
 // Expected operation for burning tokens for group
 pub const EXPECTED_BURN_FOR_GROUP_OPERATION: &str = "burn_for_group";
 
@@ -116,7 +139,6 @@ pub struct BurnMemo {
 }
 
 /// Chat group creation data structure (stored in BurnMemo.payload)
-#[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ChatGroupCreationData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -144,18 +166,86 @@ pub struct ChatGroupCreationData {
     
     /// Minimum memo interval in seconds (optional, defaults to 60)
     pub min_memo_interval: Option<i64>,
+    
+    /// Language tag, e.g. "en" or "zh-CN" (v2+, optional, max 8 characters)
+    pub lang: String,
+    
+    /// Pinned rules/welcome message (v3+, optional, max 512 characters)
+    pub pinned_message: Option<String>,
+}
+
+impl AnchorSerialize for ChatGroupCreationData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.group_id.serialize(writer)?;
+        self.name.serialize(writer)?;
+        self.description.serialize(writer)?;
+        self.image.serialize(writer)?;
+        self.tags.serialize(writer)?;
+        self.min_memo_interval.serialize(writer)?;
+        if self.version >= 2 {
+            self.lang.serialize(writer)?;
+        }
+        if self.version >= 3 {
+            self.pinned_message.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for ChatGroupCreationData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let group_id = u64::deserialize_reader(reader)?;
+        let name = String::deserialize_reader(reader)?;
+        let description = String::deserialize_reader(reader)?;
+        let image = String::deserialize_reader(reader)?;
+        let tags = Vec::<String>::deserialize_reader(reader)?;
+        let min_memo_interval = Option::<i64>::deserialize_reader(reader)?;
+        let lang = if version >= 2 {
+            String::deserialize_reader(reader)?
+        } else {
+            String::new()
+        };
+        let pinned_message = if version >= 3 {
+            Option::<String>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        Ok(Self { version, category, operation, group_id, name, description, image, tags, min_memo_interval, lang, pinned_message })
+    }
 }
 
 impl ChatGroupCreationData {
     /// Validate the structure fields
     pub fn validate(&self, expected_group_id: u64) -> Result<()> {
-        // Validate version
-        if self.version != CHAT_GROUP_CREATION_DATA_VERSION {
-            msg!("Unsupported chat group creation data version: {} (expected: {})", 
+        // Validate version (1-3)
+        if self.version == 0 || self.version > CHAT_GROUP_CREATION_DATA_VERSION {
+            msg!("Unsupported chat group creation data version: {} (expected: 1-{})", 
                  self.version, CHAT_GROUP_CREATION_DATA_VERSION);
             return Err(ErrorCode::UnsupportedChatGroupDataVersion.into());
         }
         
+        // lang cannot be carried by a v1 payload
+        if self.version < 2 && !self.lang.is_empty() {
+            msg!("lang requires chat group creation data version 2");
+            return Err(ErrorCode::UnsupportedChatGroupDataVersion.into());
+        }
+        validate_lang(&self.lang)?;
+        
+        // pinned_message cannot be carried by a v1/v2 payload
+        if self.version < 3 && self.pinned_message.is_some() {
+            msg!("pinned_message requires chat group creation data version 3");
+            return Err(ErrorCode::UnsupportedChatGroupDataVersion.into());
+        }
+        if let Some(pinned_message) = &self.pinned_message {
+            ChatGroup::validate_pinned_message(pinned_message)?;
+        }
+        
         // Validate category (must be exactly "chat")
         if self.category != EXPECTED_CATEGORY {
             msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
@@ -192,6 +282,7 @@ impl ChatGroupCreationData {
             msg!("Invalid group image: {} characters (max: {})", self.image.len(), MAX_GROUP_IMAGE_LENGTH);
             return Err(ErrorCode::InvalidGroupImage.into());
         }
+        validate_image_reference(&self.image)?;
         
         // Validate tags (optional, max MAX_TAGS_COUNT tags, each max MAX_TAG_LENGTH characters)
         if self.tags.len() > MAX_TAGS_COUNT {
@@ -200,9 +291,12 @@ impl ChatGroupCreationData {
         }
         
         for (i, tag) in self.tags.iter().enumerate() {
-            if tag.is_empty() || tag.len() > MAX_TAG_LENGTH {
+            validate_tag_content(i, tag)?;
+            // Length is measured after trimming, which is how the tag is stored
+            let length = tag.trim().len();
+            if length == 0 || length > MAX_TAG_LENGTH {
                 msg!("Invalid tag {}: '{}' (must be 1-{} characters)", i, tag, MAX_TAG_LENGTH);
-                return Err(ErrorCode::InvalidTag.into());
+                return Err(invalid_tag_error(i).into());
             }
         }
         
@@ -221,8 +315,29 @@ impl ChatGroupCreationData {
     }
 }
 
+/// Client-side encryption scheme carried by encrypted chat messages (the program never decrypts)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncryptionScheme {
+    /// Plaintext message
+    None = 0,
+    /// NaCl box (X25519 key exchange + XSalsa20-Poly1305)
+    X25519XSalsa20Poly1305 = 1,
+    /// X25519 key exchange + AES-256-GCM
+    X25519Aes256Gcm = 2,
+}
+
+impl EncryptionScheme {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::None),
+            1 => Some(Self::X25519XSalsa20Poly1305),
+            2 => Some(Self::X25519Aes256Gcm),
+            _ => None,
+        }
+    }
+}
+
 /// Chat message data structure (stored in BurnMemo.payload for send_memo_to_group)
-#[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ChatMessageData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -230,7 +345,7 @@ pub struct ChatMessageData {
     /// Category of the request (must be "chat" for memo-chat contract)
     pub category: String,
     
-    /// Operation type (must be "send_message" for sending messages)
+    /// Operation type ("send_message" for sending messages, "announce" for creator announcements)
     pub operation: String,
     
     /// Group ID (must match the target group)
@@ -247,27 +362,105 @@ pub struct ChatMessageData {
     
     /// Optional reply to signature (for message threading)
     pub reply_to_sig: Option<String>,
+    
+    /// Message is end-to-end encrypted by the client (v2+)
+    pub encrypted: bool,
+    
+    /// EncryptionScheme value; must be non-zero exactly when encrypted (v2+)
+    pub encryption_scheme: u8,
+    
+    /// Client timestamp when the message was signed (v3+, required by groups with require_fresh_messages)
+    pub client_ts: Option<i64>,
+}
+
+impl AnchorSerialize for ChatMessageData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.group_id.serialize(writer)?;
+        self.sender.serialize(writer)?;
+        self.message.serialize(writer)?;
+        self.receiver.serialize(writer)?;
+        self.reply_to_sig.serialize(writer)?;
+        if self.version >= 2 {
+            self.encrypted.serialize(writer)?;
+            self.encryption_scheme.serialize(writer)?;
+        }
+        if self.version >= 3 {
+            self.client_ts.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for ChatMessageData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let group_id = u64::deserialize_reader(reader)?;
+        let sender = String::deserialize_reader(reader)?;
+        let message = String::deserialize_reader(reader)?;
+        let receiver = Option::<String>::deserialize_reader(reader)?;
+        let reply_to_sig = Option::<String>::deserialize_reader(reader)?;
+        let (encrypted, encryption_scheme) = if version >= 2 {
+            (bool::deserialize_reader(reader)?, u8::deserialize_reader(reader)?)
+        } else {
+            (false, EncryptionScheme::None as u8)
+        };
+        let client_ts = if version >= 3 {
+            Option::<i64>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        Ok(Self { version, category, operation, group_id, sender, message, receiver, reply_to_sig, encrypted, encryption_scheme, client_ts })
+    }
 }
 
 impl ChatMessageData {
-    /// Validate the structure fields
+    /// Validate the structure fields of a send_message payload
     pub fn validate(&self, expected_group_id: u64, expected_sender: Pubkey) -> Result<()> {
-        // Validate version
-        if self.version != CHAT_GROUP_CREATION_DATA_VERSION {
-            msg!("Unsupported chat message data version: {} (expected: {})", 
-                 self.version, CHAT_GROUP_CREATION_DATA_VERSION);
+        self.validate_operation(expected_group_id, expected_sender, EXPECTED_SEND_MESSAGE_OPERATION)
+    }
+
+    /// Validate the structure fields for the given operation ("send_message" or "announce")
+    pub fn validate_operation(&self, expected_group_id: u64, expected_sender: Pubkey, expected_operation: &str) -> Result<()> {
+        // Validate version (1-3)
+        if self.version == 0 || self.version > CHAT_MESSAGE_DATA_VERSION {
+            msg!("Unsupported chat message data version: {} (expected: 1-{})", 
+                 self.version, CHAT_MESSAGE_DATA_VERSION);
+            return Err(ErrorCode::UnsupportedChatMessageDataVersion.into());
+        }
+        
+        // The encryption marker cannot be carried by a v1 payload
+        if self.version < 2 && (self.encrypted || self.encryption_scheme != EncryptionScheme::None as u8) {
+            msg!("Encrypted messages require chat message data version 2");
+            return Err(ErrorCode::UnsupportedChatMessageDataVersion.into());
+        }
+        
+        // client_ts cannot be carried by a v1/v2 payload
+        if self.version < 3 && self.client_ts.is_some() {
+            msg!("client_ts requires chat message data version 3");
             return Err(ErrorCode::UnsupportedChatMessageDataVersion.into());
         }
         
+        // A known scheme is required for encrypted messages, and plaintext must declare none
+        let scheme = EncryptionScheme::from_u8(self.encryption_scheme);
+        if scheme.is_none() || self.encrypted != (scheme != Some(EncryptionScheme::None)) {
+            msg!("Invalid encryption scheme {} for encrypted={}", self.encryption_scheme, self.encrypted);
+            return Err(ErrorCode::InvalidEncryptionScheme.into());
+        }
+        
         // Validate category (must be exactly "chat")
         if self.category != EXPECTED_CATEGORY {
             msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
             return Err(ErrorCode::InvalidCategory.into());
         }
         
-        // Validate operation (must be exactly "send_message")
-        if self.operation != EXPECTED_SEND_MESSAGE_OPERATION {
-            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, EXPECTED_SEND_MESSAGE_OPERATION);
+        // Validate operation (must be exactly the expected operation)
+        if self.operation != expected_operation {
+            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, expected_operation);
             return Err(ErrorCode::InvalidOperation.into());
         }
         
@@ -299,6 +492,10 @@ impl ChatMessageData {
         if self.message.len() > MAX_MESSAGE_LENGTH {
             return Err(ErrorCode::MessageTooLong.into());
         }
+        // Ciphertext is opaque to the program, so only plaintext gets the entropy check
+        if !self.encrypted {
+            validate_content_entropy(&self.message)?;
+        }
         
         // Validate receiver format if provided
         if let Some(ref receiver_str) = self.receiver {
@@ -363,9 +560,9 @@ impl ChatGroupBurnData {
     /// Validate the structure fields
     pub fn validate(&self, expected_group_id: u64, expected_burner: Pubkey) -> Result<()> {
         // Validate version
-        if self.version != CHAT_GROUP_CREATION_DATA_VERSION {
+        if self.version != CHAT_GROUP_BURN_DATA_VERSION {
             msg!("Unsupported chat group burn data version: {} (expected: {})", 
-                 self.version, CHAT_GROUP_CREATION_DATA_VERSION);
+                 self.version, CHAT_GROUP_BURN_DATA_VERSION);
             return Err(ErrorCode::UnsupportedChatGroupBurnDataVersion.into());
         }
         
@@ -414,6 +611,106 @@ impl ChatGroupBurnData {
     }
 }
 
+/// Reject degenerate content: entirely whitespace, or one character repeated (e.g. "aaaa")
+pub fn validate_content_entropy(content: &str) -> Result<()> {
+    let mut chars = content.chars();
+    let single_repeated_char = match chars.next() {
+        Some(first) => content.len() > first.len_utf8() && chars.all(|c| c == first),
+        None => false,
+    };
+    if content.trim().is_empty() || single_repeated_char {
+        msg!("Low entropy content rejected: {} bytes of whitespace or a single repeated character", content.len());
+        return Err(ErrorCode::LowEntropyContent.into());
+    }
+    Ok(())
+}
+
+/// Validate a BCP-47 style language tag: empty, or ASCII letters with at most one hyphen
+pub fn validate_lang(lang: &str) -> Result<()> {
+    if lang.is_empty() {
+        return Ok(());
+    }
+    let valid = lang.len() <= MAX_LANG_LENGTH
+        && lang.matches('-').count() <= 1
+        && lang.split('-').all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphabetic()));
+    if !valid {
+        msg!("Invalid language tag: '{}' (expected ASCII letters with at most one hyphen, max {} characters)", lang, MAX_LANG_LENGTH);
+        return Err(ErrorCode::InvalidLanguageTag.into());
+    }
+    Ok(())
+}
+
+/// Error for an invalid tag, carrying the tag's index so clients can highlight the right input
+/// (more than MAX_TAGS_COUNT tags are rejected with TooManyTags before any tag is checked)
+pub fn invalid_tag_error(index: usize) -> ErrorCode {
+    match index {
+        0 => ErrorCode::InvalidTagAtIndex0,
+        1 => ErrorCode::InvalidTagAtIndex1,
+        2 => ErrorCode::InvalidTagAtIndex2,
+        3 => ErrorCode::InvalidTagAtIndex3,
+        _ => ErrorCode::InvalidTag,
+    }
+}
+
+/// Reject tags that are whitespace-only or contain ASCII control characters
+/// (surrounding whitespace is fine; it is trimmed before the tag is stored)
+pub fn validate_tag_content(index: usize, tag: &str) -> Result<()> {
+    if tag.chars().any(|c| c.is_ascii_control()) || (!tag.is_empty() && tag.trim().is_empty()) {
+        msg!("Invalid tag {} content: {:?}", index, tag);
+        return Err(ErrorCode::InvalidTagContent.into());
+    }
+    Ok(())
+}
+
+/// Trim leading/trailing whitespace from each tag, so " defi " is stored as "defi"
+pub fn trim_tags(tags: &mut [String]) {
+    for tag in tags.iter_mut() {
+        let trimmed = tag.trim();
+        if trimmed.len() != tag.len() {
+            *tag = trimmed.to_string();
+        }
+    }
+}
+
+/// Lowercase mirror of a name for case-insensitive search by indexers. Falls back to ASCII-only
+/// lowercasing if full Unicode lowercasing would grow the name, so the mirror never needs more
+/// space than the name itself
+pub fn lowercase_name(name: &str) -> String {
+    let lower = name.to_lowercase();
+    if lower.len() <= name.len() {
+        lower
+    } else {
+        name.to_ascii_lowercase()
+    }
+}
+
+/// Image references must use one of these schemes (or be a bare CIDv0)
+pub const IMAGE_REFERENCE_PREFIXES: [&str; 3] = ["ipfs://", "ar://", "https://"];
+
+/// Length of a bare CIDv0 IPFS hash ("Qm" + 44 base58 characters)
+pub const CID_V0_LENGTH: usize = 46;
+
+/// Validate an image field: empty, an ipfs:// / ar:// / https:// reference, or a bare CIDv0
+pub fn validate_image_reference(image: &str) -> Result<()> {
+    if image.is_empty()
+        || IMAGE_REFERENCE_PREFIXES.iter().any(|prefix| image.starts_with(prefix))
+        || is_cid_v0(image)
+    {
+        return Ok(());
+    }
+
+    msg!("Invalid image reference: {} (expected ipfs://, ar://, https:// or a CIDv0)", image);
+    Err(ErrorCode::InvalidImageReference.into())
+}
+
+/// Whether `value` looks like a CIDv0 ("Qm..." in base58, 46 characters)
+fn is_cid_v0(value: &str) -> bool {
+    const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    value.len() == CID_V0_LENGTH
+        && value.starts_with("Qm")
+        && value.chars().all(|c| BASE58_ALPHABET.contains(c))
+}
+
 #[program]
 pub mod memo_chat {
     use super::*;
@@ -433,12 +730,45 @@ pub mod memo_chat {
         Ok(())
     }
 
+    /// Initialize the program-wide burn/mint statistics (one-time setup, admin only;
+    /// burn and mint instructions fail until it exists)
+    pub fn initialize_program_stats(ctx: Context<InitializeProgramStats>) -> Result<()> {
+        let program_stats = &mut ctx.accounts.program_stats;
+        program_stats.total_burned = 0;
+        program_stats.total_minted = 0;
+        program_stats.event_seq = 0;
+        program_stats.bump = ctx.bumps.program_stats;
+
+        msg!("Program stats initialized by admin {}", ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Exempt a wallet from rate limits (admin only), e.g. a trusted cross-post bot
+    pub fn add_rate_limit_exemption(ctx: Context<AddRateLimitExemption>, wallet: Pubkey) -> Result<()> {
+        let exemption = &mut ctx.accounts.rate_limit_exemption;
+        exemption.wallet = wallet;
+        exemption.granted_at = Clock::get()?.unix_timestamp;
+        exemption.bump = ctx.bumps.rate_limit_exemption;
+
+        msg!("Rate limit exemption granted to {} by admin {}", wallet, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Remove a wallet's rate-limit exemption (admin only); the rent goes back to the admin
+    pub fn remove_rate_limit_exemption(ctx: Context<RemoveRateLimitExemption>, wallet: Pubkey) -> Result<()> {
+        msg!("Rate limit exemption removed from {} by admin {}", wallet, ctx.accounts.admin.key());
+        Ok(())
+    }
+
     /// Create a new chat group (requires burning tokens)
     /// Note: group_id will be automatically assigned by the contract
     pub fn create_chat_group(
         ctx: Context<CreateChatGroup>,
         expected_group_id: u64, // The group_id that client expects to create
         burn_amount: u64,
+        message_burn: u64, // Per-message burn required by send_memo_to_group (0 = free)
+        store_messages: bool, // Keep each message body on-chain in a StoredMessage PDA
+        reward_scaling_enabled: bool, // Scale the send_memo_to_group mint reward by message length
     ) -> Result<()> {
         // Validate burn amount - require at least 42069 tokens for group creation
         if burn_amount < MIN_GROUP_CREATION_BURN_AMOUNT {
@@ -454,8 +784,11 @@ pub mod memo_chat {
             return Err(ErrorCode::InvalidBurnAmount.into());
         }
 
+        ChatGroup::validate_message_burn(message_burn)?;
+
         // Get the next group_id from global counter
         let global_counter = &mut ctx.accounts.global_counter;
+        global_counter.check_capacity()?;
         let actual_group_id = global_counter.total_groups;
 
         // Verify that the expected group_id matches the actual next group_id
@@ -473,8 +806,13 @@ pub mod memo_chat {
 
         // Parse and validate Borsh memo data for group creation
         let group_data = parse_group_creation_borsh_memo(&memo_data, actual_group_id, burn_amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
         
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.creator_token_account.amount;
+
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.creator.to_account_info(),
@@ -487,6 +825,11 @@ pub mod memo_chat {
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.creator_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.creator_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
         
         // Get current timestamp once and reuse
         let current_time = Clock::get()?.unix_timestamp;
@@ -504,6 +847,16 @@ pub mod memo_chat {
         chat_group.burned_amount = burn_amount;
         chat_group.min_memo_interval = group_data.min_memo_interval.unwrap_or(DEFAULT_MEMO_INTERVAL_SECONDS);
         chat_group.last_memo_time = 0;  // Set to 0 so first message is not rate-limited
+        chat_group.message_burn = message_burn;
+        chat_group.verified = false; // Only the admin can mark a group as verified
+        chat_group.archived = false;
+        chat_group.store_messages = store_messages;
+        chat_group.reward_scaling_enabled = reward_scaling_enabled;
+        chat_group.lang = group_data.lang.clone();
+        chat_group.pinned_message = group_data.pinned_message.clone().unwrap_or_default();
+        chat_group.name_lower = lowercase_name(&group_data.name);
+        chat_group.require_fresh_messages = false; // Opted into with set_require_fresh_messages
+        chat_group.last_announcement = 0;
         chat_group.bump = ctx.bumps.chat_group;
 
         // Increment global counter AFTER successful group creation
@@ -514,12 +867,20 @@ pub mod memo_chat {
         emit!(ChatGroupCreatedEvent {
             group_id: actual_group_id,
             creator: ctx.accounts.creator.key(),
+            name_lower: lowercase_name(&group_data.name),
             name: group_data.name,
             description: group_data.description,
             image: group_data.image,
             tags: group_data.tags,
+            lang: group_data.lang,
+            pinned_message: group_data.pinned_message.unwrap_or_default(),
             burn_amount,
+            message_burn,
+            store_messages,
+            reward_scaling_enabled,
             timestamp: current_time,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         // Update burn leaderboard after successful group creation
@@ -549,42 +910,105 @@ pub mod memo_chat {
             return Err(ErrorCode::MemoRequired.into());
         }
         
-        // Parse and validate Borsh memo content
-        let memo_content = parse_message_borsh_memo(&memo_data, group_id, ctx.accounts.sender.key())?;
+        // Archived groups stay readable but accept no new messages
+        ctx.accounts.chat_group.check_not_archived()?;
+
+        // Storing groups need the StoredMessage account; other groups must not pass one
+        let stored_index = ctx.accounts.chat_group
+            .stored_message_index(ctx.accounts.stored_message.is_some())?;
+
+        let message_burn = ctx.accounts.chat_group.message_burn;
+
+        // Parse and validate Borsh memo content (wrapped in BurnMemo for paid groups)
+        let message_data = parse_message_borsh_memo(
+            &memo_data, group_id, ctx.accounts.sender.key(), message_burn, EXPECTED_SEND_MESSAGE_OPERATION,
+        )?;
+        let memo_content = message_data.message;
+        let memo_hash = compute_memo_hash(&memo_data);
         
         // Get current timestamp once and reuse
         let current_time = Clock::get()?.unix_timestamp;
         
-        let chat_group = &mut ctx.accounts.chat_group;
+        // Groups requiring fresh messages reject missing or skewed client timestamps
+        ctx.accounts.chat_group.check_message_freshness(message_data.client_ts, current_time)?;
+        
+        // Check memo frequency limit (skipped for admin-exempted senders)
+        let exempt = RateLimitExemption::exempts(ctx.accounts.rate_limit_exemption.as_deref(), ctx.accounts.sender.key());
+        ctx.accounts.chat_group.check_memo_interval(current_time, exempt)?;
+
+        // Paid group: burn the per-message amount before minting
+        if message_burn > 0 {
+            let (Some(user_global_burn_stats), Some(memo_burn_program)) = (
+                ctx.accounts.user_global_burn_stats.as_ref(),
+                ctx.accounts.memo_burn_program.as_ref(),
+            ) else {
+                return Err(ErrorCode::MessageBurnAccountsRequired.into());
+            };
 
-        // Check memo frequency limit
-        if chat_group.last_memo_time > 0 {
-            let time_since_last = current_time - chat_group.last_memo_time;
-            if time_since_last < chat_group.min_memo_interval {
-                return Err(ErrorCode::MemoTooFrequent.into());
-            }
+            // Balance before burning, checked against the balance after the CPI
+            let balance_before_burn = ctx.accounts.sender_token_account.amount;
+
+            verify_burn_stats_account(&user_global_burn_stats.to_account_info())?;
+            let cpi_accounts = ProcessBurn {
+                user: ctx.accounts.sender.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                token_account: ctx.accounts.sender_token_account.to_account_info(),
+                user_global_burn_stats: user_global_burn_stats.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new(memo_burn_program.to_account_info(), cpi_accounts);
+            memo_burn::cpi::process_burn(cpi_ctx, message_burn)?;
+            ctx.accounts.sender_token_account.reload()?;
+            check_burn_reflected(balance_before_burn, ctx.accounts.sender_token_account.amount, message_burn)?;
+
+            // Track program-wide burn total
+            ctx.accounts.program_stats.record_burn(message_burn);
+
+            msg!("Burned {} tokens for message to group {}", message_burn / DECIMAL_FACTOR, group_id);
         }
 
-        // Call memo-mint contract using CPI to process_mint (user as direct signer)
-        // This allows sender to directly mint tokens without using chat group PDA
-        let cpi_program = ctx.accounts.memo_mint_program.to_account_info();
-        let cpi_accounts = ProcessMint {
-            user: ctx.accounts.sender.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            mint_authority: ctx.accounts.mint_authority.to_account_info(),
-            token_account: ctx.accounts.sender_token_account.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            instructions: ctx.accounts.instructions.to_account_info(),
-        };
-        
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        memo_mint::cpi::process_mint(cpi_ctx)?;
+        // Mint the message reward through memo-mint (sender as direct signer)
+        let minted = mint_message_reward(
+            &ctx.accounts.chat_group,
+            memo_content.len(),
+            &ctx.accounts.sender,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
+            &mut ctx.accounts.sender_token_account,
+            &ctx.accounts.token_program,
+            &ctx.accounts.memo_mint_program,
+            &ctx.accounts.instructions,
+        )?;
+
+        // Enforce the sender's daily mint cap across all groups (a rejected mint reverts with the tx)
+        let quota = &mut ctx.accounts.daily_mint_quota;
+        quota.user = ctx.accounts.sender.key();
+        quota.bump = ctx.bumps.daily_mint_quota;
+        quota.record_mint(minted, current_time)?;
+
+        // Track program-wide mint total
+        ctx.accounts.program_stats.record_mint(minted);
 
         // Update chat group statistics AFTER successful CPI
+        let chat_group = &mut ctx.accounts.chat_group;
         chat_group.memo_count = chat_group.memo_count.saturating_add(1);
         chat_group.last_memo_time = current_time;
         let memo_count = chat_group.memo_count;
 
+        // Persist the message body for groups with on-chain history
+        if let (Some(index), Some(stored_message)) = (stored_index, ctx.accounts.stored_message.as_mut()) {
+            stored_message.set_inner(StoredMessage::new(
+                group_id,
+                index,
+                ctx.accounts.sender.key(),
+                memo_content.clone(),
+                current_time,
+                ctx.bumps.stored_message.unwrap_or_default(),
+            ));
+        }
+
         // Log the memo
         msg!("Memo from {} to group {}: {}", 
              ctx.accounts.sender.key(), 
@@ -597,7 +1021,73 @@ pub mod memo_chat {
             sender: ctx.accounts.sender.key(),
             memo: memo_content,
             memo_count,
+            message_burn,
+            timestamp: current_time,
+            memo_hash,
+            encrypted: message_data.encrypted,
+            encryption_scheme: message_data.encryption_scheme,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        Ok(())
+    }
+
+    /// Post a creator announcement to a group: minted like a normal message, but exempt
+    /// from the group's min_memo_interval and not charged the per-message burn
+    pub fn announce_to_group(
+        ctx: Context<AnnounceToGroup>,
+        group_id: u64,
+    ) -> Result<()> {
+        let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
+        if !memo_found {
+            return Err(ErrorCode::MemoRequired.into());
+        }
+
+        ctx.accounts.chat_group.check_not_archived()?;
+
+        // Announcements use the send_message payload with the "announce" operation (never BurnMemo-wrapped)
+        let message_data = parse_message_borsh_memo(
+            &memo_data, group_id, ctx.accounts.creator.key(), 0, EXPECTED_ANNOUNCE_OPERATION,
+        )?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        ctx.accounts.chat_group.check_message_freshness(message_data.client_ts, current_time)?;
+
+        let minted = mint_message_reward(
+            &ctx.accounts.chat_group,
+            message_data.message.len(),
+            &ctx.accounts.creator,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
+            &mut ctx.accounts.creator_token_account,
+            &ctx.accounts.token_program,
+            &ctx.accounts.memo_mint_program,
+            &ctx.accounts.instructions,
+        )?;
+
+        // Announcements still count against the creator's daily mint cap
+        let quota = &mut ctx.accounts.daily_mint_quota;
+        quota.user = ctx.accounts.creator.key();
+        quota.bump = ctx.bumps.daily_mint_quota;
+        quota.record_mint(minted, current_time)?;
+
+        ctx.accounts.program_stats.record_mint(minted);
+
+        let memo_count = ctx.accounts.chat_group.record_announcement(current_time);
+
+        msg!("Announcement from {} to group {}: {}", ctx.accounts.creator.key(), group_id, message_data.message);
+
+        emit!(GroupAnnouncementEvent {
+            group_id,
+            creator: ctx.accounts.creator.key(),
+            message: message_data.message,
+            memo_count,
             timestamp: current_time,
+            memo_hash,
+            encrypted: message_data.encrypted,
+            encryption_scheme: message_data.encryption_scheme,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         Ok(())
@@ -623,6 +1113,9 @@ pub mod memo_chat {
             return Err(ErrorCode::InvalidBurnAmount.into());
         }
 
+        // Archived groups stay readable but accept no new burns
+        ctx.accounts.chat_group.check_not_archived()?;
+
         // Check memo instruction with enhanced validation
         let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
         if !memo_found {
@@ -631,8 +1124,13 @@ pub mod memo_chat {
 
         // Parse and validate Borsh memo content for burn operation
         parse_burn_borsh_memo(&memo_data, group_id, amount, ctx.accounts.burner.key())?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.burner_token_account.amount;
 
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.burner.to_account_info(),
@@ -647,6 +1145,11 @@ pub mod memo_chat {
         
         // Call memo-burn's process_burn instruction
         memo_burn::cpi::process_burn(cpi_ctx, amount)?;
+        ctx.accounts.burner_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.burner_token_account.amount, amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(amount);
         
         // Update chat group burned amount tracking
         let chat_group = &mut ctx.accounts.chat_group;
@@ -682,6 +1185,8 @@ pub mod memo_chat {
             amount,
             total_burned: chat_group.burned_amount,
             timestamp: Clock::get()?.unix_timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         Ok(())
@@ -701,6 +1206,179 @@ pub mod memo_chat {
         Ok(())
     }
 
+    /// Force-remove a group's burn leaderboard entry regardless of amount (admin only).
+    /// Complements the automatic min-entry eviction; an id that is not on the
+    /// leaderboard is a no-op rather than an error.
+    pub fn admin_remove_leaderboard_entry(ctx: Context<AdminRemoveLeaderboardEntry>, group_id: u64) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let Some(removed) = ctx.accounts.burn_leaderboard.admin_remove_entry(admin, group_id)? else {
+            msg!("Group {} is not on the burn leaderboard; nothing removed", group_id);
+            return Ok(());
+        };
+
+        emit!(LeaderboardEntryRemovedEvent {
+            group_id,
+            admin,
+            burned_amount: removed.burned_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Group {} removed from burn leaderboard by admin {} ({} tokens)", 
+             group_id, admin, removed.burned_amount / DECIMAL_FACTOR);
+        Ok(())
+    }
+
+    /// Archive a chat group (creator only); the group and its leaderboard entry remain readable
+    pub fn archive_chat_group(ctx: Context<ArchiveChatGroup>, group_id: u64) -> Result<()> {
+        set_group_archived(ctx, group_id, true)
+    }
+
+    /// Unarchive a chat group (creator only)
+    pub fn unarchive_chat_group(ctx: Context<ArchiveChatGroup>, group_id: u64) -> Result<()> {
+        set_group_archived(ctx, group_id, false)
+    }
+
+    /// Set or clear the pinned message of a chat group (creator only; an empty message unpins)
+    pub fn set_pinned_message(
+        ctx: Context<SetPinnedMessage>,
+        group_id: u64,
+        message: String,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator.key();
+        ctx.accounts.chat_group.set_pinned_message(creator, message)?;
+
+        emit!(PinnedMessageChangedEvent {
+            group_id,
+            creator,
+            pinned_message: ctx.accounts.chat_group.pinned_message.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Group {} pinned message updated by creator {}", group_id, creator);
+        Ok(())
+    }
+
+    /// Require (or stop requiring) a fresh client_ts on every message to a group (creator only)
+    pub fn set_require_fresh_messages(
+        ctx: Context<SetRequireFreshMessages>,
+        group_id: u64,
+        required: bool,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator.key();
+        ctx.accounts.chat_group.set_require_fresh_messages(creator, required)?;
+
+        emit!(FreshMessagesRequirementChangedEvent {
+            group_id,
+            creator,
+            require_fresh_messages: required,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Group {} require_fresh_messages set to {} by creator {}", group_id, required, creator);
+        Ok(())
+    }
+
+    /// Set or clear the verified flag of a chat group (admin only)
+    pub fn set_group_verified(
+        ctx: Context<SetGroupVerified>,
+        group_id: u64,
+        verified: bool,
+    ) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        ctx.accounts.chat_group.set_verified(admin, verified)?;
+
+        emit!(VerificationChangedEvent {
+            group_id,
+            admin,
+            verified,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Group {} verified flag set to {} by admin {}", group_id, verified, admin);
+        Ok(())
+    }
+
+
+    /// Return aggregate counts (total groups, leaderboard size and minimum) via return data
+    pub fn get_global_stats(ctx: Context<GetGlobalStats>) -> Result<()> {
+        ctx.accounts.burn_leaderboard.check_integrity()?;
+        let stats = GlobalStats::new(&ctx.accounts.global_counter, &ctx.accounts.burn_leaderboard);
+
+        msg!("Global stats: {} groups, {} leaderboard entries (min {})", 
+             stats.total_groups, stats.leaderboard_size, stats.leaderboard_min);
+        set_return_data(&stats.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Return the leaderboard entry bar (minimum burned amount and whether the board is full)
+    /// via return data, so clients can check before burning whether an amount would enter
+    pub fn get_leaderboard_min(ctx: Context<GetLeaderboardMin>) -> Result<()> {
+        ctx.accounts.burn_leaderboard.check_integrity()?;
+        let bar = LeaderboardMin::new(&ctx.accounts.burn_leaderboard);
+
+        msg!("Leaderboard min: {} (full: {})", bar.min_burned_amount, bar.is_full);
+        set_return_data(&bar.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Convert a group created before message_burn and the later fields were appended: grow
+    /// it to ChatGroup::calculate_space_max (payer covers the extra rent) and rewrite it with
+    /// the appended fields initialized (see ChatGroup::from_legacy). Anyone may migrate any
+    /// group; a group that is already current is left untouched
+    pub fn migrate_chat_group(ctx: Context<MigrateChatGroup>, group_id: u64) -> Result<()> {
+        let group_account = ctx.accounts.chat_group.to_account_info();
+        if group_account.owner != ctx.program_id {
+            return Err(ErrorCode::InvalidChatGroupAccount.into());
+        }
+        if !ChatGroup::needs_migration(&group_account.try_borrow_data()?)? {
+            msg!("Chat group {} is already migrated", group_id);
+            return Ok(());
+        }
+        let chat_group = ChatGroup::from_legacy(&group_account.try_borrow_data()?)?;
+
+        let space = ChatGroup::calculate_space_max();
+        let top_up = Rent::get()?.minimum_balance(space).saturating_sub(group_account.lamports());
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: group_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        group_account.resize(space)?;
+        chat_group.try_serialize(&mut &mut group_account.try_borrow_mut_data()?[..])?;
+
+        msg!("Chat group {} migrated to the current layout ({} bytes)", group_id, space);
+        Ok(())
+    }
+}
+
+/// Shared logic for archive_chat_group / unarchive_chat_group
+fn set_group_archived(ctx: Context<ArchiveChatGroup>, group_id: u64, archived: bool) -> Result<()> {
+    let creator = ctx.accounts.creator.key();
+    ctx.accounts.chat_group.set_archived(creator, archived)?;
+
+    emit!(ChatGroupArchivedEvent {
+        group_id,
+        creator,
+        archived,
+        timestamp: Clock::get()?.unix_timestamp,
+        event_seq: ctx.accounts.program_stats.next_event_seq(),
+    });
+
+    msg!("Group {} archived flag set to {} by creator {}", group_id, archived, creator);
+    Ok(())
 }
 
 /// Parse and validate Borsh-formatted memo data for group creation (with Base64 decoding)
@@ -763,7 +1441,7 @@ fn parse_group_creation_borsh_memo(memo_data: &[u8], expected_group_id: u64, exp
     }
     
     // Deserialize ChatGroupCreationData from payload
-    let group_data = ChatGroupCreationData::try_from_slice(&burn_memo.payload)
+    let mut group_data = ChatGroupCreationData::try_from_slice(&burn_memo.payload)
         .map_err(|_| {
             msg!("Invalid chat group creation data format in payload");
             ErrorCode::InvalidChatGroupDataFormat
@@ -771,6 +1449,7 @@ fn parse_group_creation_borsh_memo(memo_data: &[u8], expected_group_id: u64, exp
     
     // Validate the group creation data
     group_data.validate(expected_group_id)?;
+    trim_tags(&mut group_data.tags);
     
     msg!("Chat group creation data parsed successfully: group_id={}, name={}, description_len={}, image_len={}, tags_count={}", 
          group_data.group_id, group_data.name, group_data.description.len(), 
@@ -856,7 +1535,11 @@ fn parse_burn_borsh_memo(memo_data: &[u8], expected_group_id: u64, expected_amou
 }
 
 /// Parse and validate Borsh-formatted memo data for sending messages (with Base64 decoding)
-fn parse_message_borsh_memo(memo_data: &[u8], expected_group_id: u64, expected_sender: Pubkey) -> Result<String> {
+///
+/// Free groups (message_burn == 0) take ChatMessageData directly; paid groups require it
+/// wrapped in a BurnMemo whose burn_amount equals the group's message_burn, since the same
+/// memo is checked by memo-burn during the burn CPI.
+fn parse_message_borsh_memo(memo_data: &[u8], expected_group_id: u64, expected_sender: Pubkey, message_burn: u64, expected_operation: &str) -> Result<ChatMessageData> {
     // First, decode the Base64-encoded memo data
     let base64_str = std::str::from_utf8(memo_data)
         .map_err(|_| {
@@ -878,21 +1561,56 @@ fn parse_message_borsh_memo(memo_data: &[u8], expected_group_id: u64, expected_s
     
     msg!("Base64 decoded: {} bytes -> {} bytes", memo_data.len(), decoded_data.len());
     
+    let message_bytes = if message_burn > 0 {
+        let burn_memo = BurnMemo::try_from_slice(&decoded_data)
+            .map_err(|_| {
+                msg!("Paid group requires a BurnMemo-wrapped message");
+                ErrorCode::InvalidChatMessageDataFormat
+            })?;
+        
+        if burn_memo.version != BURN_MEMO_VERSION {
+            msg!("Unsupported memo version: {} (expected: {})", 
+                 burn_memo.version, BURN_MEMO_VERSION);
+            return Err(ErrorCode::UnsupportedMemoVersion.into());
+        }
+        
+        if burn_memo.burn_amount != message_burn {
+            msg!("Message burn mismatch: memo {} vs required {}", 
+                 burn_memo.burn_amount, message_burn);
+            return Err(ErrorCode::BurnAmountMismatch.into());
+        }
+        
+        if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
+            msg!("Payload too long: {} bytes (max: {})", 
+                 burn_memo.payload.len(), MAX_PAYLOAD_LENGTH);
+            return Err(ErrorCode::PayloadTooLong.into());
+        }
+        
+        burn_memo.payload
+    } else {
+        decoded_data
+    };
+    
     // Deserialize ChatMessageData from decoded bytes
-    let message_data = ChatMessageData::try_from_slice(&decoded_data)
+    let message_data = ChatMessageData::try_from_slice(&message_bytes)
         .map_err(|_| {
             msg!("Invalid Borsh format after Base64 decoding");
             ErrorCode::InvalidChatMessageDataFormat
         })?;
     
     // Validate message data
-    message_data.validate(expected_group_id, expected_sender)?;
+    message_data.validate_operation(expected_group_id, expected_sender, expected_operation)?;
     
     msg!("Chat message data parsed successfully: group_id={}, sender={}, message_len={}, receiver={:?}, reply_to={:?}", 
          message_data.group_id, message_data.sender, message_data.message.len(), 
          message_data.receiver, message_data.reply_to_sig.as_ref().map(|s| &s[..16.min(s.len())]));
 
-    Ok(message_data.message)
+    Ok(message_data)
+}
+
+/// Whether a mint has the decimals DECIMAL_FACTOR assumes (guards against a recreated mint)
+pub fn mint_decimals_valid(decimals: u8) -> bool {
+    decimals == MINT_DECIMALS
 }
 
 /// Check for memo instruction at REQUIRED index 0
@@ -911,7 +1629,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     // to leave index 0 available for memo
     if current_index < 1 {
         msg!("memo-chat instruction must be at index 1 or later, but current instruction is at index {}", current_index);
-        return Ok((false, vec![]));
+        return memo_not_at_index_zero(instructions);
     }
     
     // Check that index 0 contains the memo instruction
@@ -922,7 +1640,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
                 validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
             } else {
                 msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
-                Ok((false, vec![]))
+                memo_not_at_index_zero(instructions)
             }
         },
         Err(e) => {
@@ -932,6 +1650,28 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     }
 }
 
+/// Called when index 0 holds no memo: if a memo exists elsewhere in the transaction,
+/// fail with MemoWrongIndex instead of letting the caller report MemoRequired
+fn memo_not_at_index_zero(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    let program_ids: Vec<Pubkey> = (0..)
+        .map_while(|i| anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(i, instructions).ok())
+        .map(|ix| ix.program_id)
+        .collect();
+    check_memo_placement(&program_ids)?;
+    Ok((false, vec![]))
+}
+
+/// Reject a transaction whose SPL Memo instruction is present but not at index 0
+fn check_memo_placement(program_ids: &[Pubkey]) -> Result<()> {
+    if let Some(index) = program_ids.iter().position(|id| *id == MEMO_PROGRAM_ID) {
+        if index > 0 {
+            msg!("Memo instruction found at index {}, but it must be placed at index 0", index);
+            return Err(ErrorCode::MemoWrongIndex.into());
+        }
+    }
+    Ok(())
+}
+
 /// Validate memo data length and return result
 fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize) -> Result<(bool, Vec<u8>)> {
     let memo_length = memo_data.len();
@@ -959,16 +1699,47 @@ fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize)
     Ok((true, memo_data.to_vec()))
 }
 
-/// Burn leaderboard entry
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
-pub struct LeaderboardEntry {
-    pub group_id: u64,
-    pub burned_amount: u64,
+/// Compute the SHA-256 hash of the raw memo instruction data
+/// (emitted in events so indexers can match them to the on-chain memo)
+fn compute_memo_hash(memo_data: &[u8]) -> [u8; 32] {
+    hash(memo_data).to_bytes()
 }
 
-/// Burn leaderboard account (stores top 100 groups by burn amount)
-#[account]
-pub struct BurnLeaderboard {
+/// Check that a memo-burn process_burn CPI reduced the burner's token balance by
+/// exactly `amount` (guards against crediting burns that were not fully applied)
+fn check_burn_reflected(balance_before: u64, balance_after: u64, amount: u64) -> Result<()> {
+    if balance_before.checked_sub(balance_after) != Some(amount) {
+        msg!("Burn not reflected in balance: {} -> {} (expected {} burned)", 
+             balance_before, balance_after, amount);
+        return Err(ErrorCode::BurnAmountNotReflected.into());
+    }
+    Ok(())
+}
+
+/// Check that the account handed to memo-burn as `user_global_burn_stats` is owned by
+/// memo-burn and carries the UserGlobalBurnStats discriminator (guards against a
+/// spoofed account reaching the burn CPI)
+fn verify_burn_stats_account(account: &AccountInfo) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    if account.owner != &memo_burn::ID
+        || !data.starts_with(memo_burn::UserGlobalBurnStats::DISCRIMINATOR)
+    {
+        msg!("Invalid burn stats account: {} (owner: {})", account.key(), account.owner);
+        return Err(ErrorCode::InvalidBurnStatsAccount.into());
+    }
+    Ok(())
+}
+
+/// Burn leaderboard entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LeaderboardEntry {
+    pub group_id: u64,
+    pub burned_amount: u64,
+}
+
+/// Burn leaderboard account (stores top 100 groups by burn amount)
+#[account]
+pub struct BurnLeaderboard {
     /// Array of leaderboard entries (unsorted for performance - sort off-chain for display)
     /// Maximum 100 entries
     pub entries: Vec<LeaderboardEntry>,
@@ -980,6 +1751,9 @@ impl BurnLeaderboard {
         100 * 16 + // max entries (100 * (8 + 8) bytes each)
         64; // safety buffer
     
+    /// Maximum number of leaderboard entries
+    pub const MAX_ENTRIES: usize = 100;
+    
     /// Initialize with empty entries
     pub fn initialize(&mut self) {
         self.entries = Vec::with_capacity(100);
@@ -1012,8 +1786,31 @@ impl BurnLeaderboard {
         (found_group_pos, min_pos)
     }
     
+    /// Reject an account whose entries Vec exceeds MAX_ENTRIES (corrupted or resized data)
+    pub fn check_integrity(&self) -> Result<()> {
+        if self.entries.len() > Self::MAX_ENTRIES {
+            msg!("Leaderboard corrupted: {} entries (max: {})", self.entries.len(), Self::MAX_ENTRIES);
+            return Err(ErrorCode::LeaderboardCorrupted.into());
+        }
+        Ok(())
+    }
+    
+    /// Remove the entry for `group_id` regardless of its amount (admin only, for abuse remediation).
+    /// Returns the removed entry, or None when the id is not on the leaderboard (a no-op).
+    /// The group can re-enter through its next burn.
+    pub fn admin_remove_entry(&mut self, admin: Pubkey, group_id: u64) -> Result<Option<LeaderboardEntry>> {
+        if admin != AUTHORIZED_ADMIN_PUBKEY {
+            return Err(ErrorCode::UnauthorizedAdmin.into());
+        }
+        let position = self.entries.iter().position(|entry| entry.group_id == group_id);
+        Ok(position.map(|pos| self.entries.swap_remove(pos)))
+    }
+    
     /// update leaderboard - zero array move version
     pub fn update_leaderboard(&mut self, group_id: u64, new_burned_amount: u64) -> Result<bool> {
+        // 0. refuse to process an over-length entries Vec
+        self.check_integrity()?;
+        
         // 1. one loop to get group position and min position
         let (existing_pos, min_pos) = self.find_group_position_and_min(group_id);
         
@@ -1024,7 +1821,7 @@ impl BurnLeaderboard {
         }
         
         // 3. new group and leaderboard not full, add directly (no sort)
-        if self.entries.len() < 100 {
+        if self.entries.len() < Self::MAX_ENTRIES {
             let new_entry = LeaderboardEntry {
                 group_id,
                 burned_amount: new_burned_amount,
@@ -1062,6 +1859,166 @@ pub struct GlobalGroupCounter {
 impl GlobalGroupCounter {
     pub const SPACE: usize = 8 + // discriminator
         8; // total_groups (u64)
+
+    /// Reject creation once the counter reaches MAX_ENTITIES
+    pub fn check_capacity(&self) -> Result<()> {
+        if self.total_groups >= MAX_ENTITIES {
+            msg!("Entity limit reached: {} groups created (max: {})", self.total_groups, MAX_ENTITIES);
+            return Err(ErrorCode::EntityLimitReached.into());
+        }
+        Ok(())
+    }
+}
+
+/// Program-wide burn/mint totals (singleton PDA, initialized by admin)
+#[account]
+pub struct ProgramStats {
+    pub total_burned: u128,     // Total units burned through this program
+    pub total_minted: u128,     // Total units minted through this program
+    pub event_seq: u64,         // Sequence number of the last emitted event (0 = none yet)
+    pub bump: u8,               // PDA bump
+}
+
+impl ProgramStats {
+    pub const SPACE: usize = 8 + // discriminator
+        16 + // total_burned (u128)
+        16 + // total_minted (u128)
+        8 +  // event_seq (u64)
+        1;   // bump (u8)
+
+    /// Add a burn to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_burn(&mut self, amount: u64) {
+        self.total_burned = self.total_burned.saturating_add(u128::from(amount));
+    }
+
+    /// Add a mint to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_mint(&mut self, amount: u64) {
+        self.total_minted = self.total_minted.saturating_add(u128::from(amount));
+    }
+
+    /// Advance the event sequence and return the new value. The first event gets 1,
+    /// so indexers can detect dropped events by looking for gaps in the sequence.
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.saturating_add(1);
+        self.event_seq
+    }
+}
+
+/// Per-user record of tokens minted through chat messages in the current UTC day
+#[account]
+pub struct DailyMintQuota {
+    pub user: Pubkey,                 // Wallet this quota belongs to
+    pub day: i64,                     // UTC day (unix_timestamp / 86400) of minted_today
+    pub minted_today: u64,            // Tokens minted during `day`
+    pub bump: u8,                     // PDA bump
+}
+
+impl DailyMintQuota {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // user (Pubkey)
+        8 + // day (i64)
+        8 + // minted_today (u64)
+        1; // bump (u8)
+
+    /// Add `amount` to today's total, starting a new total when the UTC day changed;
+    /// rejects a mint that would take the total past MAX_DAILY_MINT
+    pub fn record_mint(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        let today = current_time.div_euclid(86400);
+        if today != self.day {
+            self.day = today;
+            self.minted_today = 0;
+        }
+        let total = self.minted_today.saturating_add(amount);
+        if total > MAX_DAILY_MINT {
+            msg!("Daily mint cap reached: {} + {} exceeds {} for {}", 
+                 self.minted_today, amount, MAX_DAILY_MINT, self.user);
+            return Err(ErrorCode::DailyMintCapReached.into());
+        }
+        self.minted_today = total;
+        Ok(())
+    }
+}
+
+/// Admin-granted exemption from per-wallet rate limits, seeded by the exempt wallet
+#[account]
+pub struct RateLimitExemption {
+    pub wallet: Pubkey,               // Exempt wallet
+    pub granted_at: i64,              // When the admin granted the exemption
+    pub bump: u8,                     // PDA bump
+}
+
+impl RateLimitExemption {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // wallet (Pubkey)
+        8 + // granted_at (i64)
+        1; // bump (u8)
+
+    /// Whether `signer` may skip rate-limit checks (the exemption must belong to the signer)
+    pub fn exempts(exemption: Option<&Self>, signer: Pubkey) -> bool {
+        exemption.is_some_and(|exemption| exemption.wallet == signer)
+    }
+}
+
+/// Account structure for granting a rate-limit exemption (admin only)
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddRateLimitExemption<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = RateLimitExemption::SPACE,
+        seeds = [b"exempt", wallet.as_ref()],
+        bump
+    )]
+    pub rate_limit_exemption: Account<'info, RateLimitExemption>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for removing a rate-limit exemption (admin only)
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveRateLimitExemption<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"exempt", wallet.as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Account<'info, RateLimitExemption>,
+}
+
+/// Account structure for initializing the program stats (admin only)
+#[derive(Accounts)]
+pub struct InitializeProgramStats<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramStats::SPACE,
+        seeds = [b"stats"],
+        bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Account structure for initializing global counter (admin only)
@@ -1087,7 +2044,7 @@ pub struct InitializeGlobalCounter<'info> {
 
 /// Account structure for creating a chat group
 #[derive(Accounts)]
-#[instruction(expected_group_id: u64, burn_amount: u64)]
+#[instruction(expected_group_id: u64, burn_amount: u64, message_burn: u64, store_messages: bool, reward_scaling_enabled: bool)]
 pub struct CreateChatGroup<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -1117,7 +2074,8 @@ pub struct CreateChatGroup<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1147,6 +2105,14 @@ pub struct CreateChatGroup<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for sending memo to a chat group
@@ -1159,13 +2125,15 @@ pub struct SendMemoToGroup<'info> {
     #[account(
         mut,
         seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
-        bump = chat_group.bump
+        bump = chat_group.bump,
+        constraint = ChatGroup::is_migrated(chat_group.to_account_info().data_len()) @ ErrorCode::ChatGroupNotMigrated
     )]
     pub chat_group: Account<'info, ChatGroup>,
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1192,6 +2160,120 @@ pub struct SendMemoToGroup<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Per-user daily mint quota across all groups (created on the sender's first message)
+    #[account(
+        init_if_needed,
+        payer = sender,
+        space = DailyMintQuota::SPACE,
+        seeds = [b"quota", sender.key().as_ref()],
+        bump
+    )]
+    pub daily_mint_quota: Account<'info, DailyMintQuota>,
+
+    /// User global burn statistics (only required when the group has a message_burn;
+    /// seeds are verified by memo-burn during the CPI)
+    #[account(mut)]
+    pub user_global_burn_stats: Option<Account<'info, memo_burn::UserGlobalBurnStats>>,
+
+    /// The memo-burn program (only required when the group has a message_burn)
+    pub memo_burn_program: Option<Program<'info, MemoBurn>>,
+
+    /// Stored message PDA (only for groups with store_messages; indexed by memo_count, paid by sender)
+    #[account(
+        init,
+        payer = sender,
+        space = StoredMessage::calculate_space_max(),
+        seeds = [b"chat_msg", group_id.to_le_bytes().as_ref(), chat_group.memo_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub stored_message: Option<Account<'info, StoredMessage>>,
+
+    /// System program (creates the daily mint quota and stored messages)
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Admin-granted rate-limit exemption for the sender (optional; skips the group's memo interval)
+    #[account(
+        seeds = [b"exempt", sender.key().as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+}
+
+/// Account structure for a creator announcement (creator only)
+#[derive(Accounts)]
+#[instruction(group_id: u64)]
+pub struct AnnounceToGroup<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
+        bump = chat_group.bump,
+        constraint = ChatGroup::is_migrated(chat_group.to_account_info().data_len()) @ ErrorCode::ChatGroupNotMigrated,
+        constraint = chat_group.creator == creator.key() @ ErrorCode::UnauthorizedGroupAccess
+    )]
+    pub chat_group: Account<'info, ChatGroup>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA serving as mint authority (from memo-mint program)
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+        seeds::program = memo_mint_program.key()
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = creator_token_account.owner == creator.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    /// The memo-mint program
+    pub memo_mint_program: Program<'info, MemoMint>,
+
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// Per-user daily mint quota across all groups (shared with send_memo_to_group)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = DailyMintQuota::SPACE,
+        seeds = [b"quota", creator.key().as_ref()],
+        bump
+    )]
+    pub daily_mint_quota: Account<'info, DailyMintQuota>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for burning tokens for a chat group
@@ -1204,7 +2286,8 @@ pub struct BurnTokensForGroup<'info> {
     #[account(
         mut,
         seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
-        bump = chat_group.bump
+        bump = chat_group.bump,
+        constraint = ChatGroup::is_migrated(chat_group.to_account_info().data_len()) @ ErrorCode::ChatGroupNotMigrated
     )]
     pub chat_group: Account<'info, ChatGroup>,
     
@@ -1217,7 +2300,8 @@ pub struct BurnTokensForGroup<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1245,6 +2329,77 @@ pub struct BurnTokensForGroup<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Aggregate counts returned by get_global_stats (Borsh-encoded return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GlobalStats {
+    pub total_groups: u64,
+    pub leaderboard_size: u32,
+    pub leaderboard_min: u64,   // Smallest burned amount on the leaderboard (0 if empty)
+}
+
+impl GlobalStats {
+    pub fn new(counter: &GlobalGroupCounter, leaderboard: &BurnLeaderboard) -> Self {
+        Self {
+            total_groups: counter.total_groups,
+            leaderboard_size: leaderboard.entries.len() as u32,
+            leaderboard_min: leaderboard.entries.iter().map(|e| e.burned_amount).min().unwrap_or(0),
+        }
+    }
+}
+
+/// Leaderboard entry bar returned by get_leaderboard_min (Borsh-encoded return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeaderboardMin {
+    pub min_burned_amount: u64, // Amount a newcomer must exceed (0 while the board has free slots)
+    pub is_full: bool,
+}
+
+impl LeaderboardMin {
+    pub fn new(leaderboard: &BurnLeaderboard) -> Self {
+        if leaderboard.entries.len() < BurnLeaderboard::MAX_ENTRIES {
+            return Self { min_burned_amount: 0, is_full: false };
+        }
+        Self {
+            min_burned_amount: leaderboard.entries.iter().map(|e| e.burned_amount).min().unwrap_or(0),
+            is_full: true,
+        }
+    }
+}
+
+/// Account structure for reading global stats (read-only)
+#[derive(Accounts)]
+pub struct GetGlobalStats<'info> {
+    #[account(
+        seeds = [b"global_counter"],
+        bump
+    )]
+    pub global_counter: Account<'info, GlobalGroupCounter>,
+    
+    #[account(
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+}
+
+/// Account structure for reading the leaderboard entry bar (read-only)
+#[derive(Accounts)]
+pub struct GetLeaderboardMin<'info> {
+    #[account(
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
 }
 
 /// Account structure for initializing burn leaderboard (admin only)
@@ -1268,6 +2423,194 @@ pub struct InitializeBurnLeaderboard<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Account structure for force-removing a leaderboard entry (admin only)
+#[derive(Accounts)]
+pub struct AdminRemoveLeaderboardEntry<'info> {
+    #[account(
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for migrating a legacy-sized chat group (permissionless, payer covers rent)
+#[derive(Accounts)]
+#[instruction(group_id: u64)]
+pub struct MigrateChatGroup<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: legacy groups are not decoded as ChatGroup; owner, discriminator and size are
+    /// checked in migrate_chat_group
+    #[account(
+        mut,
+        seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chat_group: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for archiving/unarchiving a chat group (creator only)
+#[derive(Accounts)]
+#[instruction(group_id: u64)]
+pub struct ArchiveChatGroup<'info> {
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
+        bump = chat_group.bump,
+        constraint = ChatGroup::is_migrated(chat_group.to_account_info().data_len()) @ ErrorCode::ChatGroupNotMigrated,
+        constraint = chat_group.creator == creator.key() @ ErrorCode::UnauthorizedGroupAccess
+    )]
+    pub chat_group: Account<'info, ChatGroup>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for setting the pinned message of a chat group (creator only)
+#[derive(Accounts)]
+#[instruction(group_id: u64)]
+pub struct SetPinnedMessage<'info> {
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
+        bump = chat_group.bump,
+        constraint = ChatGroup::is_migrated(chat_group.to_account_info().data_len()) @ ErrorCode::ChatGroupNotMigrated,
+        constraint = chat_group.creator == creator.key() @ ErrorCode::UnauthorizedGroupAccess
+    )]
+    pub chat_group: Account<'info, ChatGroup>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for setting require_fresh_messages on a chat group (creator only)
+#[derive(Accounts)]
+#[instruction(group_id: u64)]
+pub struct SetRequireFreshMessages<'info> {
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
+        bump = chat_group.bump,
+        constraint = ChatGroup::is_migrated(chat_group.to_account_info().data_len()) @ ErrorCode::ChatGroupNotMigrated,
+        constraint = chat_group.creator == creator.key() @ ErrorCode::UnauthorizedGroupAccess
+    )]
+    pub chat_group: Account<'info, ChatGroup>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for setting the verified flag of a chat group (admin only)
+#[derive(Accounts)]
+#[instruction(group_id: u64)]
+pub struct SetGroupVerified<'info> {
+    #[account(
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"chat_group", group_id.to_le_bytes().as_ref()],
+        bump = chat_group.bump,
+        constraint = ChatGroup::is_migrated(chat_group.to_account_info().data_len()) @ ErrorCode::ChatGroupNotMigrated
+    )]
+    pub chat_group: Account<'info, ChatGroup>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Mint a message reward through memo-mint (length-scaled for groups with reward scaling)
+/// and return the amount actually minted
+#[allow(clippy::too_many_arguments)]
+fn mint_message_reward<'info>(
+    chat_group: &ChatGroup,
+    message_length: usize,
+    user: &Signer<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    mint_authority: &AccountInfo<'info>,
+    token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    token_program: &Program<'info, Token2022>,
+    memo_mint_program: &Program<'info, MemoMint>,
+    instructions: &AccountInfo<'info>,
+) -> Result<u64> {
+    // Balance before minting, used to derive the minted amount for program stats
+    let balance_before_mint = token_account.amount;
+
+    // Groups with reward scaling mint a length-scaled amount (capped by memo-mint)
+    let scaled_amount = if chat_group.reward_scaling_enabled {
+        let base_amount = memo_mint::calculate_dynamic_mint_amount(mint.supply)?;
+        chat_group.scaled_mint_amount(base_amount, message_length)
+    } else {
+        None
+    };
+
+    // Call memo-mint contract using CPI to process_mint (user as direct signer)
+    // This allows the user to directly mint tokens without using chat group PDA
+    let cpi_accounts = ProcessMint {
+        user: user.to_account_info(),
+        mint: mint.to_account_info(),
+        mint_authority: mint_authority.clone(),
+        token_account: token_account.to_account_info(),
+        token_program: token_program.to_account_info(),
+        instructions: instructions.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new(memo_mint_program.to_account_info(), cpi_accounts);
+    match scaled_amount {
+        Some(amount) => memo_mint::cpi::process_mint_amount(cpi_ctx, amount)?,
+        None => memo_mint::cpi::process_mint(cpi_ctx)?,
+    }
+
+    // process_mint picks the amount from the supply tier, so derive it from the balance
+    token_account.reload()?;
+    Ok(token_account.amount.saturating_sub(balance_before_mint))
+}
+
 /// Chat group data structure
 #[account]
 pub struct ChatGroup {
@@ -1283,6 +2626,35 @@ pub struct ChatGroup {
     pub min_memo_interval: i64,     // Minimum memo interval in seconds (rate limit for send_memo_to_group only)
     pub last_memo_time: i64,        // Last send_memo_to_group timestamp (0 = no rate limit for first message)
     pub bump: u8,                   // PDA bump
+    // Fields below were appended after the original layout; groups created before
+    // them are converted by migrate_chat_group (see ChatGroup::LEGACY_SPACE)
+    pub message_burn: u64,          // Tokens burned per send_memo_to_group (0 = free, mint-only)
+    pub verified: bool,             // Set by admin only (trust signal for explorers)
+    pub archived: bool,             // Set by creator; archived groups reject messages and burns
+    pub store_messages: bool,       // Store each message body in a StoredMessage PDA (set at creation)
+    pub reward_scaling_enabled: bool, // Scale the message mint reward by message length (set at creation)
+    pub lang: String,               // Language tag (optional, max 8 chars)
+    pub pinned_message: String,     // Rules/welcome message set by the creator (empty = none, max 512 chars)
+    pub name_lower: String,         // Lowercased name for case-insensitive search
+    pub require_fresh_messages: bool, // Messages must carry a client_ts within MAX_CLOCK_SKEW_SECONDS (set by creator)
+    pub last_announcement: i64,     // Last announce_to_group timestamp (0 = none; announcements skip min_memo_interval)
+}
+
+/// ChatGroup layout written by program versions before message_burn (the prefix of ChatGroup)
+#[derive(AnchorDeserialize)]
+struct LegacyChatGroup {
+    group_id: u64,
+    creator: Pubkey,
+    created_at: i64,
+    name: String,
+    description: String,
+    image: String,
+    tags: Vec<String>,
+    memo_count: u64,
+    burned_amount: u64,
+    min_memo_interval: i64,
+    last_memo_time: i64,
+    bump: u8,
 }
 
 impl ChatGroup {
@@ -1297,12 +2669,257 @@ impl ChatGroup {
         8 + // min_memo_interval
         8 + // last_memo_time
         1 + // bump
+        8 + // message_burn
+        1 + // verified
+        1 + // archived
+        1 + // store_messages
+        1 + // reward_scaling_enabled
+        1 + // require_fresh_messages
+        8 + // last_announcement
+        4 + 8 + // lang (max 8 chars)
+        4 + MAX_PINNED_MESSAGE_LENGTH + // pinned_message (max 512 chars)
+        4 + 64 + // name_lower (never longer than name)
         4 + 64 + // name (max 64 chars)
         4 + 128 + // description (max 128 chars)
         4 + 256 + // image (max 256 chars)
         4 + (4 + 32) * 4 + // tags (max 4 tags, 32 chars each)
         128 // safety buffer
     }
+
+    /// Size of groups created before message_burn and the later fields were appended
+    /// (the calculate_space_max of that layout, which every such group was allocated with)
+    pub const LEGACY_SPACE: usize = 8 + // discriminator
+        8 + // group_id
+        32 + // creator
+        8 + // created_at
+        8 + // memo_count
+        8 + // burned_amount
+        8 + // min_memo_interval
+        8 + // last_memo_time
+        1 + // bump
+        4 + 64 + // name
+        4 + 128 + // description
+        4 + 256 + // image
+        4 + (4 + 32) * 4 + // tags
+        128; // safety buffer
+
+    /// Whether a group account of `data_len` bytes has the current layout. A legacy-sized
+    /// group may decode, but its appended fields come from whatever bytes followed its old
+    /// data (zeros or stale string bytes), so instructions reject it until migrated
+    pub fn is_migrated(data_len: usize) -> bool {
+        data_len != Self::LEGACY_SPACE
+    }
+
+    /// Whether a group account needs migrate_chat_group: true for a legacy-sized group,
+    /// false if it is already current, error for anything else
+    pub fn needs_migration(data: &[u8]) -> Result<bool> {
+        if !data.starts_with(ChatGroup::DISCRIMINATOR) {
+            return Err(ErrorCode::InvalidChatGroupAccount.into());
+        }
+        match data.len() {
+            Self::LEGACY_SPACE => Ok(true),
+            len if len == Self::calculate_space_max() => Ok(false),
+            _ => Err(ErrorCode::InvalidChatGroupAccount.into()),
+        }
+    }
+
+    /// Decode a legacy-sized group (discriminator included). The appended fields start
+    /// empty (free, unverified, active group with no stored messages), except name_lower
+    /// (derived from name)
+    pub fn from_legacy(data: &[u8]) -> Result<Self> {
+        if !Self::needs_migration(data)? {
+            return Err(ErrorCode::InvalidChatGroupAccount.into());
+        }
+        let legacy = LegacyChatGroup::deserialize(&mut &data[8..])
+            .map_err(|_| ErrorCode::InvalidChatGroupAccount)?;
+
+        Ok(Self {
+            group_id: legacy.group_id,
+            creator: legacy.creator,
+            created_at: legacy.created_at,
+            name_lower: lowercase_name(&legacy.name),
+            name: legacy.name,
+            description: legacy.description,
+            image: legacy.image,
+            tags: legacy.tags,
+            memo_count: legacy.memo_count,
+            burned_amount: legacy.burned_amount,
+            min_memo_interval: legacy.min_memo_interval,
+            last_memo_time: legacy.last_memo_time,
+            bump: legacy.bump,
+            message_burn: 0,
+            verified: false,
+            archived: false,
+            store_messages: false,
+            reward_scaling_enabled: false,
+            lang: String::new(),
+            pinned_message: String::new(),
+            require_fresh_messages: false,
+            last_announcement: 0,
+        })
+    }
+
+    /// Validate a per-message burn: 0 (free) or a whole-token amount within burn limits
+    pub fn validate_message_burn(message_burn: u64) -> Result<()> {
+        if message_burn == 0 {
+            return Ok(());
+        }
+
+        if message_burn < MIN_BURN_AMOUNT {
+            return Err(ErrorCode::BurnAmountTooSmall.into());
+        }
+
+        if message_burn > MAX_BURN_PER_TX {
+            return Err(ErrorCode::BurnAmountTooLarge.into());
+        }
+
+        if !message_burn.is_multiple_of(DECIMAL_FACTOR) {
+            return Err(ErrorCode::InvalidBurnAmount.into());
+        }
+
+        Ok(())
+    }
+
+    /// Set the verified flag (only AUTHORIZED_ADMIN_PUBKEY may change it)
+    pub fn set_verified(&mut self, admin: Pubkey, verified: bool) -> Result<()> {
+        if admin != AUTHORIZED_ADMIN_PUBKEY {
+            return Err(ErrorCode::UnauthorizedAdmin.into());
+        }
+        self.verified = verified;
+        Ok(())
+    }
+
+    /// Set the archived flag (only the group creator may change it)
+    pub fn set_archived(&mut self, caller: Pubkey, archived: bool) -> Result<()> {
+        if caller != self.creator {
+            return Err(ErrorCode::UnauthorizedGroupAccess.into());
+        }
+        self.archived = archived;
+        Ok(())
+    }
+
+    /// Validate a pinned message (empty unpins, otherwise at most MAX_PINNED_MESSAGE_LENGTH characters)
+    pub fn validate_pinned_message(message: &str) -> Result<()> {
+        if message.len() > MAX_PINNED_MESSAGE_LENGTH {
+            msg!("Pinned message too long: {} characters (max: {})", message.len(), MAX_PINNED_MESSAGE_LENGTH);
+            return Err(ErrorCode::PinnedMessageTooLong.into());
+        }
+        Ok(())
+    }
+
+    /// Set the pinned message (only the group creator may change it)
+    pub fn set_pinned_message(&mut self, caller: Pubkey, message: String) -> Result<()> {
+        if caller != self.creator {
+            return Err(ErrorCode::UnauthorizedGroupAccess.into());
+        }
+        Self::validate_pinned_message(&message)?;
+        self.pinned_message = message;
+        Ok(())
+    }
+
+    /// Mint amount for a message when reward scaling is enabled
+    /// (None mints the flat tier amount via process_mint)
+    pub fn scaled_mint_amount(&self, base_amount: u64, message_length: usize) -> Option<u64> {
+        if !self.reward_scaling_enabled {
+            return None;
+        }
+        Some(memo_mint::length_scaled_mint_amount(base_amount, message_length))
+    }
+
+    /// Index of the StoredMessage for the next message (None for non-storing groups),
+    /// checking that the account was provided exactly when the group stores messages
+    pub fn stored_message_index(&self, account_provided: bool) -> Result<Option<u64>> {
+        match (self.store_messages, account_provided) {
+            (true, true) => Ok(Some(self.memo_count)),
+            (false, false) => Ok(None),
+            (true, false) => Err(ErrorCode::StoredMessageAccountRequired.into()),
+            (false, true) => Err(ErrorCode::StoredMessagesDisabled.into()),
+        }
+    }
+
+    /// Enforce min_memo_interval since the group's last message (exempt senders skip it)
+    pub fn check_memo_interval(&self, current_time: i64, exempt: bool) -> Result<()> {
+        if !exempt && self.last_memo_time > 0 {
+            let time_since_last = current_time - self.last_memo_time;
+            if time_since_last < self.min_memo_interval {
+                return Err(ErrorCode::MemoTooFrequent.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Count a creator announcement; unlike a message it leaves last_memo_time alone,
+    /// so announcements neither wait for nor throttle regular messages. Returns the new memo_count
+    pub fn record_announcement(&mut self, current_time: i64) -> u64 {
+        self.memo_count = self.memo_count.saturating_add(1);
+        self.last_announcement = current_time;
+        self.memo_count
+    }
+
+    /// In groups requiring fresh messages, reject messages without a client_ts or whose
+    /// client_ts is more than MAX_CLOCK_SKEW_SECONDS away from `current_time` (limits replay)
+    pub fn check_message_freshness(&self, client_ts: Option<i64>, current_time: i64) -> Result<()> {
+        if !self.require_fresh_messages {
+            return Ok(());
+        }
+        let Some(client_ts) = client_ts else {
+            msg!("Group {} requires a client_ts on every message", self.group_id);
+            return Err(ErrorCode::StaleMessage.into());
+        };
+        if current_time.abs_diff(client_ts) > MAX_CLOCK_SKEW_SECONDS {
+            msg!("Stale message: client_ts {} is {}s away from {} (max: {}s)", 
+                 client_ts, current_time.abs_diff(client_ts), current_time, MAX_CLOCK_SKEW_SECONDS);
+            return Err(ErrorCode::StaleMessage.into());
+        }
+        Ok(())
+    }
+
+    /// Set whether messages must carry a fresh client_ts (only the group creator may change it)
+    pub fn set_require_fresh_messages(&mut self, caller: Pubkey, required: bool) -> Result<()> {
+        if caller != self.creator {
+            return Err(ErrorCode::UnauthorizedGroupAccess.into());
+        }
+        self.require_fresh_messages = required;
+        Ok(())
+    }
+
+    /// Reject new messages and burns on archived groups
+    pub fn check_not_archived(&self) -> Result<()> {
+        if self.archived {
+            msg!("Group {} is archived", self.group_id);
+            return Err(ErrorCode::GroupArchived.into());
+        }
+        Ok(())
+    }
+}
+
+/// Chat message stored on-chain (for groups created with store_messages)
+#[account]
+pub struct StoredMessage {
+    pub group_id: u64,              // Group this message belongs to
+    pub index: u64,                 // Group memo_count when the message was sent
+    pub sender: Pubkey,             // Message sender
+    pub message: String,            // Message content (max 512 chars)
+    pub timestamp: i64,             // Send timestamp
+    pub bump: u8,                   // PDA bump
+}
+
+impl StoredMessage {
+    pub fn new(group_id: u64, index: u64, sender: Pubkey, message: String, timestamp: i64, bump: u8) -> Self {
+        Self { group_id, index, sender, message, timestamp, bump }
+    }
+
+    /// Calculate maximum space for the account (conservative estimate)
+    pub fn calculate_space_max() -> usize {
+        8 + // discriminator
+        8 + // group_id
+        8 + // index
+        32 + // sender
+        4 + MAX_MESSAGE_LENGTH + // message (max 512 chars)
+        8 + // timestamp
+        1 + // bump
+        128 // safety buffer
+    }
 }
 
 /// Event emitted when a chat group is created
@@ -1311,11 +2928,39 @@ pub struct ChatGroupCreatedEvent {
     pub group_id: u64,
     pub creator: Pubkey,
     pub name: String,
+    pub name_lower: String,
     pub description: String,
     pub image: String,
     pub tags: Vec<String>,
+    pub lang: String,
+    pub pinned_message: String,
     pub burn_amount: u64,
+    pub message_burn: u64,
+    pub store_messages: bool,
+    pub reward_scaling_enabled: bool,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a group creator changes the pinned message
+#[event]
+pub struct PinnedMessageChangedEvent {
+    pub group_id: u64,
+    pub creator: Pubkey,
+    pub pinned_message: String,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the creator changes whether a group requires fresh messages
+#[event]
+pub struct FreshMessagesRequirementChangedEvent {
+    pub group_id: u64,
+    pub creator: Pubkey,
+    pub require_fresh_messages: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
 }
 
 /// Event emitted when a memo is sent to a group
@@ -1325,7 +2970,26 @@ pub struct MemoSentEvent {
     pub sender: Pubkey,
     pub memo: String,
     pub memo_count: u64,
+    pub message_burn: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub encrypted: bool,         // Memo is client-side ciphertext; decrypt before display
+    pub encryption_scheme: u8,   // EncryptionScheme value (0 when not encrypted)
+    pub event_seq: u64,
+}
+
+/// Event emitted when a group creator posts an announcement
+#[event]
+pub struct GroupAnnouncementEvent {
+    pub group_id: u64,
+    pub creator: Pubkey,
+    pub message: String,
+    pub memo_count: u64,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub encrypted: bool,         // Message is client-side ciphertext; decrypt before display
+    pub encryption_scheme: u8,   // EncryptionScheme value (0 when not encrypted)
+    pub event_seq: u64,
 }
 
 /// Event emitted when tokens are burned for a group
@@ -1336,6 +3000,38 @@ pub struct TokensBurnedForGroupEvent {
     pub amount: u64,
     pub total_burned: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when the creator archives or unarchives a group
+#[event]
+pub struct ChatGroupArchivedEvent {
+    pub group_id: u64,
+    pub creator: Pubkey,
+    pub archived: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin changes a group's verified flag
+#[event]
+pub struct VerificationChangedEvent {
+    pub group_id: u64,
+    pub admin: Pubkey,
+    pub verified: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin force-removes a leaderboard entry
+#[event]
+pub struct LeaderboardEntryRemovedEvent {
+    pub group_id: u64,
+    pub admin: Pubkey,
+    pub burned_amount: u64, // Amount the removed entry held
+    pub timestamp: i64,
+    pub event_seq: u64,
 }
 
 /// Error code definitions
@@ -1508,4 +3204,79 @@ pub enum ErrorCode {
 
     #[msg("Burn amount too large. Maximum allowed: 1,000,000,000,000 tokens per transaction.")]
     BurnAmountTooLarge,
+
+    #[msg("Message burn accounts required: This group charges a burn per message, so user_global_burn_stats and memo_burn_program must be provided.")]
+    MessageBurnAccountsRequired,
+
+    #[msg("Unauthorized group access: Only the group creator can perform this action.")]
+    UnauthorizedGroupAccess,
+
+    #[msg("Group archived: This group no longer accepts messages or burns.")]
+    GroupArchived,
+
+    #[msg("Leaderboard corrupted: Entry count exceeds the maximum of 100.")]
+    LeaderboardCorrupted,
+
+    #[msg("Memo at wrong index: The SPL Memo instruction must be placed at index 0 of the transaction.")]
+    MemoWrongIndex,
+
+    #[msg("Stored message account required: This group stores messages on-chain, so stored_message must be provided.")]
+    StoredMessageAccountRequired,
+
+    #[msg("Stored messages disabled: This group does not store messages, so stored_message must not be provided.")]
+    StoredMessagesDisabled,
+
+    #[msg("Invalid image reference: Image must be empty, an ipfs://, ar:// or https:// reference, or a CIDv0 hash.")]
+    InvalidImageReference,
+
+    #[msg("Entity limit reached: No more groups can be created.")]
+    EntityLimitReached,
+
+    #[msg("Burn not reflected: The token balance did not drop by the burned amount.")]
+    BurnAmountNotReflected,
+
+    #[msg("Invalid language tag: Use ASCII letters with at most one hyphen (max 8 characters), e.g. \"en\" or \"zh-CN\".")]
+    InvalidLanguageTag,
+
+    #[msg("Low entropy content: Text cannot be only whitespace or a single repeated character.")]
+    LowEntropyContent,
+
+    #[msg("Pinned message too long: Maximum 512 characters allowed.")]
+    PinnedMessageTooLong,
+
+    #[msg("Invalid tag at index 0: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex0,
+
+    #[msg("Invalid tag at index 1: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex1,
+
+    #[msg("Invalid tag at index 2: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex2,
+
+    #[msg("Invalid tag at index 3: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex3,
+
+    #[msg("Invalid encryption scheme: Encrypted messages need a known scheme; plaintext must use none.")]
+    InvalidEncryptionScheme,
+
+    #[msg("Stale message: This group requires a client timestamp within 5 minutes of the cluster clock.")]
+    StaleMessage,
+
+    #[msg("Invalid burn stats account: user_global_burn_stats must be a memo-burn UserGlobalBurnStats account.")]
+    InvalidBurnStatsAccount,
+
+    #[msg("Daily mint cap reached: This wallet has minted the maximum amount for today (UTC).")]
+    DailyMintCapReached,
+
+    #[msg("Unexpected mint decimals: The authorized mint must have 6 decimals.")]
+    UnexpectedMintDecimals,
+
+    #[msg("Invalid tag content: Tags cannot be whitespace-only or contain control characters.")]
+    InvalidTagContent,
+
+    #[msg("Invalid chat group account: Not a current or legacy-sized chat group.")]
+    InvalidChatGroupAccount,
+
+    #[msg("Chat group not migrated: Call migrate_chat_group to convert this group to the current layout.")]
+    ChatGroupNotMigrated,
 }
@@ -10,8 +10,10 @@ use anchor_spl::token_2022::Token2022;
 use memo_burn::program::MemoBurn;
 use memo_burn::cpi::accounts::ProcessBurn;
 use memo_mint::program::MemoMint;
-use memo_mint::cpi::accounts::ProcessMint;
+use memo_mint::cpi::accounts::{ProcessMint, ProcessMintTo};
 use anchor_lang::solana_program::sysvar::instructions::{ID as INSTRUCTIONS_ID};
+use anchor_lang::solana_program::program::set_return_data;
+use solana_sha256_hasher::{hash, hashv};
 use spl_memo::ID as MEMO_PROGRAM_ID;
 use base64::{Engine as _, engine::general_purpose};
 use std::str::FromStr;
@@ -41,6 +43,7 @@ pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("Gkxz6ogojD7Ni58N4SnJXy6xDxS
 
 // Token economics
 pub const DECIMAL_FACTOR: u64 = 1_000_000; // Token decimals (6)
+pub const MINT_DECIMALS: u8 = 6; // Decimals the authorized mint must have (DECIMAL_FACTOR = 10^MINT_DECIMALS)
 
 // Post creation/update/burn constants - all require at least 1 MEMO token
 pub const MIN_POST_BURN_TOKENS: u64 = 1; // Minimum tokens to burn for any post operation
@@ -49,6 +52,35 @@ pub const MIN_POST_BURN_AMOUNT: u64 = MIN_POST_BURN_TOKENS * DECIMAL_FACTOR;
 // Maximum burn per transaction (consistent with memo-burn)
 pub const MAX_BURN_PER_TX: u64 = 1_000_000_000_000 * DECIMAL_FACTOR; // 1 trillion tokens
 
+// Content-length-based minimum burn for post creation (+1 token per 100 content bytes),
+// enabled with the "content-length-burn" feature
+pub const CONTENT_LENGTH_BURN_ENABLED: bool = cfg!(feature = "content-length-burn");
+pub const CONTENT_LENGTH_BURN_STEP: usize = 100;
+
+// Maximum number of posts (creation is rejected well before the u64 counter overflows)
+pub const MAX_ENTITIES: u64 = u64::MAX / 2;
+
+// Age after which a post with no replies can be reclaimed by its creator
+pub const STALE_POST_SECONDS: i64 = 30 * 24 * 60 * 60; // 30 days
+
+// Window after creation in which the creator can fix a post's content without burning
+pub const EDIT_GRACE_SECONDS: i64 = 300; // 5 minutes
+
+// program_kind written into BurnReceipt accounts (1 = memo-project, 2 = memo-forum)
+pub const BURN_RECEIPT_PROGRAM_KIND: u8 = 2;
+
+// Minimum time between anonymous posts from the same signer
+pub const ANONYMOUS_POST_INTERVAL_SECONDS: i64 = 60;
+
+// Seed of the PDA recorded as the creator of anonymous posts
+pub const ANONYMOUS_AUTHOR_SEED: &[u8] = b"anonymous_author";
+
+// Maximum share of a tip_and_reply_post burn minted to the post creator as a tip
+pub const MAX_TIP_BPS: u16 = 2000; // 20%
+
+// Maximum per-user mint cooldown a post can set (0 = no throttle)
+pub const MAX_MINT_INTERVAL_SECONDS: i64 = 3600; // 1 hour
+
 // ===== STRING LENGTH CONSTRAINTS =====
 
 // Post metadata limits
@@ -79,17 +111,29 @@ pub const MAX_BORSH_DATA_SIZE: usize = MEMO_MAX_LENGTH;
 pub const BURN_MEMO_VERSION: u8 = 1;
 
 // Current version of data structures
-pub const POST_CREATION_DATA_VERSION: u8 = 1;
-pub const POST_BURN_DATA_VERSION: u8 = 1;
-pub const POST_MINT_DATA_VERSION: u8 = 1;
+pub const POST_CREATION_DATA_VERSION: u8 = 4; // v2 adds mentioned_project_id, v3 adds content_flags, v4 adds lang
+pub const POST_BURN_DATA_VERSION: u8 = 2; // v2 adds parent_reply_index, v1 is still accepted
+pub const POST_MINT_DATA_VERSION: u8 = 2; // v2 adds parent_reply_index, v1 is still accepted
+pub const POST_UPDATE_DATA_VERSION: u8 = 1;
+
+// Content flags (bitfield, opt-in): front ends can blur or hide flagged content
+pub const CONTENT_FLAG_NSFW: u8 = 1 << 0;
+pub const CONTENT_FLAG_SPOILER: u8 = 1 << 1;
+pub const CONTENT_FLAG_SENSITIVE: u8 = 1 << 2;
+pub const CONTENT_FLAGS_MASK: u8 = CONTENT_FLAG_NSFW | CONTENT_FLAG_SPOILER | CONTENT_FLAG_SENSITIVE;
+
+// Language tag (BCP-47 style, e.g. "en", "zh-CN"), optional
+pub const MAX_LANG_LENGTH: usize = 8;
 
 // Expected category for memo-forum contract
 pub const EXPECTED_CATEGORY: &str = "forum";
 
 // Expected operations
 pub const EXPECTED_CREATE_POST_OPERATION: &str = "create_post";
+pub const EXPECTED_CREATE_POST_FREE_OPERATION: &str = "create_post_free";
 pub const EXPECTED_BURN_FOR_POST_OPERATION: &str = "burn_for_post";
 pub const EXPECTED_MINT_FOR_POST_OPERATION: &str = "mint_for_post";
+pub const EXPECTED_QUICK_EDIT_POST_OPERATION: &str = "quick_edit_post";
 
 /// BurnMemo structure (compatible with memo-burn contract)
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -105,7 +149,6 @@ pub struct BurnMemo {
 }
 
 /// Post creation data structure (stored in BurnMemo.payload)
-#[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct PostCreationData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -130,18 +173,105 @@ pub struct PostCreationData {
     
     /// Post image (optional, max 256 characters)
     pub image: String,
+    
+    /// Project mentioned by this post (v2+, not validated on-chain - just a link)
+    pub mentioned_project_id: Option<u64>,
+    
+    /// Content flags: nsfw / spoiler / sensitive bits (v3+, default 0)
+    pub content_flags: u8,
+    
+    /// Language tag, e.g. "en" or "zh-CN" (v4+, optional, max 8 characters)
+    pub lang: String,
+}
+
+impl AnchorSerialize for PostCreationData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.creator.serialize(writer)?;
+        self.post_id.serialize(writer)?;
+        self.title.serialize(writer)?;
+        self.content.serialize(writer)?;
+        self.image.serialize(writer)?;
+        if self.version >= 2 {
+            self.mentioned_project_id.serialize(writer)?;
+        }
+        if self.version >= 3 {
+            self.content_flags.serialize(writer)?;
+        }
+        if self.version >= 4 {
+            self.lang.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for PostCreationData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let creator = String::deserialize_reader(reader)?;
+        let post_id = u64::deserialize_reader(reader)?;
+        let title = String::deserialize_reader(reader)?;
+        let content = String::deserialize_reader(reader)?;
+        let image = String::deserialize_reader(reader)?;
+        let mentioned_project_id = if version >= 2 {
+            Option::<u64>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        let content_flags = if version >= 3 {
+            u8::deserialize_reader(reader)?
+        } else {
+            0
+        };
+        let lang = if version >= 4 {
+            String::deserialize_reader(reader)?
+        } else {
+            String::new()
+        };
+        Ok(Self { version, category, operation, creator, post_id, title, content, image, mentioned_project_id, content_flags, lang })
+    }
 }
 
 impl PostCreationData {
     /// Validate the structure fields
     pub fn validate(&self, expected_creator: Pubkey, expected_post_id: u64) -> Result<()> {
-        // Validate version
-        if self.version != POST_CREATION_DATA_VERSION {
-            msg!("Unsupported post creation data version: {} (expected: {})", 
+        self.validate_for_operation(expected_creator, expected_post_id, EXPECTED_CREATE_POST_OPERATION)
+    }
+
+    /// Validate the structure fields for a creation operation
+    /// ("create_post", or "create_post_free" for mint-only posts)
+    pub fn validate_for_operation(&self, expected_creator: Pubkey, expected_post_id: u64, expected_operation: &str) -> Result<()> {
+        // Validate version (1-4)
+        if self.version == 0 || self.version > POST_CREATION_DATA_VERSION {
+            msg!("Unsupported post creation data version: {} (expected: 1-{})", 
                  self.version, POST_CREATION_DATA_VERSION);
             return Err(ErrorCode::UnsupportedPostDataVersion.into());
         }
         
+        // mentioned_project_id cannot be carried by a v1 payload
+        if self.version < 2 && self.mentioned_project_id.is_some() {
+            msg!("mentioned_project_id requires post creation data version 2");
+            return Err(ErrorCode::UnsupportedPostDataVersion.into());
+        }
+        
+        // content_flags cannot be carried by a v1/v2 payload
+        if self.version < 3 && self.content_flags != 0 {
+            msg!("content_flags requires post creation data version 3");
+            return Err(ErrorCode::UnsupportedPostDataVersion.into());
+        }
+        validate_content_flags(self.content_flags)?;
+        
+        // lang cannot be carried by a v1-v3 payload
+        if self.version < 4 && !self.lang.is_empty() {
+            msg!("lang requires post creation data version 4");
+            return Err(ErrorCode::UnsupportedPostDataVersion.into());
+        }
+        validate_lang(&self.lang)?;
+        
         // Validate category (must be exactly "forum")
         if self.category != EXPECTED_CATEGORY {
             msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
@@ -155,16 +285,16 @@ impl PostCreationData {
             return Err(ErrorCode::InvalidCategoryLength.into());
         }
         
-        // Validate operation (must be exactly "create_post")
-        if self.operation != EXPECTED_CREATE_POST_OPERATION {
-            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, EXPECTED_CREATE_POST_OPERATION);
+        // Validate operation (must be exactly the expected creation operation)
+        if self.operation != expected_operation {
+            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, expected_operation);
             return Err(ErrorCode::InvalidOperation.into());
         }
         
         // Validate operation length
-        if self.operation.len() != EXPECTED_CREATE_POST_OPERATION.len() {
+        if self.operation.len() != expected_operation.len() {
             msg!("Invalid operation length: {} bytes (expected: {} bytes for '{}')", 
-                 self.operation.len(), EXPECTED_CREATE_POST_OPERATION.len(), EXPECTED_CREATE_POST_OPERATION);
+                 self.operation.len(), expected_operation.len(), expected_operation);
             return Err(ErrorCode::InvalidOperationLength.into());
         }
         
@@ -198,6 +328,7 @@ impl PostCreationData {
                  self.content.len(), MAX_POST_CONTENT_LENGTH);
             return Err(ErrorCode::InvalidPostContent.into());
         }
+        validate_content_entropy(&self.content)?;
         
         // Validate image (optional, max 256 characters)
         if self.image.len() > MAX_POST_IMAGE_LENGTH {
@@ -205,17 +336,22 @@ impl PostCreationData {
                  self.image.len(), MAX_POST_IMAGE_LENGTH);
             return Err(ErrorCode::InvalidPostImage.into());
         }
+        validate_image_reference(&self.image)?;
         
         msg!("Post creation data validation passed: category={}, operation={}, creator={}, post_id={}", 
              self.category, self.operation, self.creator, self.post_id);
         
         Ok(())
     }
+
+    /// Dedicated mention event for the project page (None when no project is mentioned)
+    pub fn project_mention(&self, post_id: u64) -> Option<ProjectMentionedEvent> {
+        self.mentioned_project_id.map(|project_id| ProjectMentionedEvent { project_id, post_id, event_seq: 0 })
+    }
 }
 
 /// Post burn data structure (stored in BurnMemo.payload for burn_for_post)
 /// Note: Anyone can burn for a post (not just the creator)
-#[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct PostBurnData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -234,18 +370,59 @@ pub struct PostBurnData {
     
     /// Reply message (optional, max 512 characters)
     pub message: String,
+    
+    /// Index of the reply this reply answers (v2 only, None = top-level reply)
+    pub parent_reply_index: Option<u64>,
+}
+
+impl AnchorSerialize for PostBurnData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.user.serialize(writer)?;
+        self.post_id.serialize(writer)?;
+        self.message.serialize(writer)?;
+        if self.version >= 2 {
+            self.parent_reply_index.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for PostBurnData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let user = String::deserialize_reader(reader)?;
+        let post_id = u64::deserialize_reader(reader)?;
+        let message = String::deserialize_reader(reader)?;
+        let parent_reply_index = if version >= 2 {
+            Option::<u64>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        Ok(Self { version, category, operation, user, post_id, message, parent_reply_index })
+    }
 }
 
 impl PostBurnData {
     /// Validate the structure fields
     pub fn validate(&self, expected_user: Pubkey, expected_post_id: u64) -> Result<()> {
-        // Validate version
-        if self.version != POST_BURN_DATA_VERSION {
-            msg!("Unsupported post burn data version: {} (expected: {})", 
+        // Validate version (1 or 2)
+        if self.version == 0 || self.version > POST_BURN_DATA_VERSION {
+            msg!("Unsupported post burn data version: {} (expected: 1-{})", 
                  self.version, POST_BURN_DATA_VERSION);
             return Err(ErrorCode::UnsupportedPostBurnDataVersion.into());
         }
         
+        // parent_reply_index cannot be carried by a v1 payload
+        if self.version < 2 && self.parent_reply_index.is_some() {
+            msg!("parent_reply_index requires post burn data version 2");
+            return Err(ErrorCode::UnsupportedPostBurnDataVersion.into());
+        }
+        
         // Validate category (must be exactly "forum")
         if self.category != EXPECTED_CATEGORY {
             msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
@@ -307,7 +484,6 @@ impl PostBurnData {
 /// Post mint data structure (stored in BurnMemo.payload for mint_for_post)
 /// Note: Anyone can mint for a post (not just the creator)
 /// For mint operations, the burn_amount in BurnMemo should be 0
-#[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct PostMintData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -326,18 +502,59 @@ pub struct PostMintData {
     
     /// Reply message (optional, max 512 characters)
     pub message: String,
+    
+    /// Index of the reply this reply answers (v2 only, None = top-level reply)
+    pub parent_reply_index: Option<u64>,
+}
+
+impl AnchorSerialize for PostMintData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.user.serialize(writer)?;
+        self.post_id.serialize(writer)?;
+        self.message.serialize(writer)?;
+        if self.version >= 2 {
+            self.parent_reply_index.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for PostMintData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let user = String::deserialize_reader(reader)?;
+        let post_id = u64::deserialize_reader(reader)?;
+        let message = String::deserialize_reader(reader)?;
+        let parent_reply_index = if version >= 2 {
+            Option::<u64>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        Ok(Self { version, category, operation, user, post_id, message, parent_reply_index })
+    }
 }
 
 impl PostMintData {
     /// Validate the structure fields
     pub fn validate(&self, expected_user: Pubkey, expected_post_id: u64) -> Result<()> {
-        // Validate version
-        if self.version != POST_MINT_DATA_VERSION {
-            msg!("Unsupported post mint data version: {} (expected: {})", 
+        // Validate version (1 or 2)
+        if self.version == 0 || self.version > POST_MINT_DATA_VERSION {
+            msg!("Unsupported post mint data version: {} (expected: 1-{})", 
                  self.version, POST_MINT_DATA_VERSION);
             return Err(ErrorCode::UnsupportedPostMintDataVersion.into());
         }
         
+        // parent_reply_index cannot be carried by a v1 payload
+        if self.version < 2 && self.parent_reply_index.is_some() {
+            msg!("parent_reply_index requires post mint data version 2");
+            return Err(ErrorCode::UnsupportedPostMintDataVersion.into());
+        }
+        
         // Validate category (must be exactly "forum")
         if self.category != EXPECTED_CATEGORY {
             msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
@@ -396,6 +613,167 @@ impl PostMintData {
     }
 }
 
+/// Post update data structure (stored in BurnMemo.payload for quick_edit_post)
+/// Quick edits are free, so the burn_amount in BurnMemo should be 0
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PostUpdateData {
+    /// Version of this structure (for future compatibility)
+    pub version: u8,
+    
+    /// Category of the request (must be "forum" for memo-forum contract)
+    pub category: String,
+    
+    /// Operation type (must be "quick_edit_post" for quick edits)
+    pub operation: String,
+    
+    /// User pubkey as string (must match the transaction signer)
+    pub user: String,
+    
+    /// Post ID being edited
+    pub post_id: u64,
+    
+    /// New post content (required, 1-512 characters)
+    pub content: String,
+}
+
+impl PostUpdateData {
+    /// Validate the structure fields
+    pub fn validate(&self, expected_user: Pubkey, expected_post_id: u64) -> Result<()> {
+        // Validate version
+        if self.version != POST_UPDATE_DATA_VERSION {
+            msg!("Unsupported post update data version: {} (expected: {})", 
+                 self.version, POST_UPDATE_DATA_VERSION);
+            return Err(ErrorCode::UnsupportedPostUpdateDataVersion.into());
+        }
+        
+        // Validate category (must be exactly "forum")
+        if self.category != EXPECTED_CATEGORY {
+            msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
+            return Err(ErrorCode::InvalidCategory.into());
+        }
+        
+        // Validate operation (must be exactly "quick_edit_post")
+        if self.operation != EXPECTED_QUICK_EDIT_POST_OPERATION {
+            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, EXPECTED_QUICK_EDIT_POST_OPERATION);
+            return Err(ErrorCode::InvalidOperation.into());
+        }
+        
+        // Validate user pubkey matches transaction signer
+        let parsed_pubkey = Pubkey::from_str(&self.user)
+            .map_err(|_| {
+                msg!("Invalid user pubkey format: {}", self.user);
+                ErrorCode::InvalidUserPubkeyFormat
+            })?;
+        
+        if parsed_pubkey != expected_user {
+            msg!("User pubkey mismatch: memo {} vs expected {}", parsed_pubkey, expected_user);
+            return Err(ErrorCode::UserPubkeyMismatch.into());
+        }
+        
+        // Validate post_id matches expected
+        if self.post_id != expected_post_id {
+            msg!("Post ID mismatch: memo {} vs expected {}", self.post_id, expected_post_id);
+            return Err(ErrorCode::PostIdMismatch.into());
+        }
+        
+        // Validate content (required, 1-512 characters)
+        if self.content.is_empty() || self.content.len() > MAX_POST_CONTENT_LENGTH {
+            msg!("Invalid post content: {} characters (must be 1-{})", 
+                 self.content.len(), MAX_POST_CONTENT_LENGTH);
+            return Err(ErrorCode::InvalidPostContent.into());
+        }
+        validate_content_entropy(&self.content)?;
+        
+        msg!("Post update data validation passed: user={}, post_id={}", self.user, self.post_id);
+        
+        Ok(())
+    }
+}
+
+/// Validate a content_flags bitfield (reserved bits must be zero)
+pub fn validate_content_flags(content_flags: u8) -> Result<()> {
+    if content_flags & !CONTENT_FLAGS_MASK != 0 {
+        msg!("Invalid content flags: {:#010b} (allowed bits: {:#010b})", content_flags, CONTENT_FLAGS_MASK);
+        return Err(ErrorCode::InvalidContentFlags.into());
+    }
+    Ok(())
+}
+
+/// Reject degenerate content: entirely whitespace, or one character repeated (e.g. "aaaa")
+pub fn validate_content_entropy(content: &str) -> Result<()> {
+    let mut chars = content.chars();
+    let single_repeated_char = match chars.next() {
+        Some(first) => content.len() > first.len_utf8() && chars.all(|c| c == first),
+        None => false,
+    };
+    if content.trim().is_empty() || single_repeated_char {
+        msg!("Low entropy content rejected: {} bytes of whitespace or a single repeated character", content.len());
+        return Err(ErrorCode::LowEntropyContent.into());
+    }
+    Ok(())
+}
+
+/// Validate a BCP-47 style language tag: empty, or ASCII letters with at most one hyphen
+pub fn validate_lang(lang: &str) -> Result<()> {
+    if lang.is_empty() {
+        return Ok(());
+    }
+    let valid = lang.len() <= MAX_LANG_LENGTH
+        && lang.matches('-').count() <= 1
+        && lang.split('-').all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_alphabetic()));
+    if !valid {
+        msg!("Invalid language tag: '{}' (expected ASCII letters with at most one hyphen, max {} characters)", lang, MAX_LANG_LENGTH);
+        return Err(ErrorCode::InvalidLanguageTag.into());
+    }
+    Ok(())
+}
+
+/// Minimum burn for a post with `content_len` bytes of content
+pub fn min_burn_for_content_length(content_len: usize) -> u64 {
+    MIN_POST_BURN_AMOUNT + (content_len / CONTENT_LENGTH_BURN_STEP) as u64 * DECIMAL_FACTOR
+}
+
+/// Reject a post creation burn below the content-length minimum (no-op when disabled)
+pub fn check_burn_for_content_length(burn_amount: u64, content_len: usize, enabled: bool) -> Result<()> {
+    if !enabled {
+        return Ok(());
+    }
+    let min_burn = min_burn_for_content_length(content_len);
+    if burn_amount < min_burn {
+        msg!("Burn amount {} is below the minimum {} for {} bytes of content",
+             burn_amount, min_burn, content_len);
+        return Err(ErrorCode::InsufficientBurnForContentLength.into());
+    }
+    Ok(())
+}
+
+/// Image references must use one of these schemes (or be a bare CIDv0)
+pub const IMAGE_REFERENCE_PREFIXES: [&str; 3] = ["ipfs://", "ar://", "https://"];
+
+/// Length of a bare CIDv0 IPFS hash ("Qm" + 44 base58 characters)
+pub const CID_V0_LENGTH: usize = 46;
+
+/// Validate an image field: empty, an ipfs:// / ar:// / https:// reference, or a bare CIDv0
+pub fn validate_image_reference(image: &str) -> Result<()> {
+    if image.is_empty()
+        || IMAGE_REFERENCE_PREFIXES.iter().any(|prefix| image.starts_with(prefix))
+        || is_cid_v0(image)
+    {
+        return Ok(());
+    }
+
+    msg!("Invalid image reference: {} (expected ipfs://, ar://, https:// or a CIDv0)", image);
+    Err(ErrorCode::InvalidImageReference.into())
+}
+
+/// Whether `value` looks like a CIDv0 ("Qm..." in base58, 46 characters)
+fn is_cid_v0(value: &str) -> bool {
+    const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    value.len() == CID_V0_LENGTH
+        && value.starts_with("Qm")
+        && value.chars().all(|c| BASE58_ALPHABET.contains(c))
+}
+
 #[program]
 pub mod memo_forum {
     use super::*;
@@ -415,98 +793,104 @@ pub mod memo_forum {
         Ok(())
     }
 
+    /// Initialize the program-wide burn/mint statistics (one-time setup, admin only;
+    /// burn and mint instructions fail until it exists)
+    pub fn initialize_program_stats(ctx: Context<InitializeProgramStats>) -> Result<()> {
+        let program_stats = &mut ctx.accounts.program_stats;
+        program_stats.total_burned = 0;
+        program_stats.total_minted = 0;
+        program_stats.event_seq = 0;
+        program_stats.bump = ctx.bumps.program_stats;
+
+        msg!("Program stats initialized by admin {}", ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Exempt a wallet from rate limits (admin only), e.g. a trusted cross-post bot
+    pub fn add_rate_limit_exemption(ctx: Context<AddRateLimitExemption>, wallet: Pubkey) -> Result<()> {
+        let exemption = &mut ctx.accounts.rate_limit_exemption;
+        exemption.wallet = wallet;
+        exemption.granted_at = Clock::get()?.unix_timestamp;
+        exemption.bump = ctx.bumps.rate_limit_exemption;
+
+        msg!("Rate limit exemption granted to {} by admin {}", wallet, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Remove a wallet's rate-limit exemption (admin only); the rent goes back to the admin
+    pub fn remove_rate_limit_exemption(ctx: Context<RemoveRateLimitExemption>, wallet: Pubkey) -> Result<()> {
+        msg!("Rate limit exemption removed from {} by admin {}", wallet, ctx.accounts.admin.key());
+        Ok(())
+    }
+
     /// Create a new forum post (requires burning at least 1 MEMO token)
     /// Post ID is automatically assigned from the global counter
     pub fn create_post(
         ctx: Context<CreatePost>,
         expected_post_id: u64,
         burn_amount: u64,
+        min_mint_interval: i64, // Per-user cooldown for mint_for_post (0 = no throttle)
+        min_holding: u64, // Token balance (in units) repliers must hold (0 = anyone can reply)
     ) -> Result<()> {
-        // Validate burn amount - require at least 1 token for post creation
-        if burn_amount < MIN_POST_BURN_AMOUNT {
-            return Err(ErrorCode::BurnAmountTooSmall.into());
-        }
-        
-        // check burn amount limit
-        if burn_amount > MAX_BURN_PER_TX {
-            return Err(ErrorCode::BurnAmountTooLarge.into());
-        }
-        
-        if burn_amount % DECIMAL_FACTOR != 0 {
-            return Err(ErrorCode::InvalidBurnAmount.into());
-        }
-
-        // Get the next post_id from global counter
-        let global_counter = &mut ctx.accounts.global_counter;
-        let actual_post_id = global_counter.total_posts;
-
-        // Verify that the expected post_id matches the actual next post_id
-        if expected_post_id != actual_post_id {
-            msg!("Post ID mismatch: expected {}, but next available ID is {}", 
-                 expected_post_id, actual_post_id);
-            return Err(ErrorCode::PostIdMismatch.into());
-        }
-
-        // Check memo instruction
-        let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
-        if !memo_found {
-            return Err(ErrorCode::MemoRequired.into());
-        }
+        process_create_burn_post(ctx, expected_post_id, burn_amount, min_mint_interval, min_holding, None)
+    }
 
-        // Parse and validate Borsh memo data for post creation
-        let post_data = parse_post_creation_borsh_memo(&memo_data, ctx.accounts.creator.key(), actual_post_id, burn_amount)?;
-        
-        // Call memo-burn contract to burn tokens
-        let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
-        let cpi_accounts = ProcessBurn {
-            user: ctx.accounts.creator.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            token_account: ctx.accounts.creator_token_account.to_account_info(),
-            user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
-            token_program: ctx.accounts.token_program.to_account_info(),
-            instructions: ctx.accounts.instructions.to_account_info(),
-        };
-        
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
-        
-        // Get current timestamp once for consistency and efficiency
-        let timestamp = Clock::get()?.unix_timestamp;
-        
-        // Initialize post data after successful burn
-        let post = &mut ctx.accounts.post;
-        post.post_id = actual_post_id;
-        post.creator = ctx.accounts.creator.key();
-        post.created_at = timestamp;
-        post.last_updated = timestamp;
-        post.title = post_data.title.clone();
-        post.content = post_data.content.clone();
-        post.image = post_data.image.clone();
-        post.reply_count = 0; // Initialize reply count (tracks burn_for_post and mint_for_post operations)
-        post.burned_amount = burn_amount;
-        post.last_reply_time = 0; // Set to 0 initially (no replies yet)
-        post.bump = ctx.bumps.post;
-
-        // Increment global counter AFTER successful post creation
-        // Using checked_add - if overflow, creation fails (post limit reached)
-        global_counter.total_posts = global_counter.total_posts.checked_add(1)
-            .ok_or(ErrorCode::PostCounterOverflow)?;
-
-        // Emit post creation event
-        emit!(PostCreatedEvent {
-            post_id: actual_post_id,
-            creator: ctx.accounts.creator.key(),
-            title: post_data.title,
-            content: post_data.content,
-            image: post_data.image,
-            burn_amount,
-            timestamp,
-        });
+    /// Create a forum post whose `creator` is the program's anonymous author PDA.
+    ///
+    /// The real author is only recorded as `author_commitment` (e.g. from
+    /// compute_author_commitment with a secret salt) and can later prove
+    /// authorship by revealing the salt. Privacy limits: the signer still pays
+    /// fees and rent and burns from its own token account, so the wallet stays
+    /// visible in the transaction itself; the post is rate-limited per signer,
+    /// and anonymous posts cannot be reclaimed with reclaim_stale_post.
+    pub fn create_anonymous_post(
+        ctx: Context<CreatePost>,
+        expected_post_id: u64,
+        burn_amount: u64,
+        author_commitment: [u8; 32],
+    ) -> Result<()> {
+        process_create_burn_post(ctx, expected_post_id, burn_amount, 0, 0, Some(author_commitment))
+    }
 
-        msg!("Post {} created successfully by {} with {} tokens burned (total posts: {})", 
-             actual_post_id, ctx.accounts.creator.key(), burn_amount / DECIMAL_FACTOR, 
-             global_counter.total_posts);
-        Ok(())
+    /// Create a forum post without burning (free tier for users without tokens yet).
+    /// The memo is a regular post creation memo with operation "create_post_free" and
+    /// burn_amount 0; instead of burning, the creator mints tokens via memo-mint.
+    /// The post starts with burned_amount 0 and no mint cooldown.
+    pub fn create_post_free(
+        ctx: Context<CreatePostFree>,
+        expected_post_id: u64,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator.key();
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.memo_mint_program.to_account_info(),
+            ProcessMint {
+                user: ctx.accounts.creator.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                token_account: ctx.accounts.creator_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+            },
+        );
+
+        process_create_post(
+            NewPostAccounts {
+                global_counter: &mut ctx.accounts.global_counter,
+                post: &mut ctx.accounts.post,
+                post_bump: ctx.bumps.post,
+                instructions: &ctx.accounts.instructions,
+                program_stats: &mut ctx.accounts.program_stats,
+            },
+            PostFunding::Mint {
+                cpi_ctx,
+                token_account: &mut ctx.accounts.creator_token_account,
+            },
+            expected_post_id,
+            creator,
+            0,
+            0,
+            None,
+        )
     }
 
     /// Burn tokens for a post (ANY USER can reply with burn)
@@ -515,6 +899,7 @@ pub mod memo_forum {
         ctx: Context<BurnForPost>,
         post_id: u64,
         amount: u64,
+        receipt_nonce: Option<u64>, // Writes a BurnReceipt when set (costs its rent)
     ) -> Result<()> {
         // Validate burn amount - require at least 1 token
         if amount < MIN_POST_BURN_AMOUNT {
@@ -538,9 +923,16 @@ pub mod memo_forum {
 
         // Parse and validate Borsh memo content for burn operation
         // Note: user can be any user, not just the post creator
-        parse_post_burn_borsh_memo(&memo_data, amount, ctx.accounts.user.key(), post_id)?;
+        let burn_data = parse_post_burn_borsh_memo(&memo_data, amount, ctx.accounts.user.key(), post_id)?;
+        ctx.accounts.post.check_parent_reply(burn_data.parent_reply_index)?;
+        ctx.accounts.post.check_holding(ctx.accounts.user_token_account.amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.user_token_account.amount;
 
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.user.to_account_info(),
@@ -555,10 +947,25 @@ pub mod memo_forum {
         
         // Call memo-burn's process_burn instruction
         memo_burn::cpi::process_burn(cpi_ctx, amount)?;
+        ctx.accounts.user_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.user_token_account.amount, amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(amount);
         
         // Get current timestamp once for consistency and efficiency
         let timestamp = Clock::get()?.unix_timestamp;
         
+        // Write the opt-in burn receipt; reusing a nonce fails with DuplicateReceiptNonce
+        match (receipt_nonce, ctx.accounts.burn_receipt.as_mut()) {
+            (Some(_), Some(receipt)) => {
+                let bump = ctx.bumps.burn_receipt.unwrap_or_default();
+                receipt.record(ctx.accounts.user.key(), amount, post_id, timestamp, bump)?;
+            }
+            (None, None) => {}
+            _ => return Err(ErrorCode::BurnReceiptAccountMismatch.into()),
+        }
+
         // Update post statistics
         let post = &mut ctx.accounts.post;
         let old_amount = post.burned_amount;
@@ -584,7 +991,10 @@ pub mod memo_forum {
             amount,
             total_burned: post.burned_amount,
             reply_count: post.reply_count,
+            parent_reply_index: burn_data.parent_reply_index,
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         Ok(())
@@ -604,7 +1014,25 @@ pub mod memo_forum {
 
         // Parse and validate Borsh memo content for mint operation
         // Note: user can be any user, not just the post creator
-        parse_post_mint_borsh_memo(&memo_data, ctx.accounts.user.key(), post_id)?;
+        let mint_data = parse_post_mint_borsh_memo(&memo_data, ctx.accounts.user.key(), post_id)?;
+        ctx.accounts.post.check_parent_reply(mint_data.parent_reply_index)?;
+        ctx.accounts.post.check_holding(ctx.accounts.user_token_account.amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Enforce the post's per-user mint cooldown
+        let min_mint_interval = ctx.accounts.post.min_mint_interval;
+        let exempt = RateLimitExemption::exempts(ctx.accounts.rate_limit_exemption.as_deref(), ctx.accounts.user.key());
+        match ctx.accounts.mint_cooldown.as_mut() {
+            Some(mint_cooldown) => {
+                mint_cooldown.bump = ctx.bumps.mint_cooldown.unwrap_or_default();
+                mint_cooldown.check_and_record(min_mint_interval, Clock::get()?.unix_timestamp, exempt)?;
+            }
+            None if min_mint_interval > 0 && !exempt => return Err(ErrorCode::MintCooldownAccountRequired.into()),
+            None => {}
+        }
+
+        // Balance before minting, used to derive the minted amount for program stats
+        let balance_before_mint = ctx.accounts.user_token_account.amount;
 
         // Call memo-mint contract to mint tokens
         let cpi_program = ctx.accounts.memo_mint_program.to_account_info();
@@ -621,6 +1049,10 @@ pub mod memo_forum {
         
         // Call memo-mint's process_mint instruction
         memo_mint::cpi::process_mint(cpi_ctx)?;
+
+        // Track program-wide mint total (process_mint picks the amount from the supply tier)
+        ctx.accounts.user_token_account.reload()?;
+        ctx.accounts.program_stats.record_mint(ctx.accounts.user_token_account.amount.saturating_sub(balance_before_mint));
         
         // Get current timestamp once for consistency and efficiency
         let timestamp = Clock::get()?.unix_timestamp;
@@ -642,31 +1074,525 @@ pub mod memo_forum {
             post_id,
             user: ctx.accounts.user.key(),
             reply_count: post.reply_count,
+            parent_reply_index: mint_data.parent_reply_index,
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         Ok(())
     }
-}
 
-/// Parse and validate Borsh-formatted memo data for post creation (with Base64 decoding)
-fn parse_post_creation_borsh_memo(memo_data: &[u8], expected_creator: Pubkey, expected_post_id: u64, expected_amount: u64) -> Result<PostCreationData> {
-    // First, decode the Base64-encoded memo data
-    let base64_str = std::str::from_utf8(memo_data)
-        .map_err(|_| {
-            msg!("Invalid UTF-8 in memo data");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    let decoded_data = general_purpose::STANDARD.decode(base64_str)
-        .map_err(|_| {
-            msg!("Invalid Base64 encoding in memo");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    // check decoded borsh data size
-    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
-        msg!("Decoded data too large: {} bytes (max: {})", decoded_data.len(), MAX_BORSH_DATA_SIZE);
+    /// Reply with a burn and tip the post creator: burns `burn_amount` like burn_for_post
+    /// (same memo) and mints `burn_amount * tip_bps / 10000` to the creator via memo-mint.
+    /// A tip above memo-mint's per-mint cap for this memo fails (see calculate_tip_amount)
+    pub fn tip_and_reply_post(
+        ctx: Context<TipAndReplyPost>,
+        post_id: u64,
+        burn_amount: u64,
+        tip_bps: u16,
+    ) -> Result<()> {
+        if burn_amount < MIN_POST_BURN_AMOUNT {
+            return Err(ErrorCode::BurnAmountTooSmall.into());
+        }
+        
+        if burn_amount > MAX_BURN_PER_TX {
+            return Err(ErrorCode::BurnAmountTooLarge.into());
+        }
+        
+        if burn_amount % DECIMAL_FACTOR != 0 {
+            return Err(ErrorCode::InvalidBurnAmount.into());
+        }
+
+        // Check memo instruction with enhanced validation
+        let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
+        if !memo_found {
+            return Err(ErrorCode::MemoRequired.into());
+        }
+
+        // The reply memo is a regular burn_for_post memo
+        let burn_data = parse_post_burn_borsh_memo(&memo_data, burn_amount, ctx.accounts.user.key(), post_id)?;
+        ctx.accounts.post.check_parent_reply(burn_data.parent_reply_index)?;
+        ctx.accounts.post.check_holding(ctx.accounts.user_token_account.amount)?;
+        let creator = ctx.accounts.post.tip_recipient()?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        let max_mint = memo_mint::length_scaled_mint_amount(
+            memo_mint::calculate_dynamic_mint_amount(ctx.accounts.mint.supply)?,
+            memo_data.len(),
+        );
+        let tip_amount = calculate_tip_amount(burn_amount, tip_bps, max_mint)?;
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.user_token_account.amount;
+
+        // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
+        let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
+        let cpi_accounts = ProcessBurn {
+            user: ctx.accounts.user.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            token_account: ctx.accounts.user_token_account.to_account_info(),
+            user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            instructions: ctx.accounts.instructions.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.user_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.user_token_account.amount, burn_amount)?;
+
+        // Call memo-mint contract to mint the tip to the post creator
+        if tip_amount > 0 {
+            let cpi_program = ctx.accounts.memo_mint_program.to_account_info();
+            let cpi_accounts = ProcessMintTo {
+                caller: ctx.accounts.user.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                recipient_token_account: ctx.accounts.creator_token_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                instructions: ctx.accounts.instructions.to_account_info(),
+            };
+            
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            memo_mint::cpi::process_mint_amount_to(cpi_ctx, creator, tip_amount)?;
+        }
+
+        // Track program-wide burn and mint totals
+        ctx.accounts.program_stats.record_burn(burn_amount);
+        ctx.accounts.program_stats.record_mint(tip_amount);
+        
+        let timestamp = Clock::get()?.unix_timestamp;
+        
+        // Update post statistics (counted as one reply)
+        let post = &mut ctx.accounts.post;
+        post.burned_amount = post.burned_amount.saturating_add(burn_amount);
+        post.reply_count = post.reply_count.saturating_add(1);
+        post.last_reply_time = timestamp;
+        
+        msg!("Burned {} tokens for post {} by user {}, tipped {} units to creator {}", 
+             burn_amount / DECIMAL_FACTOR, post_id, ctx.accounts.user.key(), tip_amount, creator);
+        
+        emit!(PostTipReplyEvent {
+            post_id,
+            user: ctx.accounts.user.key(),
+            creator,
+            burn_amount,
+            tip_bps,
+            tip_amount,
+            total_burned: post.burned_amount,
+            reply_count: post.reply_count,
+            parent_reply_index: burn_data.parent_reply_index,
+            timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        Ok(())
+    }
+
+    /// Fix a post's content for free within EDIT_GRACE_SECONDS of creation (creator only).
+    /// Posts have no paid update path, so the content is final once the window closes.
+    pub fn quick_edit_post(
+        ctx: Context<QuickEditPost>,
+        post_id: u64,
+    ) -> Result<()> {
+        let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
+        if !memo_found {
+            return Err(ErrorCode::MemoRequired.into());
+        }
+
+        let update_data = parse_post_update_borsh_memo(&memo_data, ctx.accounts.creator.key(), post_id)?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let post = &mut ctx.accounts.post;
+        post.check_quick_edit(&update_data.content, timestamp, CONTENT_LENGTH_BURN_ENABLED)?;
+
+        post.content = update_data.content;
+        post.last_updated = timestamp;
+
+        emit!(PostEditedEvent {
+            post_id,
+            creator: ctx.accounts.creator.key(),
+            content: post.content.clone(),
+            timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Post {} quick-edited by creator {}", post_id, ctx.accounts.creator.key());
+        Ok(())
+    }
+
+    /// Reclaim a stale post (creator only)
+    /// Closes a post that never received a reply and refunds its rent to the creator.
+    /// The global post counter is not decremented, so post IDs are never reused.
+    pub fn reclaim_stale_post(
+        ctx: Context<ReclaimStalePost>,
+        post_id: u64,
+    ) -> Result<()> {
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        let post = &ctx.accounts.post;
+        post.check_reclaimable(timestamp)?;
+
+        emit!(PostReclaimedEvent {
+            post_id,
+            creator: ctx.accounts.creator.key(),
+            timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Stale post {} reclaimed by creator {}", post_id, ctx.accounts.creator.key());
+
+        // Account closure is handled automatically by Anchor through close constraint
+        Ok(())
+    }
+
+    /// Return aggregate counts (total posts) via return data
+    pub fn get_global_stats(ctx: Context<GetGlobalStats>) -> Result<()> {
+        let stats = GlobalStats::new(&ctx.accounts.global_counter);
+
+        msg!("Global stats: {} posts", stats.total_posts);
+        set_return_data(&stats.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Convert a post created before min_mint_interval and the later fields were appended:
+    /// grow it to Post::calculate_space_max (payer covers the extra rent) and rewrite it with
+    /// the appended fields empty (see Post::from_legacy). Anyone may migrate any post; a post
+    /// that is already current is left untouched
+    pub fn migrate_post(ctx: Context<MigratePost>, post_id: u64) -> Result<()> {
+        let post_account = ctx.accounts.post.to_account_info();
+        if post_account.owner != ctx.program_id {
+            return Err(ErrorCode::InvalidPostAccount.into());
+        }
+        if !Post::needs_migration(&post_account.try_borrow_data()?)? {
+            msg!("Post {} is already migrated", post_id);
+            return Ok(());
+        }
+        let post = Post::from_legacy(&post_account.try_borrow_data()?)?;
+
+        let space = Post::calculate_space_max();
+        let top_up = Rent::get()?.minimum_balance(space).saturating_sub(post_account.lamports());
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: post_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        post_account.resize(space)?;
+        post.try_serialize(&mut &mut post_account.try_borrow_mut_data()?[..])?;
+
+        msg!("Post {} migrated to the current layout ({} bytes)", post_id, space);
+        Ok(())
+    }
+}
+
+/// Accounts every post creation writes, borrowed from CreatePost or CreatePostFree
+struct NewPostAccounts<'a, 'info> {
+    global_counter: &'a mut Account<'info, GlobalPostCounter>,
+    post: &'a mut Account<'info, Post>,
+    post_bump: u8,
+    instructions: &'a AccountInfo<'info>,
+    program_stats: &'a mut ProgramStats,
+}
+
+/// How a new post is paid for
+enum PostFunding<'a, 'info> {
+    /// Burn `amount` through memo-burn (create_post, create_anonymous_post)
+    Burn {
+        amount: u64,
+        cpi_ctx: CpiContext<'a, 'a, 'a, 'info, ProcessBurn<'info>>,
+        token_account: &'a mut InterfaceAccount<'info, TokenAccount>,
+    },
+    /// Mint to the creator through memo-mint instead of burning (create_post_free)
+    Mint {
+        cpi_ctx: CpiContext<'a, 'a, 'a, 'info, ProcessMint<'info>>,
+        token_account: &'a mut InterfaceAccount<'info, TokenAccount>,
+    },
+}
+
+impl PostFunding<'_, '_> {
+    /// Amount burned for the post (0 for a free post)
+    fn burn_amount(&self) -> u64 {
+        match self {
+            PostFunding::Burn { amount, .. } => *amount,
+            PostFunding::Mint { .. } => 0,
+        }
+    }
+
+    /// Operation the post creation memo must carry
+    fn operation(&self) -> &'static str {
+        match self {
+            PostFunding::Burn { .. } => EXPECTED_CREATE_POST_OPERATION,
+            PostFunding::Mint { .. } => EXPECTED_CREATE_POST_FREE_OPERATION,
+        }
+    }
+}
+
+/// create_post and create_anonymous_post: resolve the recorded author and create
+/// the post with a memo-burn burn (`author_commitment` is Some for anonymous posts)
+fn process_create_burn_post(
+    ctx: Context<CreatePost>,
+    expected_post_id: u64,
+    burn_amount: u64,
+    min_mint_interval: i64,
+    min_holding: u64,
+    author_commitment: Option<[u8; 32]>,
+) -> Result<()> {
+    // Anonymous posts record the program PDA as creator and are rate-limited by the real signer
+    let author = match author_commitment {
+        Some(commitment) => {
+            validate_author_commitment(&commitment)?;
+            let cooldown = ctx.accounts.anonymous_post_cooldown.as_mut()
+                .ok_or(ErrorCode::AnonymousPostCooldownRequired)?;
+            cooldown.bump = ctx.bumps.anonymous_post_cooldown.unwrap_or_default();
+            let exempt = RateLimitExemption::exempts(ctx.accounts.rate_limit_exemption.as_deref(), ctx.accounts.creator.key());
+            cooldown.check_and_record(Clock::get()?.unix_timestamp, exempt)?;
+            anonymous_author()
+        }
+        None => ctx.accounts.creator.key(),
+    };
+
+    verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.memo_burn_program.to_account_info(),
+        ProcessBurn {
+            user: ctx.accounts.creator.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            token_account: ctx.accounts.creator_token_account.to_account_info(),
+            user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            instructions: ctx.accounts.instructions.to_account_info(),
+        },
+    );
+
+    process_create_post(
+        NewPostAccounts {
+            global_counter: &mut ctx.accounts.global_counter,
+            post: &mut ctx.accounts.post,
+            post_bump: ctx.bumps.post,
+            instructions: &ctx.accounts.instructions,
+            program_stats: &mut ctx.accounts.program_stats,
+        },
+        PostFunding::Burn {
+            amount: burn_amount,
+            cpi_ctx,
+            token_account: &mut ctx.accounts.creator_token_account,
+        },
+        expected_post_id,
+        author,
+        min_mint_interval,
+        min_holding,
+        author_commitment,
+    )
+}
+
+/// Shared handler for create_post, create_anonymous_post and create_post_free.
+/// `funding` selects the burn or free (mint) path; `author` is recorded as the post creator
+fn process_create_post(
+    accounts: NewPostAccounts,
+    funding: PostFunding,
+    expected_post_id: u64,
+    author: Pubkey,
+    min_mint_interval: i64,
+    min_holding: u64,
+    author_commitment: Option<[u8; 32]>,
+) -> Result<()> {
+    let NewPostAccounts { global_counter, post, post_bump, instructions, program_stats } = accounts;
+
+    // Validate the mint cooldown before doing any work
+    Post::validate_min_mint_interval(min_mint_interval)?;
+
+    let burn_amount = funding.burn_amount();
+    if let PostFunding::Burn { .. } = funding {
+        // Validate burn amount - require at least 1 token for post creation
+        if burn_amount < MIN_POST_BURN_AMOUNT {
+            return Err(ErrorCode::BurnAmountTooSmall.into());
+        }
+
+        // check burn amount limit
+        if burn_amount > MAX_BURN_PER_TX {
+            return Err(ErrorCode::BurnAmountTooLarge.into());
+        }
+
+        if burn_amount % DECIMAL_FACTOR != 0 {
+            return Err(ErrorCode::InvalidBurnAmount.into());
+        }
+    }
+
+    // Get the next post_id from global counter
+    global_counter.check_capacity()?;
+    let actual_post_id = global_counter.total_posts;
+
+    // Verify that the expected post_id matches the actual next post_id
+    if expected_post_id != actual_post_id {
+        msg!("Post ID mismatch: expected {}, but next available ID is {}", 
+             expected_post_id, actual_post_id);
+        return Err(ErrorCode::PostIdMismatch.into());
+    }
+
+    // Check memo instruction
+    let (memo_found, memo_data) = check_memo_instruction(instructions)?;
+    if !memo_found {
+        return Err(ErrorCode::MemoRequired.into());
+    }
+
+    // Parse and validate Borsh memo data (free posts carry burn_amount 0)
+    let post_data = parse_post_creation_borsh_memo(&memo_data, author, actual_post_id, burn_amount, funding.operation())?;
+    let memo_hash = compute_memo_hash(&memo_data);
+
+    let free = match funding {
+        PostFunding::Burn { amount, cpi_ctx, token_account } => {
+            check_burn_for_content_length(amount, post_data.content.len(), CONTENT_LENGTH_BURN_ENABLED)?;
+
+            // Balance before burning, checked against the balance after the CPI
+            let balance_before_burn = token_account.amount;
+            memo_burn::cpi::process_burn(cpi_ctx, amount)?;
+            token_account.reload()?;
+            check_burn_reflected(balance_before_burn, token_account.amount, amount)?;
+
+            // Track program-wide burn total
+            program_stats.record_burn(amount);
+            false
+        }
+        PostFunding::Mint { cpi_ctx, token_account } => {
+            // Balance before minting, used to derive the minted amount for program stats
+            let balance_before_mint = token_account.amount;
+            memo_mint::cpi::process_mint(cpi_ctx)?;
+
+            // Track program-wide mint total (process_mint picks the amount from the supply tier)
+            token_account.reload()?;
+            program_stats.record_mint(token_account.amount.saturating_sub(balance_before_mint));
+            true
+        }
+    };
+    
+    // Get current timestamp once for consistency and efficiency
+    let timestamp = Clock::get()?.unix_timestamp;
+    
+    // Initialize post data after the successful burn or mint
+    post.post_id = actual_post_id;
+    post.creator = author;
+    post.created_at = timestamp;
+    post.last_updated = timestamp;
+    post.title = post_data.title.clone();
+    post.content = post_data.content.clone();
+    post.image = post_data.image.clone();
+    post.reply_count = 0; // Initialize reply count (tracks burn_for_post and mint_for_post operations)
+    post.burned_amount = burn_amount; // 0 for a free post
+    post.last_reply_time = 0; // Set to 0 initially (no replies yet)
+    post.min_mint_interval = min_mint_interval;
+    post.author_commitment = author_commitment.unwrap_or_default();
+    post.content_flags = post_data.content_flags;
+    post.lang = post_data.lang.clone();
+    post.min_holding = min_holding;
+    post.bump = post_bump;
+
+    // Increment global counter AFTER successful post creation
+    // Using checked_add - if overflow, creation fails (post limit reached)
+    global_counter.total_posts = global_counter.total_posts.checked_add(1)
+        .ok_or(ErrorCode::PostCounterOverflow)?;
+
+    // Emit post creation event
+    let project_mention = post_data.project_mention(actual_post_id);
+
+    emit!(PostCreatedEvent {
+        post_id: actual_post_id,
+        creator: author,
+        title: post_data.title,
+        content: post_data.content,
+        image: post_data.image,
+        burn_amount,
+        author_commitment,
+        mentioned_project_id: post_data.mentioned_project_id,
+        content_flags: post_data.content_flags,
+        lang: post_data.lang.clone(),
+        timestamp,
+        memo_hash,
+        event_seq: program_stats.next_event_seq(),
+    });
+
+    if let Some(mut mention) = project_mention {
+        msg!("Post {} mentions project {}", mention.post_id, mention.project_id);
+        mention.event_seq = program_stats.next_event_seq();
+        emit!(mention);
+    }
+
+    if free {
+        msg!("Free post {} created by {} (total posts: {})", 
+             actual_post_id, author, global_counter.total_posts);
+    } else {
+        msg!("Post {} created successfully by {} with {} tokens burned (total posts: {})", 
+             actual_post_id, author, burn_amount / DECIMAL_FACTOR, 
+             global_counter.total_posts);
+    }
+    Ok(())
+}
+
+/// Tip for a tip_and_reply_post burn: `tip_bps` (at most MAX_TIP_BPS) of `burn_amount`.
+/// Fails if the tip exceeds `max_mint`, the most memo-mint mints for the reply memo,
+/// rather than silently tipping less than requested
+pub fn calculate_tip_amount(burn_amount: u64, tip_bps: u16, max_mint: u64) -> Result<u64> {
+    if tip_bps > MAX_TIP_BPS {
+        msg!("Tip of {} bps exceeds the maximum of {} bps", tip_bps, MAX_TIP_BPS);
+        return Err(ErrorCode::TipBpsTooHigh.into());
+    }
+    // At most burn_amount, so the narrowing back to u64 cannot truncate
+    let tip = (u128::from(burn_amount) * u128::from(tip_bps) / 10_000) as u64;
+    if tip > max_mint {
+        msg!("Tip of {} units exceeds memo-mint's cap of {} units for this memo", tip, max_mint);
+        return Err(ErrorCode::TipExceedsMintCap.into());
+    }
+    Ok(tip)
+}
+
+/// PDA recorded as the creator of anonymous posts
+pub fn anonymous_author() -> Pubkey {
+    Pubkey::find_program_address(&[ANONYMOUS_AUTHOR_SEED], &crate::ID).0
+}
+
+/// Commitment binding an anonymous post to its author: sha256(author || salt)
+pub fn compute_author_commitment(author: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+    hashv(&[author.as_ref(), salt]).to_bytes()
+}
+
+/// Reject the all-zero commitment (reserved for non-anonymous posts)
+pub fn validate_author_commitment(author_commitment: &[u8; 32]) -> Result<()> {
+    if author_commitment.iter().all(|&b| b == 0) {
+        return Err(ErrorCode::InvalidAuthorCommitment.into());
+    }
+    Ok(())
+}
+
+/// Parse and validate Borsh-formatted memo data for post creation (with Base64 decoding)
+fn parse_post_creation_borsh_memo(memo_data: &[u8], expected_creator: Pubkey, expected_post_id: u64, expected_amount: u64, expected_operation: &str) -> Result<PostCreationData> {
+    // First, decode the Base64-encoded memo data
+    let base64_str = std::str::from_utf8(memo_data)
+        .map_err(|_| {
+            msg!("Invalid UTF-8 in memo data");
+            ErrorCode::InvalidMemoFormat
+        })?;
+    
+    let decoded_data = general_purpose::STANDARD.decode(base64_str)
+        .map_err(|_| {
+            msg!("Invalid Base64 encoding in memo");
+            ErrorCode::InvalidMemoFormat
+        })?;
+    
+    // check decoded borsh data size
+    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
+        msg!("Decoded data too large: {} bytes (max: {})", decoded_data.len(), MAX_BORSH_DATA_SIZE);
         return Err(ErrorCode::InvalidMemoFormat.into());
     }
     
@@ -711,7 +1637,7 @@ fn parse_post_creation_borsh_memo(memo_data: &[u8], expected_creator: Pubkey, ex
         })?;
     
     // Validate the post creation data
-    post_data.validate(expected_creator, expected_post_id)?;
+    post_data.validate_for_operation(expected_creator, expected_post_id, expected_operation)?;
     
     msg!("Post creation data parsed successfully: creator={}, post_id={}, title={}", 
          post_data.creator, post_data.post_id, post_data.title);
@@ -720,7 +1646,7 @@ fn parse_post_creation_borsh_memo(memo_data: &[u8], expected_creator: Pubkey, ex
 }
 
 /// Parse and validate Borsh-formatted memo data for post burn (with Base64 decoding)
-fn parse_post_burn_borsh_memo(memo_data: &[u8], expected_amount: u64, expected_user: Pubkey, expected_post_id: u64) -> Result<()> {
+fn parse_post_burn_borsh_memo(memo_data: &[u8], expected_amount: u64, expected_user: Pubkey, expected_post_id: u64) -> Result<PostBurnData> {
     // First, decode the Base64-encoded memo data
     let base64_str = std::str::from_utf8(memo_data)
         .map_err(|_| {
@@ -783,12 +1709,12 @@ fn parse_post_burn_borsh_memo(memo_data: &[u8], expected_amount: u64, expected_u
     // Validate post burn data
     burn_data.validate(expected_user, expected_post_id)?;
     
-    Ok(())
+    Ok(burn_data)
 }
 
 /// Parse and validate Borsh-formatted memo data for post mint (with Base64 decoding)
 /// Note: For mint operations, the burn_amount in BurnMemo should be 0
-fn parse_post_mint_borsh_memo(memo_data: &[u8], expected_user: Pubkey, expected_post_id: u64) -> Result<()> {
+fn parse_post_mint_borsh_memo(memo_data: &[u8], expected_user: Pubkey, expected_post_id: u64) -> Result<PostMintData> {
     // First, decode the Base64-encoded memo data
     let base64_str = std::str::from_utf8(memo_data)
         .map_err(|_| {
@@ -850,7 +1776,69 @@ fn parse_post_mint_borsh_memo(memo_data: &[u8], expected_user: Pubkey, expected_
     // Validate post mint data
     mint_data.validate(expected_user, expected_post_id)?;
     
-    Ok(())
+    Ok(mint_data)
+}
+
+/// Parse and validate Borsh-formatted memo data for quick_edit_post (with Base64 decoding)
+fn parse_post_update_borsh_memo(memo_data: &[u8], expected_user: Pubkey, expected_post_id: u64) -> Result<PostUpdateData> {
+    let base64_str = std::str::from_utf8(memo_data)
+        .map_err(|_| {
+            msg!("Invalid UTF-8 in memo data");
+            ErrorCode::InvalidMemoFormat
+        })?;
+    
+    let decoded_data = general_purpose::STANDARD.decode(base64_str)
+        .map_err(|_| {
+            msg!("Invalid Base64 encoding in memo");
+            ErrorCode::InvalidMemoFormat
+        })?;
+
+    // Check decoded borsh data size
+    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
+        msg!("Decoded data too large: {} bytes (max: {})", decoded_data.len(), MAX_BORSH_DATA_SIZE);
+        return Err(ErrorCode::InvalidMemoFormat.into());
+    }
+    
+    let burn_memo = BurnMemo::try_from_slice(&decoded_data)
+        .map_err(|_| {
+            msg!("Invalid Borsh format after Base64 decoding");
+            ErrorCode::InvalidMemoFormat
+        })?;
+    
+    if burn_memo.version != BURN_MEMO_VERSION {
+        msg!("Unsupported memo version: {} (expected: {})", 
+             burn_memo.version, BURN_MEMO_VERSION);
+        return Err(ErrorCode::UnsupportedMemoVersion.into());
+    }
+    
+    // Quick edits burn nothing
+    if burn_memo.burn_amount != 0 {
+        msg!("Quick edit should have burn_amount=0, got {}", burn_memo.burn_amount);
+        return Err(ErrorCode::BurnAmountMismatch.into());
+    }
+    
+    if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
+        msg!("Payload too long: {} bytes (max: {})", 
+             burn_memo.payload.len(), MAX_PAYLOAD_LENGTH);
+        return Err(ErrorCode::PayloadTooLong.into());
+    }
+    
+    // Deserialize post update data from payload
+    let update_data = PostUpdateData::try_from_slice(&burn_memo.payload)
+        .map_err(|_| {
+            msg!("Invalid post update data format in payload");
+            ErrorCode::InvalidPostUpdateDataFormat
+        })?;
+    
+    // Validate post update data
+    update_data.validate(expected_user, expected_post_id)?;
+    
+    Ok(update_data)
+}
+
+/// Whether a mint has the decimals DECIMAL_FACTOR assumes (guards against a recreated mint)
+pub fn mint_decimals_valid(decimals: u8) -> bool {
+    decimals == MINT_DECIMALS
 }
 
 /// Check for memo instruction at REQUIRED index 0
@@ -869,7 +1857,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     // to leave index 0 available for memo
     if current_index < 1 {
         msg!("memo-forum instruction must be at index 1 or later, but current instruction is at index {}", current_index);
-        return Ok((false, vec![]));
+        return memo_not_at_index_zero(instructions);
     }
     
     // Check that index 0 contains the memo instruction
@@ -880,7 +1868,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
                 validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
             } else {
                 msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
-                Ok((false, vec![]))
+                memo_not_at_index_zero(instructions)
             }
         },
         Err(e) => {
@@ -890,6 +1878,28 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     }
 }
 
+/// Called when index 0 holds no memo: if a memo exists elsewhere in the transaction,
+/// fail with MemoWrongIndex instead of letting the caller report MemoRequired
+fn memo_not_at_index_zero(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    let program_ids: Vec<Pubkey> = (0..)
+        .map_while(|i| anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(i, instructions).ok())
+        .map(|ix| ix.program_id)
+        .collect();
+    check_memo_placement(&program_ids)?;
+    Ok((false, vec![]))
+}
+
+/// Reject a transaction whose SPL Memo instruction is present but not at index 0
+fn check_memo_placement(program_ids: &[Pubkey]) -> Result<()> {
+    if let Some(index) = program_ids.iter().position(|id| *id == MEMO_PROGRAM_ID) {
+        if index > 0 {
+            msg!("Memo instruction found at index {}, but it must be placed at index 0", index);
+            return Err(ErrorCode::MemoWrongIndex.into());
+        }
+    }
+    Ok(())
+}
+
 /// Validate memo data length and return result
 fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize) -> Result<(bool, Vec<u8>)> {
     let memo_length = memo_data.len();
@@ -917,6 +1927,37 @@ fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize)
     Ok((true, memo_data.to_vec()))
 }
 
+/// Compute the SHA-256 hash of the raw memo instruction data
+/// (emitted in events so indexers can match them to the on-chain memo)
+fn compute_memo_hash(memo_data: &[u8]) -> [u8; 32] {
+    hash(memo_data).to_bytes()
+}
+
+/// Check that a memo-burn process_burn CPI reduced the burner's token balance by
+/// exactly `amount` (guards against crediting burns that were not fully applied)
+fn check_burn_reflected(balance_before: u64, balance_after: u64, amount: u64) -> Result<()> {
+    if balance_before.checked_sub(balance_after) != Some(amount) {
+        msg!("Burn not reflected in balance: {} -> {} (expected {} burned)", 
+             balance_before, balance_after, amount);
+        return Err(ErrorCode::BurnAmountNotReflected.into());
+    }
+    Ok(())
+}
+
+/// Check that the account handed to memo-burn as `user_global_burn_stats` is owned by
+/// memo-burn and carries the UserGlobalBurnStats discriminator (guards against a
+/// spoofed account reaching the burn CPI)
+fn verify_burn_stats_account(account: &AccountInfo) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    if account.owner != &memo_burn::ID
+        || !data.starts_with(memo_burn::UserGlobalBurnStats::DISCRIMINATOR)
+    {
+        msg!("Invalid burn stats account: {} (owner: {})", account.key(), account.owner);
+        return Err(ErrorCode::InvalidBurnStatsAccount.into());
+    }
+    Ok(())
+}
+
 /// Global post counter account
 #[account]
 pub struct GlobalPostCounter {
@@ -926,6 +1967,172 @@ pub struct GlobalPostCounter {
 impl GlobalPostCounter {
     pub const SPACE: usize = 8 + // discriminator
         8; // total_posts (u64)
+
+    /// Reject creation once the counter reaches MAX_ENTITIES
+    pub fn check_capacity(&self) -> Result<()> {
+        if self.total_posts >= MAX_ENTITIES {
+            msg!("Entity limit reached: {} posts created (max: {})", self.total_posts, MAX_ENTITIES);
+            return Err(ErrorCode::EntityLimitReached.into());
+        }
+        Ok(())
+    }
+}
+
+/// Opt-in on-chain proof of one burn (PDA seeded by burner and a client-chosen nonce)
+#[account]
+pub struct BurnReceipt {
+    pub burner: Pubkey,               // Wallet that burned
+    pub amount: u64,                  // Burned amount (in units)
+    pub target_id: u64,               // Post the burn was for
+    pub timestamp: i64,               // Time of the burn (0 = unused)
+    pub program_kind: u8,             // BURN_RECEIPT_PROGRAM_KIND of the issuing program
+    pub bump: u8,                     // PDA bump
+}
+
+impl BurnReceipt {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // burner (Pubkey)
+        8 + // amount (u64)
+        8 + // target_id (u64)
+        8 + // timestamp (i64)
+        1 + // program_kind (u8)
+        1; // bump (u8)
+
+    /// BurnReceipt PDA and bump for a burner's nonce
+    pub fn find_address(burner: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"receipt", burner.as_ref(), &nonce.to_le_bytes()], &crate::ID)
+    }
+
+    /// Write the receipt, failing with DuplicateReceiptNonce if the nonce was already used
+    pub fn record(&mut self, burner: Pubkey, amount: u64, target_id: u64, timestamp: i64, bump: u8) -> Result<()> {
+        if self.timestamp != 0 {
+            msg!("Duplicate receipt nonce: receipt already written for {} at {}", self.burner, self.timestamp);
+            return Err(ErrorCode::DuplicateReceiptNonce.into());
+        }
+        self.burner = burner;
+        self.amount = amount;
+        self.target_id = target_id;
+        self.timestamp = timestamp;
+        self.program_kind = BURN_RECEIPT_PROGRAM_KIND;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// Program-wide burn/mint totals (singleton PDA, initialized by admin)
+#[account]
+pub struct ProgramStats {
+    pub total_burned: u128,     // Total units burned through this program
+    pub total_minted: u128,     // Total units minted through this program
+    pub event_seq: u64,         // Sequence number of the last emitted event (0 = none yet)
+    pub bump: u8,               // PDA bump
+}
+
+impl ProgramStats {
+    pub const SPACE: usize = 8 + // discriminator
+        16 + // total_burned (u128)
+        16 + // total_minted (u128)
+        8 +  // event_seq (u64)
+        1;   // bump (u8)
+
+    /// Add a burn to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_burn(&mut self, amount: u64) {
+        self.total_burned = self.total_burned.saturating_add(u128::from(amount));
+    }
+
+    /// Add a mint to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_mint(&mut self, amount: u64) {
+        self.total_minted = self.total_minted.saturating_add(u128::from(amount));
+    }
+
+    /// Advance the event sequence and return the new value. The first event gets 1,
+    /// so indexers can detect dropped events by looking for gaps in the sequence.
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.saturating_add(1);
+        self.event_seq
+    }
+}
+
+/// Admin-granted exemption from per-wallet rate limits, seeded by the exempt wallet
+#[account]
+pub struct RateLimitExemption {
+    pub wallet: Pubkey,               // Exempt wallet
+    pub granted_at: i64,              // When the admin granted the exemption
+    pub bump: u8,                     // PDA bump
+}
+
+impl RateLimitExemption {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // wallet (Pubkey)
+        8 + // granted_at (i64)
+        1; // bump (u8)
+
+    /// Whether `signer` may skip rate-limit checks (the exemption must belong to the signer)
+    pub fn exempts(exemption: Option<&Self>, signer: Pubkey) -> bool {
+        exemption.is_some_and(|exemption| exemption.wallet == signer)
+    }
+}
+
+/// Account structure for granting a rate-limit exemption (admin only)
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddRateLimitExemption<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = RateLimitExemption::SPACE,
+        seeds = [b"exempt", wallet.as_ref()],
+        bump
+    )]
+    pub rate_limit_exemption: Account<'info, RateLimitExemption>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for removing a rate-limit exemption (admin only)
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveRateLimitExemption<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"exempt", wallet.as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Account<'info, RateLimitExemption>,
+}
+
+/// Account structure for initializing the program stats (admin only)
+#[derive(Accounts)]
+pub struct InitializeProgramStats<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramStats::SPACE,
+        seeds = [b"stats"],
+        bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Account structure for initializing global counter (admin only)
@@ -949,6 +2156,49 @@ pub struct InitializeGlobalCounter<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Aggregate counts returned by get_global_stats (Borsh-encoded return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GlobalStats {
+    pub total_posts: u64,
+}
+
+impl GlobalStats {
+    pub fn new(counter: &GlobalPostCounter) -> Self {
+        Self {
+            total_posts: counter.total_posts,
+        }
+    }
+}
+
+/// Account structure for migrating a legacy-sized post (permissionless, payer covers rent)
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct MigratePost<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: legacy posts are not decoded as Post; owner, discriminator and size are
+    /// checked in migrate_post
+    #[account(
+        mut,
+        seeds = [b"post", post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub post: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for reading global stats (read-only)
+#[derive(Accounts)]
+pub struct GetGlobalStats<'info> {
+    #[account(
+        seeds = [b"global_counter"],
+        bump
+    )]
+    pub global_counter: Account<'info, GlobalPostCounter>,
+}
+
 /// Account structure for creating a post
 #[derive(Accounts)]
 #[instruction(expected_post_id: u64, burn_amount: u64)]
@@ -975,7 +2225,8 @@ pub struct CreatePost<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1005,11 +2256,102 @@ pub struct CreatePost<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Per-signer cooldown for anonymous posts (required by create_anonymous_post)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = AnonymousPostCooldown::SPACE,
+        seeds = [b"anonymous_post_cooldown", creator.key().as_ref()],
+        bump
+    )]
+    pub anonymous_post_cooldown: Option<Account<'info, AnonymousPostCooldown>>,
+
+    /// Admin-granted rate-limit exemption for the creator (optional; skips the anonymous post cooldown)
+    #[account(
+        seeds = [b"exempt", creator.key().as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+}
+
+/// Account structure for creating a free (mint-only) post
+#[derive(Accounts)]
+#[instruction(expected_post_id: u64)]
+pub struct CreatePostFree<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_counter"],
+        bump
+    )]
+    pub global_counter: Account<'info, GlobalPostCounter>,
+    
+    /// Post account - PDA derived from post_id (from global counter)
+    #[account(
+        init,
+        payer = creator,
+        space = Post::calculate_space_max(),
+        seeds = [b"post", expected_post_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: PDA serving as mint authority (from memo-mint program)
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+        seeds::program = memo_mint_program.key()
+    )]
+    pub mint_authority: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = creator_token_account.owner == creator.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-mint program
+    pub memo_mint_program: Program<'info, MemoMint>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for burning tokens for a post (ANY USER)
 #[derive(Accounts)]
-#[instruction(post_id: u64, amount: u64)]
+#[instruction(post_id: u64, amount: u64, receipt_nonce: Option<u64>)]
 pub struct BurnForPost<'info> {
     /// Any user can burn for a post (not restricted to creator)
     #[account(mut)]
@@ -1019,13 +2361,15 @@ pub struct BurnForPost<'info> {
         mut,
         seeds = [b"post", post_id.to_le_bytes().as_ref()],
         bump = post.bump,
+        constraint = Post::is_migrated(post.to_account_info().data_len()) @ ErrorCode::PostNotMigrated,
         // Note: NO creator constraint here - any user can burn for any post
     )]
     pub post: Account<'info, Post>,
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1053,6 +2397,26 @@ pub struct BurnForPost<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Burn receipt (required exactly when receipt_nonce is set; created on first use)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = BurnReceipt::SPACE,
+        seeds = [b"receipt", user.key().as_ref(), receipt_nonce.unwrap_or_default().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_receipt: Option<Account<'info, BurnReceipt>>,
 }
 
 /// Account structure for minting tokens for a post (ANY USER)
@@ -1067,13 +2431,15 @@ pub struct MintForPost<'info> {
         mut,
         seeds = [b"post", post_id.to_le_bytes().as_ref()],
         bump = post.bump,
+        constraint = Post::is_migrated(post.to_account_info().data_len()) @ ErrorCode::PostNotMigrated,
         // Note: NO creator constraint here - any user can mint for any post
     )]
     pub post: Account<'info, Post>,
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1100,6 +2466,218 @@ pub struct MintForPost<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Per-user mint cooldown (required when the post sets min_mint_interval)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = MintCooldown::SPACE,
+        seeds = [b"mint_cooldown", post.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub mint_cooldown: Option<Account<'info, MintCooldown>>,
+
+    /// System program (only required together with mint_cooldown)
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Admin-granted rate-limit exemption for the user (optional; skips the per-user mint cooldown)
+    #[account(
+        seeds = [b"exempt", user.key().as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+}
+
+/// Account structure for replying with a burn and tipping the post creator (ANY USER)
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct TipAndReplyPost<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"post", post_id.to_le_bytes().as_ref()],
+        bump = post.bump,
+        constraint = Post::is_migrated(post.to_account_info().data_len()) @ ErrorCode::PostNotMigrated
+    )]
+    pub post: Account<'info, Post>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// The post creator's token account, credited with the tip
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = creator_token_account.owner == post.creator @ ErrorCode::InvalidTipRecipient
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: PDA serving as mint authority (from memo-mint program)
+    #[account(
+        seeds = [b"mint_authority"],
+        bump,
+        seeds::program = memo_mint_program.key()
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    /// User global burn statistics tracking account
+    #[account(
+        mut,
+        seeds = [b"user_global_burn_stats", user.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-burn program
+    pub memo_burn_program: Program<'info, MemoBurn>,
+    
+    /// The memo-mint program
+    pub memo_mint_program: Program<'info, MemoMint>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for quick-editing a post (creator only)
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct QuickEditPost<'info> {
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"post", post_id.to_le_bytes().as_ref()],
+        bump = post.bump,
+        constraint = Post::is_migrated(post.to_account_info().data_len()) @ ErrorCode::PostNotMigrated,
+        constraint = post.creator == creator.key() @ ErrorCode::UnauthorizedPostAccess
+    )]
+    pub post: Account<'info, Post>,
+
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for reclaiming a stale post (creator only)
+#[derive(Accounts)]
+#[instruction(post_id: u64)]
+pub struct ReclaimStalePost<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = creator,
+        seeds = [b"post", post_id.to_le_bytes().as_ref()],
+        bump = post.bump,
+        constraint = Post::is_migrated(post.to_account_info().data_len()) @ ErrorCode::PostNotMigrated,
+        constraint = post.creator == creator.key() @ ErrorCode::UnauthorizedPostAccess
+    )]
+    pub post: Account<'info, Post>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Per-signer cooldown for anonymous posts, seeded by the real signer
+#[account]
+pub struct AnonymousPostCooldown {
+    pub last_post_time: i64,          // Last anonymous post by this signer (0 if never)
+    pub bump: u8,                     // PDA bump
+}
+
+impl AnonymousPostCooldown {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // last_post_time (i64)
+        1;  // bump (u8)
+
+    /// Enforce ANONYMOUS_POST_INTERVAL_SECONDS since this signer's last anonymous post,
+    /// then record current_time (exempt signers skip the check)
+    pub fn check_and_record(&mut self, current_time: i64, exempt: bool) -> Result<()> {
+        if !exempt && self.last_post_time > 0 {
+            let elapsed = current_time.saturating_sub(self.last_post_time);
+            if elapsed < ANONYMOUS_POST_INTERVAL_SECONDS {
+                msg!("Anonymous post too frequent: {}s since last post (minimum: {}s)", 
+                     elapsed, ANONYMOUS_POST_INTERVAL_SECONDS);
+                return Err(ErrorCode::AnonymousPostTooFrequent.into());
+            }
+        }
+        self.last_post_time = current_time;
+        Ok(())
+    }
+}
+
+/// Per-user mint cooldown state for one post, seeded by (post, user)
+#[account]
+pub struct MintCooldown {
+    pub last_mint_time: i64,          // Last mint_for_post by this user (0 if never)
+    pub bump: u8,                     // PDA bump
+}
+
+impl MintCooldown {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // last_mint_time (i64)
+        1;  // bump (u8)
+
+    /// Enforce min_mint_interval since this user's last mint, then record current_time
+    /// (an interval of 0 never throttles, nor does an exempt user)
+    pub fn check_and_record(&mut self, min_mint_interval: i64, current_time: i64, exempt: bool) -> Result<()> {
+        if !exempt && min_mint_interval > 0 && self.last_mint_time > 0 {
+            let elapsed = current_time.saturating_sub(self.last_mint_time);
+            if elapsed < min_mint_interval {
+                msg!("Mint too frequent: {}s since last mint (minimum: {}s)", elapsed, min_mint_interval);
+                return Err(ErrorCode::MintTooFrequent.into());
+            }
+        }
+        self.last_mint_time = current_time;
+        Ok(())
+    }
 }
 
 /// Post data structure
@@ -1118,9 +2696,51 @@ pub struct Post {
     pub burned_amount: u64,           // Total burned tokens for this post
     pub last_reply_time: i64,         // Last burn/mint_for_post operation timestamp (0 if never)
     pub bump: u8,                     // PDA bump
+    // Fields below were appended after the original layout; posts created before
+    // them are converted by migrate_post (see Post::LEGACY_SPACE)
+    pub min_mint_interval: i64,       // Per-user cooldown for mint_for_post in seconds (0 = no throttle)
+    pub author_commitment: [u8; 32],  // Hash commitment to the real author (all zero unless anonymous)
+    pub content_flags: u8,            // Content flags from the creation memo (CONTENT_FLAG_*)
+    pub lang: String,                 // Language tag (optional, max 8 chars)
+    pub min_holding: u64,             // Token balance (in units) required to reply (0 = anyone)
+}
+
+/// Post layout written by program versions before min_mint_interval (the prefix of Post)
+#[derive(AnchorDeserialize)]
+struct LegacyPost {
+    post_id: u64,
+    creator: Pubkey,
+    created_at: i64,
+    last_updated: i64,
+    title: String,
+    content: String,
+    image: String,
+    reply_count: u64,
+    burned_amount: u64,
+    last_reply_time: i64,
+    bump: u8,
 }
 
 impl Post {
+    /// Check that a replier holding `balance` units meets the post's min_holding
+    pub fn check_holding(&self, balance: u64) -> Result<()> {
+        if balance < self.min_holding {
+            msg!("Insufficient holdings to reply to post {}: {} units (minimum: {})", 
+                 self.post_id, balance, self.min_holding);
+            return Err(ErrorCode::InsufficientHoldings.into());
+        }
+        Ok(())
+    }
+
+    /// Validate a min_mint_interval (0..=MAX_MINT_INTERVAL_SECONDS)
+    pub fn validate_min_mint_interval(min_mint_interval: i64) -> Result<()> {
+        if !(0..=MAX_MINT_INTERVAL_SECONDS).contains(&min_mint_interval) {
+            msg!("Invalid min_mint_interval: {} (must be 0-{} seconds)", min_mint_interval, MAX_MINT_INTERVAL_SECONDS);
+            return Err(ErrorCode::InvalidMintInterval.into());
+        }
+        Ok(())
+    }
+
     /// Calculate maximum space for the account (conservative estimate)
     pub fn calculate_space_max() -> usize {
         8 + // discriminator
@@ -1132,11 +2752,140 @@ impl Post {
         8 + // burned_amount
         8 + // last_reply_time
         1 + // bump
+        8 + // min_mint_interval
+        32 + // author_commitment
+        1 + // content_flags
+        8 + // min_holding
+        4 + 8 + // lang (max 8 chars)
         4 + 128 + // title (max 128 chars)
         4 + 512 + // content (max 512 chars)
         4 + 256 + // image (max 256 chars)
         128 // safety buffer
     }
+
+    /// Size of posts created before min_mint_interval and the later fields were appended
+    /// (the calculate_space_max of that layout, which every such post was allocated with)
+    pub const LEGACY_SPACE: usize = 8 + // discriminator
+        8 + // post_id
+        32 + // creator
+        8 + // created_at
+        8 + // last_updated
+        8 + // reply_count
+        8 + // burned_amount
+        8 + // last_reply_time
+        1 + // bump
+        4 + 128 + // title
+        4 + 512 + // content
+        4 + 256 + // image
+        128; // safety buffer
+
+    /// Whether a post account of `data_len` bytes has the current layout. A legacy-sized
+    /// post may decode, but its appended fields come from whatever bytes followed its old
+    /// data (zeros or stale string bytes), so instructions reject it until migrated
+    pub fn is_migrated(data_len: usize) -> bool {
+        data_len != Self::LEGACY_SPACE
+    }
+
+    /// Whether a post account needs migrate_post: true for a legacy-sized post, false if
+    /// it is already current, error for anything else
+    pub fn needs_migration(data: &[u8]) -> Result<bool> {
+        if !data.starts_with(Post::DISCRIMINATOR) {
+            return Err(ErrorCode::InvalidPostAccount.into());
+        }
+        match data.len() {
+            Self::LEGACY_SPACE => Ok(true),
+            len if len == Self::calculate_space_max() => Ok(false),
+            _ => Err(ErrorCode::InvalidPostAccount.into()),
+        }
+    }
+
+    /// Decode a legacy-sized post (discriminator included). The appended fields start
+    /// empty: no mint throttle, not anonymous, no content flags or language, open replies
+    pub fn from_legacy(data: &[u8]) -> Result<Self> {
+        if !Self::needs_migration(data)? {
+            return Err(ErrorCode::InvalidPostAccount.into());
+        }
+        let legacy = LegacyPost::deserialize(&mut &data[8..])
+            .map_err(|_| ErrorCode::InvalidPostAccount)?;
+
+        Ok(Self {
+            post_id: legacy.post_id,
+            creator: legacy.creator,
+            created_at: legacy.created_at,
+            last_updated: legacy.last_updated,
+            title: legacy.title,
+            content: legacy.content,
+            image: legacy.image,
+            reply_count: legacy.reply_count,
+            burned_amount: legacy.burned_amount,
+            last_reply_time: legacy.last_reply_time,
+            bump: legacy.bump,
+            min_mint_interval: 0,
+            author_commitment: [0; 32],
+            content_flags: 0,
+            lang: String::new(),
+            min_holding: 0,
+        })
+    }
+
+    /// Check that a reply's parent (if any) is an existing reply of this post.
+    /// Replies are indexed 0..reply_count in the order they were made.
+    pub fn check_parent_reply(&self, parent_reply_index: Option<u64>) -> Result<()> {
+        if let Some(parent) = parent_reply_index {
+            if parent >= self.reply_count {
+                msg!("Invalid parent reply index {} for post {} ({} replies)", 
+                     parent, self.post_id, self.reply_count);
+                return Err(ErrorCode::InvalidParentReplyIndex.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that a free quick edit to `new_content` is allowed at `current_time`: only within
+    /// EDIT_GRACE_SECONDS of creation, and (with content-length burns enabled) without moving
+    /// the content into a higher burn tier than the one paid at creation
+    pub fn check_quick_edit(&self, new_content: &str, current_time: i64, content_length_burn: bool) -> Result<()> {
+        let age = current_time.saturating_sub(self.created_at);
+        if age > EDIT_GRACE_SECONDS {
+            msg!("Edit window closed for post {}: created {} seconds ago (window: {})", 
+                 self.post_id, age, EDIT_GRACE_SECONDS);
+            return Err(ErrorCode::EditWindowClosed.into());
+        }
+        if content_length_burn
+            && min_burn_for_content_length(new_content.len()) > min_burn_for_content_length(self.content.len())
+        {
+            msg!("Quick edit of post {} grows the content past its burn tier", self.post_id);
+            return Err(ErrorCode::InsufficientBurnForContentLength.into());
+        }
+        Ok(())
+    }
+
+    /// Wallet that receives tip_and_reply_post tips (anonymous posts have no reachable creator)
+    pub fn tip_recipient(&self) -> Result<Pubkey> {
+        if self.author_commitment != [0; 32] {
+            msg!("Post {} is anonymous and cannot receive tips", self.post_id);
+            return Err(ErrorCode::CannotTipAnonymousPost.into());
+        }
+        Ok(self.creator)
+    }
+
+    /// Check whether the post can be reclaimed at `current_time`
+    /// (no replies and older than STALE_POST_SECONDS)
+    pub fn check_reclaimable(&self, current_time: i64) -> Result<()> {
+        if self.reply_count > 0 {
+            msg!("Post {} has {} replies and cannot be reclaimed", self.post_id, self.reply_count);
+            return Err(ErrorCode::PostHasReplies.into());
+        }
+        
+        let age = current_time.saturating_sub(self.created_at);
+        if age <= STALE_POST_SECONDS {
+            msg!("Post {} is not stale yet: age {} seconds (must exceed {})", 
+                 self.post_id, age, STALE_POST_SECONDS);
+            return Err(ErrorCode::PostNotStale.into());
+        }
+        
+        Ok(())
+    }
 }
 
 /// Event emitted when a post is created
@@ -1148,7 +2897,21 @@ pub struct PostCreatedEvent {
     pub content: String,
     pub image: String,
     pub burn_amount: u64,
+    pub author_commitment: Option<[u8; 32]>,
+    pub mentioned_project_id: Option<u64>,
+    pub content_flags: u8,
+    pub lang: String,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a post mentions a memo-project project (not validated on-chain)
+#[event]
+pub struct ProjectMentionedEvent {
+    pub project_id: u64,
+    pub post_id: u64,
+    pub event_seq: u64,
 }
 
 /// Event emitted when tokens are burned for a post
@@ -1159,7 +2922,27 @@ pub struct TokensBurnedForPostEvent {
     pub amount: u64,
     pub total_burned: u64,
     pub reply_count: u64,
+    pub parent_reply_index: Option<u64>,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted for a tip_and_reply_post reply (burn plus tip minted to the creator)
+#[event]
+pub struct PostTipReplyEvent {
+    pub post_id: u64,
+    pub user: Pubkey,
+    pub creator: Pubkey,
+    pub burn_amount: u64,
+    pub tip_bps: u16,
+    pub tip_amount: u64,
+    pub total_burned: u64,
+    pub reply_count: u64,
+    pub parent_reply_index: Option<u64>,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Event emitted when tokens are minted for a post
@@ -1168,7 +2951,30 @@ pub struct TokensMintedForPostEvent {
     pub post_id: u64,
     pub user: Pubkey,
     pub reply_count: u64,
+    pub parent_reply_index: Option<u64>,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a creator quick-edits a post within the grace window
+#[event]
+pub struct PostEditedEvent {
+    pub post_id: u64,
+    pub creator: Pubkey,
+    pub content: String,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a stale post is reclaimed by its creator
+#[event]
+pub struct PostReclaimedEvent {
+    pub post_id: u64,
+    pub creator: Pubkey,
     pub timestamp: i64,
+    pub event_seq: u64,
 }
 
 /// Error code definitions
@@ -1281,4 +3087,97 @@ pub enum ErrorCode {
     
     #[msg("Reply message too long: Message must be at most 512 characters.")]
     ReplyMessageTooLong,
+
+    #[msg("Post has replies: Only posts without replies can be reclaimed.")]
+    PostHasReplies,
+
+    #[msg("Post not stale: Post must be older than 30 days to be reclaimed.")]
+    PostNotStale,
+
+    #[msg("Memo at wrong index: The SPL Memo instruction must be placed at index 0 of the transaction.")]
+    MemoWrongIndex,
+
+    #[msg("Invalid mint interval: min_mint_interval must be between 0 and 3600 seconds.")]
+    InvalidMintInterval,
+
+    #[msg("Mint too frequent: Wait for the post's min_mint_interval before minting again.")]
+    MintTooFrequent,
+
+    #[msg("Mint cooldown account required: This post has a min_mint_interval, so mint_cooldown must be provided.")]
+    MintCooldownAccountRequired,
+
+    #[msg("Invalid image reference: Image must be empty, an ipfs://, ar:// or https:// reference, or a CIDv0 hash.")]
+    InvalidImageReference,
+
+    #[msg("Anonymous post too frequent: Please wait before creating another anonymous post.")]
+    AnonymousPostTooFrequent,
+
+    #[msg("Anonymous post cooldown account required: create_anonymous_post needs anonymous_post_cooldown.")]
+    AnonymousPostCooldownRequired,
+
+    #[msg("Invalid author commitment: The commitment must not be all zeros.")]
+    InvalidAuthorCommitment,
+
+    #[msg("Entity limit reached: No more posts can be created.")]
+    EntityLimitReached,
+
+    #[msg("Invalid parent reply index: The parent must be an existing reply of this post.")]
+    InvalidParentReplyIndex,
+
+    #[msg("Invalid content flags: Only the nsfw, spoiler and sensitive bits may be set.")]
+    InvalidContentFlags,
+
+    #[msg("Burn not reflected: The token balance did not drop by the burned amount.")]
+    BurnAmountNotReflected,
+
+    #[msg("Invalid language tag: Use ASCII letters with at most one hyphen (max 8 characters), e.g. \"en\" or \"zh-CN\".")]
+    InvalidLanguageTag,
+
+    #[msg("Low entropy content: Text cannot be only whitespace or a single repeated character.")]
+    LowEntropyContent,
+
+    #[msg("Insufficient burn for content length: Posts require 1 additional token per 100 bytes of content.")]
+    InsufficientBurnForContentLength,
+
+    #[msg("Invalid burn stats account: user_global_burn_stats must be a memo-burn UserGlobalBurnStats account.")]
+    InvalidBurnStatsAccount,
+
+    #[msg("Unexpected mint decimals: The authorized mint must have 6 decimals.")]
+    UnexpectedMintDecimals,
+
+    #[msg("Edit window closed: Posts can only be quick-edited within 5 minutes of creation.")]
+    EditWindowClosed,
+
+    #[msg("Unsupported post update data version. Please use the correct structure version.")]
+    UnsupportedPostUpdateDataVersion,
+
+    #[msg("Invalid post update data format: Failed to deserialize quick edit payload.")]
+    InvalidPostUpdateDataFormat,
+
+    #[msg("Insufficient holdings: This post only accepts replies from holders of its minimum token balance.")]
+    InsufficientHoldings,
+
+    #[msg("Duplicate receipt nonce: A burn receipt already exists for this nonce.")]
+    DuplicateReceiptNonce,
+
+    #[msg("Burn receipt account mismatch: Pass the receipt account exactly when receipt_nonce is set.")]
+    BurnReceiptAccountMismatch,
+
+    #[msg("Tip too high: tip_bps cannot exceed 2000 (20% of the burn).")]
+    TipBpsTooHigh,
+
+    #[msg("Invalid tip recipient: The tip token account must belong to the post creator.")]
+    InvalidTipRecipient,
+
+    #[msg("Cannot tip anonymous post: Anonymous posts have no creator wallet to tip.")]
+    CannotTipAnonymousPost,
+
+    #[msg("Tip exceeds mint cap: memo-mint cannot mint this much for the reply memo; lower tip_bps or burn_amount.")]
+    TipExceedsMintCap,
+
+    #[msg("Invalid post account: Not a current or legacy-sized post.")]
+    InvalidPostAccount,
+
+    #[msg("Post not migrated: Call migrate_post to convert this post to the current layout.")]
+    PostNotMigrated,
 }
@@ -37,9 +37,9 @@ mod tests {
     #[test]
     fn test_version_constants() {
         assert_eq!(BURN_MEMO_VERSION, 1);
-        assert_eq!(POST_CREATION_DATA_VERSION, 1);
-        assert_eq!(POST_BURN_DATA_VERSION, 1);
-        assert_eq!(POST_MINT_DATA_VERSION, 1);
+        assert_eq!(POST_CREATION_DATA_VERSION, 4);
+        assert_eq!(POST_BURN_DATA_VERSION, 2);
+        assert_eq!(POST_MINT_DATA_VERSION, 2);
     }
 
     #[test]
@@ -64,6 +64,9 @@ mod tests {
             title: "Test Post Title".to_string(),
             content: "Test post content for the forum".to_string(),
             image: "https://example.com/image.png".to_string(),
+            mentioned_project_id: None,
+            content_flags: 0,
+            lang: String::new(),
         }
     }
 
@@ -88,6 +91,9 @@ mod tests {
             title: "A".to_string(), // minimum 1 char
             content: "B".to_string(), // minimum 1 char
             image: String::new(), // optional
+            mentioned_project_id: None,
+            content_flags: 0,
+            lang: String::new(),
         };
         assert!(data.validate(creator, post_id).is_ok());
     }
@@ -103,8 +109,11 @@ mod tests {
             creator: creator.to_string(),
             post_id,
             title: "T".repeat(MAX_POST_TITLE_LENGTH),
-            content: "C".repeat(MAX_POST_CONTENT_LENGTH),
-            image: "I".repeat(MAX_POST_IMAGE_LENGTH),
+            content: "Cc".repeat(MAX_POST_CONTENT_LENGTH / 2),
+            image: format!("https://{}", "I".repeat(MAX_POST_IMAGE_LENGTH - "https://".len())),
+            mentioned_project_id: None,
+            content_flags: 0,
+            lang: String::new(),
         };
         assert!(data.validate(creator, post_id).is_ok());
     }
@@ -219,6 +228,7 @@ mod tests {
             user: user.to_string(),
             post_id,
             message: "Burning tokens to reply to this post".to_string(),
+            parent_reply_index: None,
         }
     }
 
@@ -322,6 +332,7 @@ mod tests {
             user: user.to_string(),
             post_id,
             message: "Minting tokens to reply to this post".to_string(),
+            parent_reply_index: None,
         }
     }
 
@@ -442,7 +453,12 @@ mod tests {
             8 + // reply_count
             8 + // burned_amount
             8 + // last_reply_time
+            8 + // min_mint_interval
+            32 + // author_commitment
+            1 + // content_flags
+            8 + // min_holding
             1 + // bump
+            4 + 8 + // lang
             4 + 128 + // title
             4 + 512 + // content
             4 + 256 + // image
@@ -456,7 +472,7 @@ mod tests {
         let space = Post::calculate_space_max();
         
         // Minimum required (without buffer)
-        let minimum = 8 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 
+        let minimum = 8 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 1 + 8 + 1 + (4 + 8) +
                      (4 + 128) + (4 + 512) + (4 + 256);
         
         // Space should be greater than minimum due to buffer
@@ -464,6 +480,63 @@ mod tests {
         assert_eq!(space - minimum, 128); // 128 byte buffer
     }
 
+    // ============================================================================
+    // Stale Post Reclaim Tests
+    // ============================================================================
+
+    fn create_post(created_at: i64, reply_count: u64) -> Post {
+        Post {
+            post_id: 7,
+            creator: Pubkey::new_unique(),
+            created_at,
+            last_updated: created_at,
+            title: "Title".to_string(),
+            content: "Content".to_string(),
+            image: String::new(),
+            reply_count,
+            burned_amount: MIN_POST_BURN_AMOUNT,
+            last_reply_time: 0,
+            bump: 255,
+            min_mint_interval: 0,
+            author_commitment: [0u8; 32],
+            content_flags: 0,
+            lang: String::new(),
+            min_holding: 0,
+        }
+    }
+
+    #[test]
+    fn test_stale_post_constant() {
+        assert_eq!(STALE_POST_SECONDS, 30 * 24 * 60 * 60);
+    }
+
+    #[test]
+    fn test_reclaim_time_gate() {
+        let created_at = 1_700_000_000;
+        let post = create_post(created_at, 0);
+
+        assert!(post.check_reclaimable(created_at).is_err());
+        assert!(post.check_reclaimable(created_at + STALE_POST_SECONDS).is_err(),
+                "Post exactly STALE_POST_SECONDS old is not yet stale");
+        assert!(post.check_reclaimable(created_at + STALE_POST_SECONDS + 1).is_ok());
+    }
+
+    #[test]
+    fn test_reclaim_not_stale_error() {
+        let post = create_post(1_700_000_000, 0);
+        let err = post.check_reclaimable(1_700_000_001).unwrap_err();
+        assert_eq!(err, ErrorCode::PostNotStale.into());
+    }
+
+    #[test]
+    fn test_reclaim_rejected_with_replies() {
+        let created_at = 1_700_000_000;
+        let post = create_post(created_at, 1);
+
+        let err = post.check_reclaimable(created_at + STALE_POST_SECONDS * 2).unwrap_err();
+        assert_eq!(err, ErrorCode::PostHasReplies.into());
+    }
+
     // ============================================================================
     // BurnMemo Serialization Tests
     // ============================================================================
@@ -739,6 +812,9 @@ mod tests {
             title: title.to_string(),
             content: content.to_string(),
             image: image.to_string(),
+            mentioned_project_id: None,
+            content_flags: 0,
+            lang: String::new(),
         };
         
         let payload = post_data.try_to_vec().unwrap();
@@ -770,6 +846,7 @@ mod tests {
             user: user.to_string(),
             post_id,
             message: message.to_string(),
+            parent_reply_index: None,
         };
         
         let payload = burn_data.try_to_vec().unwrap();
@@ -800,6 +877,7 @@ mod tests {
             user: user.to_string(),
             post_id,
             message: message.to_string(),
+            parent_reply_index: None,
         };
         
         let payload = mint_data.try_to_vec().unwrap();
@@ -834,7 +912,7 @@ mod tests {
             "https://example.com/image.png",
         );
         
-        let result = parse_post_creation_borsh_memo(&memo_data, creator, post_id, burn_amount);
+        let result = parse_post_creation_borsh_memo(&memo_data, creator, post_id, burn_amount, EXPECTED_CREATE_POST_OPERATION);
         assert!(result.is_ok(), "Valid post creation memo should parse successfully");
         
         let post_data = result.unwrap();
@@ -859,7 +937,7 @@ mod tests {
             "",
         );
         
-        let result = parse_post_creation_borsh_memo(&memo_data, creator, post_id, expected_burn_amount);
+        let result = parse_post_creation_borsh_memo(&memo_data, creator, post_id, expected_burn_amount, EXPECTED_CREATE_POST_OPERATION);
         assert!(result.is_err(), "Mismatched burn amount should fail parsing");
     }
 
@@ -879,7 +957,7 @@ mod tests {
             "",
         );
         
-        let result = parse_post_creation_borsh_memo(&memo_data, creator2, post_id, burn_amount);
+        let result = parse_post_creation_borsh_memo(&memo_data, creator2, post_id, burn_amount, EXPECTED_CREATE_POST_OPERATION);
         assert!(result.is_err(), "Mismatched user should fail parsing");
     }
 
@@ -890,7 +968,7 @@ mod tests {
         let burn_amount = MIN_POST_BURN_AMOUNT;
         let invalid_base64 = b"not valid base64!!!".to_vec();
         
-        let result = parse_post_creation_borsh_memo(&invalid_base64, creator, post_id, burn_amount);
+        let result = parse_post_creation_borsh_memo(&invalid_base64, creator, post_id, burn_amount, EXPECTED_CREATE_POST_OPERATION);
         assert!(result.is_err(), "Invalid base64 should fail parsing");
     }
 
@@ -1012,6 +1090,7 @@ mod tests {
             user: user.to_string(),
             post_id,
             message: "Test".to_string(),
+            parent_reply_index: None,
         };
         
         let payload = mint_data.try_to_vec().unwrap();
@@ -1030,4 +1109,1342 @@ mod tests {
         let result = parse_post_mint_borsh_memo(&memo_data, user, post_id);
         assert!(result.is_err(), "Mint memo with non-zero burn_amount should fail");
     }
+
+    // ============================================================================
+    // Memo Hash Tests
+    // ============================================================================
+
+    #[test]
+    fn test_compute_memo_hash_known_vector() {
+        // SHA-256("abc")
+        let expected: [u8; 32] = [
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22, 0x23,
+            0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad,
+        ];
+        assert_eq!(compute_memo_hash(b"abc"), expected);
+    }
+
+    #[test]
+    fn test_compute_memo_hash_matches_burn_memo_bytes() {
+        let user = Pubkey::new_unique();
+        let memo_data = create_post_burn_memo(MIN_POST_BURN_AMOUNT, user, 3, "Great post!");
+
+        assert!(parse_post_burn_borsh_memo(&memo_data, MIN_POST_BURN_AMOUNT, user, 3).is_ok());
+        assert_eq!(compute_memo_hash(&memo_data), hash(&memo_data).to_bytes());
+
+        let other_data = create_post_burn_memo(MIN_POST_BURN_AMOUNT, user, 4, "Great post!");
+        assert_ne!(compute_memo_hash(&memo_data), compute_memo_hash(&other_data));
+    }
+
+    // ============================================================================
+    // Global Stats Tests
+    // ============================================================================
+
+    #[test]
+    fn test_global_stats_round_trip() {
+        use borsh::BorshSerialize;
+
+        let counter = GlobalPostCounter { total_posts: 314 };
+        let return_data = GlobalStats::new(&counter).try_to_vec().unwrap();
+        let decoded = GlobalStats::try_from_slice(&return_data).unwrap();
+
+        assert_eq!(decoded.total_posts, counter.total_posts);
+        assert_eq!(return_data, 314u64.to_le_bytes().to_vec());
+    }
+
+    // ============================================================================
+    // Memo Placement Tests
+    // ============================================================================
+
+    #[test]
+    fn test_memo_placement_no_memo_anywhere() {
+        // No memo at all: placement check passes so the caller reports MemoRequired
+        assert!(check_memo_placement(&[]).is_ok());
+        assert!(check_memo_placement(&[Pubkey::new_unique(), crate::ID]).is_ok());
+    }
+
+    #[test]
+    fn test_memo_placement_memo_at_wrong_index() {
+        let program_ids = [Pubkey::new_unique(), crate::ID, MEMO_PROGRAM_ID];
+        let err = check_memo_placement(&program_ids).unwrap_err();
+        assert_eq!(err, ErrorCode::MemoWrongIndex.into());
+
+        // A memo at index 0 is the valid layout
+        assert!(check_memo_placement(&[MEMO_PROGRAM_ID, crate::ID]).is_ok());
+    }
+
+    // ============================================================================
+    // Program Stats Tests
+    // ============================================================================
+
+    fn create_program_stats() -> ProgramStats {
+        ProgramStats {
+            total_burned: 0,
+            total_minted: 0,
+            event_seq: 0,
+            bump: 255,
+        }
+    }
+
+    // Handler-level tests: drive instructions through the program entrypoint with
+    // fabricated accounts (CPIs are unavailable off-chain, so burns stop short of the CPI)
+
+    use anchor_lang::solana_program::entrypoint::ProgramResult;
+    use anchor_lang::solana_program::program_error::ProgramError;
+    use anchor_lang::solana_program::program_pack::Pack;
+    use anchor_lang::InstructionData;
+    use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+    use anchor_spl::token_2022::spl_token_2022::state::{
+        Account as TokenAccountState, AccountState, Mint as MintState,
+    };
+
+    struct TestAccount {
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        data: Vec<u8>,
+        is_signer: bool,
+        executable: bool,
+    }
+
+    impl TestAccount {
+        fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+            Self { key, owner, lamports: 1_000_000_000, data, is_signer: false, executable: false }
+        }
+
+        fn signer(key: Pubkey) -> Self {
+            Self { is_signer: true, ..Self::new(key, anchor_lang::system_program::ID, Vec::new()) }
+        }
+
+        fn program(key: Pubkey) -> Self {
+            Self { executable: true, ..Self::new(key, anchor_lang::solana_program::bpf_loader_upgradeable::ID, Vec::new()) }
+        }
+
+        fn serialized<T: AccountSerialize>(key: Pubkey, owner: Pubkey, account: &T) -> Self {
+            let mut data = Vec::new();
+            account.try_serialize(&mut data).unwrap();
+            Self::new(key, owner, data)
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(&self.key, self.is_signer, true, &mut self.lamports, &mut self.data, &self.owner, self.executable, 0)
+        }
+    }
+
+    static INSTALL_STUBS: std::sync::Once = std::sync::Once::new();
+
+    const HANDLER_TEST_TIME: i64 = 1_700_000_000;
+
+    /// Sysvars the handlers read (Rent is read up front by init_if_needed accounts)
+    struct RuntimeStubs;
+
+    impl SyscallStubs for RuntimeStubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock { unix_timestamp: HANDLER_TEST_TIME, ..Clock::default() };
+            unsafe { std::ptr::write_unaligned(var_addr as *mut Clock, clock) };
+            0
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { std::ptr::write_unaligned(var_addr as *mut Rent, Rent::default()) };
+            0
+        }
+    }
+
+    fn run_instruction(accounts: &mut [TestAccount], data: Vec<u8>) -> ProgramResult {
+        INSTALL_STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(RuntimeStubs));
+        });
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(TestAccount::info).collect();
+        crate::entry(&crate::ID, &infos, &data)
+    }
+
+    fn anchor_error(code: anchor_lang::error::ErrorCode) -> ProgramError {
+        anchor_lang::error::Error::from(code).into()
+    }
+
+    fn mint_account() -> TestAccount {
+        let mut data = vec![0u8; MintState::LEN];
+        let mint = MintState { supply: 1_000_000 * DECIMAL_FACTOR, decimals: MINT_DECIMALS, is_initialized: true, ..Default::default() };
+        MintState::pack(mint, &mut data).unwrap();
+        TestAccount::new(AUTHORIZED_MINT_PUBKEY, anchor_spl::token_2022::ID, data)
+    }
+
+    fn token_account(owner: Pubkey) -> TestAccount {
+        let mut data = vec![0u8; TokenAccountState::LEN];
+        let state = TokenAccountState {
+            mint: AUTHORIZED_MINT_PUBKEY,
+            owner,
+            amount: 1_000 * DECIMAL_FACTOR,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        TokenAccountState::pack(state, &mut data).unwrap();
+        TestAccount::new(Pubkey::new_unique(), anchor_spl::token_2022::ID, data)
+    }
+
+    fn program_stats_account(key: Pubkey) -> TestAccount {
+        let (_, bump) = Pubkey::find_program_address(&[b"stats"], &crate::ID);
+        TestAccount::serialized(key, crate::ID, &ProgramStats { bump, ..create_program_stats() })
+    }
+
+    const BURN_PROGRAM_STATS_INDEX: usize = 9;
+
+    fn burn_for_post_accounts(user: Pubkey) -> Vec<TestAccount> {
+        let post = create_post(0, 0);
+        let (post_key, post_bump) = Pubkey::find_program_address(&[b"post", post.post_id.to_le_bytes().as_ref()], &crate::ID);
+        let (burn_stats_key, burn_stats_bump) = Pubkey::find_program_address(&[b"user_global_burn_stats", user.as_ref()], &memo_burn::ID);
+        let burn_stats = memo_burn::UserGlobalBurnStats { user, total_burned: 0, burn_count: 0, last_burn_time: 0, bump: burn_stats_bump };
+        vec![
+            TestAccount::signer(user),
+            TestAccount::serialized(post_key, crate::ID, &Post { bump: post_bump, ..post }),
+            mint_account(),
+            token_account(user),
+            TestAccount::serialized(burn_stats_key, memo_burn::ID, &burn_stats),
+            TestAccount::program(anchor_spl::token_2022::ID),
+            TestAccount::program(memo_burn::ID),
+            TestAccount::new(INSTRUCTIONS_ID, anchor_lang::solana_program::sysvar::ID, Vec::new()),
+            TestAccount::program(anchor_lang::system_program::ID),
+            program_stats_account(Pubkey::find_program_address(&[b"stats"], &crate::ID).0),
+            // No burn receipt
+            TestAccount::program(crate::ID),
+        ]
+    }
+
+    fn burn_for_post(accounts: &mut [TestAccount], amount: u64) -> ProgramResult {
+        let post_id = create_post(0, 0).post_id;
+        run_instruction(accounts, crate::instruction::BurnForPost { post_id, amount, receipt_nonce: None }.data())
+    }
+
+    #[test]
+    fn test_burn_for_post_accepts_program_stats_pda() {
+        let mut accounts = burn_for_post_accounts(Pubkey::new_unique());
+
+        // Account validation passes and the handler body runs (rejecting the amount)
+        let err = burn_for_post(&mut accounts, MIN_POST_BURN_AMOUNT - 1).unwrap_err();
+        assert_eq!(err, anchor_lang::error::Error::from(ErrorCode::BurnAmountTooSmall).into());
+    }
+
+    #[test]
+    fn test_burn_for_post_requires_program_stats() {
+        // Omitted the way optional accounts are (program id placeholder)
+        let mut accounts = burn_for_post_accounts(Pubkey::new_unique());
+        accounts[BURN_PROGRAM_STATS_INDEX] = TestAccount::program(crate::ID);
+        let err = burn_for_post(&mut accounts, MIN_POST_BURN_AMOUNT).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram));
+
+        // Left off the end of the account list
+        let mut accounts = burn_for_post_accounts(Pubkey::new_unique());
+        accounts.truncate(BURN_PROGRAM_STATS_INDEX);
+        let err = burn_for_post(&mut accounts, MIN_POST_BURN_AMOUNT).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::AccountNotEnoughKeys));
+    }
+
+    #[test]
+    fn test_burn_for_post_rejects_substituted_program_stats() {
+        let mut accounts = burn_for_post_accounts(Pubkey::new_unique());
+        accounts[BURN_PROGRAM_STATS_INDEX] = program_stats_account(Pubkey::new_unique());
+
+        let err = burn_for_post(&mut accounts, MIN_POST_BURN_AMOUNT).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::ConstraintSeeds));
+    }
+
+    fn read_program_stats(account: &TestAccount) -> ProgramStats {
+        ProgramStats::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// Instructions sysvar for the transaction [memo, this program's instruction],
+    /// currently executing index 1
+    fn instructions_sysvar(memo: &[u8]) -> TestAccount {
+        let instructions: [(Pubkey, &[u8]); 2] = [(MEMO_PROGRAM_ID, memo), (crate::ID, &[])];
+        let mut data = (instructions.len() as u16).to_le_bytes().to_vec();
+        data.resize(2 + 2 * instructions.len(), 0);
+        for (i, (program_id, ix_data)) in instructions.iter().enumerate() {
+            let offset = data.len() as u16;
+            data[2 + 2 * i..4 + 2 * i].copy_from_slice(&offset.to_le_bytes());
+            data.extend_from_slice(&0u16.to_le_bytes()); // no account metas
+            data.extend_from_slice(program_id.as_ref());
+            data.extend_from_slice(&(ix_data.len() as u16).to_le_bytes());
+            data.extend_from_slice(ix_data);
+        }
+        data.extend_from_slice(&1u16.to_le_bytes());
+        TestAccount::new(INSTRUCTIONS_ID, anchor_lang::solana_program::sysvar::ID, data)
+    }
+
+    fn quick_edit_post_accounts(creator: Pubkey, content: &str) -> Vec<TestAccount> {
+        let post = create_post(HANDLER_TEST_TIME, 0);
+        let (post_key, post_bump) = Pubkey::find_program_address(&[b"post", post.post_id.to_le_bytes().as_ref()], &crate::ID);
+        let memo = create_post_update_memo(creator, post.post_id, content);
+        let mut post_account = TestAccount::serialized(post_key, crate::ID, &Post { creator, bump: post_bump, ..post });
+        post_account.data.resize(post_account.data.len() + content.len(), 0);
+        vec![
+            TestAccount::signer(creator),
+            post_account,
+            instructions_sysvar(&memo),
+            program_stats_account(Pubkey::find_program_address(&[b"stats"], &crate::ID).0),
+        ]
+    }
+
+    #[test]
+    fn test_event_seq_consecutive_quick_edits() {
+        let creator = Pubkey::new_unique();
+        let post_id = create_post(0, 0).post_id;
+        let mut accounts = quick_edit_post_accounts(creator, "Content, typo fixed");
+
+        // The first event gets 1 and every edit advances the sequence
+        run_instruction(&mut accounts, crate::instruction::QuickEditPost { post_id }.data()).unwrap();
+        assert_eq!(read_program_stats(&accounts[3]).event_seq, 1);
+
+        run_instruction(&mut accounts, crate::instruction::QuickEditPost { post_id }.data()).unwrap();
+        assert_eq!(read_program_stats(&accounts[3]).event_seq, 2);
+    }
+
+    #[test]
+    fn test_quick_edit_post_requires_program_stats() {
+        let post_id = create_post(0, 0).post_id;
+        let mut accounts = quick_edit_post_accounts(Pubkey::new_unique(), "Content, typo fixed");
+        accounts.pop();
+
+        let err = run_instruction(&mut accounts, crate::instruction::QuickEditPost { post_id }.data()).unwrap_err();
+        assert_eq!(err, anchor_error(anchor_lang::error::ErrorCode::AccountNotEnoughKeys));
+    }
+
+    // ============================================================================
+    // Post Layout Migration Tests
+    // ============================================================================
+
+    /// Post layout before min_mint_interval, as written by earlier program versions
+    #[derive(AnchorSerialize)]
+    struct PostV1 {
+        post_id: u64,
+        creator: Pubkey,
+        created_at: i64,
+        last_updated: i64,
+        title: String,
+        content: String,
+        image: String,
+        reply_count: u64,
+        burned_amount: u64,
+        last_reply_time: i64,
+        bump: u8,
+    }
+
+    fn legacy_post() -> PostV1 {
+        PostV1 {
+            post_id: 7,
+            creator: Pubkey::new_unique(),
+            created_at: 1_600_000_000,
+            last_updated: 1_650_000_000,
+            title: "Old post".to_string(),
+            content: "Written before anonymous posts".to_string(),
+            image: String::new(),
+            reply_count: 4,
+            burned_amount: MIN_POST_BURN_AMOUNT,
+            last_reply_time: 1_660_000_000,
+            bump: 254,
+        }
+    }
+
+    /// A legacy-sized account holding `legacy`, followed by stale bytes from longer
+    /// strings written before an update shrank them
+    fn legacy_post_data(legacy: &PostV1) -> Vec<u8> {
+        let mut data = vec![b'x'; Post::LEGACY_SPACE];
+        let encoded = legacy.try_to_vec().unwrap();
+        data[..8].copy_from_slice(Post::DISCRIMINATOR);
+        data[8..8 + encoded.len()].copy_from_slice(&encoded);
+        data
+    }
+
+    #[test]
+    fn test_post_layout_extends_legacy_layout() {
+        let legacy = legacy_post();
+        let post = Post::from_legacy(&legacy_post_data(&legacy)).unwrap();
+
+        // The current layout starts with the legacy fields, bump included, in the same order
+        let legacy_bytes = legacy.try_to_vec().unwrap();
+        let current_bytes = post.try_to_vec().unwrap();
+        assert_eq!(&current_bytes[..legacy_bytes.len()], legacy_bytes.as_slice());
+        assert!(Post::calculate_space_max() > Post::LEGACY_SPACE);
+    }
+
+    #[test]
+    fn test_post_from_legacy_ignores_stale_bytes() {
+        let legacy = legacy_post();
+        let post = Post::from_legacy(&legacy_post_data(&legacy)).unwrap();
+
+        assert_eq!(post.creator, legacy.creator);
+        assert_eq!(post.content, legacy.content);
+        assert_eq!(post.reply_count, 4);
+        assert_eq!(post.last_reply_time, legacy.last_reply_time);
+        assert_eq!(post.bump, 254);
+
+        // Appended fields start empty rather than decoding the stale tail
+        assert_eq!(post.author_commitment, [0u8; 32]);
+        assert_eq!(post.min_mint_interval, 0);
+        assert_eq!(post.lang, "");
+        assert_eq!(post.min_holding, 0);
+    }
+
+    #[test]
+    fn test_post_needs_migration() {
+        let legacy_data = legacy_post_data(&legacy_post());
+        assert!(Post::needs_migration(&legacy_data).unwrap());
+
+        let mut current = Vec::new();
+        create_post(0, 0).try_serialize(&mut current).unwrap();
+        current.resize(Post::calculate_space_max(), 0);
+        assert!(!Post::needs_migration(&current).unwrap());
+        let err = Post::from_legacy(&current).err().unwrap();
+        assert_eq!(err, ErrorCode::InvalidPostAccount.into());
+
+        // Anything else is not a post
+        let err = Post::needs_migration(&legacy_data[..legacy_data.len() - 1]).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidPostAccount.into());
+        let mut foreign = legacy_data.clone();
+        foreign[0] ^= 1;
+        let err = Post::needs_migration(&foreign).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidPostAccount.into());
+    }
+
+    #[test]
+    fn test_unmigrated_post_rejected_by_instructions() {
+        let post_id = create_post(0, 0).post_id;
+        let mut accounts = quick_edit_post_accounts(Pubkey::new_unique(), "Content, typo fixed");
+        accounts[1].data.resize(Post::LEGACY_SPACE, 0);
+
+        let err = run_instruction(&mut accounts, crate::instruction::QuickEditPost { post_id }.data()).unwrap_err();
+        assert_eq!(err, anchor_lang::error::Error::from(ErrorCode::PostNotMigrated).into());
+        assert_eq!(read_program_stats(&accounts[3]).event_seq, 0);
+    }
+
+    // ============================================================================
+    // Mint Cooldown Tests
+    // ============================================================================
+
+    fn create_mint_cooldown() -> MintCooldown {
+        MintCooldown {
+            last_mint_time: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_min_mint_interval_bounds() {
+        assert!(Post::validate_min_mint_interval(0).is_ok());
+        assert!(Post::validate_min_mint_interval(MAX_MINT_INTERVAL_SECONDS).is_ok());
+
+        let err = Post::validate_min_mint_interval(MAX_MINT_INTERVAL_SECONDS + 1).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidMintInterval.into());
+        let err = Post::validate_min_mint_interval(-1).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidMintInterval.into());
+    }
+
+    #[test]
+    fn test_mint_cooldown_boundary() {
+        let mut cooldown = create_mint_cooldown();
+
+        // First mint is never throttled
+        assert!(cooldown.check_and_record(60, 1_000, false).is_ok());
+
+        // One second short of the interval is rejected and leaves the state untouched
+        let err = cooldown.check_and_record(60, 1_059, false).unwrap_err();
+        assert_eq!(err, ErrorCode::MintTooFrequent.into());
+        assert_eq!(cooldown.last_mint_time, 1_000);
+
+        // Exactly the interval is allowed
+        assert!(cooldown.check_and_record(60, 1_060, false).is_ok());
+        assert_eq!(cooldown.last_mint_time, 1_060);
+    }
+
+    #[test]
+    fn test_mint_cooldown_zero_interval_never_throttles() {
+        let mut cooldown = create_mint_cooldown();
+
+        assert!(cooldown.check_and_record(0, 1_000, false).is_ok());
+        assert!(cooldown.check_and_record(0, 1_000, false).is_ok());
+        assert!(cooldown.check_and_record(0, 1_001, false).is_ok());
+    }
+
+    #[test]
+    fn test_mint_cooldown_is_independent_per_user() {
+        let post_key = Pubkey::find_program_address(&[b"post", &7u64.to_le_bytes()], &crate::ID).0;
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+
+        // Each user gets their own cooldown PDA for the same post
+        let cooldown_pda = |user: &Pubkey| {
+            Pubkey::find_program_address(&[b"mint_cooldown", post_key.as_ref(), user.as_ref()], &crate::ID).0
+        };
+        assert_ne!(cooldown_pda(&alice), cooldown_pda(&bob));
+
+        let mut alice_cooldown = create_mint_cooldown();
+        let mut bob_cooldown = create_mint_cooldown();
+
+        assert!(alice_cooldown.check_and_record(300, 1_000, false).is_ok());
+        // Alice is throttled, Bob is not
+        assert!(alice_cooldown.check_and_record(300, 1_100, false).is_err());
+        assert!(bob_cooldown.check_and_record(300, 1_100, false).is_ok());
+        assert!(bob_cooldown.check_and_record(300, 1_200, false).is_err());
+        assert!(alice_cooldown.check_and_record(300, 1_300, false).is_ok());
+    }
+
+    // ============================================================================
+    // Image Reference Tests
+    // ============================================================================
+
+    const TEST_CID_V0: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+    #[test]
+    fn test_image_reference_accepted_schemes() {
+        assert!(validate_image_reference("").is_ok());
+        assert!(validate_image_reference("ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi").is_ok());
+        assert!(validate_image_reference("ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U").is_ok());
+        assert!(validate_image_reference("https://example.com/image.png").is_ok());
+
+        assert_eq!(TEST_CID_V0.len(), CID_V0_LENGTH);
+        assert!(validate_image_reference(TEST_CID_V0).is_ok());
+    }
+
+    #[test]
+    fn test_image_reference_rejects_bare_filename() {
+        let err = validate_image_reference("image.png").unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidImageReference.into());
+
+        // Plain http and CID look-alikes are rejected too
+        assert!(validate_image_reference("http://example.com/image.png").is_err());
+        assert!(validate_image_reference(&TEST_CID_V0[..CID_V0_LENGTH - 1]).is_err());
+        assert!(validate_image_reference(&TEST_CID_V0.replace('Y', "0")).is_err());
+    }
+
+    #[test]
+    fn test_creation_data_rejects_invalid_image_reference() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 1);
+        data.image = "image.png".to_string();
+        let err = data.validate(creator, 1).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidImageReference.into());
+
+        data.image = TEST_CID_V0.to_string();
+        assert!(data.validate(creator, 1).is_ok());
+    }
+
+    // ============================================================================
+    // Anonymous Post Tests
+    // ============================================================================
+
+    #[test]
+    fn test_anonymous_post_stores_commitment() {
+        let author = Pubkey::new_unique();
+        let salt = [7u8; 32];
+        let commitment = compute_author_commitment(&author, &salt);
+        assert!(validate_author_commitment(&commitment).is_ok());
+
+        let mut post = create_post(0, 0);
+        post.creator = anonymous_author();
+        post.author_commitment = commitment;
+
+        // The post shows the program PDA, the commitment opens only with the right author and salt
+        assert_ne!(post.creator, author);
+        assert_eq!(post.author_commitment, compute_author_commitment(&author, &salt));
+        assert_ne!(post.author_commitment, compute_author_commitment(&Pubkey::new_unique(), &salt));
+        assert_ne!(post.author_commitment, compute_author_commitment(&author, &[8u8; 32]));
+    }
+
+    #[test]
+    fn test_anonymous_post_memo_names_program_author() {
+        let data = create_valid_post_creation_data(anonymous_author(), 3);
+        assert!(data.validate(anonymous_author(), 3).is_ok());
+
+        // A memo naming the real signer is rejected for an anonymous post
+        let signer = Pubkey::new_unique();
+        let data = create_valid_post_creation_data(signer, 3);
+        assert!(data.validate(anonymous_author(), 3).is_err());
+    }
+
+    #[test]
+    fn test_anonymous_post_rejects_zero_commitment() {
+        let err = validate_author_commitment(&[0u8; 32]).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidAuthorCommitment.into());
+    }
+
+    #[test]
+    fn test_anonymous_post_rate_limited_by_real_signer() {
+        // Cooldown accounts are seeded by the real signer, so each signer has its own
+        let mut signer_a = AnonymousPostCooldown { last_post_time: 0, bump: 255 };
+        let mut signer_b = AnonymousPostCooldown { last_post_time: 0, bump: 255 };
+
+        signer_a.check_and_record(1_000, false).unwrap();
+        let err = signer_a.check_and_record(1_000 + ANONYMOUS_POST_INTERVAL_SECONDS - 1, false).unwrap_err();
+        assert_eq!(err, ErrorCode::AnonymousPostTooFrequent.into());
+
+        // Another signer is not affected
+        signer_b.check_and_record(1_001, false).unwrap();
+
+        // After the interval the first signer may post again
+        signer_a.check_and_record(1_000 + ANONYMOUS_POST_INTERVAL_SECONDS, false).unwrap();
+        assert_eq!(signer_a.last_post_time, 1_000 + ANONYMOUS_POST_INTERVAL_SECONDS);
+    }
+
+    // ============================================================================
+    // Entity Limit Tests
+    // ============================================================================
+
+    #[test]
+    fn test_counter_below_entity_limit() {
+        let counter = GlobalPostCounter { total_posts: MAX_ENTITIES - 1 };
+        assert!(counter.check_capacity().is_ok());
+    }
+
+    #[test]
+    fn test_counter_at_entity_limit_rejected() {
+        let counter = GlobalPostCounter { total_posts: MAX_ENTITIES };
+        let err = counter.check_capacity().unwrap_err();
+        assert_eq!(err, ErrorCode::EntityLimitReached.into());
+
+        let counter = GlobalPostCounter { total_posts: u64::MAX };
+        assert!(counter.check_capacity().is_err());
+    }
+
+    // ============================================================================
+    // PostCreationData v2 (mentioned_project_id) Tests
+    // ============================================================================
+
+    /// Legacy v1 layout, as serialized by existing clients
+    #[derive(AnchorSerialize)]
+    struct PostCreationDataV1 {
+        version: u8,
+        category: String,
+        operation: String,
+        creator: String,
+        post_id: u64,
+        title: String,
+        content: String,
+        image: String,
+    }
+
+    #[test]
+    fn test_post_creation_data_v1_payload_decodes() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        let legacy = PostCreationDataV1 {
+            version: 1,
+            category: EXPECTED_CATEGORY.to_string(),
+            operation: EXPECTED_CREATE_POST_OPERATION.to_string(),
+            creator: creator.to_string(),
+            post_id: 5,
+            title: "Old client".to_string(),
+            content: "Posted before mentions existed".to_string(),
+            image: String::new(),
+        };
+        let bytes = legacy.try_to_vec().unwrap();
+
+        let decoded = PostCreationData::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded.mentioned_project_id, None);
+        assert!(decoded.validate(creator, 5).is_ok());
+        assert!(decoded.project_mention(5).is_none());
+
+        // Re-encoding a v1 value reproduces the legacy bytes exactly
+        assert_eq!(decoded.try_to_vec().unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_post_creation_data_with_mention() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 9);
+        data.version = 2;
+        data.mentioned_project_id = Some(42);
+
+        let decoded = PostCreationData::try_from_slice(&data.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.mentioned_project_id, Some(42));
+        assert!(decoded.validate(creator, 9).is_ok());
+
+        let mention = decoded.project_mention(9).unwrap();
+        assert_eq!(mention.project_id, 42);
+        assert_eq!(mention.post_id, 9);
+    }
+
+    #[test]
+    fn test_post_creation_data_without_mention() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 9);
+        data.version = 2;
+
+        let bytes = data.try_to_vec().unwrap();
+        // v2 always carries the Option tag, even when empty
+        assert_eq!(*bytes.last().unwrap(), 0u8);
+
+        let decoded = PostCreationData::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded.mentioned_project_id, None);
+        assert!(decoded.validate(creator, 9).is_ok());
+        assert!(decoded.project_mention(9).is_none());
+    }
+
+    #[test]
+    fn test_post_creation_data_v1_with_mention_invalid() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 1);
+        data.version = 1;
+        data.mentioned_project_id = Some(3);
+        let err = data.validate(creator, 1).unwrap_err();
+        assert_eq!(err, ErrorCode::UnsupportedPostDataVersion.into());
+    }
+
+    // ============================================================================
+    // Threaded Reply (parent_reply_index) Tests
+    // ============================================================================
+
+    /// Legacy v1 reply layout (identical for burn and mint), as serialized by existing clients
+    #[derive(AnchorSerialize)]
+    struct PostReplyDataV1 {
+        version: u8,
+        category: String,
+        operation: String,
+        user: String,
+        post_id: u64,
+        message: String,
+    }
+
+    #[test]
+    fn test_reply_data_v1_payload_decodes() {
+        use borsh::BorshSerialize;
+
+        let user = Pubkey::new_unique();
+        let legacy = PostReplyDataV1 {
+            version: 1,
+            category: EXPECTED_CATEGORY.to_string(),
+            operation: EXPECTED_BURN_FOR_POST_OPERATION.to_string(),
+            user: user.to_string(),
+            post_id: 7,
+            message: "old client".to_string(),
+        };
+        let bytes = legacy.try_to_vec().unwrap();
+
+        let decoded = PostBurnData::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded.parent_reply_index, None);
+        assert!(decoded.validate(user, 7).is_ok());
+        assert_eq!(decoded.try_to_vec().unwrap(), bytes);
+
+        let mint_legacy = PostReplyDataV1 {
+            operation: EXPECTED_MINT_FOR_POST_OPERATION.to_string(),
+            ..legacy
+        };
+        let decoded = PostMintData::try_from_slice(&mint_legacy.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.parent_reply_index, None);
+        assert!(decoded.validate(user, 7).is_ok());
+    }
+
+    #[test]
+    fn test_reply_with_valid_parent_index() {
+        use borsh::BorshSerialize;
+
+        let user = Pubkey::new_unique();
+        let mut burn_data = create_valid_post_burn_data(user, 7);
+        burn_data.parent_reply_index = Some(2);
+        let decoded = PostBurnData::try_from_slice(&burn_data.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.parent_reply_index, Some(2));
+        assert!(decoded.validate(user, 7).is_ok());
+
+        let mut mint_data = create_valid_post_mint_data(user, 7);
+        mint_data.parent_reply_index = Some(0);
+        let decoded = PostMintData::try_from_slice(&mint_data.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.parent_reply_index, Some(0));
+
+        // Post with 3 replies: indices 0..=2 are valid parents
+        let post = create_post(0, 3);
+        assert!(post.check_parent_reply(Some(0)).is_ok());
+        assert!(post.check_parent_reply(Some(2)).is_ok());
+    }
+
+    #[test]
+    fn test_reply_with_out_of_range_parent() {
+        let post = create_post(0, 3);
+        let err = post.check_parent_reply(Some(3)).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidParentReplyIndex.into());
+        assert!(post.check_parent_reply(Some(u64::MAX)).is_err());
+
+        // A post without replies has no valid parent
+        assert!(create_post(0, 0).check_parent_reply(Some(0)).is_err());
+    }
+
+    #[test]
+    fn test_top_level_reply() {
+        assert!(create_post(0, 0).check_parent_reply(None).is_ok());
+        assert!(create_post(0, 5).check_parent_reply(None).is_ok());
+    }
+
+    #[test]
+    fn test_reply_data_v1_with_parent_invalid() {
+        let user = Pubkey::new_unique();
+        let mut burn_data = create_valid_post_burn_data(user, 7);
+        burn_data.version = 1;
+        burn_data.parent_reply_index = Some(0);
+        assert_eq!(burn_data.validate(user, 7).unwrap_err(), ErrorCode::UnsupportedPostBurnDataVersion.into());
+
+        let mut mint_data = create_valid_post_mint_data(user, 7);
+        mint_data.version = 1;
+        mint_data.parent_reply_index = Some(0);
+        assert_eq!(mint_data.validate(user, 7).unwrap_err(), ErrorCode::UnsupportedPostMintDataVersion.into());
+    }
+
+    // ============================================================================
+    // Content Flags Tests
+    // ============================================================================
+
+    #[test]
+    fn test_post_creation_data_nsfw_flag() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 4);
+        data.content_flags = CONTENT_FLAG_NSFW;
+
+        let decoded = PostCreationData::try_from_slice(&data.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.content_flags, CONTENT_FLAG_NSFW);
+        assert!(decoded.validate(creator, 4).is_ok());
+
+        data.content_flags = CONTENT_FLAGS_MASK;
+        assert!(data.validate(creator, 4).is_ok());
+    }
+
+    #[test]
+    fn test_post_creation_data_rejects_reserved_flag_bits() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 4);
+        data.content_flags = CONTENT_FLAG_NSFW | 0b1000_0000;
+        let err = data.validate(creator, 4).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidContentFlags.into());
+
+        data.content_flags = 1 << 3;
+        assert!(data.validate(creator, 4).is_err());
+    }
+
+    #[test]
+    fn test_post_creation_data_v2_with_flags_invalid() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 4);
+        data.version = 2;
+        data.content_flags = CONTENT_FLAG_SPOILER;
+        let err = data.validate(creator, 4).unwrap_err();
+        assert_eq!(err, ErrorCode::UnsupportedPostDataVersion.into());
+    }
+
+    // ============================================================================
+    // Language Tag Tests
+    // ============================================================================
+
+    #[test]
+    fn test_post_creation_data_valid_lang() {
+        use borsh::BorshSerialize;
+
+        let creator = Pubkey::new_unique();
+        for lang in ["en", "zh-CN", "pt-BR", "fil"] {
+            let mut data = create_valid_post_creation_data(creator, 5);
+            data.lang = lang.to_string();
+
+            let decoded = PostCreationData::try_from_slice(&data.try_to_vec().unwrap()).unwrap();
+            assert_eq!(decoded.lang, lang);
+            assert!(decoded.validate(creator, 5).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_post_creation_data_invalid_lang() {
+        let creator = Pubkey::new_unique();
+        for lang in ["en1", "zh-CN-x", "-en", "en-", "en_US", "abcdefghi"] {
+            let mut data = create_valid_post_creation_data(creator, 5);
+            data.lang = lang.to_string();
+            let err = data.validate(creator, 5).unwrap_err();
+            assert_eq!(err, ErrorCode::InvalidLanguageTag.into());
+        }
+    }
+
+    #[test]
+    fn test_post_creation_data_default_empty_lang() {
+        let creator = Pubkey::new_unique();
+        let data = create_valid_post_creation_data(creator, 5);
+        assert!(data.lang.is_empty());
+        assert!(data.validate(creator, 5).is_ok());
+
+        // A v3 payload cannot carry a language tag
+        let mut data = create_valid_post_creation_data(creator, 5);
+        data.version = 3;
+        data.lang = "en".to_string();
+        assert_eq!(data.validate(creator, 5).unwrap_err(), ErrorCode::UnsupportedPostDataVersion.into());
+    }
+
+    // ============================================================================
+    // Content Entropy Tests
+    // ============================================================================
+
+    #[test]
+    fn test_post_content_all_spaces_rejected() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 6);
+        data.content = " ".repeat(69);
+        let err = data.validate(creator, 6).unwrap_err();
+        assert_eq!(err, ErrorCode::LowEntropyContent.into());
+
+        // Mixed whitespace is just as empty
+        data.content = " \t\n ".to_string();
+        assert!(data.validate(creator, 6).is_err());
+    }
+
+    #[test]
+    fn test_post_content_normal_accepted() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 6);
+        data.content = "  Padded but real content  ".to_string();
+        assert!(data.validate(creator, 6).is_ok());
+
+        // A single character is short, not degenerate
+        data.content = "B".to_string();
+        assert!(data.validate(creator, 6).is_ok());
+    }
+
+    #[test]
+    fn test_post_content_repeated_char_rejected() {
+        let creator = Pubkey::new_unique();
+        let mut data = create_valid_post_creation_data(creator, 6);
+        data.content = "a".repeat(100);
+        let err = data.validate(creator, 6).unwrap_err();
+        assert_eq!(err, ErrorCode::LowEntropyContent.into());
+
+        data.content = "é".repeat(10);
+        assert!(data.validate(creator, 6).is_err());
+    }
+
+    // ============================================================================
+    // Burn Balance Check Tests
+    // ============================================================================
+
+    #[test]
+    fn test_burn_reflected_exact_amount() {
+        assert!(check_burn_reflected(5_000_000, 4_000_000, 1_000_000).is_ok());
+        assert!(check_burn_reflected(1_000_000, 0, 1_000_000).is_ok());
+    }
+
+    #[test]
+    fn test_burn_not_reflected_rejected() {
+        // A burn program that under-burns (or burns nothing) is rejected
+        let err = check_burn_reflected(5_000_000, 4_500_000, 1_000_000).unwrap_err();
+        assert_eq!(err, ErrorCode::BurnAmountNotReflected.into());
+        assert!(check_burn_reflected(5_000_000, 5_000_000, 1_000_000).is_err());
+
+        // So is a balance that grew or dropped by more than the amount
+        assert!(check_burn_reflected(5_000_000, 6_000_000, 1_000_000).is_err());
+        assert!(check_burn_reflected(5_000_000, 3_000_000, 1_000_000).is_err());
+    }
+
+    // ============================================================================
+    // Rate Limit Exemption Tests
+    // ============================================================================
+
+    fn create_exemption(wallet: Pubkey) -> RateLimitExemption {
+        RateLimitExemption {
+            wallet,
+            granted_at: 1_700_000_000,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_exemption_matches_signer_only() {
+        let bot = Pubkey::new_unique();
+        let exemption = create_exemption(bot);
+
+        assert!(RateLimitExemption::exempts(Some(&exemption), bot));
+        assert!(!RateLimitExemption::exempts(Some(&exemption), Pubkey::new_unique()));
+        assert!(!RateLimitExemption::exempts(None, bot));
+    }
+
+    #[test]
+    fn test_exempt_wallet_skips_anonymous_post_cooldown() {
+        let bot = Pubkey::new_unique();
+        let exempt = RateLimitExemption::exempts(Some(&create_exemption(bot)), bot);
+        let mut bot_cooldown = AnonymousPostCooldown { last_post_time: 0, bump: 255 };
+        let mut user_cooldown = AnonymousPostCooldown { last_post_time: 0, bump: 255 };
+
+        // The exempt wallet posts back-to-back
+        bot_cooldown.check_and_record(1_000, exempt).unwrap();
+        bot_cooldown.check_and_record(1_001, exempt).unwrap();
+        assert_eq!(bot_cooldown.last_post_time, 1_001);
+
+        // A normal wallet is throttled
+        user_cooldown.check_and_record(1_000, false).unwrap();
+        let err = user_cooldown.check_and_record(1_001, false).unwrap_err();
+        assert_eq!(err, ErrorCode::AnonymousPostTooFrequent.into());
+    }
+
+    #[test]
+    fn test_exempt_wallet_skips_mint_cooldown() {
+        let mut bot_cooldown = create_mint_cooldown();
+        let mut user_cooldown = create_mint_cooldown();
+
+        assert!(bot_cooldown.check_and_record(60, 1_000, true).is_ok());
+        assert!(bot_cooldown.check_and_record(60, 1_001, true).is_ok());
+
+        assert!(user_cooldown.check_and_record(60, 1_000, false).is_ok());
+        let err = user_cooldown.check_and_record(60, 1_001, false).unwrap_err();
+        assert_eq!(err, ErrorCode::MintTooFrequent.into());
+    }
+
+    // ============================================================================
+    // Free (Mint-only) Post Tests
+    // ============================================================================
+
+    /// Borsh+Base64 memo for create_post_free (burn_amount 0)
+    fn create_free_post_memo(creator: Pubkey, post_id: u64, burn_amount: u64) -> Vec<u8> {
+        use borsh::BorshSerialize;
+
+        let mut post_data = create_valid_post_creation_data(creator, post_id);
+        post_data.operation = EXPECTED_CREATE_POST_FREE_OPERATION.to_string();
+        let burn_memo = BurnMemo {
+            version: BURN_MEMO_VERSION,
+            burn_amount,
+            payload: post_data.try_to_vec().unwrap(),
+        };
+        general_purpose::STANDARD.encode(burn_memo.try_to_vec().unwrap()).into_bytes()
+    }
+
+    #[test]
+    fn test_free_post_memo_parses_with_zero_burn() {
+        let creator = Pubkey::new_unique();
+        let memo_data = create_free_post_memo(creator, 7, 0);
+
+        let post_data = parse_post_creation_borsh_memo(&memo_data, creator, 7, 0, EXPECTED_CREATE_POST_FREE_OPERATION).unwrap();
+        assert_eq!(post_data.operation, EXPECTED_CREATE_POST_FREE_OPERATION);
+        assert_eq!(post_data.post_id, 7);
+    }
+
+    #[test]
+    fn test_free_post_memo_rejects_burn_amount() {
+        let creator = Pubkey::new_unique();
+        let memo_data = create_free_post_memo(creator, 7, MIN_POST_BURN_AMOUNT);
+
+        let err = parse_post_creation_borsh_memo(&memo_data, creator, 7, 0, EXPECTED_CREATE_POST_FREE_OPERATION).err().unwrap();
+        assert_eq!(err, ErrorCode::BurnAmountMismatch.into());
+    }
+
+    #[test]
+    fn test_free_and_paid_operations_are_not_interchangeable() {
+        let creator = Pubkey::new_unique();
+
+        // A free memo cannot create a paid post...
+        let mut free_data = create_valid_post_creation_data(creator, 1);
+        free_data.operation = EXPECTED_CREATE_POST_FREE_OPERATION.to_string();
+        assert_eq!(free_data.validate(creator, 1).unwrap_err(), ErrorCode::InvalidOperation.into());
+        assert!(free_data.validate_for_operation(creator, 1, EXPECTED_CREATE_POST_FREE_OPERATION).is_ok());
+
+        // ...and a paid memo cannot create a free post
+        let paid_data = create_valid_post_creation_data(creator, 1);
+        let err = paid_data.validate_for_operation(creator, 1, EXPECTED_CREATE_POST_FREE_OPERATION).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidOperation.into());
+    }
+
+    // ============================================================================
+    // Content-Length Burn Tests
+    // ============================================================================
+
+    #[test]
+    fn test_short_post_requires_base_burn() {
+        let creator = Pubkey::new_unique();
+        let post_data = create_valid_post_creation_data(creator, 1);
+        assert!(post_data.content.len() < CONTENT_LENGTH_BURN_STEP);
+
+        assert_eq!(min_burn_for_content_length(post_data.content.len()), MIN_POST_BURN_AMOUNT);
+        assert!(check_burn_for_content_length(MIN_POST_BURN_AMOUNT, post_data.content.len(), true).is_ok());
+    }
+
+    #[test]
+    fn test_long_post_requires_higher_burn() {
+        let content_len = 500;
+        let required = MIN_POST_BURN_AMOUNT + 5 * DECIMAL_FACTOR;
+        assert_eq!(min_burn_for_content_length(content_len), required);
+
+        let err = check_burn_for_content_length(MIN_POST_BURN_AMOUNT, content_len, true).unwrap_err();
+        assert_eq!(err, ErrorCode::InsufficientBurnForContentLength.into());
+        let err = check_burn_for_content_length(required - DECIMAL_FACTOR, content_len, true).unwrap_err();
+        assert_eq!(err, ErrorCode::InsufficientBurnForContentLength.into());
+        assert!(check_burn_for_content_length(required, content_len, true).is_ok());
+    }
+
+    #[test]
+    fn test_content_length_burn_disabled_accepts_base_burn() {
+        assert!(check_burn_for_content_length(MIN_POST_BURN_AMOUNT, MAX_POST_CONTENT_LENGTH, false).is_ok());
+    }
+
+    // ============================================================================
+    // Burn Stats Account Verification Tests
+    // ============================================================================
+
+    /// Run verify_burn_stats_account against an account with the given owner and data
+    fn verify_burn_stats_with(owner: Pubkey, mut data: Vec<u8>) -> Result<()> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 1_000_000u64;
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        verify_burn_stats_account(&account)
+    }
+
+    fn burn_stats_data() -> Vec<u8> {
+        let mut data = memo_burn::UserGlobalBurnStats::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(&[0u8; 64]);
+        data
+    }
+
+    #[test]
+    fn test_verify_burn_stats_account_accepts_memo_burn_account() {
+        assert!(verify_burn_stats_with(memo_burn::ID, burn_stats_data()).is_ok());
+    }
+
+    #[test]
+    fn test_verify_burn_stats_account_rejects_wrong_owner() {
+        let err = verify_burn_stats_with(Pubkey::new_unique(), burn_stats_data()).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBurnStatsAccount.into());
+    }
+
+    #[test]
+    fn test_verify_burn_stats_account_rejects_wrong_discriminator() {
+        let err = verify_burn_stats_with(memo_burn::ID, vec![0u8; 72]).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBurnStatsAccount.into());
+
+        let err = verify_burn_stats_with(memo_burn::ID, Vec::new()).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidBurnStatsAccount.into());
+    }
+
+    // ============================================================================
+    // Mint Decimals Tests
+    // ============================================================================
+
+    #[test]
+    fn test_six_decimal_mint_accepted() {
+        assert!(mint_decimals_valid(6));
+        assert_eq!(10u64.pow(MINT_DECIMALS as u32), DECIMAL_FACTOR);
+    }
+
+    #[test]
+    fn test_nine_decimal_mint_rejected() {
+        assert!(!mint_decimals_valid(9));
+        assert!(!mint_decimals_valid(0));
+    }
+
+    // ============================================================================
+    // Quick Edit Tests
+    // ============================================================================
+
+    fn create_post_update_memo(user: Pubkey, post_id: u64, content: &str) -> Vec<u8> {
+        use borsh::BorshSerialize;
+
+        let update_data = PostUpdateData {
+            version: POST_UPDATE_DATA_VERSION,
+            category: EXPECTED_CATEGORY.to_string(),
+            operation: EXPECTED_QUICK_EDIT_POST_OPERATION.to_string(),
+            user: user.to_string(),
+            post_id,
+            content: content.to_string(),
+        };
+
+        let burn_memo = BurnMemo {
+            version: BURN_MEMO_VERSION,
+            burn_amount: 0,
+            payload: update_data.try_to_vec().unwrap(),
+        };
+
+        general_purpose::STANDARD.encode(burn_memo.try_to_vec().unwrap()).into_bytes()
+    }
+
+    #[test]
+    fn test_quick_edit_within_window_is_free() {
+        let created_at = 1_700_000_000;
+        let post = create_post(created_at, 0);
+        let memo = create_post_update_memo(post.creator, post.post_id, "Content, typo fixed");
+
+        let update_data = parse_post_update_borsh_memo(&memo, post.creator, post.post_id).unwrap();
+        assert_eq!(update_data.content, "Content, typo fixed");
+
+        assert!(post.check_quick_edit(&update_data.content, created_at, false).is_ok());
+        assert!(post.check_quick_edit(&update_data.content, created_at + EDIT_GRACE_SECONDS, false).is_ok());
+    }
+
+    #[test]
+    fn test_quick_edit_after_window_rejected() {
+        let created_at = 1_700_000_000;
+        let post = create_post(created_at, 0);
+
+        let err = post.check_quick_edit("Late edit", created_at + EDIT_GRACE_SECONDS + 1, false).unwrap_err();
+        assert_eq!(err, ErrorCode::EditWindowClosed.into());
+    }
+
+    #[test]
+    fn test_quick_edit_rejects_burn_and_wrong_post() {
+        let post = create_post(1_700_000_000, 0);
+
+        let err = parse_post_update_borsh_memo(
+            &create_post_update_memo(post.creator, post.post_id, "Edit"),
+            post.creator,
+            post.post_id + 1,
+        ).err().unwrap();
+        assert_eq!(err, ErrorCode::PostIdMismatch.into());
+
+        let mut memo = create_post_update_memo(post.creator, post.post_id, "Edit");
+        let mut burn_memo = BurnMemo::try_from_slice(&general_purpose::STANDARD.decode(&memo).unwrap()).unwrap();
+        burn_memo.burn_amount = DECIMAL_FACTOR;
+        memo = general_purpose::STANDARD.encode(borsh::to_vec(&burn_memo).unwrap()).into_bytes();
+        let err = parse_post_update_borsh_memo(&memo, post.creator, post.post_id).err().unwrap();
+        assert_eq!(err, ErrorCode::BurnAmountMismatch.into());
+    }
+
+    #[test]
+    fn test_quick_edit_cannot_skip_content_length_burn() {
+        let created_at = 1_700_000_000;
+        let post = create_post(created_at, 0);
+        let longer = "x".repeat(CONTENT_LENGTH_BURN_STEP + 1);
+
+        assert!(post.check_quick_edit(&longer, created_at, false).is_ok());
+        let err = post.check_quick_edit(&longer, created_at, true).unwrap_err();
+        assert_eq!(err, ErrorCode::InsufficientBurnForContentLength.into());
+        assert!(post.check_quick_edit("Shorter", created_at, true).is_ok());
+    }
+
+    // ============================================================================
+    // Reply Holding Gate Tests
+    // ============================================================================
+
+    #[test]
+    fn test_holder_passes_min_holding() {
+        let mut post = create_post(0, 0);
+        post.min_holding = 100 * DECIMAL_FACTOR;
+
+        assert!(post.check_holding(100 * DECIMAL_FACTOR).is_ok());
+        assert!(post.check_holding(5_000 * DECIMAL_FACTOR).is_ok());
+    }
+
+    #[test]
+    fn test_non_holder_rejected_by_min_holding() {
+        let mut post = create_post(0, 0);
+        post.min_holding = 100 * DECIMAL_FACTOR;
+
+        let err = post.check_holding(0).unwrap_err();
+        assert_eq!(err, ErrorCode::InsufficientHoldings.into());
+        let err = post.check_holding(100 * DECIMAL_FACTOR - 1).unwrap_err();
+        assert_eq!(err, ErrorCode::InsufficientHoldings.into());
+    }
+
+    #[test]
+    fn test_zero_min_holding_allows_anyone() {
+        assert!(create_post(0, 0).check_holding(0).is_ok());
+    }
+
+    // ============================================================================
+    // Burn Receipt Tests
+    // ============================================================================
+
+    fn empty_receipt() -> BurnReceipt {
+        BurnReceipt {
+            burner: Pubkey::default(),
+            amount: 0,
+            target_id: 0,
+            timestamp: 0,
+            program_kind: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_burn_receipt_records_fields() {
+        let burner = Pubkey::new_unique();
+        let (_, bump) = BurnReceipt::find_address(&burner, 7);
+        let mut receipt = empty_receipt();
+
+        receipt.record(burner, 42 * DECIMAL_FACTOR, 12, 1_700_000_000, bump).unwrap();
+
+        assert_eq!(receipt.burner, burner);
+        assert_eq!(receipt.amount, 42 * DECIMAL_FACTOR);
+        assert_eq!(receipt.target_id, 12);
+        assert_eq!(receipt.timestamp, 1_700_000_000);
+        assert_eq!(receipt.program_kind, 2);
+        assert_eq!(receipt.bump, bump);
+        assert_eq!(8 + receipt.try_to_vec().unwrap().len(), BurnReceipt::SPACE);
+    }
+
+    #[test]
+    fn test_burn_receipt_duplicate_nonce_rejected() {
+        let burner = Pubkey::new_unique();
+        let mut receipt = empty_receipt();
+        receipt.record(burner, DECIMAL_FACTOR, 1, 1_700_000_000, 255).unwrap();
+
+        // Same nonce maps to the same (already written) receipt account
+        let err = receipt.record(burner, 2 * DECIMAL_FACTOR, 2, 1_700_000_100, 255).unwrap_err();
+        assert_eq!(err, ErrorCode::DuplicateReceiptNonce.into());
+        assert_eq!(receipt.amount, DECIMAL_FACTOR);
+        assert_eq!(receipt.target_id, 1);
+    }
+
+    #[test]
+    fn test_burn_receipt_address_per_nonce() {
+        let burner = Pubkey::new_unique();
+        assert_eq!(BurnReceipt::find_address(&burner, 1), BurnReceipt::find_address(&burner, 1));
+        assert_ne!(BurnReceipt::find_address(&burner, 1).0, BurnReceipt::find_address(&burner, 2).0);
+        assert_ne!(BurnReceipt::find_address(&burner, 1).0, BurnReceipt::find_address(&Pubkey::new_unique(), 1).0);
+    }
+
+    // ============================================================================
+    // Tip And Reply Tests
+    // ============================================================================
+
+    #[test]
+    fn test_tip_amount_math() {
+        let burn = 10 * DECIMAL_FACTOR;
+        assert_eq!(calculate_tip_amount(burn, 0, u64::MAX).unwrap(), 0);
+        assert_eq!(calculate_tip_amount(burn, 1, u64::MAX).unwrap(), burn / 10_000);
+        assert_eq!(calculate_tip_amount(burn, 500, u64::MAX).unwrap(), burn / 20); // 5%
+        assert_eq!(calculate_tip_amount(burn, MAX_TIP_BPS, u64::MAX).unwrap(), burn / 5); // 20%
+        // No overflow at the largest allowed burn
+        assert_eq!(calculate_tip_amount(MAX_BURN_PER_TX, MAX_TIP_BPS, u64::MAX).unwrap(), MAX_BURN_PER_TX / 5);
+    }
+
+    #[test]
+    fn test_tip_above_mint_cap_rejected() {
+        let burn = 10 * DECIMAL_FACTOR;
+        // A 20% tip of 10 tokens is 2 tokens; exactly at the cap is fine
+        assert_eq!(calculate_tip_amount(burn, MAX_TIP_BPS, 2 * DECIMAL_FACTOR).unwrap(), 2 * DECIMAL_FACTOR);
+        // Above what memo-mint will mint for the memo it fails instead of tipping less
+        let result = calculate_tip_amount(burn, MAX_TIP_BPS, DECIMAL_FACTOR);
+        assert_eq!(result.unwrap_err(), ErrorCode::TipExceedsMintCap.into());
+        // A zero tip never hits the cap
+        assert_eq!(calculate_tip_amount(burn, 0, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_tip_bps_cap() {
+        let burn = 10 * DECIMAL_FACTOR;
+        assert!(calculate_tip_amount(burn, MAX_TIP_BPS, u64::MAX).is_ok());
+        let result = calculate_tip_amount(burn, MAX_TIP_BPS + 1, u64::MAX);
+        assert_eq!(result.unwrap_err(), ErrorCode::TipBpsTooHigh.into());
+        let result = calculate_tip_amount(burn, u16::MAX, u64::MAX);
+        assert_eq!(result.unwrap_err(), ErrorCode::TipBpsTooHigh.into());
+    }
+
+    #[test]
+    fn test_tip_credits_post_creator() {
+        let post = create_post(1_700_000_000, 0);
+        assert_eq!(post.tip_recipient().unwrap(), post.creator);
+
+        // Anonymous posts are created by the shared anonymous PDA, not a tippable wallet
+        let mut anonymous = create_post(1_700_000_000, 0);
+        anonymous.creator = anonymous_author();
+        anonymous.author_commitment = [7u8; 32];
+        assert_eq!(anonymous.tip_recipient().unwrap_err(), ErrorCode::CannotTipAnonymousPost.into());
+    }
+
+    #[test]
+    fn test_program_may_request_mint_amount() {
+        // process_mint_amount_to only accepts CPIs from memo-mint's caller allowlist
+        assert!(memo_mint::MINT_AMOUNT_CALLER_PROGRAMS.contains(&crate::ID));
+    }
 }
@@ -4,6 +4,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use anchor_spl::token_2022::{self, Token2022};
+use memo_burn::program::MemoBurn;
 use anchor_lang::solana_program::sysvar::instructions::{ID as INSTRUCTIONS_ID};
 use spl_memo::ID as MEMO_PROGRAM_ID;
 
@@ -21,6 +22,26 @@ pub const AUTHORIZED_MINT_PUBKEY: Pubkey = pubkey!("memoX1sJsBY6od7CfQ58XooRALwn
 #[cfg(not(feature = "mainnet"))]
 pub const AUTHORIZED_MINT_PUBKEY: Pubkey = pubkey!("HLCoc7wNDavNMfWWw2Bwd7U7A24cesuhBSNkxZgvZm1");
 
+// Authorized admin key - different for testnet and mainnet
+#[cfg(feature = "mainnet")]
+pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("FVvewrVHqg2TPWXkesc3CJ7xxWnPtAkzN9nCpvr6UCtQ");
+
+#[cfg(not(feature = "mainnet"))]
+pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("Gkxz6ogojD7Ni58N4SnJXy6xDxSvH5kPFCz92sTZWBVn");
+
+// Programs allowed to call process_mint_amount / process_mint_amount_to (memo-chat, memo-forum)
+#[cfg(feature = "mainnet")]
+pub const MINT_AMOUNT_CALLER_PROGRAMS: [Pubkey; 2] = [
+    pubkey!("Hni4qE8GGW5uwBWzUEkpPBDRwXvKCWhM96teieAReRyd"), // memo-chat
+    pubkey!("6gzhG5BveTkJfTi466toX4qmN3BtU9qp1Grnk61GvmXD"), // memo-forum
+];
+
+#[cfg(not(feature = "mainnet"))]
+pub const MINT_AMOUNT_CALLER_PROGRAMS: [Pubkey; 2] = [
+    pubkey!("54ky4LNnRsbYioDSBKNrc5hG8HoDyZ6yhf8TuncxTBRF"), // memo-chat
+    pubkey!("9kwS5nSidmoHq84TyNzqFrtD29odp4sdRxm97tCbdpbS"), // memo-forum
+];
+
 // compile-time constant safety validation
 const _: () = {
     // ensure max supply calculation won't overflow
@@ -44,6 +65,7 @@ pub const MEMO_MAX_LENGTH: usize = 800;
 
 // Token decimal factor (decimal=6 means 1 token = 1,000,000 units)
 pub const DECIMAL_FACTOR: u64 = 1_000_000;
+pub const MINT_DECIMALS: u8 = 6; // Decimals the authorized mint must have (DECIMAL_FACTOR = 10^MINT_DECIMALS)
 
 // Maximum supply cap (10 trillion tokens)
 pub const MAX_SUPPLY_TOKENS: u64 = 10_000_000_000_000;
@@ -64,6 +86,18 @@ pub const TIER_4_MINT_AMOUNT: u64 = DECIMAL_FACTOR / 1_000;  // 0.001 token
 pub const TIER_5_MINT_AMOUNT: u64 = DECIMAL_FACTOR / 10_000; // 0.0001 token
 pub const TIER_6_MINT_AMOUNT: u64 = 1;                       // 0.000001 token (1 lamport)
 
+// Length buckets for process_mint_amount: (max length in bytes, percent of the tier amount).
+// Longer messages may mint slightly more, capped at 200% of the tier amount.
+pub const MINT_LENGTH_BUCKETS: [(usize, u64); 4] = [
+    (100, 100),
+    (200, 125),
+    (400, 150),
+    (usize::MAX, 200),
+];
+
+// Burn-proof airdrop: share of a user's cumulative memo-burn total minted back once (100 bps = 1%)
+pub const AIRDROP_RATE_BPS: u64 = 100;
+
 #[program]
 pub mod memo_mint {
     use super::*;
@@ -80,6 +114,25 @@ pub mod memo_mint {
             &ctx.accounts.token_program,
             ctx.program_id,
             ctx.bumps.mint_authority,
+            None,
+        )
+    }
+
+    /// Process token minting with a caller-computed amount
+    /// The amount may not exceed the tier amount scaled by the memo length
+    /// (see MINT_LENGTH_BUCKETS); mints to the caller's own token account.
+    /// Only callable via CPI from MINT_AMOUNT_CALLER_PROGRAMS.
+    pub fn process_mint_amount(ctx: Context<ProcessMint>, amount: u64) -> Result<()> {
+        check_mint_amount_caller(&ctx.accounts.instructions)?;
+        execute_mint_operation(
+            &ctx.accounts.instructions,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
+            &ctx.accounts.token_account,
+            &ctx.accounts.token_program,
+            ctx.program_id,
+            ctx.bumps.mint_authority,
+            Some(amount),
         )
     }
 
@@ -95,11 +148,73 @@ pub mod memo_mint {
             &ctx.accounts.token_program,
             ctx.program_id,
             ctx.bumps.mint_authority,
+            None,
         )
     }
+
+    /// Process token minting with a caller-computed amount (bounded like process_mint_amount)
+    /// Mints to a specified recipient's token account (e.g. memo-forum reply tips).
+    /// Only callable via CPI from MINT_AMOUNT_CALLER_PROGRAMS.
+    pub fn process_mint_amount_to(ctx: Context<ProcessMintTo>, _recipient: Pubkey, amount: u64) -> Result<()> {
+        check_mint_amount_caller(&ctx.accounts.instructions)?;
+        execute_mint_operation(
+            &ctx.accounts.instructions,
+            &ctx.accounts.mint,
+            &ctx.accounts.mint_authority,
+            &ctx.accounts.recipient_token_account,
+            &ctx.accounts.token_program,
+            ctx.program_id,
+            ctx.bumps.mint_authority,
+            Some(amount),
+        )
+    }
+
+    /// Enable or disable burn-proof airdrop claims (admin only)
+    pub fn set_airdrop_enabled(ctx: Context<SetAirdropEnabled>, enabled: bool) -> Result<()> {
+        let config = &mut ctx.accounts.airdrop_config;
+        config.airdrop_enabled = enabled;
+        config.bump = ctx.bumps.airdrop_config;
+
+        msg!("Burn airdrop {} by admin {}", if enabled { "enabled" } else { "disabled" }, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Claim a one-time airdrop of AIRDROP_RATE_BPS of the caller's cumulative
+    /// memo-burn total, minted to the caller's own token account
+    pub fn claim_burn_airdrop(ctx: Context<ClaimBurnAirdrop>) -> Result<()> {
+        ctx.accounts.airdrop_config.check_enabled()?;
+
+        let total_burned = ctx.accounts.user_global_burn_stats.total_burned;
+        let current_supply = ctx.accounts.mint.supply;
+        let amount = calculate_airdrop_amount(total_burned, current_supply)?;
+
+        // Mark the claim before minting so a second claim fails even within the same transaction
+        let claim = &mut ctx.accounts.airdrop_claim;
+        claim.claim(ctx.accounts.user.key(), amount, Clock::get()?.unix_timestamp)?;
+        claim.bump = ctx.bumps.airdrop_claim;
+
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::MintTo {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.token_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                &[&[b"mint_authority".as_ref(), &[ctx.bumps.mint_authority]]]
+            ),
+            amount
+        )?;
+
+        msg!("Burn airdrop of {} tokens ({} units) claimed by {} for {} units burned",
+             calculate_token_count_safe(amount)?, amount, ctx.accounts.user.key(), total_burned);
+        Ok(())
+    }
 }
 
 /// Shared mint operation logic
+/// (`requested_amount` is Some for process_mint_amount and process_mint_amount_to, None mints the tier amount)
+#[allow(clippy::too_many_arguments)]
 fn execute_mint_operation<'info>(
     instructions: &AccountInfo<'info>,
     mint: &InterfaceAccount<'info, Mint>,
@@ -108,6 +223,7 @@ fn execute_mint_operation<'info>(
     token_program: &Program<'info, Token2022>,
     program_id: &Pubkey,
     mint_authority_bump: u8,
+    requested_amount: Option<u64>,
 ) -> Result<()> {
     // Check for memo instruction with length constraints
     let (memo_found, memo_data) = check_memo_instruction(instructions)?;
@@ -132,7 +248,7 @@ fn execute_mint_operation<'info>(
     
     // Get current supply and calculate dynamic mint amount
     let current_supply = mint.supply;
-    let amount = calculate_dynamic_mint_amount(current_supply)?;
+    let amount = resolve_mint_amount(current_supply, requested_amount, memo_data.len())?;
     
     // Execute token mint operation
     token_2022::mint_to(
@@ -158,6 +274,46 @@ fn execute_mint_operation<'info>(
     Ok(())
 }
 
+/// The caller-computed amount instructions must be reached by CPI from an allowlisted program:
+/// the currently executing top-level instruction (per the instructions sysvar) must belong to it
+fn check_mint_amount_caller(instructions: &AccountInfo) -> Result<()> {
+    let current_index = anchor_lang::solana_program::sysvar::instructions::load_current_index_checked(instructions)?;
+    let current = anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(current_index as usize, instructions)?;
+    check_caller_program(&current.program_id)
+}
+
+/// Check a calling program against MINT_AMOUNT_CALLER_PROGRAMS
+fn check_caller_program(program_id: &Pubkey) -> Result<()> {
+    if !MINT_AMOUNT_CALLER_PROGRAMS.contains(program_id) {
+        msg!("Program {} may not request a mint amount", program_id);
+        return Err(ErrorCode::UnauthorizedMintCaller.into());
+    }
+    Ok(())
+}
+
+/// Airdrop owed for a cumulative burn total (AIRDROP_RATE_BPS of it), bounded by the supply cap
+pub fn calculate_airdrop_amount(total_burned: u64, current_supply: u64) -> Result<u64> {
+    // At most total_burned, so the narrowing back to u64 cannot truncate
+    let amount = (u128::from(total_burned) * u128::from(AIRDROP_RATE_BPS) / 10_000) as u64;
+    if amount == 0 {
+        msg!("Nothing to claim: {} units burned", total_burned);
+        return Err(ErrorCode::AirdropAmountZero.into());
+    }
+
+    let new_supply = current_supply.checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    if new_supply > MAX_SUPPLY_LAMPORTS {
+        return Err(ErrorCode::SupplyLimitReached.into());
+    }
+
+    Ok(amount)
+}
+
+/// Whether a mint has the decimals DECIMAL_FACTOR assumes (guards against a recreated mint)
+pub fn mint_decimals_valid(decimals: u8) -> bool {
+    decimals == MINT_DECIMALS
+}
+
 /// Check for memo instruction at REQUIRED index 0
 /// 
 /// IMPORTANT: This contract enforces memo at index 0:
@@ -174,7 +330,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     // to leave index 0 available for memo
     if current_index < 1 {
         msg!("process_mint must be at index 1 or later, but current instruction is at index {}", current_index);
-        return Ok((false, vec![]));
+        return memo_not_at_index_zero(instructions);
     }
     
     // Check that index 0 contains the memo instruction
@@ -185,7 +341,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
                 validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
             } else {
                 msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
-                Ok((false, vec![]))
+                memo_not_at_index_zero(instructions)
             }
         },
         Err(e) => {
@@ -194,6 +350,28 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
         }
     }
 }
+
+/// Called when index 0 holds no memo: if a memo exists elsewhere in the transaction,
+/// fail with MemoWrongIndex instead of letting the caller report MemoRequired
+fn memo_not_at_index_zero(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    let program_ids: Vec<Pubkey> = (0..)
+        .map_while(|i| anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(i, instructions).ok())
+        .map(|ix| ix.program_id)
+        .collect();
+    check_memo_placement(&program_ids)?;
+    Ok((false, vec![]))
+}
+
+/// Reject a transaction whose SPL Memo instruction is present but not at index 0
+fn check_memo_placement(program_ids: &[Pubkey]) -> Result<()> {
+    if let Some(index) = program_ids.iter().position(|id| *id == MEMO_PROGRAM_ID) {
+        if index > 0 {
+            msg!("Memo instruction found at index {}, but it must be placed at index 0", index);
+            return Err(ErrorCode::MemoWrongIndex.into());
+        }
+    }
+    Ok(())
+}
 /// Validate memo data length and return result
 fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize) -> Result<(bool, Vec<u8>)> {
     let memo_length = memo_data.len();
@@ -221,8 +399,41 @@ fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize)
     Ok((true, memo_data.to_vec()))
 }
 
+/// Scale a tier mint amount by message length (see MINT_LENGTH_BUCKETS)
+pub fn length_scaled_mint_amount(base_amount: u64, length: usize) -> u64 {
+    let percent = MINT_LENGTH_BUCKETS
+        .iter()
+        .find(|(max_length, _)| length <= *max_length)
+        .map(|(_, percent)| *percent)
+        .unwrap_or(100);
+    base_amount.saturating_mul(percent) / 100
+}
+
+/// Pick the amount to mint: the tier amount, or a requested amount bounded by
+/// the length-scaled tier amount for this memo
+fn resolve_mint_amount(current_supply: u64, requested_amount: Option<u64>, memo_length: usize) -> Result<u64> {
+    let base_amount = calculate_dynamic_mint_amount(current_supply)?;
+    let Some(amount) = requested_amount else {
+        return Ok(base_amount);
+    };
+
+    let max_amount = length_scaled_mint_amount(base_amount, memo_length);
+    if amount == 0 || amount > max_amount {
+        msg!("Invalid mint amount: {} (allowed: 1-{} for a {} byte memo)", amount, max_amount, memo_length);
+        return Err(ErrorCode::InvalidMintAmount.into());
+    }
+
+    let new_supply = current_supply.checked_add(amount)
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    if new_supply > MAX_SUPPLY_LAMPORTS {
+        return Err(ErrorCode::SupplyLimitReached.into());
+    }
+
+    Ok(amount)
+}
+
 /// Calculate dynamic mint amount based on current supply with hard cap
-fn calculate_dynamic_mint_amount(current_supply: u64) -> Result<u64> {
+pub fn calculate_dynamic_mint_amount(current_supply: u64) -> Result<u64> {
     // Check hard limit first
     if current_supply >= MAX_SUPPLY_LAMPORTS {
         return Err(ErrorCode::SupplyLimitReached.into());
@@ -276,7 +487,8 @@ pub struct ProcessMint<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -310,7 +522,8 @@ pub struct ProcessMintTo<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -335,6 +548,138 @@ pub struct ProcessMintTo<'info> {
     pub instructions: AccountInfo<'info>,
 }
 
+/// Global switch for burn-proof airdrop claims (singleton PDA, set by admin)
+#[account]
+pub struct AirdropConfig {
+    pub airdrop_enabled: bool,  // Claims are rejected while false
+    pub bump: u8,               // PDA bump
+}
+
+impl AirdropConfig {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // airdrop_enabled
+        1;  // bump
+
+    pub fn check_enabled(&self) -> Result<()> {
+        if !self.airdrop_enabled {
+            msg!("Burn airdrop is disabled");
+            return Err(ErrorCode::AirdropDisabled.into());
+        }
+        Ok(())
+    }
+}
+
+/// Per-user record of the one-time burn-proof airdrop
+#[account]
+pub struct AirdropClaim {
+    pub user: Pubkey,           // Claimant
+    pub airdrop_claimed: bool,  // Set on the first (and only) claim
+    pub amount: u64,            // Units minted by the claim
+    pub claimed_at: i64,        // Claim timestamp
+    pub bump: u8,               // PDA bump
+}
+
+impl AirdropClaim {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // user
+        1 +  // airdrop_claimed
+        8 +  // amount
+        8 +  // claimed_at
+        1;   // bump
+
+    /// Record the claim, rejecting a user who has already claimed
+    pub fn claim(&mut self, user: Pubkey, amount: u64, timestamp: i64) -> Result<()> {
+        if self.airdrop_claimed {
+            msg!("Burn airdrop already claimed by {} at {}", self.user, self.claimed_at);
+            return Err(ErrorCode::AirdropAlreadyClaimed.into());
+        }
+        self.user = user;
+        self.airdrop_claimed = true;
+        self.amount = amount;
+        self.claimed_at = timestamp;
+        Ok(())
+    }
+}
+
+/// Account structure for toggling the burn airdrop (admin only)
+#[derive(Accounts)]
+pub struct SetAirdropEnabled<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AirdropConfig::SPACE,
+        seeds = [b"airdrop_config"],
+        bump
+    )]
+    pub airdrop_config: Account<'info, AirdropConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for claiming the burn-proof airdrop
+#[derive(Accounts)]
+pub struct ClaimBurnAirdrop<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: PDA serving as mint authority
+    #[account(
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = token_account.owner == user.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The user's cumulative burn total, as tracked by memo-burn
+    #[account(
+        seeds = [b"user_global_burn_stats", user.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+
+    #[account(
+        seeds = [b"airdrop_config"],
+        bump = airdrop_config.bump
+    )]
+    pub airdrop_config: Account<'info, AirdropConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = AirdropClaim::SPACE,
+        seeds = [b"airdrop_claim", user.key().as_ref()],
+        bump
+    )]
+    pub airdrop_claim: Account<'info, AirdropClaim>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    /// The memo-burn program owning user_global_burn_stats
+    pub memo_burn_program: Program<'info, MemoBurn>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// Error code definitions
 #[error_code]
 pub enum ErrorCode {
@@ -367,6 +712,30 @@ pub enum ErrorCode {
 
     #[msg("Arithmetic overflow detected.")]
     ArithmeticOverflow,
+
+    #[msg("Memo at wrong index: The SPL Memo instruction must be placed at index 0 of the transaction.")]
+    MemoWrongIndex,
+
+    #[msg("Invalid mint amount: Requested amount must be between 1 unit and the length-scaled tier amount.")]
+    InvalidMintAmount,
+
+    #[msg("Unexpected mint decimals: The authorized mint must have 6 decimals.")]
+    UnexpectedMintDecimals,
+
+    #[msg("Unauthorized: Only the admin can perform this action.")]
+    UnauthorizedAdmin,
+
+    #[msg("Burn airdrop is disabled.")]
+    AirdropDisabled,
+
+    #[msg("Burn airdrop already claimed: Each user can claim once.")]
+    AirdropAlreadyClaimed,
+
+    #[msg("Nothing to claim: Burn total is too small for a non-zero airdrop.")]
+    AirdropAmountZero,
+
+    #[msg("Unauthorized caller: Only allowlisted programs may request a mint amount.")]
+    UnauthorizedMintCaller,
 }
 
 // Unit tests in separate file
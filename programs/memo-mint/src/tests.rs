@@ -4,6 +4,9 @@
 //! - calculate_dynamic_mint_amount: Dynamic tier-based minting logic
 //! - validate_memo_length: Memo validation with length constraints
 //! - calculate_token_count_safe: Safe floating-point token display calculations
+//! - resolve_mint_amount: Length-scaled amounts for process_mint_amount
+//! - calculate_airdrop_amount / AirdropClaim: Burn-proof airdrop claims
+//! - check_caller_program: Caller allowlist for process_mint_amount(_to)
 
 use super::*;
 
@@ -1028,6 +1031,191 @@ mod integration_tests {
     }
 }
 
+// ============================================================================
+// Tests for check_memo_placement()
+// ============================================================================
+
+#[cfg(test)]
+mod memo_placement_tests {
+    use super::*;
+
+    #[test]
+    fn test_memo_placement_no_memo_anywhere() {
+        // No memo at all: placement check passes so the caller reports MemoRequired
+        assert!(check_memo_placement(&[]).is_ok());
+        assert!(check_memo_placement(&[Pubkey::new_unique(), crate::ID]).is_ok());
+    }
+
+    #[test]
+    fn test_memo_placement_memo_at_wrong_index() {
+        let program_ids = [Pubkey::new_unique(), crate::ID, MEMO_PROGRAM_ID];
+        let err = check_memo_placement(&program_ids).unwrap_err();
+        assert_eq!(err, ErrorCode::MemoWrongIndex.into());
+
+        // A memo at index 0 is the valid layout
+        assert!(check_memo_placement(&[MEMO_PROGRAM_ID, crate::ID]).is_ok());
+    }
+}
+
+// ============================================================================
+// Tests for length_scaled_mint_amount() and resolve_mint_amount()
+// ============================================================================
+
+#[cfg(test)]
+mod resolve_mint_amount_tests {
+    use super::*;
+
+    #[test]
+    fn test_length_buckets_scale_tier_amount() {
+        assert_eq!(length_scaled_mint_amount(TIER_1_MINT_AMOUNT, 0), TIER_1_MINT_AMOUNT);
+        assert_eq!(length_scaled_mint_amount(TIER_1_MINT_AMOUNT, 100), TIER_1_MINT_AMOUNT);
+        assert_eq!(length_scaled_mint_amount(TIER_1_MINT_AMOUNT, 101), TIER_1_MINT_AMOUNT * 125 / 100);
+        assert_eq!(length_scaled_mint_amount(TIER_1_MINT_AMOUNT, 400), TIER_1_MINT_AMOUNT * 150 / 100);
+        assert_eq!(length_scaled_mint_amount(TIER_1_MINT_AMOUNT, MEMO_MAX_LENGTH), TIER_1_MINT_AMOUNT * 2);
+    }
+
+    #[test]
+    fn test_default_mint_uses_tier_amount() {
+        assert_eq!(resolve_mint_amount(0, None, MEMO_MAX_LENGTH).unwrap(), TIER_1_MINT_AMOUNT);
+    }
+
+    #[test]
+    fn test_requested_amount_within_length_cap() {
+        let max = length_scaled_mint_amount(TIER_1_MINT_AMOUNT, 300);
+        assert_eq!(resolve_mint_amount(0, Some(max), 300).unwrap(), max);
+        assert_eq!(resolve_mint_amount(0, Some(1), 300).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_requested_amount_above_length_cap_rejected() {
+        // A short memo cannot claim the long-message amount
+        let long_amount = length_scaled_mint_amount(TIER_1_MINT_AMOUNT, MEMO_MAX_LENGTH);
+        let err = resolve_mint_amount(0, Some(long_amount), MEMO_MIN_LENGTH).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidMintAmount.into());
+
+        let err = resolve_mint_amount(0, Some(0), MEMO_MAX_LENGTH).unwrap_err();
+        assert_eq!(err, ErrorCode::InvalidMintAmount.into());
+    }
+
+    #[test]
+    fn test_requested_amount_respects_supply_cap() {
+        let supply = MAX_SUPPLY_LAMPORTS - TIER_6_MINT_AMOUNT;
+        let err = resolve_mint_amount(supply, Some(2 * TIER_6_MINT_AMOUNT), MEMO_MAX_LENGTH).unwrap_err();
+        assert_eq!(err, ErrorCode::SupplyLimitReached.into());
+    }
+}
+
+// ============================================================================
+// Tests for mint_decimals_valid()
+// ============================================================================
+
+#[cfg(test)]
+mod mint_decimals_tests {
+    use super::*;
+
+    #[test]
+    fn test_six_decimal_mint_accepted() {
+        assert!(mint_decimals_valid(6));
+        assert_eq!(10u64.pow(MINT_DECIMALS as u32), DECIMAL_FACTOR);
+    }
+
+    #[test]
+    fn test_nine_decimal_mint_rejected() {
+        assert!(!mint_decimals_valid(9));
+        assert!(!mint_decimals_valid(0));
+    }
+}
+
+// ============================================================================
+// Tests for burn-proof airdrop claims
+// ============================================================================
+
+#[cfg(test)]
+mod burn_airdrop_tests {
+    use super::*;
+
+    #[test]
+    fn test_airdrop_amount_proportional_to_burn() {
+        // 1% of 10,000 burned tokens
+        let amount = calculate_airdrop_amount(10_000 * DECIMAL_FACTOR, 0).unwrap();
+        assert_eq!(amount, 100 * DECIMAL_FACTOR);
+
+        // Rounds down to whole units
+        assert_eq!(calculate_airdrop_amount(199, 0).unwrap(), 1);
+        assert_eq!(
+            calculate_airdrop_amount(99, 0).unwrap_err(),
+            ErrorCode::AirdropAmountZero.into()
+        );
+
+        // Still bounded by the supply cap
+        assert_eq!(
+            calculate_airdrop_amount(10_000 * DECIMAL_FACTOR, MAX_SUPPLY_LAMPORTS).unwrap_err(),
+            ErrorCode::SupplyLimitReached.into()
+        );
+    }
+
+    #[test]
+    fn test_airdrop_double_claim_rejected() {
+        let user = Pubkey::new_unique();
+        let mut claim = AirdropClaim {
+            user: Pubkey::default(),
+            airdrop_claimed: false,
+            amount: 0,
+            claimed_at: 0,
+            bump: 255,
+        };
+
+        claim.claim(user, 100 * DECIMAL_FACTOR, 1_700_000_000).unwrap();
+        assert!(claim.airdrop_claimed);
+        assert_eq!(claim.user, user);
+        assert_eq!(claim.amount, 100 * DECIMAL_FACTOR);
+
+        let err = claim.claim(user, 100 * DECIMAL_FACTOR, 1_700_000_100).unwrap_err();
+        assert_eq!(err, ErrorCode::AirdropAlreadyClaimed.into());
+        assert_eq!(claim.claimed_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_airdrop_disabled_rejects_claim() {
+        let mut config = AirdropConfig { airdrop_enabled: false, bump: 255 };
+        assert_eq!(config.check_enabled().unwrap_err(), ErrorCode::AirdropDisabled.into());
+
+        config.airdrop_enabled = true;
+        assert!(config.check_enabled().is_ok());
+    }
+}
+
+// ============================================================================
+// Tests for check_caller_program()
+// ============================================================================
+
+#[cfg(test)]
+mod mint_amount_caller_tests {
+    use super::*;
+
+    #[test]
+    fn test_allowlisted_programs_may_request_amount() {
+        for program_id in MINT_AMOUNT_CALLER_PROGRAMS {
+            assert!(check_caller_program(&program_id).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_direct_call_rejected() {
+        // A transaction calling memo-mint directly has memo-mint as the current instruction's program
+        let err = check_caller_program(&crate::ID).unwrap_err();
+        assert_eq!(err, ErrorCode::UnauthorizedMintCaller.into());
+    }
+
+    #[test]
+    fn test_unknown_program_rejected() {
+        let err = check_caller_program(&Pubkey::new_unique()).unwrap_err();
+        assert_eq!(err, ErrorCode::UnauthorizedMintCaller.into());
+        let err = check_caller_program(&memo_burn::ID).unwrap_err();
+        assert_eq!(err, ErrorCode::UnauthorizedMintCaller.into());
+    }
+}
+
 // ============================================================================
 // Comprehensive Test Summary
 // ============================================================================
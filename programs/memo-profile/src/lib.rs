@@ -7,6 +7,7 @@ use anchor_spl::token_2022::Token2022;
 use memo_burn::program::MemoBurn;
 use memo_burn::cpi::accounts::ProcessBurn;
 use anchor_lang::solana_program::sysvar::instructions::{ID as INSTRUCTIONS_ID};
+use solana_sha256_hasher::hash;
 use std::str::FromStr;
 use spl_memo::ID as MEMO_PROGRAM_ID;
 use base64::{Engine as _, engine::general_purpose};
@@ -25,11 +26,19 @@ pub const AUTHORIZED_MINT_PUBKEY: Pubkey = pubkey!("memoX1sJsBY6od7CfQ58XooRALwn
 #[cfg(not(feature = "mainnet"))]
 pub const AUTHORIZED_MINT_PUBKEY: Pubkey = pubkey!("HLCoc7wNDavNMfWWw2Bwd7U7A24cesuhBSNkxZgvZm1");
 
+// Authorized admin key - different for testnet and mainnet
+#[cfg(feature = "mainnet")]
+pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("FVvewrVHqg2TPWXkesc3CJ7xxWnPtAkzN9nCpvr6UCtQ");
+
+#[cfg(not(feature = "mainnet"))]
+pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("Gkxz6ogojD7Ni58N4SnJXy6xDxSvH5kPFCz92sTZWBVn");
+
 
 // ===== BUSINESS LOGIC CONSTANTS =====
 
 // Token economics
 pub const DECIMAL_FACTOR: u64 = 1_000_000; // Token decimals (6)
+pub const MINT_DECIMALS: u8 = 6; // Decimals the authorized mint must have (DECIMAL_FACTOR = 10^MINT_DECIMALS)
 pub const MIN_PROFILE_CREATION_BURN_TOKENS: u64 = 420; // Minimum tokens to burn for profile creation
 pub const MIN_PROFILE_CREATION_BURN_AMOUNT: u64 = MIN_PROFILE_CREATION_BURN_TOKENS * DECIMAL_FACTOR;
 
@@ -288,6 +297,19 @@ impl ProfileUpdateData {
 pub mod memo_profile {
     use super::*;
 
+    /// Initialize the program-wide burn/mint statistics (one-time setup, admin only;
+    /// burn and mint instructions fail until it exists)
+    pub fn initialize_program_stats(ctx: Context<InitializeProgramStats>) -> Result<()> {
+        let program_stats = &mut ctx.accounts.program_stats;
+        program_stats.total_burned = 0;
+        program_stats.total_minted = 0;
+        program_stats.event_seq = 0;
+        program_stats.bump = ctx.bumps.program_stats;
+
+        msg!("Program stats initialized by admin {}", ctx.accounts.admin.key());
+        Ok(())
+    }
+
     /// Create a user profile (requires burning tokens)
     pub fn create_profile(
         ctx: Context<CreateProfile>,
@@ -315,8 +337,13 @@ pub mod memo_profile {
 
         // Parse and validate Borsh memo data for profile creation
         let profile_data = parse_profile_creation_borsh_memo(&memo_data, ctx.accounts.user.key(), burn_amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
         
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.user_token_account.amount;
+
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.user.to_account_info(),
@@ -329,6 +356,11 @@ pub mod memo_profile {
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.user_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.user_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
         
         // Initialize profile data after successful burn
         let profile = &mut ctx.accounts.profile;
@@ -348,6 +380,8 @@ pub mod memo_profile {
             about_me: profile_data.about_me,
             burn_amount,
             timestamp: Clock::get()?.unix_timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         msg!("Profile created successfully for user {} with {} tokens burned", 
@@ -383,8 +417,13 @@ pub mod memo_profile {
 
         // Parse and validate Borsh memo data for profile update
         let profile_data = parse_profile_update_borsh_memo(&memo_data, ctx.accounts.user.key(), burn_amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
         
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.user_token_account.amount;
+
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.user.to_account_info(),
@@ -397,6 +436,11 @@ pub mod memo_profile {
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.user_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.user_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
 
         let profile = &mut ctx.accounts.profile;
         
@@ -424,6 +468,8 @@ pub mod memo_profile {
             about_me: profile.about_me.clone(),
             burn_amount,
             timestamp: Clock::get()?.unix_timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         msg!("Profile updated successfully for user {} with {} tokens burned", 
@@ -445,6 +491,7 @@ pub mod memo_profile {
             user: user_pubkey,
             username,
             timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
         msg!("Profile deleted successfully for user {}", user_pubkey);
@@ -481,6 +528,42 @@ fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize)
     Ok((true, memo_data.to_vec()))
 }
 
+/// Compute the SHA-256 hash of the raw memo instruction data
+/// (emitted in events so indexers can match them to the on-chain memo)
+fn compute_memo_hash(memo_data: &[u8]) -> [u8; 32] {
+    hash(memo_data).to_bytes()
+}
+
+/// Check that a memo-burn process_burn CPI reduced the burner's token balance by
+/// exactly `amount` (guards against crediting burns that were not fully applied)
+fn check_burn_reflected(balance_before: u64, balance_after: u64, amount: u64) -> Result<()> {
+    if balance_before.checked_sub(balance_after) != Some(amount) {
+        msg!("Burn not reflected in balance: {} -> {} (expected {} burned)", 
+             balance_before, balance_after, amount);
+        return Err(ErrorCode::BurnAmountNotReflected.into());
+    }
+    Ok(())
+}
+
+/// Check that the account handed to memo-burn as `user_global_burn_stats` is owned by
+/// memo-burn and carries the UserGlobalBurnStats discriminator (guards against a
+/// spoofed account reaching the burn CPI)
+fn verify_burn_stats_account(account: &AccountInfo) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    if account.owner != &memo_burn::ID
+        || !data.starts_with(memo_burn::UserGlobalBurnStats::DISCRIMINATOR)
+    {
+        msg!("Invalid burn stats account: {} (owner: {})", account.key(), account.owner);
+        return Err(ErrorCode::InvalidBurnStatsAccount.into());
+    }
+    Ok(())
+}
+
+/// Whether a mint has the decimals DECIMAL_FACTOR assumes (guards against a recreated mint)
+pub fn mint_decimals_valid(decimals: u8) -> bool {
+    decimals == MINT_DECIMALS
+}
+
 /// Check for memo instruction at REQUIRED index 0
 /// 
 /// IMPORTANT: This contract enforces memo at index 0:
@@ -497,7 +580,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     // to leave index 0 available for memo
     if current_index < 1 {
         msg!("memo-profile instruction must be at index 1 or later, but current instruction is at index {}", current_index);
-        return Ok((false, vec![]));
+        return memo_not_at_index_zero(instructions);
     }
     
     // Check that index 0 contains the memo instruction
@@ -508,7 +591,7 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
                 validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
             } else {
                 msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
-                Ok((false, vec![]))
+                memo_not_at_index_zero(instructions)
             }
         },
         Err(e) => {
@@ -518,6 +601,28 @@ fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)>
     }
 }
 
+/// Called when index 0 holds no memo: if a memo exists elsewhere in the transaction,
+/// fail with MemoWrongIndex instead of letting the caller report MemoRequired
+fn memo_not_at_index_zero(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    let program_ids: Vec<Pubkey> = (0..)
+        .map_while(|i| anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(i, instructions).ok())
+        .map(|ix| ix.program_id)
+        .collect();
+    check_memo_placement(&program_ids)?;
+    Ok((false, vec![]))
+}
+
+/// Reject a transaction whose SPL Memo instruction is present but not at index 0
+fn check_memo_placement(program_ids: &[Pubkey]) -> Result<()> {
+    if let Some(index) = program_ids.iter().position(|id| *id == MEMO_PROGRAM_ID) {
+        if index > 0 {
+            msg!("Memo instruction found at index {}, but it must be placed at index 0", index);
+            return Err(ErrorCode::MemoWrongIndex.into());
+        }
+    }
+    Ok(())
+}
+
 /// Parse and validate Borsh-formatted memo data for profile creation (with Base64 decoding)
 fn parse_profile_creation_borsh_memo(memo_data: &[u8], expected_user: Pubkey, expected_amount: u64) -> Result<ProfileCreationData> {
     // First, decode the Base64-encoded memo data
@@ -671,6 +776,61 @@ fn parse_profile_update_borsh_memo(memo_data: &[u8], expected_user: Pubkey, expe
     Ok(profile_data)
 }
 
+/// Program-wide burn/mint totals (singleton PDA, initialized by admin)
+#[account]
+pub struct ProgramStats {
+    pub total_burned: u128,     // Total units burned through this program
+    pub total_minted: u128,     // Total units minted through this program
+    pub event_seq: u64,         // Sequence number of the last emitted event (0 = none yet)
+    pub bump: u8,               // PDA bump
+}
+
+impl ProgramStats {
+    pub const SPACE: usize = 8 + // discriminator
+        16 + // total_burned (u128)
+        16 + // total_minted (u128)
+        8 +  // event_seq (u64)
+        1;   // bump (u8)
+
+    /// Add a burn to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_burn(&mut self, amount: u64) {
+        self.total_burned = self.total_burned.saturating_add(u128::from(amount));
+    }
+
+    /// Add a mint to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_mint(&mut self, amount: u64) {
+        self.total_minted = self.total_minted.saturating_add(u128::from(amount));
+    }
+
+    /// Advance the event sequence and return the new value. The first event gets 1,
+    /// so indexers can detect dropped events by looking for gaps in the sequence.
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.saturating_add(1);
+        self.event_seq
+    }
+}
+
+/// Account structure for initializing the program stats (admin only)
+#[derive(Accounts)]
+pub struct InitializeProgramStats<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramStats::SPACE,
+        seeds = [b"stats"],
+        bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// Account structure for creating a profile
 #[derive(Accounts)]
 pub struct CreateProfile<'info> {
@@ -688,7 +848,8 @@ pub struct CreateProfile<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -718,6 +879,14 @@ pub struct CreateProfile<'info> {
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for updating a profile
@@ -728,7 +897,8 @@ pub struct UpdateProfile<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -764,6 +934,14 @@ pub struct UpdateProfile<'info> {
 
     /// memo-burn program for CPI
     pub memo_burn_program: Program<'info, MemoBurn>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Account structure for deleting a profile
@@ -780,6 +958,14 @@ pub struct DeleteProfile<'info> {
         constraint = profile.user == user.key() @ ErrorCode::UnauthorizedProfileAccess,
     )]
     pub profile: Account<'info, Profile>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
 /// Profile data structure
@@ -818,6 +1004,8 @@ pub struct ProfileCreatedEvent {
     pub about_me: Option<String>,
     pub burn_amount: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Event emitted when a profile is updated
@@ -829,6 +1017,8 @@ pub struct ProfileUpdatedEvent {
     pub about_me: Option<String>,
     pub burn_amount: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Event emitted when a profile is deleted
@@ -837,6 +1027,7 @@ pub struct ProfileDeletedEvent {
     pub user: Pubkey,
     pub username: String,
     pub timestamp: i64,
+    pub event_seq: u64,
 }
 
 /// Error code definitions
@@ -913,6 +1104,21 @@ pub enum ErrorCode {
 
     #[msg("Payload too long. (maximum 787 bytes).")]
     PayloadTooLong,
+
+    #[msg("Memo at wrong index: The SPL Memo instruction must be placed at index 0 of the transaction.")]
+    MemoWrongIndex,
+
+    #[msg("Unauthorized: Only the authorized admin can perform this operation")]
+    UnauthorizedAdmin,
+
+    #[msg("Burn not reflected: The token balance did not drop by the burned amount.")]
+    BurnAmountNotReflected,
+
+    #[msg("Invalid burn stats account: user_global_burn_stats must be a memo-burn UserGlobalBurnStats account.")]
+    InvalidBurnStatsAccount,
+
+    #[msg("Unexpected mint decimals: The authorized mint must have 6 decimals.")]
+    UnexpectedMintDecimals,
 }
 
 // ============================================================================
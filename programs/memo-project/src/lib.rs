@@ -5,13 +5,17 @@ use anchor_lang::prelude::*;
 
 #[cfg(test)]
 mod tests;
+#[cfg(any(test, feature = "client"))]
+pub mod memo_builder;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 use anchor_spl::token_2022::Token2022;
 use memo_burn::program::MemoBurn;
 use memo_burn::cpi::accounts::ProcessBurn;
 use anchor_lang::solana_program::sysvar::instructions::{ID as INSTRUCTIONS_ID};
+use anchor_lang::solana_program::program::set_return_data;
+use solana_sha256_hasher::hash;
 use spl_memo::ID as MEMO_PROGRAM_ID;
-use base64::{Engine as _, engine::general_purpose};
+use base64::{Engine as _, alphabet, engine::{general_purpose, DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig}};
 use std::str::FromStr;
 
 // Program ID - different for testnet and mainnet
@@ -40,6 +44,7 @@ pub const AUTHORIZED_ADMIN_PUBKEY: Pubkey = pubkey!("Gkxz6ogojD7Ni58N4SnJXy6xDxS
 
 // Token economics
 pub const DECIMAL_FACTOR: u64 = 1_000_000; // Token decimals (6)
+pub const MINT_DECIMALS: u8 = 6; // Decimals the authorized mint must have (DECIMAL_FACTOR = 10^MINT_DECIMALS)
 pub const MIN_PROJECT_CREATION_BURN_TOKENS: u64 = 42069; // Minimum tokens to burn for project creation
 pub const MIN_PROJECT_CREATION_BURN_AMOUNT: u64 = MIN_PROJECT_CREATION_BURN_TOKENS * DECIMAL_FACTOR;
 
@@ -51,9 +56,37 @@ pub const MIN_PROJECT_BURN_AMOUNT: u64 = MIN_PROJECT_BURN_TOKENS * DECIMAL_FACTO
 pub const MIN_PROJECT_UPDATE_BURN_TOKENS: u64 = 42069; // Minimum tokens to burn for project update
 pub const MIN_PROJECT_UPDATE_BURN_AMOUNT: u64 = MIN_PROJECT_UPDATE_BURN_TOKENS * DECIMAL_FACTOR;
 
+// Project boost constants: a boost multiplies the project's trending score while active
+pub const BOOST_SECONDS_PER_TOKEN: i64 = 60; // Each burned token buys one minute of boost
+pub const MAX_BOOST_DURATION_SECONDS: i64 = 7 * 24 * 3600; // A boost never runs more than 7 days ahead
+pub const BASE_BOOST_MULTIPLIER: u8 = 2; // Multiplier of a fresh boost
+pub const MAX_BOOST_MULTIPLIER: u8 = 5; // Boosting an active boost stacks +1, up to this cap
+
+// Window of the rolling burn-rate metric (Project::burns_last_24h)
+pub const BURN_RATE_WINDOW_SECONDS: i64 = 24 * 3600; // 24 hours
+
+// Trending eligibility: burns on at least this many distinct UTC days
+pub const MIN_ACTIVE_DAYS: u16 = 3;
+pub const SECONDS_PER_DAY: i64 = 24 * 3600;
+
+// Burn histogram bucket bounds in tokens: <1k, 1k-10k, 10k-100k, >=100k
+pub const BURN_HISTOGRAM_BOUNDS_TOKENS: [u64; 3] = [1_000, 10_000, 100_000];
+
 // Maximum burn per transaction (consistent with memo-burn)
 pub const MAX_BURN_PER_TX: u64 = 1_000_000_000_000 * DECIMAL_FACTOR; // 1 trillion tokens
 
+// Maximum number of projects (creation is rejected well before the u64 counter overflows)
+pub const MAX_ENTITIES: u64 = u64::MAX / 2;
+
+// Minimum interval between two project creations by the same wallet
+pub const PROJECT_CREATE_COOLDOWN_SECONDS: i64 = 3600; // 1 hour
+
+// Age after which anyone may close an IdempotencyMarker
+pub const IDEMPOTENCY_MARKER_TTL_SECONDS: i64 = 3600; // 1 hour
+
+// program_kind written into BurnReceipt accounts (1 = memo-project, 2 = memo-forum)
+pub const BURN_RECEIPT_PROGRAM_KIND: u8 = 1;
+
 // ===== STRING LENGTH CONSTRAINTS =====
 
 // Project metadata limits
@@ -64,6 +97,33 @@ pub const MAX_PROJECT_WEBSITE_LENGTH: usize = 128;
 pub const MAX_TAGS_COUNT: usize = 4;
 pub const MAX_TAG_LENGTH: usize = 32;
 
+// Hard ceilings for the EconomyConfig tag limits; account space is always reserved against these.
+// Tags are used verbatim as PDA seeds (TagStats, TagProjectList), so a tag can never exceed
+// Solana's 32-byte seed limit.
+pub const MAX_TAGS_COUNT_LIMIT: usize = 8;
+pub const MAX_TAG_LENGTH_LIMIT: usize = 32;
+
+// ProgramConfig.operation_flags bits (a set bit enables the operation)
+pub const OPERATION_FLAG_CREATE: u8 = 1 << 0;   // create_project, create_and_burn, fork_project
+pub const OPERATION_FLAG_UPDATE: u8 = 1 << 1;   // update_project, schedule_project_update, apply_scheduled_update
+pub const OPERATION_FLAG_BURN: u8 = 1 << 2;     // burn_for_project
+pub const OPERATION_FLAG_MINT: u8 = 1 << 3;     // Reserved (memo-project has no mint instruction)
+pub const OPERATION_FLAG_SUPPORT: u8 = 1 << 4;  // boost_project
+pub const OPERATION_FLAGS_MASK: u8 = OPERATION_FLAG_CREATE | OPERATION_FLAG_UPDATE | OPERATION_FLAG_BURN
+    | OPERATION_FLAG_MINT | OPERATION_FLAG_SUPPORT;
+
+// Project IDs per TagProjectList page; a full page rolls over to a continuation page
+pub const MAX_TAG_LIST_PROJECTS: usize = 500;
+
+// Co-owners whose majority must co-sign updates when a project requires quorum
+pub const MAX_CO_OWNERS: usize = 3;
+
+// Admin keys in the AdminConfig multisig for sensitive admin operations
+pub const MAX_ADMIN_SIGNERS: usize = 5;
+
+// Project slugs for human-readable URLs (lowercase ascii letters, digits and hyphens; fits one PDA seed)
+pub const MAX_PROJECT_SLUG_LENGTH: usize = 32;
+
 // Memo length constraints (consistent with memo-mint and memo-burn)
 pub const MEMO_MIN_LENGTH: usize = 69;
 pub const MEMO_MAX_LENGTH: usize = 800;
@@ -83,8 +143,8 @@ pub const MAX_BORSH_DATA_SIZE: usize = MEMO_MAX_LENGTH;
 // Current version of BurnMemo structure (consistent with memo-burn)
 pub const BURN_MEMO_VERSION: u8 = 1;
 
-// Current version of ProjectCreationData structure
-pub const PROJECT_CREATION_DATA_VERSION: u8 = 1;
+// Current version of ProjectCreationData structure (v2 adds goal, v3 adds parent_project_id; v1 is still accepted)
+pub const PROJECT_CREATION_DATA_VERSION: u8 = 3;
 
 // Current version of ProjectUpdateData structure  
 pub const PROJECT_UPDATE_DATA_VERSION: u8 = 1;
@@ -101,9 +161,30 @@ pub const EXPECTED_UPDATE_OPERATION: &str = "update_project";
 // maximum burn message length
 pub const MAX_BURN_MESSAGE_LENGTH: usize = 696;
 
+// Donor message board: burn messages are stored truncated to this many bytes
+pub const MAX_BOARD_MESSAGE_LENGTH: usize = 128;
+
 // expected operation for project burn
 pub const EXPECTED_BURN_FOR_PROJECT_OPERATION: &str = "burn_for_project";
 
+// expected operation for project boost
+pub const EXPECTED_BOOST_PROJECT_OPERATION: &str = "boost_project";
+
+// Current version of ProjectBoostData structure
+pub const PROJECT_BOOST_DATA_VERSION: u8 = 1;
+
+// expected operation for project fork
+pub const EXPECTED_FORK_PROJECT_OPERATION: &str = "fork_project";
+
+// Current version of ProjectForkData structure
+pub const PROJECT_FORK_DATA_VERSION: u8 = 1;
+
+// Current version of ProjectBurnData structure (v2 adds ref_post_id, v3 adds ref_sig; v1 is still accepted)
+pub const PROJECT_BURN_DATA_VERSION: u8 = 3;
+
+// Transaction signature length (base58 decoded), used to validate ref_sig
+pub const SIGNATURE_LENGTH_BYTES: usize = 64;
+
 /// BurnMemo structure (compatible with memo-burn contract)
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BurnMemo {
@@ -118,7 +199,6 @@ pub struct BurnMemo {
 }
 
 /// Project creation data structure (stored in BurnMemo.payload)
-#[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ProjectCreationData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -146,18 +226,93 @@ pub struct ProjectCreationData {
     
     /// Tags (optional, max 4 tags, each max 32 characters)
     pub tags: Vec<String>,
+    
+    /// Donation goal in token units (v2+, 0 = no goal)
+    pub goal: u64,
+    
+    /// Parent project for a sub-project (v3+, None = top-level project)
+    pub parent_project_id: Option<u64>,
+}
+
+impl AnchorSerialize for ProjectCreationData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.project_id.serialize(writer)?;
+        self.name.serialize(writer)?;
+        self.description.serialize(writer)?;
+        self.image.serialize(writer)?;
+        self.website.serialize(writer)?;
+        self.tags.serialize(writer)?;
+        if self.version >= 2 {
+            self.goal.serialize(writer)?;
+        }
+        if self.version >= 3 {
+            self.parent_project_id.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for ProjectCreationData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let project_id = u64::deserialize_reader(reader)?;
+        let name = String::deserialize_reader(reader)?;
+        let description = String::deserialize_reader(reader)?;
+        let image = String::deserialize_reader(reader)?;
+        let website = String::deserialize_reader(reader)?;
+        let tags = Vec::<String>::deserialize_reader(reader)?;
+        let goal = if version >= 2 {
+            u64::deserialize_reader(reader)?
+        } else {
+            0
+        };
+        let parent_project_id = if version >= 3 {
+            Option::<u64>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        Ok(Self { version, category, operation, project_id, name, description, image, website, tags, goal, parent_project_id })
+    }
 }
 
 impl ProjectCreationData {
-    /// Validate the structure fields
+    /// Validate the structure fields against the default tag limits
     pub fn validate(&self, expected_project_id: u64) -> Result<()> {
-        // Validate version
-        if self.version != PROJECT_CREATION_DATA_VERSION {
-            msg!("Unsupported project creation data version: {} (expected: {})", 
+        self.validate_with_config(expected_project_id, &EconomyConfig::resolve(None))
+    }
+
+    /// Validate the structure fields, taking tag limits from the economy config
+    pub fn validate_with_config(&self, expected_project_id: u64, economy: &EconomyConfig) -> Result<()> {
+        // Validate version (1-3)
+        if self.version == 0 || self.version > PROJECT_CREATION_DATA_VERSION {
+            msg!("Unsupported project creation data version: {} (expected: 1-{})", 
                  self.version, PROJECT_CREATION_DATA_VERSION);
             return Err(ErrorCode::UnsupportedProjectDataVersion.into());
         }
         
+        // goal cannot be carried by a v1 payload
+        if self.version < 2 && self.goal != 0 {
+            msg!("goal requires project creation data version 2");
+            return Err(ErrorCode::UnsupportedProjectDataVersion.into());
+        }
+        
+        // parent_project_id cannot be carried by a v1/v2 payload
+        if self.version < 3 && self.parent_project_id.is_some() {
+            msg!("parent_project_id requires project creation data version 3");
+            return Err(ErrorCode::UnsupportedProjectDataVersion.into());
+        }
+        
+        // A project cannot be its own parent
+        if self.parent_project_id == Some(self.project_id) {
+            msg!("Project {} cannot be its own parent", self.project_id);
+            return Err(ErrorCode::InvalidParentProject.into());
+        }
+        
         // Validate category (must be exactly "project")
         if self.category != EXPECTED_CATEGORY {
             msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
@@ -210,6 +365,7 @@ impl ProjectCreationData {
                  self.image.len(), MAX_PROJECT_IMAGE_LENGTH);
             return Err(ErrorCode::InvalidProjectImage.into());
         }
+        validate_image_reference(&self.image)?;
         
         // Validate website (optional, max 128 characters)
         if self.website.len() > MAX_PROJECT_WEBSITE_LENGTH {
@@ -218,18 +374,8 @@ impl ProjectCreationData {
             return Err(ErrorCode::InvalidProjectWebsite.into());
         }
         
-        // Validate tags (optional, max 4 tags, each max 32 characters)
-        if self.tags.len() > MAX_TAGS_COUNT {
-            msg!("Too many tags: {} (max: {})", self.tags.len(), MAX_TAGS_COUNT);
-            return Err(ErrorCode::TooManyTags.into());
-        }
-        
-        for (i, tag) in self.tags.iter().enumerate() {
-            if tag.is_empty() || tag.len() > MAX_TAG_LENGTH {
-                msg!("Invalid tag {}: '{}' (must be 1-{} characters)", i, tag, MAX_TAG_LENGTH);
-                return Err(ErrorCode::InvalidTag.into());
-            }
-        }
+        // Validate tags (optional, max 4 tags of 32 characters unless the economy config says otherwise)
+        economy.check_tags(&self.tags)?;
         
         msg!("Project creation data validation passed: category={}, operation={}, project_id={}, name={}, tags_count={}", 
              self.category, self.operation, self.project_id, self.name, self.tags.len());
@@ -262,8 +408,13 @@ pub struct ProjectUpdateData {
 }
 
 impl ProjectUpdateData {
-    /// Validate the structure fields
+    /// Validate the structure fields against the default tag limits
     pub fn validate(&self, expected_project_id: u64) -> Result<()> {
+        self.validate_with_config(expected_project_id, &EconomyConfig::resolve(None))
+    }
+
+    /// Validate the structure fields, taking tag limits from the economy config
+    pub fn validate_with_config(&self, expected_project_id: u64, economy: &EconomyConfig) -> Result<()> {
         // Validate version
         if self.version != PROJECT_UPDATE_DATA_VERSION {
             msg!("Unsupported project update data version: {} (expected: {})", 
@@ -328,6 +479,7 @@ impl ProjectUpdateData {
                      new_image.len(), MAX_PROJECT_IMAGE_LENGTH);
                 return Err(ErrorCode::InvalidProjectImage.into());
             }
+            validate_image_reference(new_image)?;
         }
         
         // Validate website (optional, max 128 characters)
@@ -339,19 +491,9 @@ impl ProjectUpdateData {
             }
         }
         
-        // Validate tags (optional, max 4 tags, each max 32 characters)
+        // Validate tags (optional, max 4 tags of 32 characters unless the economy config says otherwise)
         if let Some(ref new_tags) = self.tags {
-            if new_tags.len() > MAX_TAGS_COUNT {
-                msg!("Too many tags: {} (max: {})", new_tags.len(), MAX_TAGS_COUNT);
-                return Err(ErrorCode::TooManyTags.into());
-            }
-            
-            for (i, tag) in new_tags.iter().enumerate() {
-                if tag.is_empty() || tag.len() > MAX_TAG_LENGTH {
-                    msg!("Invalid tag {}: '{}' (must be 1-{} characters)", i, tag, MAX_TAG_LENGTH);
-                    return Err(ErrorCode::InvalidTag.into());
-                }
-            }
+            economy.check_tags(new_tags)?;
         }
         
         msg!("Project update data validation passed: category={}, operation={}, project_id={}", 
@@ -362,7 +504,9 @@ impl ProjectUpdateData {
 }
 
 /// Project burn data structure (stored in BurnMemo.payload for burn_for_project)
-#[derive(AnchorSerialize, AnchorDeserialize)]
+///
+/// Borsh is implemented by hand so that older payloads still decode: the trailing
+/// ref_post_id is only read and written when version >= 2, and ref_sig when version >= 3.
 pub struct ProjectBurnData {
     /// Version of this structure (for future compatibility)
     pub version: u8,
@@ -381,15 +525,73 @@ pub struct ProjectBurnData {
     
     /// Burn message (optional, max 696 characters)
     pub message: String,
+    
+    /// Forum post this burn refers to (v2+, not validated on-chain - just a link)
+    pub ref_post_id: Option<u64>,
+    
+    /// Prior transaction this burn refers to, e.g. a grant payout (v3+, base58 signature)
+    pub ref_sig: Option<String>,
+}
+
+impl AnchorSerialize for ProjectBurnData {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.version.serialize(writer)?;
+        self.category.serialize(writer)?;
+        self.operation.serialize(writer)?;
+        self.project_id.serialize(writer)?;
+        self.burner.serialize(writer)?;
+        self.message.serialize(writer)?;
+        if self.version >= 2 {
+            self.ref_post_id.serialize(writer)?;
+        }
+        if self.version >= 3 {
+            self.ref_sig.serialize(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl AnchorDeserialize for ProjectBurnData {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let category = String::deserialize_reader(reader)?;
+        let operation = String::deserialize_reader(reader)?;
+        let project_id = u64::deserialize_reader(reader)?;
+        let burner = String::deserialize_reader(reader)?;
+        let message = String::deserialize_reader(reader)?;
+        let ref_post_id = if version >= 2 {
+            Option::<u64>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        let ref_sig = if version >= 3 {
+            Option::<String>::deserialize_reader(reader)?
+        } else {
+            None
+        };
+        Ok(Self { version, category, operation, project_id, burner, message, ref_post_id, ref_sig })
+    }
 }
 
 impl ProjectBurnData {
     /// Validate the structure fields
     pub fn validate(&self, expected_project_id: u64, expected_burner: Pubkey) -> Result<()> {
-        // Validate version
-        if self.version != PROJECT_CREATION_DATA_VERSION {
-            msg!("Unsupported project burn data version: {} (expected: {})", 
-                 self.version, PROJECT_CREATION_DATA_VERSION);
+        // Validate version (1-3)
+        if self.version == 0 || self.version > PROJECT_BURN_DATA_VERSION {
+            msg!("Unsupported project burn data version: {} (expected: 1-{})", 
+                 self.version, PROJECT_BURN_DATA_VERSION);
+            return Err(ErrorCode::UnsupportedProjectBurnDataVersion.into());
+        }
+        
+        // ref_post_id cannot be carried by a v1 payload
+        if self.version < 2 && self.ref_post_id.is_some() {
+            msg!("ref_post_id requires project burn data version 2");
+            return Err(ErrorCode::UnsupportedProjectBurnDataVersion.into());
+        }
+        
+        // ref_sig cannot be carried by a v1/v2 payload
+        if self.version < 3 && self.ref_sig.is_some() {
+            msg!("ref_sig requires project burn data version 3");
             return Err(ErrorCode::UnsupportedProjectBurnDataVersion.into());
         }
         
@@ -444,6 +646,25 @@ impl ProjectBurnData {
             return Err(ErrorCode::BurnMessageTooLong.into());
         }
         
+        // Validate ref_sig format if provided (same rules as memo-chat's reply_to_sig)
+        if let Some(ref ref_sig) = self.ref_sig {
+            if !ref_sig.is_empty() {
+                // Validate signature format (base58 encoded, 64 bytes when decoded)
+                match bs58::decode(ref_sig).into_vec() {
+                    Ok(decoded) => {
+                        if decoded.len() != SIGNATURE_LENGTH_BYTES {
+                            msg!("Invalid ref signature length: {} bytes (expected {})", decoded.len(), SIGNATURE_LENGTH_BYTES);
+                            return Err(ErrorCode::InvalidRefSignatureFormat.into());
+                        }
+                    },
+                    Err(_) => {
+                        msg!("Invalid ref signature encoding: {}", ref_sig);
+                        return Err(ErrorCode::InvalidRefSignatureFormat.into());
+                    }
+                }
+            }
+        }
+        
         msg!("Project burn data validation passed: category={}, operation={}, project_id={}, burner={}", 
              self.category, self.operation, self.project_id, self.burner);
         
@@ -451,6 +672,235 @@ impl ProjectBurnData {
     }
 }
 
+/// Project boost data structure (stored in BurnMemo.payload for boost_project)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProjectBoostData {
+    /// Version of this structure (for future compatibility)
+    pub version: u8,
+    
+    /// Category of the request (must be "project" for memo-project contract)
+    pub category: String,
+    
+    /// Operation type (must be "boost_project" for boosting)
+    pub operation: String,
+    
+    /// Project ID (must match the target project)
+    pub project_id: u64,
+    
+    /// Burner pubkey as string (must match the transaction signer)
+    pub burner: String,
+}
+
+impl ProjectBoostData {
+    /// Validate the structure fields
+    pub fn validate(&self, expected_project_id: u64, expected_burner: Pubkey) -> Result<()> {
+        // Validate version
+        if self.version != PROJECT_BOOST_DATA_VERSION {
+            msg!("Unsupported project boost data version: {} (expected: {})", 
+                 self.version, PROJECT_BOOST_DATA_VERSION);
+            return Err(ErrorCode::UnsupportedProjectBoostDataVersion.into());
+        }
+        
+        // Validate category (must be exactly "project")
+        if self.category != EXPECTED_CATEGORY {
+            msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
+            return Err(ErrorCode::InvalidCategory.into());
+        }
+        
+        // Validate operation (must be exactly "boost_project")
+        if self.operation != EXPECTED_BOOST_PROJECT_OPERATION {
+            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, EXPECTED_BOOST_PROJECT_OPERATION);
+            return Err(ErrorCode::InvalidOperation.into());
+        }
+        
+        // Validate project_id matches
+        if self.project_id != expected_project_id {
+            msg!("Project ID mismatch: memo {} vs expected {}", self.project_id, expected_project_id);
+            return Err(ErrorCode::ProjectIdMismatch.into());
+        }
+        
+        // Validate burner pubkey matches transaction signer
+        let parsed_pubkey = Pubkey::from_str(&self.burner)
+            .map_err(|_| {
+                msg!("Invalid burner pubkey format: {}", self.burner);
+                ErrorCode::InvalidBurnerPubkeyFormat
+            })?;
+        
+        if parsed_pubkey != expected_burner {
+            msg!("Burner pubkey mismatch: memo {} vs expected {}", parsed_pubkey, expected_burner);
+            return Err(ErrorCode::BurnerPubkeyMismatch.into());
+        }
+        
+        msg!("Project boost data validation passed: project_id={}, burner={}", self.project_id, self.burner);
+        
+        Ok(())
+    }
+}
+
+/// Project fork data structure (stored in BurnMemo.payload for fork_project)
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProjectForkData {
+    /// Version of this structure (for future compatibility)
+    pub version: u8,
+    
+    /// Category of the request (must be "project" for memo-project contract)
+    pub category: String,
+    
+    /// Operation type (must be "fork_project" for forking)
+    pub operation: String,
+    
+    /// Project ID of the new project (must match the expected project_id)
+    pub project_id: u64,
+    
+    /// Project ID of the project being forked
+    pub source_project_id: u64,
+}
+
+impl ProjectForkData {
+    /// Validate the structure fields
+    pub fn validate(&self, expected_project_id: u64, expected_source_project_id: u64) -> Result<()> {
+        // Validate version
+        if self.version != PROJECT_FORK_DATA_VERSION {
+            msg!("Unsupported project fork data version: {} (expected: {})", 
+                 self.version, PROJECT_FORK_DATA_VERSION);
+            return Err(ErrorCode::UnsupportedProjectForkDataVersion.into());
+        }
+        
+        // Validate category (must be exactly "project")
+        if self.category != EXPECTED_CATEGORY {
+            msg!("Invalid category: '{}' (expected: '{}')", self.category, EXPECTED_CATEGORY);
+            return Err(ErrorCode::InvalidCategory.into());
+        }
+        
+        // Validate operation (must be exactly "fork_project")
+        if self.operation != EXPECTED_FORK_PROJECT_OPERATION {
+            msg!("Invalid operation: '{}' (expected: '{}')", self.operation, EXPECTED_FORK_PROJECT_OPERATION);
+            return Err(ErrorCode::InvalidOperation.into());
+        }
+        
+        // Validate project_id matches
+        if self.project_id != expected_project_id {
+            msg!("Project ID mismatch: memo {} vs expected {}", self.project_id, expected_project_id);
+            return Err(ErrorCode::ProjectIdMismatch.into());
+        }
+        
+        // Validate source_project_id matches
+        if self.source_project_id != expected_source_project_id {
+            msg!("Source project ID mismatch: memo {} vs expected {}", 
+                 self.source_project_id, expected_source_project_id);
+            return Err(ErrorCode::SourceProjectIdMismatch.into());
+        }
+        
+        msg!("Project fork data validation passed: project_id={}, source_project_id={}", 
+             self.project_id, self.source_project_id);
+        
+        Ok(())
+    }
+}
+
+/// Description of a forked project: the source description followed by a "(fork of #N)" note,
+/// truncated (at a char boundary) so the result stays within MAX_PROJECT_DESCRIPTION_LENGTH
+pub fn fork_description(description: &str, source_project_id: u64) -> String {
+    let note = format!("fork of #{}", source_project_id);
+    if description.is_empty() {
+        return note;
+    }
+    let suffix = format!(" ({})", note);
+    let mut end = description.len().min(MAX_PROJECT_DESCRIPTION_LENGTH.saturating_sub(suffix.len()));
+    while !description.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}{}", &description[..end], suffix)
+}
+
+/// Error for an invalid tag, carrying the tag's index so clients can highlight the right input.
+/// Every index below MAX_TAGS_COUNT_LIMIT has its own variant (more tags than the configured
+/// maximum are rejected with TooManyTags before any tag is checked)
+pub fn invalid_tag_error(index: usize) -> ErrorCode {
+    match index {
+        0 => ErrorCode::InvalidTagAtIndex0,
+        1 => ErrorCode::InvalidTagAtIndex1,
+        2 => ErrorCode::InvalidTagAtIndex2,
+        3 => ErrorCode::InvalidTagAtIndex3,
+        4 => ErrorCode::InvalidTagAtIndex4,
+        5 => ErrorCode::InvalidTagAtIndex5,
+        6 => ErrorCode::InvalidTagAtIndex6,
+        7 => ErrorCode::InvalidTagAtIndex7,
+        _ => ErrorCode::InvalidTag,
+    }
+}
+
+/// Reject tags that are whitespace-only or contain ASCII control characters
+/// (surrounding whitespace is fine; it is trimmed before the tag is stored)
+pub fn validate_tag_content(index: usize, tag: &str) -> Result<()> {
+    if tag.chars().any(|c| c.is_ascii_control()) || (!tag.is_empty() && tag.trim().is_empty()) {
+        msg!("Invalid tag {} content: {:?}", index, tag);
+        return Err(ErrorCode::InvalidTagContent.into());
+    }
+    Ok(())
+}
+
+/// Trim leading/trailing whitespace from each tag, so " defi " is stored as "defi"
+pub fn trim_tags(tags: &mut [String]) {
+    for tag in tags.iter_mut() {
+        let trimmed = tag.trim();
+        if trimmed.len() != tag.len() {
+            *tag = trimmed.to_string();
+        }
+    }
+}
+
+/// Lowercase mirror of a name for case-insensitive search by indexers. Falls back to ASCII-only
+/// lowercasing if full Unicode lowercasing would grow the name, so the mirror never needs more
+/// space than the name itself
+pub fn lowercase_name(name: &str) -> String {
+    let lower = name.to_lowercase();
+    if lower.len() <= name.len() {
+        lower
+    } else {
+        name.to_ascii_lowercase()
+    }
+}
+
+/// Check that a project slug is 1..=MAX_PROJECT_SLUG_LENGTH characters of lowercase ascii
+/// letters, digits and hyphens, neither starting nor ending with a hyphen
+pub fn validate_project_slug(slug: &str) -> Result<()> {
+    let valid_chars = slug.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+    if slug.is_empty() || slug.len() > MAX_PROJECT_SLUG_LENGTH || !valid_chars
+        || slug.starts_with('-') || slug.ends_with('-') {
+        msg!("Invalid project slug: '{}'", slug);
+        return Err(ErrorCode::InvalidProjectSlug.into());
+    }
+    Ok(())
+}
+
+/// Image references must use one of these schemes (or be a bare CIDv0)
+pub const IMAGE_REFERENCE_PREFIXES: [&str; 3] = ["ipfs://", "ar://", "https://"];
+
+/// Length of a bare CIDv0 IPFS hash ("Qm" + 44 base58 characters)
+pub const CID_V0_LENGTH: usize = 46;
+
+/// Validate an image field: empty, an ipfs:// / ar:// / https:// reference, or a bare CIDv0
+pub fn validate_image_reference(image: &str) -> Result<()> {
+    if image.is_empty()
+        || IMAGE_REFERENCE_PREFIXES.iter().any(|prefix| image.starts_with(prefix))
+        || is_cid_v0(image)
+    {
+        return Ok(());
+    }
+
+    msg!("Invalid image reference: {} (expected ipfs://, ar://, https:// or a CIDv0)", image);
+    Err(ErrorCode::InvalidImageReference.into())
+}
+
+/// Whether `value` looks like a CIDv0 ("Qm..." in base58, 46 characters)
+fn is_cid_v0(value: &str) -> bool {
+    const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    value.len() == CID_V0_LENGTH
+        && value.starts_with("Qm")
+        && value.chars().all(|c| BASE58_ALPHABET.contains(c))
+}
+
 #[program]
 pub mod memo_project {
     use super::*;
@@ -470,37 +920,144 @@ pub mod memo_project {
         Ok(())
     }
 
+    /// Initialize the program-wide burn/mint statistics (one-time setup, admin only;
+    /// burn and mint instructions fail until it exists)
+    pub fn initialize_program_stats(ctx: Context<InitializeProgramStats>) -> Result<()> {
+        let program_stats = &mut ctx.accounts.program_stats;
+        program_stats.total_burned = 0;
+        program_stats.total_minted = 0;
+        program_stats.event_seq = 0;
+        program_stats.bump = ctx.bumps.program_stats;
+
+        msg!("Program stats initialized by admin {}", ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Exempt a wallet from rate limits (admin only), e.g. a trusted cross-post bot
+    pub fn add_rate_limit_exemption(ctx: Context<AddRateLimitExemption>, wallet: Pubkey) -> Result<()> {
+        let exemption = &mut ctx.accounts.rate_limit_exemption;
+        exemption.wallet = wallet;
+        exemption.granted_at = Clock::get()?.unix_timestamp;
+        exemption.bump = ctx.bumps.rate_limit_exemption;
+
+        msg!("Rate limit exemption granted to {} by admin {}", wallet, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Close an IdempotencyMarker once it is older than IDEMPOTENCY_MARKER_TTL_SECONDS
+    /// (anyone can call); the rent goes back to the wallet that created it
+    pub fn close_idempotency_marker(ctx: Context<CloseIdempotencyMarker>) -> Result<()> {
+        ctx.accounts.idempotency_marker.check_expired(Clock::get()?.unix_timestamp)?;
+
+        msg!("Idempotency marker of {} closed by {}", 
+             ctx.accounts.idempotency_marker.signer, ctx.accounts.closer.key());
+        Ok(())
+    }
+
+    /// Remove a wallet's rate-limit exemption (admin only); the rent goes back to the admin
+    pub fn remove_rate_limit_exemption(ctx: Context<RemoveRateLimitExemption>, wallet: Pubkey) -> Result<()> {
+        msg!("Rate limit exemption removed from {} by admin {}", wallet, ctx.accounts.admin.key());
+        Ok(())
+    }
+
+    /// Create the donor message board of a project (anyone can pay for it).
+    /// Accounts created via CPI are limited to 10 KiB, so the board is created
+    /// at that size and must be grown with extend_message_board before use.
+    pub fn initialize_message_board(ctx: Context<InitializeMessageBoard>, project_id: u64) -> Result<()> {
+        let board = &mut ctx.accounts.message_board;
+        board.project_id = project_id;
+        board.current_index = 0;
+        board.records = Vec::new();
+        board.bump = ctx.bumps.message_board;
+
+        msg!("Message board initialized for project {} by {}", project_id, ctx.accounts.payer.key());
+        Ok(())
+    }
+
+    /// Create the recent-burners ring buffer of a project (anyone can pay for it). From then
+    /// on burn_for_project and boost_project require it, so it sees every burn
+    pub fn initialize_recent_burners(ctx: Context<InitializeRecentBurners>, project_id: u64) -> Result<()> {
+        let recent = &mut ctx.accounts.recent_burners;
+        recent.project_id = project_id;
+        recent.current_index = 0;
+        recent.burners = Vec::new();
+        recent.bump = ctx.bumps.recent_burners;
+        ctx.accounts.project.recent_burners_enabled = true;
+
+        msg!("Recent burners initialized for project {} by {}", project_id, ctx.accounts.payer.key());
+        Ok(())
+    }
+
+    /// Grow the donor message board to its full size (ProjectMessageBoard::SPACE)
+    pub fn extend_message_board(ctx: Context<ExtendMessageBoard>, project_id: u64) -> Result<()> {
+        msg!("Message board for project {} extended to {} bytes", 
+             project_id, ctx.accounts.message_board.to_account_info().data_len());
+        Ok(())
+    }
+
     /// Create a new project (requires burning tokens)
     /// Note: project_id will be automatically assigned by the contract
-    pub fn create_project(
-        ctx: Context<CreateProject>,
+    /// `burn_cap` optionally limits the project's total burns (0 = unlimited)
+    /// `idempotency_key` optionally guards against retried submissions (see IdempotencyMarker)
+    pub fn create_project<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateProject<'info>>,
         expected_project_id: u64, // The project_id that client expects to create
         burn_amount: u64,
+        burn_cap: u64,
+        idempotency_key: Option<[u8; 16]>,
     ) -> Result<()> {
-        // Validate burn amount - require at least 69420 tokens for project creation
-        if burn_amount < MIN_PROJECT_CREATION_BURN_AMOUNT {
-            return Err(ErrorCode::BurnAmountTooSmall.into());
-        }
+        process_create_project(ctx, expected_project_id, burn_amount, 0, burn_cap, idempotency_key)
+    }
+
+    /// Create a new project and make its first burn_for_project-style burn in one instruction.
+    /// The memo is a regular project creation memo whose burn_amount is create_burn + extra_burn;
+    /// both amounts are burned in a single process_burn CPI. The project has no burn cap.
+    pub fn create_and_burn<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateProject<'info>>,
+        expected_project_id: u64,
+        create_burn: u64,
+        extra_burn: u64,
+        idempotency_key: Option<[u8; 16]>,
+    ) -> Result<()> {
+        process_create_project(ctx, expected_project_id, create_burn, extra_burn, 0, idempotency_key)
+    }
+
+    /// Create a new project whose metadata is copied from an existing one (requires the full
+    /// creation burn). The description gets a "(fork of #N)" note and the new project records
+    /// `forked_from`; the fork starts with no burn cap or goal.
+    pub fn fork_project<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForkProject<'info>>,
+        source_project_id: u64,
+        expected_project_id: u64,
+        burn_amount: u64,
+    ) -> Result<()> {
+        ProgramConfig::check_operation(&ctx.accounts.program_config, OPERATION_FLAG_CREATE)?;
+
+        // Validate burn amount against the economy config (same minimum as create_project)
+        let economy = EconomyConfig::load(&ctx.accounts.economy_config)?;
+        economy.check_create_burn(burn_amount)?;
         
-        // check burn amount limit
         if burn_amount > MAX_BURN_PER_TX {
             return Err(ErrorCode::BurnAmountTooLarge.into());
         }
         
-        if burn_amount % DECIMAL_FACTOR != 0 {
+        if !burn_amount.is_multiple_of(DECIMAL_FACTOR) {
             return Err(ErrorCode::InvalidBurnAmount.into());
         }
 
-        // Get the next project_id from global counter
+        // Get the next project_id from global counter and verify it matches the expected one
         let global_counter = &mut ctx.accounts.global_counter;
-        let actual_project_id = global_counter.total_projects;
+        global_counter.check_capacity()?;
+        let actual_project_id = global_counter.check_expected_id(expected_project_id)?;
 
-        // Verify that the expected project_id matches the actual next project_id
-        if expected_project_id != actual_project_id {
-            msg!("Project ID mismatch: expected {}, but next available ID is {}", 
-                 expected_project_id, actual_project_id);
-            return Err(ErrorCode::ProjectIdMismatch.into());
-        }
+        // The project PDA must be fresh; an existing one means the counter was reset
+        Project::check_account_unused(&ctx.accounts.project, actual_project_id)?;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        // Forks share the per-wallet creation cooldown
+        let exempt = RateLimitExemption::exempts(ctx.accounts.rate_limit_exemption.as_deref(), ctx.accounts.creator.key());
+        ctx.accounts.creator_cooldown.check(timestamp, exempt)?;
 
         // Check memo instruction
         let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
@@ -508,10 +1065,15 @@ pub mod memo_project {
             return Err(ErrorCode::MemoRequired.into());
         }
 
-        // Parse and validate Borsh memo data for project creation
-        let project_data = parse_project_creation_borsh_memo(&memo_data, actual_project_id, burn_amount)?;
-        
+        // Parse and validate Borsh memo data for project fork
+        parse_project_fork_borsh_memo(&memo_data, actual_project_id, source_project_id, burn_amount)?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.creator_token_account.amount;
+
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.creator.to_account_info(),
@@ -524,69 +1086,82 @@ pub mod memo_project {
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
-        
-        // Get current timestamp once for consistency and efficiency
-        let timestamp = Clock::get()?.unix_timestamp;
-        
-        // Initialize project data after successful burn
-        let project = &mut ctx.accounts.project;
-        project.project_id = actual_project_id;
-        project.creator = ctx.accounts.creator.key();
-        project.created_at = timestamp;
-        project.last_updated = timestamp;
-        project.name = project_data.name.clone();
-        project.description = project_data.description.clone();
-        project.image = project_data.image.clone();
-        project.website = project_data.website.clone();
-        project.tags = project_data.tags.clone();
-        project.memo_count = 0; // Initialize memo_count (only tracks burn_for_project operations)
+        ctx.accounts.creator_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.creator_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
+
+        // Count tag usage and index the fork by tag
+        record_tags(
+            &ctx.accounts.source_project.tags,
+            actual_project_id,
+            ctx.remaining_accounts,
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        // Create the fork after successful burn
+        let mut project = Project::new(actual_project_id, ctx.accounts.creator.key(), timestamp, ctx.bumps.project);
+        project.copy_metadata_from(&ctx.accounts.source_project);
         project.burned_amount = burn_amount;
-        project.last_memo_time = 0; // Set to 0 initially (no burn_for_project memos yet)
-        project.bump = ctx.bumps.project;
+        create_project_account(
+            &project,
+            &ctx.accounts.project,
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let leaderboard_amount = project.leaderboard_amount();
 
         // Increment global counter AFTER successful project creation
         global_counter.total_projects = global_counter.total_projects.checked_add(1)
             .ok_or(ErrorCode::ProjectCounterOverflow)?;
 
-        // Emit project creation event
-        emit!(ProjectCreatedEvent {
+        // Record this creation for the creator's cooldown
+        let creator_cooldown = &mut ctx.accounts.creator_cooldown;
+        creator_cooldown.creator = ctx.accounts.creator.key();
+        creator_cooldown.last_created = timestamp;
+        creator_cooldown.bump = ctx.bumps.creator_cooldown;
+
+        emit!(ProjectForkedEvent {
             project_id: actual_project_id,
+            source_project_id,
             creator: ctx.accounts.creator.key(),
-            name: project_data.name,
-            description: project_data.description,
-            image: project_data.image,
-            website: project_data.website,
-            tags: project_data.tags,
+            name: project.name.clone(),
+            name_lower: project.name_lower.clone(),
+            description: project.description.clone(),
+            image: project.image.clone(),
+            website: project.website.clone(),
+            tags: project.tags.clone(),
             burn_amount,
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
-        // Update burn leaderboard after successful project creation
+        // Update burn leaderboard after successful fork
         let leaderboard = &mut ctx.accounts.burn_leaderboard;
-        let entered_leaderboard = leaderboard.update_leaderboard(actual_project_id, burn_amount)?;
-
-        if entered_leaderboard {
+        if leaderboard.update_leaderboard(actual_project_id, leaderboard_amount)? {
             msg!("Project {} entered burn leaderboard", actual_project_id);
-        } else {
-            msg!("Project {} burn amount {} not sufficient for leaderboard", 
-                 actual_project_id, burn_amount / DECIMAL_FACTOR);
         }
 
-        msg!("Project {} created successfully by {} with {} tokens burned", 
-             actual_project_id, ctx.accounts.creator.key(), burn_amount / DECIMAL_FACTOR);
+        msg!("Project {} forked from project {} by {} with {} tokens burned", 
+             actual_project_id, source_project_id, ctx.accounts.creator.key(), burn_amount / DECIMAL_FACTOR);
         Ok(())
     }
 
-    /// Update an existing project (requires burning tokens)
-    pub fn update_project(
-        ctx: Context<UpdateProject>,
+    /// Update an existing project (requires burning tokens). Tags the update adds are counted
+    /// and indexed like on creation, from the leading remaining accounts (see record_added_tags)
+    pub fn update_project<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateProject<'info>>,
         project_id: u64,
         burn_amount: u64,
     ) -> Result<()> {
-        // Validate burn amount - require at least 42069 tokens for project update
-        if burn_amount < MIN_PROJECT_UPDATE_BURN_AMOUNT {
-            return Err(ErrorCode::BurnAmountTooSmall.into());
-        }
+        ProgramConfig::check_operation(&ctx.accounts.program_config, OPERATION_FLAG_UPDATE)?;
+
+        // Validate burn amount against the economy config (defaults to 42069 tokens)
+        let economy = EconomyConfig::load(&ctx.accounts.economy_config)?;
+        economy.check_update_burn(burn_amount)?;
         
         // check burn amount limit
         if burn_amount > MAX_BURN_PER_TX {
@@ -603,10 +1178,19 @@ pub mod memo_project {
             return Err(ErrorCode::MemoRequired.into());
         }
 
+        // Shared projects need a majority of co-owners to co-sign (passed as remaining accounts)
+        let signers = instruction_signers(&ctx.accounts.updater, ctx.remaining_accounts);
+        ctx.accounts.project.check_quorum(&signers)?;
+
         // Parse and validate Borsh memo data for project update
-        let update_data = parse_project_update_borsh_memo(&memo_data, project_id, burn_amount)?;
+        let update_data = parse_project_update_borsh_memo(&memo_data, project_id, burn_amount, &economy)?;
+        let memo_hash = compute_memo_hash(&memo_data);
         
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.updater_token_account.amount;
+
         // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
             user: ctx.accounts.updater.to_account_info(),
@@ -619,55 +1203,63 @@ pub mod memo_project {
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
         memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.updater_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.updater_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
 
         // Get current timestamp once for consistency and efficiency
         let timestamp = Clock::get()?.unix_timestamp;
 
+        // Count and index the tags this update adds
+        let added_tags = ctx.accounts.project.added_tags(update_data.tags.as_deref());
+        record_added_tags(
+            &added_tags,
+            project_id,
+            ctx.remaining_accounts,
+            &ctx.accounts.updater.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
         let project = &mut ctx.accounts.project;
         
-        // Update fields if provided in memo data
-        if let Some(new_name) = update_data.name {
-            project.name = new_name;
-        }
-        
-        if let Some(new_description) = update_data.description {
-            project.description = new_description;
-        }
-        
-        if let Some(new_image) = update_data.image {
-            project.image = new_image;
-        }
-        
-        if let Some(new_website) = update_data.website {
-            project.website = new_website;
-        }
-        
-        if let Some(new_tags) = update_data.tags {
-            project.tags = new_tags;
-        }
-        
-        // Update burn amount and timestamp
-        project.burned_amount = project.burned_amount.saturating_add(burn_amount);
-        project.last_updated = timestamp;
+        // Update fields if provided in memo data, then the burn amount and timestamps
         // Note: last_memo_time is NOT updated here - only tracks burn_for_project operations
+        project.record_update(update_data, burn_amount, timestamp);
+        let goal_reached = project.check_goal_reached();
 
         // Emit project update event
         emit!(ProjectUpdatedEvent {
             project_id,
             updater: ctx.accounts.updater.key(),
             name: project.name.clone(),
+            name_lower: project.name_lower.clone(),
             description: project.description.clone(),
             image: project.image.clone(),
             website: project.website.clone(),
             tags: project.tags.clone(), // Emit all tags
             burn_amount,
             total_burned: project.burned_amount,
+            last_activity: project.last_activity,
             timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
-        // Update burn leaderboard after successful project update
+        if goal_reached {
+            emit!(GoalReachedEvent {
+                project_id,
+                goal: project.goal,
+                total: project.burned_amount,
+                timestamp,
+                event_seq: ctx.accounts.program_stats.next_event_seq(),
+            });
+        }
+
+        // Update burn leaderboard after successful project update (capped if burn_cap is set)
         let leaderboard = &mut ctx.accounts.burn_leaderboard;
-        let total_burned = project.burned_amount;
+        let total_burned = project.leaderboard_amount();
         let entered_leaderboard = leaderboard.update_leaderboard(project_id, total_burned)?;
 
         if entered_leaderboard {
@@ -684,535 +1276,4196 @@ pub mod memo_project {
         Ok(())
     }
 
-    /// Initialize the burn leaderboard (one-time setup, admin only)
-    pub fn initialize_burn_leaderboard(ctx: Context<InitializeBurnLeaderboard>) -> Result<()> {
-        // Verify admin authorization
-        if ctx.accounts.admin.key() != AUTHORIZED_ADMIN_PUBKEY {
-            return Err(ErrorCode::UnauthorizedAdmin.into());
-        }
-
-        let leaderboard = &mut ctx.accounts.burn_leaderboard;
-        leaderboard.initialize(); // Use the initialize method
-        
-        msg!("Burn leaderboard initialized by admin {}", ctx.accounts.admin.key());
-        Ok(())
-    }
-
-    /// Burn tokens for a project (only project creator can burn)
-    pub fn burn_for_project(
-        ctx: Context<BurnForProject>,
+    /// Burn for a project update now but apply it at `apply_at` (same memo, burn and signer
+    /// rules as update_project). The fields wait in the project's PendingUpdate account,
+    /// one scheduled update per project at a time. Projects that require quorum cannot
+    /// schedule updates, since nothing collects the co-owners' signatures at apply time
+    pub fn schedule_project_update(
+        ctx: Context<ScheduleProjectUpdate>,
         project_id: u64,
-        amount: u64,
+        apply_at: i64,
+        burn_amount: u64,
     ) -> Result<()> {
-        // Validate burn amount
-        if amount < MIN_PROJECT_BURN_AMOUNT {
-            return Err(ErrorCode::BurnAmountTooSmall.into());
-        }
+        ProgramConfig::check_operation(&ctx.accounts.program_config, OPERATION_FLAG_UPDATE)?;
+
+        // Validate burn amount against the economy config (defaults to 42069 tokens)
+        let economy = EconomyConfig::load(&ctx.accounts.economy_config)?;
+        economy.check_update_burn(burn_amount)?;
         
-        // Check burn amount limit
-        if amount > MAX_BURN_PER_TX {
+        if burn_amount > MAX_BURN_PER_TX {
             return Err(ErrorCode::BurnAmountTooLarge.into());
         }
         
-        if amount % DECIMAL_FACTOR != 0 {
+        if burn_amount % DECIMAL_FACTOR != 0 {
             return Err(ErrorCode::InvalidBurnAmount.into());
         }
 
-        // Check memo instruction with enhanced validation
+        // Check memo instruction
         let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
         if !memo_found {
             return Err(ErrorCode::MemoRequired.into());
         }
 
-        // Parse and validate Borsh memo content for burn operation
-        parse_project_burn_borsh_memo(&memo_data, project_id, amount, ctx.accounts.burner.key())?;
+        // Scheduled updates apply without co-owner signatures
+        ctx.accounts.project.check_schedulable()?;
 
-        // Call memo-burn contract to burn tokens
+        // The memo uses the update_project payload; its fields are stored, not applied
+        let update_data = parse_project_update_borsh_memo(&memo_data, project_id, burn_amount, &economy)?;
+        let memo_hash = compute_memo_hash(&memo_data);
+        let timestamp = Clock::get()?.unix_timestamp;
+        ctx.accounts.pending_update.schedule(
+            project_id,
+            ctx.accounts.updater.key(),
+            apply_at,
+            timestamp,
+            update_data,
+            memo_hash,
+            ctx.bumps.pending_update,
+        )?;
+        
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.updater_token_account.amount;
+
+        // Call memo-burn contract to burn tokens (the burn counts now, not at apply time)
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
         let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
         let cpi_accounts = ProcessBurn {
-            user: ctx.accounts.burner.to_account_info(),
+            user: ctx.accounts.updater.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
-            token_account: ctx.accounts.burner_token_account.to_account_info(),
+            token_account: ctx.accounts.updater_token_account.to_account_info(),
             user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
             token_program: ctx.accounts.token_program.to_account_info(),
             instructions: ctx.accounts.instructions.to_account_info(),
         };
         
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        
-        // Call memo-burn's process_burn instruction
-        memo_burn::cpi::process_burn(cpi_ctx, amount)?;
-        
-        // Get current timestamp once for consistency and efficiency
-        let timestamp = Clock::get()?.unix_timestamp;
-        
-        // Update project burned amount tracking
+        memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.updater_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.updater_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
+
         let project = &mut ctx.accounts.project;
-        let old_amount = project.burned_amount;
-        project.burned_amount = project.burned_amount.saturating_add(amount);
-        
-        // Update memo count (only burn_for_project operations count as memos)
-        project.memo_count = project.memo_count.saturating_add(1);
-        
-        // Update last memo time (only tracks burn_for_project operations)
-        project.last_memo_time = timestamp;
-        
-        if project.burned_amount == u64::MAX && old_amount < u64::MAX {
-            msg!("Warning: burned_amount overflow detected for project {}", project_id);
+        project.record_update_burn(burn_amount, timestamp);
+        let goal_reached = project.check_goal_reached();
+
+        emit!(ProjectUpdateScheduledEvent {
+            project_id,
+            scheduler: ctx.accounts.updater.key(),
+            apply_at,
+            burn_amount,
+            total_burned: project.burned_amount,
+            timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        if goal_reached {
+            emit!(GoalReachedEvent {
+                project_id,
+                goal: project.goal,
+                total: project.burned_amount,
+                timestamp,
+                event_seq: ctx.accounts.program_stats.next_event_seq(),
+            });
         }
-        
-        msg!("Successfully burned {} tokens for project {}", amount / DECIMAL_FACTOR, project_id);
-        
-        // Update burn leaderboard after successful burn
-        let leaderboard = &mut ctx.accounts.burn_leaderboard;
-        let total_burned = project.burned_amount;
-        let entered_leaderboard = leaderboard.update_leaderboard(project_id, total_burned)?;
 
-        if entered_leaderboard {
-            msg!("Project {} updated in burn leaderboard with total {} tokens", 
-                 project_id, total_burned / DECIMAL_FACTOR);
-        } else {
-            msg!("Project {} total burn amount {} not sufficient for leaderboard", 
-                 project_id, total_burned / DECIMAL_FACTOR);
+        // Update burn leaderboard with the scheduling burn (capped if burn_cap is set)
+        let total_burned = project.leaderboard_amount();
+        ctx.accounts.burn_leaderboard.update_leaderboard(project_id, total_burned)?;
+
+        msg!("Project {} update scheduled for {} by {} with {} tokens burned", 
+             project_id, apply_at, ctx.accounts.updater.key(), burn_amount / DECIMAL_FACTOR);
+        Ok(())
+    }
+
+    /// Apply a project's scheduled update once its apply_at has passed (callable by anyone).
+    /// Closes the PendingUpdate account, returning its rent to the scheduler. Tags the update
+    /// adds are recorded from the remaining accounts, paid by the caller (see record_added_tags).
+    /// If the scheduler may no longer update the project, or the project has started requiring
+    /// quorum, the update is discarded unapplied
+    pub fn apply_scheduled_update<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyScheduledUpdate<'info>>,
+        project_id: u64,
+    ) -> Result<()> {
+        ProgramConfig::check_operation(&ctx.accounts.program_config, OPERATION_FLAG_UPDATE)?;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let pending_update = &ctx.accounts.pending_update;
+        if !pending_update.is_authorized(&ctx.accounts.project) {
+            // Authority or quorum mode changed since scheduling: close the account without
+            // applying, so the stale update neither lands nor blocks new schedules
+            msg!("Scheduled update of project {} discarded: {} may no longer update it without quorum", 
+                 project_id, pending_update.scheduler);
+            return Ok(());
         }
+        pending_update.check_due(timestamp)?;
 
-        // Emit burn event
-        emit!(TokensBurnedForProjectEvent {
+        let update_data = pending_update.to_update_data();
+        let added_tags = ctx.accounts.project.added_tags(update_data.tags.as_deref());
+        record_added_tags(
+            &added_tags,
             project_id,
-            burner: ctx.accounts.burner.key(),
-            amount,
+            ctx.remaining_accounts,
+            &ctx.accounts.caller.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        let pending_update = &ctx.accounts.pending_update;
+        let project = &mut ctx.accounts.project;
+        project.apply_update(update_data);
+        project.last_updated = timestamp;
+        project.last_activity = timestamp;
+
+        // Same event as update_project; the burn was already reported when scheduling
+        emit!(ProjectUpdatedEvent {
+            project_id,
+            updater: pending_update.scheduler,
+            name: project.name.clone(),
+            name_lower: project.name_lower.clone(),
+            description: project.description.clone(),
+            image: project.image.clone(),
+            website: project.website.clone(),
+            tags: project.tags.clone(),
+            burn_amount: 0,
             total_burned: project.burned_amount,
+            last_activity: project.last_activity,
             timestamp,
+            memo_hash: pending_update.memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
         });
 
+        msg!("Scheduled update of project {} (due {}) applied by {}", 
+             project_id, pending_update.apply_at, ctx.accounts.caller.key());
         Ok(())
     }
-}
 
-/// Parse and validate Borsh-formatted memo data for project creation (with Base64 decoding)
-fn parse_project_creation_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_amount: u64) -> Result<ProjectCreationData> {
-    // First, decode the Base64-encoded memo data
-    let base64_str = std::str::from_utf8(memo_data)
-        .map_err(|_| {
-            msg!("Invalid UTF-8 in memo data");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    let decoded_data = general_purpose::STANDARD.decode(base64_str)
-        .map_err(|_| {
-            msg!("Invalid Base64 encoding in memo");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    // check decoded borsh data size
-    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
-        msg!("Decoded data too large: {} bytes (max: {})", decoded_data.len(), MAX_BORSH_DATA_SIZE);
-        return Err(ErrorCode::InvalidMemoFormat.into());
-    }
-    
-    msg!("Base64 decoded: {} bytes -> {} bytes", memo_data.len(), decoded_data.len());
-    
-    // Deserialize Borsh data from decoded bytes (following memo-burn pattern)
-    let burn_memo = BurnMemo::try_from_slice(&decoded_data)
-        .map_err(|_| {
-            msg!("Invalid Borsh format after Base64 decoding");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    // Validate version compatibility
-    if burn_memo.version != BURN_MEMO_VERSION {
-        msg!("Unsupported memo version: {} (expected: {})", 
-             burn_memo.version, BURN_MEMO_VERSION);
-        return Err(ErrorCode::UnsupportedMemoVersion.into());
+    /// Initialize the burn leaderboard (one-time setup, admin only)
+    pub fn initialize_burn_leaderboard(ctx: Context<InitializeBurnLeaderboard>) -> Result<()> {
+        // Verify admin authorization
+        if ctx.accounts.admin.key() != AUTHORIZED_ADMIN_PUBKEY {
+            return Err(ErrorCode::UnauthorizedAdmin.into());
+        }
+
+        let leaderboard = &mut ctx.accounts.burn_leaderboard;
+        leaderboard.initialize(); // Use the initialize method
+        
+        msg!("Burn leaderboard initialized by admin {}", ctx.accounts.admin.key());
+        Ok(())
     }
-    
-    // Validate burn amount matches
-    if burn_memo.burn_amount != expected_amount {
-        msg!("Burn amount mismatch: memo {} vs expected {}", 
-             burn_memo.burn_amount, expected_amount);
-        return Err(ErrorCode::BurnAmountMismatch.into());
+
+    /// Grow the burn leaderboard to hold up to `new_capacity` entries (admin only, max 500)
+    pub fn resize_leaderboard(ctx: Context<ResizeLeaderboard>, new_capacity: u16) -> Result<()> {
+        let leaderboard = &mut ctx.accounts.burn_leaderboard;
+        let old_capacity = leaderboard.capacity();
+        leaderboard.resize(new_capacity)?;
+
+        msg!("Burn leaderboard resized from {} to {} entries by admin {}", 
+             old_capacity, new_capacity, ctx.accounts.admin.key());
+        Ok(())
     }
-    
-    // Validate payload length does not exceed maximum allowed value
-    if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
-        msg!("Payload too long: {} bytes (max: {})", 
-             burn_memo.payload.len(), MAX_PAYLOAD_LENGTH);
-        return Err(ErrorCode::PayloadTooLong.into());
+
+    /// Shrink the burn leaderboard to `new_capacity` entries (admin only), refunding the
+    /// freed rent to the admin. Fails while more than `new_capacity` entries are in use.
+    pub fn shrink_leaderboard(ctx: Context<ResizeLeaderboard>, new_capacity: u16) -> Result<()> {
+        let leaderboard = &mut ctx.accounts.burn_leaderboard;
+        let old_capacity = leaderboard.capacity();
+        leaderboard.shrink(new_capacity)?;
+
+        msg!("Burn leaderboard shrunk from {} to {} entries by admin {}", 
+             old_capacity, new_capacity, ctx.accounts.admin.key());
+        Ok(())
     }
-    
-    msg!("Borsh+Base64 memo validation passed: version {}, {} units, payload: {} bytes", 
-         burn_memo.version, expected_amount, burn_memo.payload.len());
-    
-    // Deserialize ProjectCreationData from payload
-    let project_data = ProjectCreationData::try_from_slice(&burn_memo.payload)
-        .map_err(|_| {
-            msg!("Invalid project creation data format in payload");
-            ErrorCode::InvalidProjectDataFormat
-        })?;
-    
-    // Validate the project creation data
-    project_data.validate(expected_project_id)?;
-    
-    msg!("Project creation data parsed successfully: project_id={}, name={}, description_len={}, website_len={}, tags_count={}", 
-         project_data.project_id, project_data.name, project_data.description.len(), 
-         project_data.website.len(), project_data.tags.len());
 
-    Ok(project_data)
-}
+    /// Force-remove a project's burn leaderboard entry regardless of amount (admin multisig).
+    /// Complements the automatic min-entry eviction; an id that is not on the
+    /// leaderboard is a no-op rather than an error.
+    pub fn admin_remove_leaderboard_entry(ctx: Context<AdminRemoveLeaderboardEntry>, project_id: u64) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let signers = instruction_signers(&ctx.accounts.admin, ctx.remaining_accounts);
+        let Some(removed) = ctx.accounts.burn_leaderboard.admin_remove_entry(&ctx.accounts.admin_config, &signers, project_id)? else {
+            msg!("Project {} is not on the burn leaderboard; nothing removed", project_id);
+            return Ok(());
+        };
 
-/// Parse and validate Borsh-formatted memo data for project update (with Base64 decoding)
-fn parse_project_update_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_amount: u64) -> Result<ProjectUpdateData> {
-    // First, decode the Base64-encoded memo data
-    let base64_str = std::str::from_utf8(memo_data)
-        .map_err(|_| {
-            msg!("Invalid UTF-8 in memo data");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    let decoded_data = general_purpose::STANDARD.decode(base64_str)
-        .map_err(|_| {
-            msg!("Invalid Base64 encoding in memo");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    // check decoded borsh data size
-    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
-        msg!("Decoded data too large: {} bytes (max: {})", decoded_data.len(), MAX_BORSH_DATA_SIZE);
-        return Err(ErrorCode::InvalidMemoFormat.into());
+        emit!(LeaderboardEntryRemovedEvent {
+            project_id,
+            admin,
+            burned_amount: removed.burned_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} removed from burn leaderboard by admin {} ({} tokens)", 
+             project_id, admin, removed.burned_amount / DECIMAL_FACTOR);
+        Ok(())
     }
-    
-    msg!("Base64 decoded: {} bytes -> {} bytes", memo_data.len(), decoded_data.len());
-    
-    // Deserialize Borsh data from decoded bytes (following memo-burn pattern)
-    let burn_memo = BurnMemo::try_from_slice(&decoded_data)
-        .map_err(|_| {
-            msg!("Invalid Borsh format after Base64 decoding");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    // Validate version compatibility
-    if burn_memo.version != BURN_MEMO_VERSION {
-        msg!("Unsupported memo version: {} (expected: {})", 
-             burn_memo.version, BURN_MEMO_VERSION);
-        return Err(ErrorCode::UnsupportedMemoVersion.into());
+
+    /// Pin a project on the burn leaderboard so it is never evicted, e.g. to feature
+    /// official projects (admin only, max BurnLeaderboard::MAX_PINNED pinned entries).
+    /// A project that is not on the leaderboard yet is added with its current amount.
+    pub fn pin_project(ctx: Context<PinProject>, project_id: u64) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let burned_amount = ctx.accounts.project.leaderboard_amount();
+        ctx.accounts.burn_leaderboard.pin_entry(admin, project_id, burned_amount)?;
+
+        emit!(ProjectPinnedEvent {
+            project_id,
+            admin,
+            burned_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} pinned on burn leaderboard by admin {}", project_id, admin);
+        Ok(())
     }
-    
-    // Validate burn amount matches
-    if burn_memo.burn_amount != expected_amount {
-        msg!("Burn amount mismatch: memo {} vs expected {}", 
-             burn_memo.burn_amount, expected_amount);
-        return Err(ErrorCode::BurnAmountMismatch.into());
+
+    /// Capture the current top leaderboard entries for an epoch (admin only, once per epoch)
+    pub fn snapshot_leaderboard(ctx: Context<SnapshotLeaderboard>, epoch: u64) -> Result<()> {
+        let snapshot = &mut ctx.accounts.snapshot;
+        snapshot.check_unused(epoch)?;
+
+        let captured_at = Clock::get()?.unix_timestamp;
+        **snapshot = LeaderboardSnapshot::capture(
+            epoch,
+            &ctx.accounts.burn_leaderboard,
+            captured_at,
+            ctx.bumps.snapshot,
+        )?;
+
+        emit!(LeaderboardSnapshotEvent {
+            epoch,
+            admin: ctx.accounts.admin.key(),
+            entry_count: snapshot.entries.len() as u8,
+            timestamp: captured_at,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Leaderboard snapshot for epoch {} captured with {} entries", 
+             epoch, snapshot.entries.len());
+        Ok(())
     }
-    
-    // Validate payload length does not exceed maximum allowed value
-    if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
-        msg!("Payload too long: {} bytes (max: {})", 
-             burn_memo.payload.len(), MAX_PAYLOAD_LENGTH);
-        return Err(ErrorCode::PayloadTooLong.into());
+
+    /// Set or clear the verified flag of a project (admin only)
+    pub fn set_project_verified(
+        ctx: Context<SetProjectVerified>,
+        project_id: u64,
+        verified: bool,
+    ) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        ctx.accounts.project.set_verified(admin, verified)?;
+
+        emit!(VerificationChangedEvent {
+            project_id,
+            admin,
+            verified,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} verified flag set to {} by admin {}", project_id, verified, admin);
+        Ok(())
+    }
+
+    /// Freeze or unfreeze burns on a project under dispute (admin only, not the creator).
+    /// While frozen, burn_for_project and boost_project fail; reads are unaffected
+    pub fn admin_freeze_project_burns(
+        ctx: Context<AdminFreezeProjectBurns>,
+        project_id: u64,
+        frozen: bool,
+    ) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        ctx.accounts.project.set_burns_frozen(admin, frozen)?;
+
+        emit!(ProjectBurnsFrozenEvent {
+            project_id,
+            admin,
+            frozen,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} burns frozen set to {} by admin {}", project_id, frozen, admin);
+        Ok(())
+    }
+
+    /// Break-glass correction of a project's burned_amount (admin only).
+    /// Resets burned_amount to an audited value (e.g. after it was clamped at u64::MAX)
+    /// and re-runs the leaderboard update with the corrected amount.
+    pub fn recompute_project_burns(
+        ctx: Context<RecomputeProjectBurns>,
+        project_id: u64,
+        correct_value: u64,
+    ) -> Result<()> {
+        let admin = ctx.accounts.admin.key();
+        let project = &mut ctx.accounts.project;
+        let old_amount = project.correct_burned_amount(admin, correct_value)?;
+
+        let leaderboard_amount = project.leaderboard_amount();
+        let in_leaderboard = ctx.accounts.burn_leaderboard.update_leaderboard(project_id, leaderboard_amount)?;
+
+        emit!(BurnAmountCorrectedEvent {
+            project_id,
+            admin,
+            old_amount,
+            new_amount: correct_value,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} burned_amount corrected from {} to {} by admin {} (in leaderboard: {})", 
+             project_id, old_amount, correct_value, admin, in_leaderboard);
+        Ok(())
+    }
+
+    /// Set or clear the delegate allowed to update project metadata (creator only)
+    pub fn set_project_delegate(
+        ctx: Context<SetProjectDelegate>,
+        project_id: u64,
+        delegate: Option<Pubkey>,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator.key();
+        let old_delegate = ctx.accounts.project.set_delegate(creator, delegate)?;
+        let timestamp = Clock::get()?.unix_timestamp;
+        ctx.accounts.project.last_activity = timestamp;
+
+        emit!(DelegateChangedEvent {
+            project_id,
+            creator,
+            old_delegate,
+            new_delegate: delegate,
+            timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} delegate set to {:?} by creator {}", project_id, delegate, creator);
+        Ok(())
+    }
+
+    /// Set the co-owners of a shared project and whether updates need their quorum (creator only).
+    /// With quorum required, update_project must carry a majority of co-owners as signers
+    /// in its remaining accounts, and so must this instruction: the creator alone cannot
+    /// replace the co-owners or switch quorum off.
+    pub fn set_co_owners(
+        ctx: Context<SetCoOwners>,
+        project_id: u64,
+        co_owners: Vec<Pubkey>,
+        require_quorum: bool,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator.key();
+        let signers = instruction_signers(&ctx.accounts.creator, ctx.remaining_accounts);
+        ctx.accounts.project.check_quorum(&signers)?;
+        ctx.accounts.project.set_co_owners(creator, co_owners.clone(), require_quorum)?;
+        let timestamp = Clock::get()?.unix_timestamp;
+        ctx.accounts.project.last_activity = timestamp;
+
+        emit!(CoOwnersChangedEvent {
+            project_id,
+            creator,
+            co_owners,
+            require_quorum,
+            timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} co-owners updated by creator {} (quorum required: {})", 
+             project_id, creator, require_quorum);
+        Ok(())
+    }
+
+    /// Claim a globally unique slug for a project (creator only, once per project).
+    /// The ProjectSlug PDA seeded by the slug maps it back to the project id
+    pub fn set_project_slug(
+        ctx: Context<SetProjectSlug>,
+        project_id: u64,
+        slug: String,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        ctx.accounts.project.set_slug(creator, slug.clone())?;
+        ctx.accounts.project_slug.claim(project_id, timestamp, ctx.bumps.project_slug)?;
+        ctx.accounts.project.last_activity = timestamp;
+
+        emit!(ProjectSlugSetEvent {
+            project_id,
+            creator,
+            slug: slug.clone(),
+            timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} slug set to '{}' by creator {}", project_id, slug, creator);
+        Ok(())
+    }
+
+    /// Endorse another creator's project. The signer proves they are an established creator by
+    /// passing a project they own; each endorser can endorse a target once and never their own project
+    pub fn endorse_project(
+        ctx: Context<EndorseProject>,
+        target_project_id: u64,
+        endorser_project_id: u64,
+    ) -> Result<()> {
+        let endorser = ctx.accounts.endorser.key();
+        let timestamp = Clock::get()?.unix_timestamp;
+        let endorsement_count = ctx.accounts.target_project.add_endorsement(endorser)?;
+        ctx.accounts.endorsement.claim(
+            target_project_id,
+            endorser,
+            endorser_project_id,
+            timestamp,
+            ctx.bumps.endorsement,
+        )?;
+
+        emit!(ProjectEndorsedEvent {
+            target_project_id,
+            endorser,
+            endorser_project_id,
+            endorsement_count,
+            timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Project {} endorsed by {} (creator of project {}), {} endorsements", 
+             target_project_id, endorser, endorser_project_id, endorsement_count);
+        Ok(())
+    }
+
+    /// Create or update the economy config holding minimum burn amounts and tag limits (admin only)
+    pub fn set_economy_config(
+        ctx: Context<SetEconomyConfig>,
+        min_create_burn: u64,
+        min_update_burn: u64,
+        min_burn: u64,
+        max_tags: u8,
+        max_tag_length: u8,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.economy_config;
+        config.min_create_burn = min_create_burn;
+        config.min_update_burn = min_update_burn;
+        config.min_burn = min_burn;
+        config.max_tags = max_tags;
+        config.max_tag_length = max_tag_length;
+        config.validate()?;
+        config.bump = ctx.bumps.economy_config;
+
+        emit!(EconomyConfigUpdatedEvent {
+            admin: ctx.accounts.admin.key(),
+            min_create_burn,
+            min_update_burn,
+            min_burn,
+            max_tags,
+            max_tag_length,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Economy config set: create {} / update {} / burn {} tokens, {} tags of {} characters", 
+             min_create_burn / DECIMAL_FACTOR, min_update_burn / DECIMAL_FACTOR, min_burn / DECIMAL_FACTOR,
+             max_tags, max_tag_length);
+        Ok(())
+    }
+
+    /// Create the admin multisig seeded with AUTHORIZED_ADMIN_PUBKEY as its only signer (1-of-1).
+    /// Sensitive admin operations check the AdminConfig threshold from then on
+    pub fn initialize_admin_config(ctx: Context<InitializeAdminConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.admin_config;
+        config.signers = vec![AUTHORIZED_ADMIN_PUBKEY];
+        config.threshold = 1;
+        config.bump = ctx.bumps.admin_config;
+
+        emit!(AdminConfigUpdatedEvent {
+            signers: config.signers.clone(),
+            threshold: config.threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Admin config initialized with {} as the only signer", AUTHORIZED_ADMIN_PUBKEY);
+        Ok(())
+    }
+
+    /// Replace the admin signer set and threshold (max MAX_ADMIN_SIGNERS signers). Needs the
+    /// current threshold of signatures: the caller plus other signers passed as remaining accounts
+    pub fn set_admin_signers(ctx: Context<SetAdminSigners>, new_signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        let signers = instruction_signers(&ctx.accounts.admin, ctx.remaining_accounts);
+        let config = &mut ctx.accounts.admin_config;
+        config.check_threshold(&signers)?;
+        AdminConfig::validate(&new_signers, threshold)?;
+
+        config.signers = new_signers;
+        config.threshold = threshold;
+
+        emit!(AdminConfigUpdatedEvent {
+            signers: config.signers.clone(),
+            threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Admin config set to {}-of-{}", threshold, config.signers.len());
+        Ok(())
+    }
+
+    /// Create or update the operation switches (OPERATION_FLAG_*) for phased rollouts (admin multisig).
+    /// A cleared bit makes the matching instructions fail with OperationDisabled
+    pub fn set_operation_flags(ctx: Context<SetOperationFlags>, operation_flags: u8) -> Result<()> {
+        let signers = instruction_signers(&ctx.accounts.admin, ctx.remaining_accounts);
+        ctx.accounts.admin_config.check_threshold(&signers)?;
+
+        if operation_flags & !OPERATION_FLAGS_MASK != 0 {
+            msg!("Invalid operation flags: {:#010b} (allowed bits: {:#010b})", operation_flags, OPERATION_FLAGS_MASK);
+            return Err(ErrorCode::InvalidOperationFlags.into());
+        }
+
+        let config = &mut ctx.accounts.program_config;
+        config.operation_flags = operation_flags;
+        config.bump = ctx.bumps.program_config;
+
+        emit!(OperationFlagsUpdatedEvent {
+            admin: ctx.accounts.admin.key(),
+            operation_flags,
+            timestamp: Clock::get()?.unix_timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        msg!("Operation flags set to {:#010b}", operation_flags);
+        Ok(())
+    }
+
+    /// Burn tokens for a project (only project creator can burn)
+    pub fn burn_for_project(
+        ctx: Context<BurnForProject>,
+        project_id: u64,
+        amount: u64,
+        receipt_nonce: Option<u64>, // Writes a BurnReceipt when set (costs its rent)
+    ) -> Result<()> {
+        ProgramConfig::check_operation(&ctx.accounts.program_config, OPERATION_FLAG_BURN)?;
+
+        // Validate burn amount against the economy config (defaults to 420 tokens)
+        let economy = EconomyConfig::load(&ctx.accounts.economy_config)?;
+        economy.check_burn(amount)?;
+        
+        // Check burn amount limit
+        if amount > MAX_BURN_PER_TX {
+            return Err(ErrorCode::BurnAmountTooLarge.into());
+        }
+        
+        if amount % DECIMAL_FACTOR != 0 {
+            return Err(ErrorCode::InvalidBurnAmount.into());
+        }
+
+        // Check memo instruction with enhanced validation
+        let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
+        if !memo_found {
+            return Err(ErrorCode::MemoRequired.into());
+        }
+
+        // Reject burns while the admin has frozen the project or its optional burn cap has been reached
+        ctx.accounts.project.check_burns_not_frozen()?;
+        ctx.accounts.project.check_burn_cap()?;
+        ctx.accounts.project.check_recent_burners(ctx.accounts.recent_burners.is_some())?;
+
+        // Parse and validate Borsh memo content for burn operation
+        let burn_data = parse_project_burn_borsh_memo(&memo_data, project_id, amount, ctx.accounts.burner.key())?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // A donor message must land on the supporter wall, so it needs the (extended) board
+        let board_len = ctx.accounts.message_board.as_ref().map(|board| board.to_account_info().data_len());
+        ProjectMessageBoard::check_for_message(board_len, &burn_data.message)?;
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.burner_token_account.amount;
+
+        // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
+        let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
+        let cpi_accounts = ProcessBurn {
+            user: ctx.accounts.burner.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            token_account: ctx.accounts.burner_token_account.to_account_info(),
+            user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            instructions: ctx.accounts.instructions.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        
+        // Call memo-burn's process_burn instruction
+        memo_burn::cpi::process_burn(cpi_ctx, amount)?;
+        ctx.accounts.burner_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.burner_token_account.amount, amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(amount);
+        
+        // Get current timestamp once for consistency and efficiency
+        let timestamp = Clock::get()?.unix_timestamp;
+        
+        // Update project burned amount tracking
+        let project = &mut ctx.accounts.project;
+        let old_amount = project.burned_amount;
+        
+        // Update burned amount, memo count and last memo time
+        // (only burn_for_project operations count as memos)
+        project.record_burn(amount, timestamp);
+        let goal_reached = project.check_goal_reached();
+        
+        if project.burned_amount == u64::MAX && old_amount < u64::MAX {
+            msg!("Warning: burned_amount overflow detected for project {}", project_id);
+        }
+
+        // Write the opt-in burn receipt; reusing a nonce fails with DuplicateReceiptNonce
+        match (receipt_nonce, ctx.accounts.burn_receipt.as_mut()) {
+            (Some(_), Some(receipt)) => {
+                let bump = ctx.bumps.burn_receipt.unwrap_or_default();
+                receipt.record(ctx.accounts.burner.key(), amount, project_id, timestamp, bump)?;
+            }
+            (None, None) => {}
+            _ => return Err(ErrorCode::BurnReceiptAccountMismatch.into()),
+        }
+
+        // Append the donor's message to the project's supporter wall
+        if let Some(message_board) = ctx.accounts.message_board.as_mut() {
+            message_board.add_record(BoardRecord::new(
+                ctx.accounts.burner.key(),
+                amount,
+                &burn_data.message,
+                timestamp,
+            ));
+        }
+
+        if let Some(recent_burners) = ctx.accounts.recent_burners.as_mut() {
+            recent_burners.add_burner(RecentBurner {
+                burner: ctx.accounts.burner.key(),
+                amount,
+                timestamp,
+            });
+        }
+        
+        msg!("Successfully burned {} tokens for project {}", amount / DECIMAL_FACTOR, project_id);
+        
+        // Update burn leaderboard after successful burn (capped if burn_cap is set)
+        let leaderboard = &mut ctx.accounts.burn_leaderboard;
+        let total_burned = project.leaderboard_amount();
+        let entered_leaderboard = leaderboard.update_leaderboard(project_id, total_burned)?;
+
+        if entered_leaderboard {
+            msg!("Project {} updated in burn leaderboard with total {} tokens", 
+                 project_id, total_burned / DECIMAL_FACTOR);
+        } else {
+            msg!("Project {} total burn amount {} not sufficient for leaderboard", 
+                 project_id, total_burned / DECIMAL_FACTOR);
+        }
+
+        // Emit burn event
+        emit!(TokensBurnedForProjectEvent {
+            project_id,
+            burner: ctx.accounts.burner.key(),
+            amount,
+            total_burned: project.burned_amount,
+            burns_last_24h: project.burns_last_24h,
+            last_activity: project.last_activity,
+            histogram_bucket: burn_histogram_bucket(amount),
+            ref_post_id: burn_data.ref_post_id,
+            ref_sig: burn_data.ref_sig,
+            timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        if goal_reached {
+            emit!(GoalReachedEvent {
+                project_id,
+                goal: project.goal,
+                total: project.burned_amount,
+                timestamp,
+                event_seq: ctx.accounts.program_stats.next_event_seq(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Burn tokens to boost a project's trending score for a window proportional to the burn
+    /// (only the project creator can boost; boost burns are not added to burned_amount)
+    pub fn boost_project(
+        ctx: Context<BoostProject>,
+        project_id: u64,
+        burn_amount: u64,
+    ) -> Result<()> {
+        ProgramConfig::check_operation(&ctx.accounts.program_config, OPERATION_FLAG_SUPPORT)?;
+
+        // Validate burn amount against the economy config (same minimum as burn_for_project)
+        let economy = EconomyConfig::load(&ctx.accounts.economy_config)?;
+        economy.check_burn(burn_amount)?;
+        
+        if burn_amount > MAX_BURN_PER_TX {
+            return Err(ErrorCode::BurnAmountTooLarge.into());
+        }
+        
+        if burn_amount % DECIMAL_FACTOR != 0 {
+            return Err(ErrorCode::InvalidBurnAmount.into());
+        }
+
+        // Check memo instruction with enhanced validation
+        let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
+        if !memo_found {
+            return Err(ErrorCode::MemoRequired.into());
+        }
+
+        ctx.accounts.project.check_burns_not_frozen()?;
+        ctx.accounts.project.check_recent_burners(ctx.accounts.recent_burners.is_some())?;
+
+        parse_project_boost_borsh_memo(&memo_data, project_id, burn_amount, ctx.accounts.creator.key())?;
+        let memo_hash = compute_memo_hash(&memo_data);
+
+        // Balance before burning, checked against the balance after the CPI
+        let balance_before_burn = ctx.accounts.creator_token_account.amount;
+
+        // Call memo-burn contract to burn tokens
+        verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
+        let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
+        let cpi_accounts = ProcessBurn {
+            user: ctx.accounts.creator.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            token_account: ctx.accounts.creator_token_account.to_account_info(),
+            user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            instructions: ctx.accounts.instructions.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        
+        memo_burn::cpi::process_burn(cpi_ctx, burn_amount)?;
+        ctx.accounts.creator_token_account.reload()?;
+        check_burn_reflected(balance_before_burn, ctx.accounts.creator_token_account.amount, burn_amount)?;
+
+        // Track program-wide burn total
+        ctx.accounts.program_stats.record_burn(burn_amount);
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let project = &mut ctx.accounts.project;
+        project.apply_boost(burn_amount, timestamp);
+        let histogram_bucket = project.record_histogram(burn_amount);
+        project.record_active_day(timestamp);
+
+        if let Some(recent_burners) = ctx.accounts.recent_burners.as_mut() {
+            recent_burners.add_burner(RecentBurner {
+                burner: ctx.accounts.creator.key(),
+                amount: burn_amount,
+                timestamp,
+            });
+        }
+
+        msg!("Project {} boosted x{} until {} ({} tokens burned)", 
+             project_id, project.boost_multiplier, project.boost_until, burn_amount / DECIMAL_FACTOR);
+
+        emit!(ProjectBoostedEvent {
+            project_id,
+            creator: ctx.accounts.creator.key(),
+            amount: burn_amount,
+            boost_multiplier: project.boost_multiplier,
+            boost_until: project.boost_until,
+            last_activity: project.last_activity,
+            histogram_bucket,
+            timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+
+        Ok(())
+    }
+
+    /// Validate the memo at index 0 without touching any state (intended for simulation).
+    /// The result is returned via return data as a Borsh-encoded MemoValidationStatus
+    /// instead of failing the transaction, so clients can inspect why a memo is rejected.
+    pub fn validate_memo(ctx: Context<ValidateMemo>, expected_amount: u64) -> Result<()> {
+        let status = match check_memo_instruction(&ctx.accounts.instructions) {
+            Ok((true, memo_data)) => classify_burn_memo(&memo_data, expected_amount),
+            Ok((false, _)) => MemoValidationStatus::MemoMissing,
+            Err(_) => MemoValidationStatus::InvalidMemoLength,
+        };
+
+        msg!("Memo validation status: {:?}", status);
+        set_return_data(&status.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Return aggregate counts (total projects, leaderboard size and minimum) via return data
+    pub fn get_global_stats(ctx: Context<GetGlobalStats>) -> Result<()> {
+        ctx.accounts.burn_leaderboard.check_integrity()?;
+        let stats = GlobalStats::new(&ctx.accounts.global_counter, &ctx.accounts.burn_leaderboard);
+
+        msg!("Global stats: {} projects, {} leaderboard entries (min {})", 
+             stats.total_projects, stats.leaderboard_size, stats.leaderboard_min);
+        set_return_data(&stats.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Return the leaderboard entry bar (minimum burned amount and whether the board is full)
+    /// via return data, so clients can check before burning whether an amount would enter
+    pub fn get_leaderboard_min(ctx: Context<GetLeaderboardMin>) -> Result<()> {
+        ctx.accounts.burn_leaderboard.check_integrity()?;
+        let bar = LeaderboardMin::new(&ctx.accounts.burn_leaderboard);
+
+        msg!("Leaderboard min: {} (full: {})", bar.min_burned_amount, bar.is_full);
+        set_return_data(&bar.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Return a project's metadata together with its leaderboard rank and current trend score
+    /// via return data (Borsh-encoded ProjectWithRank), saving clients a separate leaderboard fetch
+    pub fn get_project_with_rank(ctx: Context<GetProjectWithRank>, project_id: u64) -> Result<()> {
+        ctx.accounts.burn_leaderboard.check_integrity()?;
+        let now = Clock::get()?.unix_timestamp;
+        let result = ProjectWithRank::new(&ctx.accounts.project, &ctx.accounts.burn_leaderboard, now);
+
+        match result.rank {
+            Some(rank) => msg!("Project {} ranked {} of {}", project_id, rank, result.total_entries),
+            None => msg!("Project {} not on the leaderboard ({} entries)", project_id, result.total_entries),
+        }
+        msg!("Project {} trend score: {}", project_id, result.trend_score);
+        set_return_data(&result.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Dry-run space calculator: validate the given field lengths and return the project
+    /// account size create_project allocates for them (always calculate_space_max) and its
+    /// rent-exempt minimum via return data (Borsh-encoded RequiredSpace)
+    pub fn calculate_required_space(
+        _ctx: Context<CalculateRequiredSpace>,
+        name_len: u32,
+        desc_len: u32,
+        image_len: u32,
+        website_len: u32,
+        tag_count: u8,
+    ) -> Result<()> {
+        let space = Project::allocated_space(
+            name_len as usize,
+            desc_len as usize,
+            image_len as usize,
+            website_len as usize,
+            tag_count as usize,
+        )?;
+        let required = RequiredSpace::new(space, &Rent::get()?);
+
+        msg!("Required space: {} bytes ({} lamports rent-exempt)", required.space, required.rent_lamports);
+        set_return_data(&required.try_to_vec()?);
+
+        Ok(())
+    }
+
+    /// Convert a project created before burn_cap and the later fields were appended: grow it
+    /// to Project::calculate_space_max (payer covers the extra rent) and rewrite it with the
+    /// appended fields initialized (see Project::from_legacy). Anyone may migrate any project;
+    /// a project that is already current is left untouched
+    pub fn migrate_project(ctx: Context<MigrateProject>, project_id: u64) -> Result<()> {
+        let project_account = ctx.accounts.project.to_account_info();
+        if project_account.owner != ctx.program_id {
+            return Err(ErrorCode::InvalidProjectAccount.into());
+        }
+        if !Project::needs_migration(&project_account.try_borrow_data()?)? {
+            msg!("Project {} is already migrated", project_id);
+            return Ok(());
+        }
+        let project = Project::from_legacy(&project_account.try_borrow_data()?)?;
+
+        let space = Project::calculate_space_max();
+        let top_up = Rent::get()?.minimum_balance(space).saturating_sub(project_account.lamports());
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: project_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        project_account.resize(space)?;
+        project.try_serialize(&mut &mut project_account.try_borrow_mut_data()?[..])?;
+
+        msg!("Project {} migrated to the current layout ({} bytes)", project_id, space);
+        Ok(())
+    }
+}
+
+/// Result of validate_memo, returned to the caller via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoValidationStatus {
+    Ok,
+    MemoMissing,
+    InvalidMemoLength,
+    InvalidUtf8,
+    BadBase64,
+    DataTooLarge,
+    BadBorsh,
+    UnsupportedMemoVersion,
+    AmountMismatch,
+    PayloadTooLong,
+    InvalidCategory,
+    UnknownOperation,
+    InvalidPayload,
+    NonCanonicalBase64,
+}
+
+/// Leading fields shared by every memo-project payload, used to pick the payload type
+#[derive(AnchorDeserialize)]
+struct PayloadHeader {
+    version: u8,
+    category: String,
+    operation: String,
+}
+
+/// Run the full decode + payload parse on raw memo data and report the first failure
+/// as a status code instead of an error (mirrors the parse_*_borsh_memo functions).
+/// Payload checks are self-consistent only: there is no target project or signer to compare against.
+pub fn classify_burn_memo(memo_data: &[u8], expected_amount: u64) -> MemoValidationStatus {
+    let base64_str = match std::str::from_utf8(memo_data) {
+        Ok(s) => s,
+        Err(_) => return MemoValidationStatus::InvalidUtf8,
+    };
+
+    let decoded_data = match decode_canonical_base64(base64_str) {
+        Ok(data) => data,
+        Err(Base64DecodeError::Invalid) => return MemoValidationStatus::BadBase64,
+        Err(Base64DecodeError::NonCanonical) => return MemoValidationStatus::NonCanonicalBase64,
+    };
+
+    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
+        return MemoValidationStatus::DataTooLarge;
+    }
+
+    let burn_memo = match BurnMemo::try_from_slice(&decoded_data) {
+        Ok(memo) => memo,
+        Err(_) => return MemoValidationStatus::BadBorsh,
+    };
+
+    if burn_memo.version != BURN_MEMO_VERSION {
+        return MemoValidationStatus::UnsupportedMemoVersion;
+    }
+
+    if burn_memo.burn_amount != expected_amount {
+        return MemoValidationStatus::AmountMismatch;
+    }
+
+    if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
+        return MemoValidationStatus::PayloadTooLong;
+    }
+
+    // Read only the common prefix; the full payload is parsed below by operation
+    let header = match PayloadHeader::deserialize(&mut &burn_memo.payload[..]) {
+        Ok(header) => header,
+        Err(_) => return MemoValidationStatus::InvalidPayload,
+    };
+
+    if header.category != EXPECTED_CATEGORY {
+        return MemoValidationStatus::InvalidCategory;
+    }
+
+    let payload_valid = match header.operation.as_str() {
+        EXPECTED_OPERATION => ProjectCreationData::try_from_slice(&burn_memo.payload)
+            .map(|data| data.validate(data.project_id).is_ok())
+            .unwrap_or(false),
+        EXPECTED_UPDATE_OPERATION => ProjectUpdateData::try_from_slice(&burn_memo.payload)
+            .map(|data| data.validate(data.project_id).is_ok())
+            .unwrap_or(false),
+        EXPECTED_BURN_FOR_PROJECT_OPERATION => ProjectBurnData::try_from_slice(&burn_memo.payload)
+            .map(|data| match Pubkey::from_str(&data.burner) {
+                Ok(burner) => data.validate(data.project_id, burner).is_ok(),
+                Err(_) => false,
+            })
+            .unwrap_or(false),
+        EXPECTED_FORK_PROJECT_OPERATION => ProjectForkData::try_from_slice(&burn_memo.payload)
+            .map(|data| data.validate(data.project_id, data.source_project_id).is_ok())
+            .unwrap_or(false),
+        _ => return MemoValidationStatus::UnknownOperation,
+    };
+
+    if !payload_valid {
+        msg!("Payload version {} failed validation", header.version);
+        return MemoValidationStatus::InvalidPayload;
+    }
+
+    MemoValidationStatus::Ok
+}
+
+/// Histogram bucket for a burn of `amount` units (see BURN_HISTOGRAM_BOUNDS_TOKENS)
+pub fn burn_histogram_bucket(amount: u64) -> u8 {
+    let tokens = amount / DECIMAL_FACTOR;
+    BURN_HISTOGRAM_BOUNDS_TOKENS.iter().filter(|bound| tokens >= **bound).count() as u8
+}
+
+/// Attach a new project to its parent: the parent account must be passed exactly when the memo
+/// names one (Anchor has already checked its owner and discriminator). Returns the parent's new child_count.
+fn link_parent_project(parent_project_id: Option<u64>, parent: Option<&mut Project>) -> Result<Option<u64>> {
+    match (parent_project_id, parent) {
+        (Some(parent_project_id), Some(parent)) => parent.add_child(parent_project_id).map(Some),
+        (None, None) => Ok(None),
+        (Some(parent_project_id), None) => {
+            msg!("Parent project {} account not provided", parent_project_id);
+            Err(ErrorCode::ParentProjectNotFound.into())
+        }
+        (None, Some(_)) => Err(ErrorCode::ParentProjectMismatch.into()),
+    }
+}
+
+/// Check that the remaining accounts hold a TagStats PDA for every tag; a tagged project
+/// can't skip its tag counters
+pub fn check_tag_stats_accounts(tag_count: usize, account_count: usize) -> Result<()> {
+    if account_count < tag_count {
+        msg!("Missing TagStats accounts: {} tags but {} remaining accounts", tag_count, account_count);
+        return Err(ErrorCode::MissingTagStatsAccounts.into());
+    }
+    Ok(())
+}
+
+/// Check that the accounts after the TagStats PDAs hold a (tail page, next page)
+/// TagProjectList pair for every tag; a tagged project can't skip the tag index
+pub fn check_tag_project_list_accounts(tag_count: usize, account_count: usize) -> Result<()> {
+    if account_count < tag_count * 2 {
+        msg!("Missing TagProjectList accounts: {} tags need {} accounts, got {}", tag_count, tag_count * 2, account_count);
+        return Err(ErrorCode::MissingTagProjectListAccounts.into());
+    }
+    Ok(())
+}
+
+/// Record a project's tags from the remaining accounts: one TagStats PDA per tag (in tag
+/// order) followed by a (tail page, next page) TagProjectList pair per tag, all required
+/// whenever there are tags.
+fn record_tags<'info>(
+    tags: &[String],
+    project_id: u64,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    check_tag_stats_accounts(tags.len(), remaining_accounts.len())?;
+    let (tag_stats_accounts, tag_list_accounts) = remaining_accounts.split_at(tags.len());
+    check_tag_project_list_accounts(tags.len(), tag_list_accounts.len())?;
+
+    record_tag_usage(tags, tag_stats_accounts, payer, system_program)?;
+    record_tag_projects(tags, project_id, tag_list_accounts, payer, system_program)?;
+
+    Ok(())
+}
+
+/// Record the tags an update adds to a project (see Project::added_tags). Their accounts,
+/// laid out as in record_tags, lead the remaining accounts; co-owner signers follow them.
+fn record_added_tags<'info>(
+    added_tags: &[String],
+    project_id: u64,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let tag_account_count = remaining_accounts.len().min(added_tags.len() * 3);
+    record_tags(added_tags, project_id, &remaining_accounts[..tag_account_count], payer, system_program)
+}
+
+/// Create a TagProjectList page (paid by `payer`) at its PDA and store `list` in it
+fn create_tag_project_list<'info>(
+    list: &TagProjectList,
+    list_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let page_bytes = list.page.to_le_bytes();
+    let bump = [list.bump];
+    let seeds: Vec<&[u8]> = if list.page == 0 {
+        vec![b"tag_list", list.tag.as_bytes(), &bump]
+    } else {
+        vec![b"tag_list", list.tag.as_bytes(), &page_bytes, &bump]
+    };
+    let signer_seeds: &[&[&[u8]]] = &[&seeds];
+
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.clone(),
+                to: list_account.clone(),
+            },
+            signer_seeds,
+        ),
+        Rent::get()?.minimum_balance(TagProjectList::SPACE),
+        TagProjectList::SPACE as u64,
+        &crate::ID,
+    )?;
+
+    list.try_serialize(&mut &mut list_account.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Append `project_id` to the last TagProjectList page of each tag, creating the first page
+/// on first use and rolling over to the next page when the last one is full.
+/// `list_accounts` must hold a (tail page, next page) pair per tag, in the same order as `tags`.
+fn record_tag_projects<'info>(
+    tags: &[String],
+    project_id: u64,
+    list_accounts: &'info [AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if list_accounts.len() != tags.len() * 2 {
+        msg!("Expected {} TagProjectList accounts, got {}", tags.len() * 2, list_accounts.len());
+        return Err(ErrorCode::InvalidTagProjectListAccount.into());
+    }
+
+    for (tag, pair) in tags.iter().zip(list_accounts.chunks(2)) {
+        let (tail_account, next_account) = (&pair[0], &pair[1]);
+
+        if tail_account.data_is_empty() {
+            // First project with this tag: the tail must be page 0
+            let (expected, bump) = TagProjectList::find_address(tag, 0);
+            if tail_account.key() != expected {
+                msg!("TagProjectList account mismatch for tag '{}': {} vs expected {}", tag, tail_account.key(), expected);
+                return Err(ErrorCode::InvalidTagProjectListAccount.into());
+            }
+
+            let mut list = TagProjectList::new(tag.clone(), 0, bump);
+            list.append(project_id)?;
+            create_tag_project_list(&list, tail_account, payer, system_program)?;
+            continue;
+        }
+
+        let mut tail: Account<TagProjectList> = Account::try_from(tail_account)?;
+        if tail.tag != *tag || tail.next.is_some() {
+            msg!("TagProjectList {} is not the last page for tag '{}'", tail_account.key(), tag);
+            return Err(ErrorCode::InvalidTagProjectListAccount.into());
+        }
+
+        if !tail.is_full() {
+            tail.append(project_id)?;
+            tail.exit(&crate::ID)?;
+            continue;
+        }
+
+        let (expected_next, next_bump) = TagProjectList::find_address(tag, tail.page + 1);
+        if next_account.key() != expected_next {
+            msg!("TagProjectList continuation mismatch for tag '{}': {} vs expected {}", tag, next_account.key(), expected_next);
+            return Err(ErrorCode::InvalidTagProjectListAccount.into());
+        }
+
+        let mut next = tail.roll_over(expected_next, next_bump);
+        next.append(project_id)?;
+        create_tag_project_list(&next, next_account, payer, system_program)?;
+        tail.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Increment the TagStats PDA of each tag, creating it (paid by `payer`) on first use.
+/// `tag_accounts` must hold exactly one account per tag, in the same order as `tags`.
+fn record_tag_usage<'info>(
+    tags: &[String],
+    tag_accounts: &'info [AccountInfo<'info>],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if tag_accounts.len() != tags.len() {
+        msg!("Expected {} TagStats accounts, got {}", tags.len(), tag_accounts.len());
+        return Err(ErrorCode::InvalidTagStatsAccount.into());
+    }
+
+    for (tag, tag_account) in tags.iter().zip(tag_accounts) {
+        let (expected, bump) = TagStats::find_address(tag);
+        if tag_account.key() != expected {
+            msg!("TagStats account mismatch for tag '{}': {} vs expected {}", tag, tag_account.key(), expected);
+            return Err(ErrorCode::InvalidTagStatsAccount.into());
+        }
+
+        if tag_account.data_is_empty() {
+            let signer_seeds: &[&[&[u8]]] = &[&[b"tag", tag.as_bytes(), &[bump]]];
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: payer.clone(),
+                        to: tag_account.clone(),
+                    },
+                    signer_seeds,
+                ),
+                Rent::get()?.minimum_balance(TagStats::SPACE),
+                TagStats::SPACE as u64,
+                &crate::ID,
+            )?;
+
+            let mut stats = TagStats {
+                tag: tag.clone(),
+                usage_count: 0,
+                bump,
+            };
+            stats.record_usage();
+            stats.try_serialize(&mut &mut tag_account.try_borrow_mut_data()?[..])?;
+        } else {
+            let mut stats: Account<TagStats> = Account::try_from(tag_account)?;
+            stats.record_usage();
+            stats.exit(&crate::ID)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the project PDA (sized at Project::calculate_space_max, paid by `payer`) and write
+/// `project` into it. Like Anchor's `init`, a PDA that was already sent lamports is topped up,
+/// allocated and assigned instead; callers check Project::check_account_unused first.
+fn create_project_account<'info>(
+    project: &Project,
+    project_account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let space = Project::calculate_space_max();
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let id_bytes = project.project_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"project", id_bytes.as_ref(), &[project.bump]]];
+
+    let current_lamports = project_account.lamports();
+    if current_lamports == 0 {
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: project_account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent_lamports,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        let top_up = rent_lamports.saturating_sub(current_lamports);
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    anchor_lang::system_program::Transfer {
+                        from: payer.clone(),
+                        to: project_account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        anchor_lang::system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Allocate {
+                    account_to_allocate: project_account.clone(),
+                },
+                signer_seeds,
+            ),
+            space as u64,
+        )?;
+        anchor_lang::system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                anchor_lang::system_program::Assign {
+                    account_to_assign: project_account.clone(),
+                },
+                signer_seeds,
+            ),
+            &crate::ID,
+        )?;
+    }
+
+    project.try_serialize(&mut &mut project_account.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Shared logic for create_project / create_and_burn.
+/// `burn_amount` pays for creation; a non-zero `extra_burn` is recorded as the first
+/// burn_for_project burn (memo_count 1) and emits its own TokensBurnedForProjectEvent.
+fn process_create_project<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateProject<'info>>,
+    expected_project_id: u64,
+    burn_amount: u64,
+    extra_burn: u64,
+    burn_cap: u64,
+    idempotency_key: Option<[u8; 16]>,
+) -> Result<()> {
+    ProgramConfig::check_operation(&ctx.accounts.program_config, OPERATION_FLAG_CREATE)?;
+
+    // Validate burn amount against the economy config (defaults to 42069 tokens)
+    let economy = EconomyConfig::load(&ctx.accounts.economy_config)?;
+    economy.check_create_burn(burn_amount)?;
+    
+    // check burn amount limit
+    if burn_amount > MAX_BURN_PER_TX {
+        return Err(ErrorCode::BurnAmountTooLarge.into());
+    }
+    
+    if burn_amount % DECIMAL_FACTOR != 0 {
+        return Err(ErrorCode::InvalidBurnAmount.into());
+    }
+
+    // The extra burn follows the burn_for_project rules, and the combined
+    // amount must still fit in a single process_burn call
+    if extra_burn > 0 {
+        economy.check_burn(extra_burn)?;
+        if !extra_burn.is_multiple_of(DECIMAL_FACTOR) {
+            return Err(ErrorCode::InvalidBurnAmount.into());
+        }
+    }
+    let total_burn = total_create_burn(burn_amount, extra_burn)?;
+
+    // Get current timestamp once for consistency and efficiency
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // A retried submission with the same idempotency key fails with DuplicateRequest
+    match (idempotency_key, ctx.accounts.idempotency_marker.as_mut()) {
+        (Some(key), Some(marker)) => {
+            let bump = ctx.bumps.idempotency_marker.unwrap_or_default();
+            marker.claim(ctx.accounts.creator.key(), key, timestamp, bump)?;
+        }
+        (None, None) => {}
+        _ => return Err(ErrorCode::IdempotencyMarkerMismatch.into()),
+    }
+
+    // Get the next project_id from global counter and verify it matches the expected one
+    let global_counter = &mut ctx.accounts.global_counter;
+    global_counter.check_capacity()?;
+    let actual_project_id = global_counter.check_expected_id(expected_project_id)?;
+
+    // The project PDA must be fresh; an existing one means the counter was reset
+    Project::check_account_unused(&ctx.accounts.project, actual_project_id)?;
+
+    // Enforce per-wallet creation cooldown (first creation is always allowed)
+    let exempt = RateLimitExemption::exempts(ctx.accounts.rate_limit_exemption.as_deref(), ctx.accounts.creator.key());
+    ctx.accounts.creator_cooldown.check(timestamp, exempt)?;
+
+    // Check memo instruction
+    let (memo_found, memo_data) = check_memo_instruction(&ctx.accounts.instructions)?;
+    if !memo_found {
+        return Err(ErrorCode::MemoRequired.into());
+    }
+
+    // Parse and validate Borsh memo data for project creation
+    let project_data = parse_project_creation_borsh_memo(&memo_data, actual_project_id, total_burn, &economy)?;
+    let memo_hash = compute_memo_hash(&memo_data);
+    
+    // Balance before burning, checked against the balance after the CPI
+    let balance_before_burn = ctx.accounts.creator_token_account.amount;
+
+    // Call memo-burn contract to burn tokens
+    verify_burn_stats_account(&ctx.accounts.user_global_burn_stats.to_account_info())?;
+    let cpi_program = ctx.accounts.memo_burn_program.to_account_info();
+    let cpi_accounts = ProcessBurn {
+        user: ctx.accounts.creator.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        token_account: ctx.accounts.creator_token_account.to_account_info(),
+        user_global_burn_stats: ctx.accounts.user_global_burn_stats.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        instructions: ctx.accounts.instructions.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    memo_burn::cpi::process_burn(cpi_ctx, total_burn)?;
+    ctx.accounts.creator_token_account.reload()?;
+    check_burn_reflected(balance_before_burn, ctx.accounts.creator_token_account.amount, total_burn)?;
+
+    // Track program-wide burn total
+    ctx.accounts.program_stats.record_burn(total_burn);
+
+    // A sub-project requires its parent's Project account, which gains a child
+    let parent_child_count = link_parent_project(
+        project_data.parent_project_id,
+        ctx.accounts.parent_project.as_deref_mut(),
+    )?;
+
+    // Count tag usage and index the project by tag
+    record_tags(
+        &project_data.tags,
+        actual_project_id,
+        ctx.remaining_accounts,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    
+    // Create the project after successful burn
+    let mut project = Project::new(actual_project_id, ctx.accounts.creator.key(), timestamp, ctx.bumps.project);
+    project.name = project_data.name.clone();
+    project.description = project_data.description.clone();
+    project.image = project_data.image.clone();
+    project.website = project_data.website.clone();
+    project.tags = project_data.tags.clone();
+    project.burned_amount = burn_amount;
+    project.burn_cap = burn_cap;
+    project.goal = project_data.goal;
+    project.name_lower = lowercase_name(&project_data.name);
+    project.parent_project_id = project_data.parent_project_id;
+    if extra_burn > 0 {
+        project.record_burn(extra_burn, timestamp);
+    }
+    let goal_reached = project.check_goal_reached();
+    create_project_account(
+        &project,
+        &ctx.accounts.project,
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    let leaderboard_amount = project.leaderboard_amount();
+    let total_burned = project.burned_amount;
+    let burns_last_24h = project.burns_last_24h;
+
+    // Increment global counter AFTER successful project creation
+    global_counter.total_projects = global_counter.total_projects.checked_add(1)
+        .ok_or(ErrorCode::ProjectCounterOverflow)?;
+
+    // Record this creation for the creator's cooldown
+    let creator_cooldown = &mut ctx.accounts.creator_cooldown;
+    creator_cooldown.creator = ctx.accounts.creator.key();
+    creator_cooldown.last_created = timestamp;
+    creator_cooldown.bump = ctx.bumps.creator_cooldown;
+
+    // Emit project creation event
+    emit!(ProjectCreatedEvent {
+        project_id: actual_project_id,
+        creator: ctx.accounts.creator.key(),
+        name_lower: lowercase_name(&project_data.name),
+        name: project_data.name,
+        description: project_data.description,
+        image: project_data.image,
+        website: project_data.website,
+        tags: project_data.tags,
+        burn_amount,
+        burn_cap,
+        goal: project_data.goal,
+        timestamp,
+        memo_hash,
+        event_seq: ctx.accounts.program_stats.next_event_seq(),
+    });
+
+    if let (Some(parent_project_id), Some(child_count)) = (project_data.parent_project_id, parent_child_count) {
+        emit!(SubProjectCreatedEvent {
+            parent_project_id,
+            project_id: actual_project_id,
+            child_count,
+            timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+    }
+
+    if extra_burn > 0 {
+        emit!(TokensBurnedForProjectEvent {
+            project_id: actual_project_id,
+            burner: ctx.accounts.creator.key(),
+            amount: extra_burn,
+            total_burned,
+            burns_last_24h,
+            last_activity: timestamp,
+            histogram_bucket: burn_histogram_bucket(extra_burn),
+            ref_post_id: None,
+            ref_sig: None,
+            timestamp,
+            memo_hash,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+    }
+
+    if goal_reached {
+        emit!(GoalReachedEvent {
+            project_id: actual_project_id,
+            goal: project_data.goal,
+            total: total_burned,
+            timestamp,
+            event_seq: ctx.accounts.program_stats.next_event_seq(),
+        });
+    }
+
+    // Update burn leaderboard after successful project creation
+    let leaderboard = &mut ctx.accounts.burn_leaderboard;
+    let entered_leaderboard = leaderboard.update_leaderboard(actual_project_id, leaderboard_amount)?;
+
+    if entered_leaderboard {
+        msg!("Project {} entered burn leaderboard", actual_project_id);
+    } else {
+        msg!("Project {} burn amount {} not sufficient for leaderboard", 
+             actual_project_id, burn_amount / DECIMAL_FACTOR);
+    }
+
+    msg!("Project {} created successfully by {} with {} tokens burned", 
+         actual_project_id, ctx.accounts.creator.key(), total_burn / DECIMAL_FACTOR);
+    Ok(())
+}
+
+/// Combined burn for create_and_burn (must stay within MAX_BURN_PER_TX)
+fn total_create_burn(create_burn: u64, extra_burn: u64) -> Result<u64> {
+    match create_burn.checked_add(extra_burn) {
+        Some(total) if total <= MAX_BURN_PER_TX => Ok(total),
+        _ => Err(ErrorCode::BurnAmountTooLarge.into()),
+    }
+}
+
+/// Project operation a memo is decoded for (selects the log prefix and error variant)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectMemoOperation {
+    Create,
+    Update,
+    Burn,
+    Boost,
+    Fork,
+}
+
+impl ProjectMemoOperation {
+    /// Operation name used in log messages
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProjectMemoOperation::Create => "create_project",
+            ProjectMemoOperation::Update => "update_project",
+            ProjectMemoOperation::Burn => "burn_for_project",
+            ProjectMemoOperation::Boost => "boost_project",
+            ProjectMemoOperation::Fork => "fork_project",
+        }
+    }
+
+    /// Error returned when a memo for this operation cannot be decoded
+    pub fn invalid_memo_error(&self) -> ErrorCode {
+        match self {
+            ProjectMemoOperation::Create => ErrorCode::ProjectCreateMemoInvalid,
+            ProjectMemoOperation::Update => ErrorCode::ProjectUpdateMemoInvalid,
+            ProjectMemoOperation::Burn => ErrorCode::ProjectBurnMemoInvalid,
+            ProjectMemoOperation::Boost => ErrorCode::ProjectBoostMemoInvalid,
+            ProjectMemoOperation::Fork => ErrorCode::ProjectForkMemoInvalid,
+        }
+    }
+}
+
+/// Decode and validate the Base64+Borsh BurnMemo envelope shared by all project operations
+/// Base64 engine that accepts any padding and non-zero trailing bits; only used to tell
+/// non-canonical encodings apart from invalid ones
+const LENIENT_BASE64: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new()
+        .with_decode_allow_trailing_bits(true)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Why a memo string failed canonical Base64 decoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64DecodeError {
+    /// Not Base64 at all
+    Invalid,
+    /// Valid Base64, but not the canonical encoding of its bytes (padding or trailing bits differ)
+    NonCanonical,
+}
+
+/// Decode standard Base64, accepting only the one canonical encoding of the bytes,
+/// so every decoded memo has a single string form (and a single memo_hash)
+pub fn decode_canonical_base64(input: &str) -> std::result::Result<Vec<u8>, Base64DecodeError> {
+    let decoded = LENIENT_BASE64.decode(input).map_err(|_| Base64DecodeError::Invalid)?;
+    if general_purpose::STANDARD.encode(&decoded) != input {
+        return Err(Base64DecodeError::NonCanonical);
+    }
+    Ok(decoded)
+}
+
+/// Read a payload's category (the string after its version byte) without deserializing the
+/// rest; None when the payload is too short to hold one
+pub fn peek_payload_category(payload: &[u8]) -> Option<String> {
+    let mut reader = payload;
+    u8::deserialize_reader(&mut reader).ok()?;
+    String::deserialize_reader(&mut reader).ok()
+}
+
+fn decode_project_burn_memo(memo_data: &[u8], expected_amount: u64, operation: ProjectMemoOperation) -> Result<BurnMemo> {
+    let label = operation.label();
+
+    // First, decode the Base64-encoded memo data
+    let base64_str = std::str::from_utf8(memo_data)
+        .map_err(|_| {
+            msg!("[{}] Invalid UTF-8 in memo data", label);
+            operation.invalid_memo_error()
+        })?;
+    
+    let decoded_data = decode_canonical_base64(base64_str)
+        .map_err(|err| match err {
+            Base64DecodeError::Invalid => {
+                msg!("[{}] Invalid Base64 encoding in memo", label);
+                operation.invalid_memo_error()
+            }
+            Base64DecodeError::NonCanonical => {
+                msg!("[{}] Non-canonical Base64 encoding in memo", label);
+                ErrorCode::NonCanonicalBase64
+            }
+        })?;
+    
+    // check decoded borsh data size
+    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
+        msg!("[{}] Decoded data too large: {} bytes (max: {})", label, decoded_data.len(), MAX_BORSH_DATA_SIZE);
+        return Err(operation.invalid_memo_error().into());
+    }
+    
+    msg!("Base64 decoded: {} bytes -> {} bytes", memo_data.len(), decoded_data.len());
+    
+    // Deserialize Borsh data from decoded bytes (following memo-burn pattern)
+    let burn_memo = BurnMemo::try_from_slice(&decoded_data)
+        .map_err(|_| {
+            msg!("[{}] Invalid Borsh format after Base64 decoding", label);
+            operation.invalid_memo_error()
+        })?;
+    
+    // Validate version compatibility
+    if burn_memo.version != BURN_MEMO_VERSION {
+        msg!("[{}] Unsupported memo version: {} (expected: {})", 
+             label, burn_memo.version, BURN_MEMO_VERSION);
+        return Err(ErrorCode::UnsupportedMemoVersion.into());
+    }
+    
+    // Validate burn amount matches
+    if burn_memo.burn_amount != expected_amount {
+        msg!("[{}] Burn amount mismatch: memo {} vs expected {}", 
+             label, burn_memo.burn_amount, expected_amount);
+        return Err(ErrorCode::BurnAmountMismatch.into());
+    }
+    
+    // Validate payload length does not exceed maximum allowed value
+    if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
+        msg!("[{}] Payload too long: {} bytes (max: {})", 
+             label, burn_memo.payload.len(), MAX_PAYLOAD_LENGTH);
+        return Err(ErrorCode::PayloadTooLong.into());
+    }
+    
+    // Cheap category pre-check, so a memo meant for another program fails with a specific error
+    if let Some(category) = peek_payload_category(&burn_memo.payload).filter(|category| category != EXPECTED_CATEGORY) {
+        msg!("[{}] Wrong program category: '{}' (expected: '{}')", label, category, EXPECTED_CATEGORY);
+        return Err(ErrorCode::WrongProgramCategory.into());
+    }
+    
+    msg!("[{}] Borsh+Base64 memo validation passed: version {}, {} units, payload: {} bytes", 
+         label, burn_memo.version, expected_amount, burn_memo.payload.len());
+
+    Ok(burn_memo)
+}
+
+/// Parse and validate Borsh-formatted memo data for project creation (with Base64 decoding)
+fn parse_project_creation_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_amount: u64, economy: &EconomyConfig) -> Result<ProjectCreationData> {
+    let operation = ProjectMemoOperation::Create;
+    let burn_memo = decode_project_burn_memo(memo_data, expected_amount, operation)?;
+    
+    // Deserialize ProjectCreationData from payload
+    let mut project_data = ProjectCreationData::try_from_slice(&burn_memo.payload)
+        .map_err(|_| {
+            msg!("[{}] Invalid project creation data format in payload", operation.label());
+            operation.invalid_memo_error()
+        })?;
+    
+    // Validate the project creation data
+    project_data.validate_with_config(expected_project_id, economy)?;
+    trim_tags(&mut project_data.tags);
+    
+    msg!("Project creation data parsed successfully: project_id={}, name={}, description_len={}, website_len={}, tags_count={}", 
+         project_data.project_id, project_data.name, project_data.description.len(), 
+         project_data.website.len(), project_data.tags.len());
+
+    Ok(project_data)
+}
+
+/// Parse and validate Borsh-formatted memo data for project update (with Base64 decoding)
+fn parse_project_update_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_amount: u64, economy: &EconomyConfig) -> Result<ProjectUpdateData> {
+    let operation = ProjectMemoOperation::Update;
+    let burn_memo = decode_project_burn_memo(memo_data, expected_amount, operation)?;
+    
+    // Deserialize ProjectUpdateData from payload
+    let mut update_data = ProjectUpdateData::try_from_slice(&burn_memo.payload)
+        .map_err(|_| {
+            msg!("[{}] Invalid project update data format in payload", operation.label());
+            operation.invalid_memo_error()
+        })?;
+    
+    // Validate the project update data
+    update_data.validate_with_config(expected_project_id, economy)?;
+    if let Some(tags) = update_data.tags.as_mut() {
+        trim_tags(tags);
+    }
+    
+    msg!("Project update data parsed successfully: project_id={}, has updates: name={}, description={}, image={}, website={}, tag={}", 
+         update_data.project_id, 
+         update_data.name.is_some(),
+         update_data.description.is_some(),
+         update_data.image.is_some(),
+         update_data.website.is_some(),
+         update_data.tags.is_some());
+
+    Ok(update_data)
+}
+
+/// Parse and validate Borsh-formatted memo data for project burn (with Base64 decoding)
+fn parse_project_burn_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_amount: u64, expected_burner: Pubkey) -> Result<ProjectBurnData> {
+    let operation = ProjectMemoOperation::Burn;
+    let burn_memo = decode_project_burn_memo(memo_data, expected_amount, operation)?;
+    
+    // Deserialize project burn data from payload
+    let burn_data = ProjectBurnData::try_from_slice(&burn_memo.payload)
+        .map_err(|_| {
+            msg!("[{}] Invalid project burn data format in payload", operation.label());
+            operation.invalid_memo_error()
+        })?;
+    
+    // Validate project burn data
+    burn_data.validate(expected_project_id, expected_burner)?;
+    
+    Ok(burn_data)
+}
+
+/// Parse and validate Borsh-formatted memo data for project boost (with Base64 decoding)
+fn parse_project_boost_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_amount: u64, expected_burner: Pubkey) -> Result<ProjectBoostData> {
+    let operation = ProjectMemoOperation::Boost;
+    let burn_memo = decode_project_burn_memo(memo_data, expected_amount, operation)?;
+    
+    // Deserialize project boost data from payload
+    let boost_data = ProjectBoostData::try_from_slice(&burn_memo.payload)
+        .map_err(|_| {
+            msg!("[{}] Invalid project boost data format in payload", operation.label());
+            operation.invalid_memo_error()
+        })?;
+    
+    // Validate project boost data
+    boost_data.validate(expected_project_id, expected_burner)?;
+    
+    Ok(boost_data)
+}
+
+/// Parse and validate Borsh-formatted memo data for project fork (with Base64 decoding)
+fn parse_project_fork_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_source_project_id: u64, expected_amount: u64) -> Result<ProjectForkData> {
+    let operation = ProjectMemoOperation::Fork;
+    let burn_memo = decode_project_burn_memo(memo_data, expected_amount, operation)?;
+    
+    // Deserialize project fork data from payload
+    let fork_data = ProjectForkData::try_from_slice(&burn_memo.payload)
+        .map_err(|_| {
+            msg!("[{}] Invalid project fork data format in payload", operation.label());
+            operation.invalid_memo_error()
+        })?;
+    
+    // Validate project fork data
+    fork_data.validate(expected_project_id, expected_source_project_id)?;
+    
+    Ok(fork_data)
+}
+
+/// Whether a mint has the decimals DECIMAL_FACTOR assumes (guards against a recreated mint)
+pub fn mint_decimals_valid(decimals: u8) -> bool {
+    decimals == MINT_DECIMALS
+}
+
+/// Check for memo instruction at REQUIRED index 0
+/// 
+/// IMPORTANT: This contract enforces memo at index 0:
+/// - Index 0: SPL Memo instruction (REQUIRED)
+/// - Index 1+: memo-project instructions (create_project, update_project, etc.)
+/// 
+/// Compute budget instructions can be placed anywhere in the transaction
+/// as they are processed by Solana runtime before instruction execution.
+fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    // Get current instruction index
+    let current_index = anchor_lang::solana_program::sysvar::instructions::load_current_index_checked(instructions)?;
+    
+    // Current instruction must be at index 1 or later
+    // to leave index 0 available for memo
+    if current_index < 1 {
+        msg!("memo-project instruction must be at index 1 or later, but current instruction is at index {}", current_index);
+        return memo_not_at_index_zero(instructions);
+    }
+    
+    // Check that index 0 contains the memo instruction
+    match anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(0, instructions) {
+        Ok(ix) => {
+            if ix.program_id == MEMO_PROGRAM_ID {
+                msg!("Found memo instruction at required index 0");
+                validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
+            } else {
+                msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
+                memo_not_at_index_zero(instructions)
+            }
+        },
+        Err(e) => {
+            msg!("Failed to load instruction at required index 0: {:?}", e);
+            Ok((false, vec![]))
+        }
+    }
+}
+
+/// Called when index 0 holds no memo: if a memo exists elsewhere in the transaction,
+/// fail with MemoWrongIndex instead of letting the caller report MemoRequired
+fn memo_not_at_index_zero(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
+    let program_ids: Vec<Pubkey> = (0..)
+        .map_while(|i| anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(i, instructions).ok())
+        .map(|ix| ix.program_id)
+        .collect();
+    check_memo_placement(&program_ids)?;
+    Ok((false, vec![]))
+}
+
+/// Reject a transaction whose SPL Memo instruction is present but not at index 0
+fn check_memo_placement(program_ids: &[Pubkey]) -> Result<()> {
+    if let Some(index) = program_ids.iter().position(|id| *id == MEMO_PROGRAM_ID) {
+        if index > 0 {
+            msg!("Memo instruction found at index {}, but it must be placed at index 0", index);
+            return Err(ErrorCode::MemoWrongIndex.into());
+        }
+    }
+    Ok(())
+}
+
+/// Validate memo data length and return result
+fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize) -> Result<(bool, Vec<u8>)> {
+    let memo_length = memo_data.len();
+    
+    // Ensure data is not empty
+    if memo_data.is_empty() {
+        msg!("Memo data is empty");
+        return Err(ErrorCode::MemoTooShort.into());
+    }
+    
+    // Check minimum length requirement
+    if memo_length < min_length {
+        msg!("Memo too short: {} bytes (minimum: {})", memo_length, min_length);
+        return Err(ErrorCode::MemoTooShort.into());
+    }
+    
+    // Check maximum length requirement
+    if memo_length > max_length {
+        msg!("Memo too long: {} bytes (maximum: {})", memo_length, max_length);
+        return Err(ErrorCode::MemoTooLong.into());
+    }
+    
+    // Length is valid, return memo data
+    msg!("Memo length validation passed: {} bytes (range: {}-{})", memo_length, min_length, max_length);
+    Ok((true, memo_data.to_vec()))
+}
+
+/// Compute the SHA-256 hash of the raw memo instruction data
+/// (emitted in events so indexers can match them to the on-chain memo)
+fn compute_memo_hash(memo_data: &[u8]) -> [u8; 32] {
+    hash(memo_data).to_bytes()
+}
+
+/// Check that a memo-burn process_burn CPI reduced the burner's token balance by
+/// exactly `amount` (guards against crediting burns that were not fully applied)
+fn check_burn_reflected(balance_before: u64, balance_after: u64, amount: u64) -> Result<()> {
+    if balance_before.checked_sub(balance_after) != Some(amount) {
+        msg!("Burn not reflected in balance: {} -> {} (expected {} burned)", 
+             balance_before, balance_after, amount);
+        return Err(ErrorCode::BurnAmountNotReflected.into());
+    }
+    Ok(())
+}
+
+/// Check that the account handed to memo-burn as `user_global_burn_stats` is owned by
+/// memo-burn and carries the UserGlobalBurnStats discriminator (guards against a
+/// spoofed account reaching the burn CPI)
+fn verify_burn_stats_account(account: &AccountInfo) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    if account.owner != &memo_burn::ID
+        || !data.starts_with(memo_burn::UserGlobalBurnStats::DISCRIMINATOR)
+    {
+        msg!("Invalid burn stats account: {} (owner: {})", account.key(), account.owner);
+        return Err(ErrorCode::InvalidBurnStatsAccount.into());
+    }
+    Ok(())
+}
+
+/// Burn leaderboard entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LeaderboardEntry {
+    pub project_id: u64,
+    pub burned_amount: u64,
+}
+
+impl LeaderboardEntry {
+    pub const SPACE: usize = 8 + // project_id
+        8; // burned_amount
+}
+
+/// Burn leaderboard account (stores the top projects by burn amount, 100 by default)
+#[account]
+pub struct BurnLeaderboard {
+    /// Array of leaderboard entries (unsorted for performance - sort off-chain for display)
+    /// Maximum `capacity` entries
+    pub entries: Vec<LeaderboardEntry>,
+    
+    /// Maximum number of entries (0 = account created before resizing, i.e. MAX_ENTRIES)
+    pub capacity: u16,
+
+    /// Project ids pinned by pin_project (max MAX_PINNED); their entries are never evicted.
+    /// Kept out of LeaderboardEntry so existing accounts keep their layout: accounts created
+    /// before pinning decode this from their zeroed safety buffer as empty
+    pub pinned: Vec<u64>,
+}
+
+impl BurnLeaderboard {
+    pub const SPACE: usize = 8 + // discriminator
+        4 + // Vec length prefix
+        100 * LeaderboardEntry::SPACE + // max entries (100 * (8 + 8) bytes each)
+        2 + // capacity
+        4 + Self::MAX_PINNED * 8 + // pinned
+        64; // safety buffer
+    
+    /// Default number of leaderboard entries
+    pub const MAX_ENTRIES: usize = 100;
+    
+    /// Upper bound for resize_leaderboard
+    pub const MAX_CAPACITY: u16 = 500;
+    
+    /// Maximum number of pinned entries (keeps the board from being starved)
+    pub const MAX_PINNED: usize = 5;
+    
+    /// Account size for a leaderboard holding `capacity` entries
+    pub fn space_for(capacity: usize) -> usize {
+        8 + 4 + capacity * LeaderboardEntry::SPACE + 2 + 4 + Self::MAX_PINNED * 8 + 64
+    }
+    
+    /// Initialize with empty entries
+    pub fn initialize(&mut self) {
+        self.entries = Vec::with_capacity(100);
+        self.capacity = Self::MAX_ENTRIES as u16;
+        self.pinned = Vec::new();
+    }
+    
+    /// Maximum number of entries this account holds
+    pub fn capacity(&self) -> usize {
+        if self.capacity == 0 {
+            Self::MAX_ENTRIES
+        } else {
+            self.capacity as usize
+        }
+    }
+    
+    /// Grow the capacity (admin resize); existing entries are kept as they are
+    pub fn resize(&mut self, new_capacity: u16) -> Result<()> {
+        if new_capacity > Self::MAX_CAPACITY || (new_capacity as usize) < self.capacity() {
+            msg!("Invalid leaderboard capacity: {} (must be {}-{})", 
+                 new_capacity, self.capacity(), Self::MAX_CAPACITY);
+            return Err(ErrorCode::InvalidLeaderboardCapacity.into());
+        }
+        self.capacity = new_capacity;
+        Ok(())
+    }
+    
+    /// Lower the capacity (admin shrink); all current entries must still fit
+    pub fn shrink(&mut self, new_capacity: u16) -> Result<()> {
+        if new_capacity == 0 || (new_capacity as usize) > self.capacity() {
+            msg!("Invalid leaderboard capacity: {} (must be 1-{})", new_capacity, self.capacity());
+            return Err(ErrorCode::InvalidLeaderboardCapacity.into());
+        }
+        if self.entries.len() > new_capacity as usize {
+            msg!("Cannot shrink leaderboard to {} entries: {} entries in use", 
+                 new_capacity, self.entries.len());
+            return Err(ErrorCode::LeaderboardTooManyEntries.into());
+        }
+        self.capacity = new_capacity;
+        Ok(())
+    }
+    
+    /// find project position and min burned_amount position (core optimization)
+    pub fn find_project_position_and_min(&self, project_id: u64) -> (Option<usize>, Option<usize>) {
+        if self.entries.is_empty() {
+            return (None, None);
+        }
+        
+        let mut min_pos = None;
+        let mut min_amount = u64::MAX;
+        let mut found_project_pos = None;
+        
+        // loop all elements
+        for (i, entry) in self.entries.iter().enumerate() {
+            // record target project position
+            if entry.project_id == project_id {
+                found_project_pos = Some(i);
+            }
+            
+            // always record min position (pinned entries are never evicted)
+            if entry.burned_amount < min_amount && !self.pinned.contains(&entry.project_id) {
+                min_amount = entry.burned_amount;
+                min_pos = Some(i);
+            }
+        }
+        
+        (found_project_pos, min_pos)
+    }
+    
+    /// Reject an account whose entries Vec exceeds its capacity (corrupted data)
+    pub fn check_integrity(&self) -> Result<()> {
+        if self.entries.len() > self.capacity() {
+            msg!("Leaderboard corrupted: {} entries (max: {})", self.entries.len(), self.capacity());
+            return Err(ErrorCode::LeaderboardCorrupted.into());
+        }
+        Ok(())
+    }
+    
+    /// Remove the entry for `project_id` regardless of its amount (admin multisig, for abuse remediation).
+    /// Returns the removed entry, or None when the id is not on the leaderboard (a no-op).
+    /// The project can re-enter through its next burn.
+    pub fn admin_remove_entry(&mut self, admin_config: &AdminConfig, signers: &[Pubkey], project_id: u64) -> Result<Option<LeaderboardEntry>> {
+        admin_config.check_threshold(signers)?;
+        self.pinned.retain(|pinned_id| *pinned_id != project_id);
+        let position = self.entries.iter().position(|entry| entry.project_id == project_id);
+        Ok(position.map(|pos| self.entries.swap_remove(pos)))
+    }
+    
+    /// update leaderboard - zero array move version
+    pub fn update_leaderboard(&mut self, project_id: u64, new_burned_amount: u64) -> Result<bool> {
+        // 0. refuse to process an over-length entries Vec
+        self.check_integrity()?;
+        
+        // 1. one loop to get project position and min position
+        let (existing_pos, min_pos) = self.find_project_position_and_min(project_id);
+        
+        // 2. if project exists, update burned_amount (zero move)
+        if let Some(pos) = existing_pos {
+            self.entries[pos].burned_amount = new_burned_amount;
+            return Ok(true);
+        }
+        
+        // 3. new project and leaderboard not full, add directly (no sort)
+        if self.entries.len() < self.capacity() {
+            let new_entry = LeaderboardEntry {
+                project_id,
+                burned_amount: new_burned_amount,
+            };
+            self.entries.push(new_entry);
+            return Ok(true);
+        }
+        
+        // 4. new project and leaderboard full, check if can replace min value
+        if let Some(min_position) = min_pos {
+            let min_amount = self.entries[min_position].burned_amount;
+            if new_burned_amount > min_amount {
+                // replace min value entry (zero move)
+                self.entries[min_position] = LeaderboardEntry {
+                    project_id,
+                    burned_amount: new_burned_amount,
+                };
+                return Ok(true);
+            } else {
+                // new value not big enough, cannot enter leaderboard
+                return Ok(false);
+            }
+        }
+        
+        Ok(false)
+    }
+
+    /// Pin `project_id` so eviction skips it (admin only), adding it with `burned_amount`
+    /// when it is not on the leaderboard yet (replacing the lowest unpinned entry when full).
+    /// At most MAX_PINNED entries can be pinned; pinning an already pinned entry is a no-op.
+    pub fn pin_entry(&mut self, admin: Pubkey, project_id: u64, burned_amount: u64) -> Result<()> {
+        if admin != AUTHORIZED_ADMIN_PUBKEY {
+            return Err(ErrorCode::UnauthorizedAdmin.into());
+        }
+        self.check_integrity()?;
+
+        if self.pinned.contains(&project_id) {
+            return Ok(());
+        }
+
+        if self.pinned.len() >= Self::MAX_PINNED {
+            msg!("Cannot pin project {}: {} entries already pinned (max: {})", 
+                 project_id, self.pinned.len(), Self::MAX_PINNED);
+            return Err(ErrorCode::TooManyPinnedEntries.into());
+        }
+
+        let (existing_pos, min_pos) = self.find_project_position_and_min(project_id);
+        let pinned_entry = LeaderboardEntry {
+            project_id,
+            burned_amount,
+        };
+        match (existing_pos, min_pos) {
+            (Some(_), _) => {}
+            (None, _) if self.entries.len() < self.capacity() => self.entries.push(pinned_entry),
+            (None, Some(min_position)) => self.entries[min_position] = pinned_entry,
+            (None, None) => return Err(ErrorCode::TooManyPinnedEntries.into()),
+        }
+        self.pinned.push(project_id);
+        Ok(())
+    }
+
+    /// 1-based rank of `project_id` in top_entries order, or None when it is not on the leaderboard
+    pub fn rank_of(&self, project_id: u64) -> Option<u32> {
+        let entry = self.entries.iter().find(|entry| entry.project_id == project_id)?;
+        let ahead = self.entries.iter()
+            .filter(|other| {
+                other.burned_amount > entry.burned_amount
+                    || (other.burned_amount == entry.burned_amount && other.project_id < entry.project_id)
+            })
+            .count();
+        Some(ahead as u32 + 1)
+    }
+
+    /// Entries sorted by burned_amount (descending, ties by project_id), truncated to `limit`
+    pub fn top_entries(&self, limit: usize) -> Vec<LeaderboardEntry> {
+        let mut sorted = self.entries.clone();
+        sorted.sort_by(|a, b| {
+            b.burned_amount.cmp(&a.burned_amount).then(a.project_id.cmp(&b.project_id))
+        });
+        sorted.truncate(limit);
+        sorted
+    }
+}
+
+/// Historical copy of the top leaderboard entries for one epoch
+#[account]
+pub struct LeaderboardSnapshot {
+    pub epoch: u64,                        // Epoch this snapshot belongs to
+    pub captured_at: i64,                  // Capture timestamp (0 = not captured yet)
+    pub entries: Vec<LeaderboardEntry>,    // Top entries, sorted by burned_amount (max 25)
+    pub bump: u8,                          // PDA bump
+}
+
+impl LeaderboardSnapshot {
+    /// Maximum number of entries stored per snapshot
+    pub const MAX_ENTRIES: usize = 25;
+
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // epoch
+        8 + // captured_at
+        4 + Self::MAX_ENTRIES * LeaderboardEntry::SPACE + // entries (25 * (8 + 8) bytes each)
+        1 + // bump
+        32; // safety buffer
+
+    /// Build a snapshot of the leaderboard's current top entries
+    pub fn capture(epoch: u64, leaderboard: &BurnLeaderboard, captured_at: i64, bump: u8) -> Result<Self> {
+        leaderboard.check_integrity()?;
+        Ok(Self {
+            epoch,
+            captured_at,
+            entries: leaderboard.top_entries(Self::MAX_ENTRIES),
+            bump,
+        })
+    }
+
+    /// Check that no snapshot has been captured into this account yet
+    pub fn check_unused(&self, epoch: u64) -> Result<()> {
+        if self.captured_at != 0 {
+            msg!("Leaderboard snapshot for epoch {} already exists", epoch);
+            return Err(ErrorCode::SnapshotAlreadyExists.into());
+        }
+        Ok(())
+    }
+}
+
+/// One donor message on a project's message board
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BoardRecord {
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub message: String, // max MAX_BOARD_MESSAGE_LENGTH bytes
+    pub timestamp: i64,
+}
+
+impl BoardRecord {
+    pub const SPACE: usize = 32 + // donor
+        8 + // amount
+        4 + MAX_BOARD_MESSAGE_LENGTH + // message
+        8; // timestamp
+
+    /// Build a record, truncating the message to MAX_BOARD_MESSAGE_LENGTH bytes
+    /// (on a UTF-8 character boundary)
+    pub fn new(donor: Pubkey, amount: u64, message: &str, timestamp: i64) -> Self {
+        let mut end = message.len().min(MAX_BOARD_MESSAGE_LENGTH);
+        while !message.is_char_boundary(end) {
+            end -= 1;
+        }
+        Self {
+            donor,
+            amount,
+            message: message[..end].to_string(),
+            timestamp,
+        }
+    }
+}
+
+/// Donor message board of a project: ring buffer of the latest 69 burn messages
+#[account]
+pub struct ProjectMessageBoard {
+    pub project_id: u64,              // Project this board belongs to
+    pub current_index: u8,            // Next slot to overwrite once the board is full
+    pub records: Vec<BoardRecord>,    // Latest records (max MAX_RECORDS)
+    pub bump: u8,                     // PDA bump
+}
+
+impl ProjectMessageBoard {
+    pub const MAX_RECORDS: usize = 69;
+
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // project_id
+        1 + // current_index
+        4 + Self::MAX_RECORDS * BoardRecord::SPACE + // records
+        1; // bump
+
+    /// Size at creation (accounts created via CPI are capped at 10 KiB)
+    pub const INITIAL_SPACE: usize = 10_240;
+
+    /// Check a burn against the board passed with it (`board_len` is its account size, None
+    /// when omitted): a message needs the board, and a provided board must be extended
+    pub fn check_for_message(board_len: Option<usize>, message: &str) -> Result<()> {
+        match board_len {
+            None if !message.is_empty() => Err(ErrorCode::MessageBoardRequired.into()),
+            Some(len) if len < Self::SPACE => Err(ErrorCode::MessageBoardNotExtended.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Append a record, overwriting the oldest one once MAX_RECORDS is reached
+    pub fn add_record(&mut self, record: BoardRecord) {
+        if self.records.len() < Self::MAX_RECORDS {
+            self.records.push(record);
+        } else {
+            self.records[self.current_index as usize] = record;
+        }
+        self.current_index = ((self.current_index as usize + 1) % Self::MAX_RECORDS) as u8;
+    }
+}
+
+/// One burn in a project's RecentBurners ring buffer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct RecentBurner {
+    pub burner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+impl RecentBurner {
+    pub const SPACE: usize = 32 + // burner
+        8 + // amount
+        8; // timestamp
+}
+
+/// Latest burn_for_project / boost_project burns of a project, for "recent supporters" views
+/// without scanning events
+#[account]
+pub struct RecentBurners {
+    pub project_id: u64,              // Project these burns belong to
+    pub current_index: u8,            // Next slot to overwrite once the buffer is full
+    pub burners: Vec<RecentBurner>,   // Latest burns (max MAX_BURNERS), in slot order
+    pub bump: u8,                     // PDA bump
+}
+
+impl RecentBurners {
+    pub const MAX_BURNERS: usize = 20;
+
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // project_id
+        1 + // current_index
+        4 + Self::MAX_BURNERS * RecentBurner::SPACE + // burners
+        1; // bump
+
+    /// Append a burn, overwriting the oldest one once MAX_BURNERS is reached
+    pub fn add_burner(&mut self, burner: RecentBurner) {
+        if self.burners.len() < Self::MAX_BURNERS {
+            self.burners.push(burner);
+        } else {
+            self.burners[self.current_index as usize] = burner;
+        }
+        self.current_index = ((self.current_index as usize + 1) % Self::MAX_BURNERS) as u8;
+    }
+
+    /// Burns from newest to oldest (the newest is in the slot before current_index)
+    pub fn newest_first(&self) -> Vec<RecentBurner> {
+        let len = self.burners.len();
+        if len == 0 {
+            return Vec::new();
+        }
+        let newest = (self.current_index as usize + len - 1) % len;
+        (0..len).map(|i| self.burners[(newest + len - i) % len]).collect()
+    }
+}
+
+/// Global project counter account
+#[account]
+pub struct GlobalProjectCounter {
+    pub total_projects: u64,          // Total number of projects created (starts at 0)
+}
+
+impl GlobalProjectCounter {
+    pub const SPACE: usize = 8 + // discriminator
+        8; // total_projects (u64)
+
+    /// Reject creation once the counter reaches MAX_ENTITIES
+    pub fn check_capacity(&self) -> Result<()> {
+        if self.total_projects >= MAX_ENTITIES {
+            msg!("Entity limit reached: {} projects created (max: {})", self.total_projects, MAX_ENTITIES);
+            return Err(ErrorCode::EntityLimitReached.into());
+        }
+        Ok(())
+    }
+
+    /// Verify the client's expected project_id against the counter and return it
+    pub fn check_expected_id(&self, expected_project_id: u64) -> Result<u64> {
+        if expected_project_id > self.total_projects {
+            msg!("Project ID {} is ahead of the counter (next available ID is {})", 
+                 expected_project_id, self.total_projects);
+            return Err(ErrorCode::ProjectIdExceedsCounter.into());
+        }
+
+        if expected_project_id != self.total_projects {
+            msg!("Project ID mismatch: expected {}, but next available ID is {}", 
+                 expected_project_id, self.total_projects);
+            return Err(ErrorCode::ProjectIdMismatch.into());
+        }
+
+        Ok(self.total_projects)
+    }
+}
+
+/// Keys that signed an instruction: the caller plus any signer passed as a remaining account
+fn instruction_signers(caller: &Signer, remaining_accounts: &[AccountInfo]) -> Vec<Pubkey> {
+    std::iter::once(caller.key())
+        .chain(remaining_accounts.iter().filter(|account| account.is_signer).map(|account| account.key()))
+        .collect()
+}
+
+/// Admin multisig for sensitive admin operations (replaces the single AUTHORIZED_ADMIN_PUBKEY
+/// check for them once initialize_admin_config has run)
+#[account]
+pub struct AdminConfig {
+    pub signers: Vec<Pubkey>,         // Admin keys (max MAX_ADMIN_SIGNERS, no duplicates)
+    pub threshold: u8,                // Signatures from `signers` required (1..=signers.len())
+    pub bump: u8,                     // PDA bump
+}
+
+impl AdminConfig {
+    pub const SPACE: usize = 8 + // discriminator
+        4 + 32 * MAX_ADMIN_SIGNERS + // signers
+        1 + // threshold
+        1; // bump
+
+    /// 1 to MAX_ADMIN_SIGNERS distinct signers and a threshold between 1 and their count
+    pub fn validate(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        if signers.is_empty() || signers.len() > MAX_ADMIN_SIGNERS {
+            msg!("Invalid admin config: {} signers (must be 1-{})", signers.len(), MAX_ADMIN_SIGNERS);
+            return Err(ErrorCode::InvalidAdminConfig.into());
+        }
+        if signers.iter().enumerate().any(|(i, signer)| signers[..i].contains(signer)) {
+            msg!("Invalid admin config: duplicate signer");
+            return Err(ErrorCode::InvalidAdminConfig.into());
+        }
+        if threshold == 0 || threshold as usize > signers.len() {
+            msg!("Invalid admin config: threshold {} for {} signers", threshold, signers.len());
+            return Err(ErrorCode::InvalidAdminConfig.into());
+        }
+        Ok(())
+    }
+
+    /// Check that at least `threshold` of the admin keys are among `signers`
+    pub fn check_threshold(&self, signers: &[Pubkey]) -> Result<()> {
+        let approvals = self.signers.iter().filter(|admin| signers.contains(admin)).count();
+        if approvals < self.threshold as usize {
+            msg!("Admin threshold not met: {} of {} admins signed (need {})", 
+                 approvals, self.signers.len(), self.threshold);
+            return Err(ErrorCode::AdminThresholdNotMet.into());
+        }
+        Ok(())
+    }
+}
+
+/// Admin-managed operation switches (OPERATION_FLAG_*); every operation is enabled until it is created
+#[account]
+pub struct ProgramConfig {
+    pub operation_flags: u8,          // Enabled operations (OPERATION_FLAG_* bits)
+    pub bump: u8,                     // PDA bump
+}
+
+impl ProgramConfig {
+    pub const SPACE: usize = 8 + // discriminator
+        1 + // operation_flags
+        1; // bump
+
+    /// Flags stored in the `[b"program_config"]` account, or all operations if it does not exist yet
+    pub fn load_flags(account: &AccountInfo) -> Result<u8> {
+        if account.data_is_empty() {
+            return Ok(OPERATION_FLAGS_MASK);
+        }
+        if account.owner != &crate::ID {
+            msg!("Invalid program config account: {} (owner: {})", account.key(), account.owner);
+            return Err(ErrorCode::InvalidProgramConfigAccount.into());
+        }
+        let data = account.try_borrow_data()?;
+        let config = Self::try_deserialize(&mut &data[..])?;
+        Ok(config.operation_flags)
+    }
+
+    pub fn check_enabled(operation_flags: u8, operation: u8) -> Result<()> {
+        if operation_flags & operation == 0 {
+            msg!("Operation {:#010b} is disabled (enabled: {:#010b})", operation, operation_flags);
+            return Err(ErrorCode::OperationDisabled.into());
+        }
+        Ok(())
+    }
+
+    /// Fail with OperationDisabled unless `operation` is enabled in the config account
+    pub fn check_operation(account: &AccountInfo, operation: u8) -> Result<()> {
+        Self::check_enabled(Self::load_flags(account)?, operation)
+    }
+}
+
+/// Admin-managed minimum burn amounts (overrides the MIN_PROJECT_* constants when present)
+#[account]
+pub struct EconomyConfig {
+    pub min_create_burn: u64,         // Minimum burn for create_project
+    pub min_update_burn: u64,         // Minimum burn for update_project
+    pub min_burn: u64,                // Minimum burn for burn_for_project
+    pub max_tags: u8,                 // Maximum tags per project (capped at MAX_TAGS_COUNT_LIMIT)
+    pub max_tag_length: u8,           // Maximum characters per tag (capped at MAX_TAG_LENGTH_LIMIT)
+    pub bump: u8,                     // PDA bump
+}
+
+impl EconomyConfig {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // min_create_burn
+        8 + // min_update_burn
+        8 + // min_burn
+        1 + // max_tags
+        1 + // max_tag_length
+        1; // bump
+
+    /// The given config, or the compile-time constants when none has been created
+    pub fn resolve(config: Option<&Self>) -> Self {
+        match config {
+            Some(config) => config.clone(),
+            None => Self {
+                min_create_burn: MIN_PROJECT_CREATION_BURN_AMOUNT,
+                min_update_burn: MIN_PROJECT_UPDATE_BURN_AMOUNT,
+                min_burn: MIN_PROJECT_BURN_AMOUNT,
+                max_tags: MAX_TAGS_COUNT as u8,
+                max_tag_length: MAX_TAG_LENGTH as u8,
+                bump: 0,
+            },
+        }
+    }
+
+    /// Config stored in the `[b"economy_config"]` account, or the constants if it does not
+    /// exist yet. The account is required, so once the admin creates it every caller gets it
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        // Also enforced by the seeds constraint; an empty stand-in must not yield the constants
+        let expected = Pubkey::find_program_address(&[b"economy_config"], &crate::ID).0;
+        if account.key() != expected {
+            msg!("Invalid economy config account: {} (expected: {})", account.key(), expected);
+            return Err(ErrorCode::InvalidEconomyConfigAccount.into());
+        }
+        if account.data_is_empty() {
+            return Ok(Self::resolve(None));
+        }
+        if account.owner != &crate::ID {
+            msg!("Invalid economy config account: {} (owner: {})", account.key(), account.owner);
+            return Err(ErrorCode::InvalidEconomyConfigAccount.into());
+        }
+        let data = account.try_borrow_data()?;
+        Self::try_deserialize(&mut &data[..])
+    }
+
+    /// Every minimum must be a whole number of tokens between 1 token and MAX_BURN_PER_TX,
+    /// and the tag limits must stay within the space reserved for them
+    pub fn validate(&self) -> Result<()> {
+        for amount in [self.min_create_burn, self.min_update_burn, self.min_burn] {
+            if !(DECIMAL_FACTOR..=MAX_BURN_PER_TX).contains(&amount) || !amount.is_multiple_of(DECIMAL_FACTOR) {
+                msg!("Invalid economy config amount: {}", amount);
+                return Err(ErrorCode::InvalidEconomyConfig.into());
+            }
+        }
+        if !(1..=MAX_TAGS_COUNT_LIMIT).contains(&(self.max_tags as usize))
+            || !(1..=MAX_TAG_LENGTH_LIMIT).contains(&(self.max_tag_length as usize)) {
+            msg!("Invalid economy config tag limits: {} tags of {} characters", self.max_tags, self.max_tag_length);
+            return Err(ErrorCode::InvalidEconomyConfig.into());
+        }
+        Ok(())
+    }
+
+    /// Check a tag list against the configured count and per-tag length
+    pub fn check_tags(&self, tags: &[String]) -> Result<()> {
+        let max_tags = self.max_tags as usize;
+        let max_tag_length = self.max_tag_length as usize;
+        if tags.len() > max_tags {
+            msg!("Too many tags: {} (max: {})", tags.len(), max_tags);
+            return Err(ErrorCode::TooManyTags.into());
+        }
+        for (i, tag) in tags.iter().enumerate() {
+            validate_tag_content(i, tag)?;
+            // Length is measured after trimming, which is how the tag is stored
+            let length = tag.trim().len();
+            if length == 0 || length > max_tag_length {
+                msg!("Invalid tag {}: '{}' (must be 1-{} characters)", i, tag, max_tag_length);
+                return Err(invalid_tag_error(i).into());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn check_create_burn(&self, burn_amount: u64) -> Result<()> {
+        Self::check_min(burn_amount, self.min_create_burn)
+    }
+
+    pub fn check_update_burn(&self, burn_amount: u64) -> Result<()> {
+        Self::check_min(burn_amount, self.min_update_burn)
+    }
+
+    pub fn check_burn(&self, burn_amount: u64) -> Result<()> {
+        Self::check_min(burn_amount, self.min_burn)
+    }
+
+    fn check_min(burn_amount: u64, minimum: u64) -> Result<()> {
+        if burn_amount < minimum {
+            msg!("Burn amount too small: {} (minimum: {})", burn_amount, minimum);
+            return Err(ErrorCode::BurnAmountTooSmall.into());
+        }
+        Ok(())
+    }
+}
+
+/// Per-wallet project creation cooldown account
+#[account]
+pub struct CreatorCooldown {
+    pub creator: Pubkey,              // Wallet this cooldown belongs to
+    pub last_created: i64,            // Last create_project timestamp (0 if never created)
+    pub bump: u8,                     // PDA bump
+}
+
+impl CreatorCooldown {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // creator (Pubkey)
+        8 + // last_created (i64)
+        1; // bump (u8)
+
+    /// Check whether the creator may create another project at `current_time`
+    /// (exempt creators skip the cooldown)
+    pub fn check(&self, current_time: i64, exempt: bool) -> Result<()> {
+        if !exempt && self.last_created > 0 {
+            let time_since_last = current_time.saturating_sub(self.last_created);
+            if time_since_last < PROJECT_CREATE_COOLDOWN_SECONDS {
+                msg!("Project creation too frequent: {} seconds since last creation (cooldown: {})",
+                     time_since_last, PROJECT_CREATE_COOLDOWN_SECONDS);
+                return Err(ErrorCode::CreateTooFrequent.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Marks a (signer, idempotency key) pair as used by a create request, so a retried
+/// submission fails with DuplicateRequest instead of being processed twice
+#[account]
+pub struct IdempotencyMarker {
+    pub signer: Pubkey,               // Wallet that sent the request (receives the rent on close)
+    pub key: [u8; 16],                // Client-chosen idempotency key
+    pub created_at: i64,              // Time the request was processed (0 = unused)
+    pub bump: u8,                     // PDA bump
+}
+
+impl IdempotencyMarker {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // signer (Pubkey)
+        16 + // key ([u8; 16])
+        8 + // created_at (i64)
+        1; // bump (u8)
+
+    /// Record the request, failing with DuplicateRequest if the marker was already used
+    pub fn claim(&mut self, signer: Pubkey, key: [u8; 16], current_time: i64, bump: u8) -> Result<()> {
+        if self.created_at != 0 {
+            msg!("Duplicate request: idempotency key already used by {} at {}", self.signer, self.created_at);
+            return Err(ErrorCode::DuplicateRequest.into());
+        }
+        self.signer = signer;
+        self.key = key;
+        self.created_at = current_time;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Check that the marker is old enough to be closed
+    pub fn check_expired(&self, current_time: i64) -> Result<()> {
+        let age = current_time.saturating_sub(self.created_at);
+        if age < IDEMPOTENCY_MARKER_TTL_SECONDS {
+            msg!("Idempotency marker is {} seconds old (closable after {})", age, IDEMPOTENCY_MARKER_TTL_SECONDS);
+            return Err(ErrorCode::IdempotencyMarkerNotExpired.into());
+        }
+        Ok(())
+    }
+}
+
+/// Per-tag usage counter (PDA seeded by the tag bytes), powers "most-used tags"
+#[account]
+pub struct TagStats {
+    pub tag: String,                  // Tag as written in the project (max 32 chars)
+    pub usage_count: u64,             // Number of projects created with this tag
+    pub bump: u8,                     // PDA bump
+}
+
+impl TagStats {
+    pub const SPACE: usize = 8 + // discriminator
+        4 + MAX_TAG_LENGTH_LIMIT + // tag
+        8 + // usage_count (u64)
+        1; // bump (u8)
+
+    /// TagStats PDA and bump for a tag
+    pub fn find_address(tag: &str) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"tag", tag.as_bytes()], &crate::ID)
+    }
+
+    /// Count one more project using this tag
+    pub fn record_usage(&mut self) {
+        self.usage_count = self.usage_count.saturating_add(1);
+    }
+}
+
+/// Page of project IDs created with a tag, for "tagged defi OR nft" searches (clients fetch
+/// a few tag lists and union them). Page 0 is seeded by the tag bytes; when a page holds
+/// MAX_TAG_LIST_PROJECTS IDs, new projects go to the page its `next` pointer names.
+/// Lists are append-only: tag changes in update_project are not reflected.
+#[account]
+pub struct TagProjectList {
+    pub tag: String,                  // Tag as written in the project (max 32 chars)
+    pub page: u32,                    // Page index in the chain (0 = first page)
+    pub next: Option<Pubkey>,         // Continuation page, set once this page is full
+    pub project_ids: Vec<u64>,        // Project IDs in creation order
+    pub bump: u8,                     // PDA bump
+}
+
+impl TagProjectList {
+    pub const SPACE: usize = 8 + // discriminator
+        4 + MAX_TAG_LENGTH_LIMIT + // tag
+        4 + // page (u32)
+        1 + 32 + // next (Option<Pubkey>)
+        4 + 8 * MAX_TAG_LIST_PROJECTS + // project_ids
+        1; // bump (u8)
+
+    pub fn new(tag: String, page: u32, bump: u8) -> Self {
+        Self {
+            tag,
+            page,
+            next: None,
+            project_ids: Vec::new(),
+            bump,
+        }
+    }
+
+    /// TagProjectList PDA and bump for a page of a tag's chain
+    pub fn find_address(tag: &str, page: u32) -> (Pubkey, u8) {
+        if page == 0 {
+            Pubkey::find_program_address(&[b"tag_list", tag.as_bytes()], &crate::ID)
+        } else {
+            Pubkey::find_program_address(&[b"tag_list", tag.as_bytes(), &page.to_le_bytes()], &crate::ID)
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.project_ids.len() >= MAX_TAG_LIST_PROJECTS
+    }
+
+    /// Append a project ID to this page
+    pub fn append(&mut self, project_id: u64) -> Result<()> {
+        if self.is_full() {
+            msg!("TagProjectList page {} for tag '{}' is full", self.page, self.tag);
+            return Err(ErrorCode::TagProjectListFull.into());
+        }
+        self.project_ids.push(project_id);
+        Ok(())
+    }
+
+    /// Point this page at `next_address` and return the (empty) continuation page
+    pub fn roll_over(&mut self, next_address: Pubkey, next_bump: u8) -> TagProjectList {
+        self.next = Some(next_address);
+        TagProjectList::new(self.tag.clone(), self.page + 1, next_bump)
+    }
+}
+
+/// Slug -> project mapping (PDA seeded by the slug, so each slug belongs to one project)
+#[account]
+pub struct ProjectSlug {
+    pub project_id: u64,              // Project owning the slug
+    pub claimed_at: i64,              // Claim timestamp (0 = unclaimed)
+    pub bump: u8,                     // PDA bump
+}
+
+impl ProjectSlug {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // project_id
+        8 + // claimed_at
+        1 + // bump
+        16; // safety buffer
+
+    /// Claim this slug for `project_id`; fails when another project already holds it
+    pub fn claim(&mut self, project_id: u64, current_time: i64, bump: u8) -> Result<()> {
+        if self.claimed_at != 0 {
+            msg!("Slug already taken by project {}", self.project_id);
+            return Err(ErrorCode::ProjectSlugTaken.into());
+        }
+        self.project_id = project_id;
+        self.claimed_at = current_time;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// A project update waiting for its apply_at (PDA seeded by project id, closed when applied)
+#[account]
+pub struct PendingUpdate {
+    pub project_id: u64,              // Project the update belongs to
+    pub scheduler: Pubkey,            // Wallet that scheduled (and paid for) the update
+    pub apply_at: i64,                // Earliest apply time (0 = nothing scheduled)
+    pub name: Option<String>,         // Fields to apply, as in ProjectUpdateData
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub website: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub memo_hash: [u8; 32],          // Hash of the scheduling memo
+    pub bump: u8,                     // PDA bump
+}
+
+impl PendingUpdate {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // project_id
+        32 + // scheduler
+        8 + // apply_at
+        1 + 4 + MAX_PROJECT_NAME_LENGTH + // name
+        1 + 4 + MAX_PROJECT_DESCRIPTION_LENGTH + // description
+        1 + 4 + MAX_PROJECT_IMAGE_LENGTH + // image
+        1 + 4 + MAX_PROJECT_WEBSITE_LENGTH + // website
+        1 + 4 + (4 + MAX_TAG_LENGTH_LIMIT) * MAX_TAGS_COUNT_LIMIT + // tags (hard ceiling)
+        32 + // memo_hash
+        1 + // bump
+        32; // safety buffer
+
+    /// Store a validated update to apply at `apply_at`, which must be in the future;
+    /// fails while another update for the project is still pending
+    #[allow(clippy::too_many_arguments)]
+    pub fn schedule(
+        &mut self,
+        project_id: u64,
+        scheduler: Pubkey,
+        apply_at: i64,
+        current_time: i64,
+        update_data: ProjectUpdateData,
+        memo_hash: [u8; 32],
+        bump: u8,
+    ) -> Result<()> {
+        if self.apply_at != 0 {
+            msg!("Project {} already has an update scheduled for {}", project_id, self.apply_at);
+            return Err(ErrorCode::UpdateAlreadyScheduled.into());
+        }
+        if apply_at <= current_time {
+            msg!("apply_at {} is not in the future (now: {})", apply_at, current_time);
+            return Err(ErrorCode::InvalidApplyAt.into());
+        }
+        self.project_id = project_id;
+        self.scheduler = scheduler;
+        self.apply_at = apply_at;
+        self.name = update_data.name;
+        self.description = update_data.description;
+        self.image = update_data.image;
+        self.website = update_data.website;
+        self.tags = update_data.tags;
+        self.memo_hash = memo_hash;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Check that the update may be applied at `current_time`
+    pub fn check_due(&self, current_time: i64) -> Result<()> {
+        if current_time < self.apply_at {
+            msg!("Scheduled update of project {} is due at {} (now: {})", 
+                 self.project_id, self.apply_at, current_time);
+            return Err(ErrorCode::ScheduledUpdateNotDue.into());
+        }
+        Ok(())
+    }
+
+    /// Whether the update may still land on `project`: the scheduler is still the creator or
+    /// the delegate, and the project does not require quorum (a scheduled update carries no
+    /// co-owner signatures). Checked again at apply time since both can change after scheduling
+    pub fn is_authorized(&self, project: &Project) -> bool {
+        project.can_update(self.scheduler) && !project.require_quorum
+    }
+
+    /// The stored fields as update data for Project::apply_update
+    pub fn to_update_data(&self) -> ProjectUpdateData {
+        ProjectUpdateData {
+            version: PROJECT_UPDATE_DATA_VERSION,
+            category: EXPECTED_CATEGORY.to_string(),
+            operation: EXPECTED_UPDATE_OPERATION.to_string(),
+            project_id: self.project_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            image: self.image.clone(),
+            website: self.website.clone(),
+            tags: self.tags.clone(),
+        }
+    }
+}
+
+/// One creator's endorsement of a project (PDA seeded by target project and endorser)
+#[account]
+pub struct Endorsement {
+    pub target_project_id: u64,       // Endorsed project
+    pub endorser: Pubkey,             // Endorsing creator
+    pub endorser_project_id: u64,     // Project the endorser proved ownership of
+    pub endorsed_at: i64,             // Endorsement timestamp (0 = unused)
+    pub bump: u8,                     // PDA bump
+}
+
+impl Endorsement {
+    pub const SPACE: usize = 8 + // discriminator
+        8 + // target_project_id
+        32 + // endorser
+        8 + // endorser_project_id
+        8 + // endorsed_at
+        1 + // bump
+        16; // safety buffer
+
+    /// Record the endorsement; fails when this endorser already endorsed the target
+    pub fn claim(
+        &mut self,
+        target_project_id: u64,
+        endorser: Pubkey,
+        endorser_project_id: u64,
+        current_time: i64,
+        bump: u8,
+    ) -> Result<()> {
+        if self.endorsed_at != 0 {
+            msg!("Project {} already endorsed by {}", target_project_id, endorser);
+            return Err(ErrorCode::AlreadyEndorsed.into());
+        }
+        self.target_project_id = target_project_id;
+        self.endorser = endorser;
+        self.endorser_project_id = endorser_project_id;
+        self.endorsed_at = current_time;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// Opt-in on-chain proof of one burn (PDA seeded by burner and a client-chosen nonce)
+#[account]
+pub struct BurnReceipt {
+    pub burner: Pubkey,               // Wallet that burned
+    pub amount: u64,                  // Burned amount (in units)
+    pub target_id: u64,               // Project the burn was for
+    pub timestamp: i64,               // Time of the burn (0 = unused)
+    pub program_kind: u8,             // BURN_RECEIPT_PROGRAM_KIND of the issuing program
+    pub bump: u8,                     // PDA bump
+}
+
+impl BurnReceipt {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // burner (Pubkey)
+        8 + // amount (u64)
+        8 + // target_id (u64)
+        8 + // timestamp (i64)
+        1 + // program_kind (u8)
+        1; // bump (u8)
+
+    /// BurnReceipt PDA and bump for a burner's nonce
+    pub fn find_address(burner: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"receipt", burner.as_ref(), &nonce.to_le_bytes()], &crate::ID)
+    }
+
+    /// Write the receipt, failing with DuplicateReceiptNonce if the nonce was already used
+    pub fn record(&mut self, burner: Pubkey, amount: u64, target_id: u64, timestamp: i64, bump: u8) -> Result<()> {
+        if self.timestamp != 0 {
+            msg!("Duplicate receipt nonce: receipt already written for {} at {}", self.burner, self.timestamp);
+            return Err(ErrorCode::DuplicateReceiptNonce.into());
+        }
+        self.burner = burner;
+        self.amount = amount;
+        self.target_id = target_id;
+        self.timestamp = timestamp;
+        self.program_kind = BURN_RECEIPT_PROGRAM_KIND;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+/// Program-wide burn/mint totals (singleton PDA, initialized by admin)
+#[account]
+pub struct ProgramStats {
+    pub total_burned: u128,     // Total units burned through this program
+    pub total_minted: u128,     // Total units minted through this program
+    pub event_seq: u64,         // Sequence number of the last emitted event (0 = none yet)
+    pub bump: u8,               // PDA bump
+}
+
+impl ProgramStats {
+    pub const SPACE: usize = 8 + // discriminator
+        16 + // total_burned (u128)
+        16 + // total_minted (u128)
+        8 +  // event_seq (u64)
+        1;   // bump (u8)
+
+    /// Add a burn to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_burn(&mut self, amount: u64) {
+        self.total_burned = self.total_burned.saturating_add(u128::from(amount));
+    }
+
+    /// Add a mint to the running total (saturates at u128::MAX instead of overflowing)
+    pub fn record_mint(&mut self, amount: u64) {
+        self.total_minted = self.total_minted.saturating_add(u128::from(amount));
+    }
+
+    /// Advance the event sequence and return the new value. The first event gets 1,
+    /// so indexers can detect dropped events by looking for gaps in the sequence.
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.saturating_add(1);
+        self.event_seq
+    }
+}
+
+/// Admin-granted exemption from per-wallet rate limits, seeded by the exempt wallet
+#[account]
+pub struct RateLimitExemption {
+    pub wallet: Pubkey,               // Exempt wallet
+    pub granted_at: i64,              // When the admin granted the exemption
+    pub bump: u8,                     // PDA bump
+}
+
+impl RateLimitExemption {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // wallet (Pubkey)
+        8 + // granted_at (i64)
+        1; // bump (u8)
+
+    /// Whether `signer` may skip rate-limit checks (the exemption must belong to the signer)
+    pub fn exempts(exemption: Option<&Self>, signer: Pubkey) -> bool {
+        exemption.is_some_and(|exemption| exemption.wallet == signer)
+    }
+}
+
+/// Account structure for granting a rate-limit exemption (admin only)
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddRateLimitExemption<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = RateLimitExemption::SPACE,
+        seeds = [b"exempt", wallet.as_ref()],
+        bump
+    )]
+    pub rate_limit_exemption: Account<'info, RateLimitExemption>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for closing an expired idempotency marker (anyone)
+#[derive(Accounts)]
+pub struct CloseIdempotencyMarker<'info> {
+    pub closer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"idem", idempotency_marker.signer.as_ref(), idempotency_marker.key.as_ref()],
+        bump = idempotency_marker.bump
+    )]
+    pub idempotency_marker: Account<'info, IdempotencyMarker>,
+
+    /// CHECK: Receives the marker's rent; must be the wallet that created it
+    #[account(mut, address = idempotency_marker.signer)]
+    pub signer: AccountInfo<'info>,
+}
+
+/// Account structure for removing a rate-limit exemption (admin only)
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct RemoveRateLimitExemption<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"exempt", wallet.as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Account<'info, RateLimitExemption>,
+}
+
+/// Account structure for initializing the program stats (admin only)
+#[derive(Accounts)]
+pub struct InitializeProgramStats<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramStats::SPACE,
+        seeds = [b"stats"],
+        bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for initializing global counter (admin only)
+#[derive(Accounts)]
+pub struct InitializeGlobalCounter<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = GlobalProjectCounter::SPACE,
+        seeds = [b"global_counter"],
+        bump
+    )]
+    pub global_counter: Account<'info, GlobalProjectCounter>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for creating a project
+#[derive(Accounts)]
+#[instruction(expected_project_id: u64, burn_amount: u64, burn_cap: u64, idempotency_key: Option<[u8; 16]>)]
+pub struct CreateProject<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_counter"],
+        bump
+    )]
+    pub global_counter: Account<'info, GlobalProjectCounter>,
+    
+    /// CHECK: New project PDA, created by the handler (see create_project_account) instead of
+    /// `init` so an already-used id fails with ProjectIdAlreadyUsed rather than an opaque
+    /// system program error, and an older, differently sized account never hits ConstraintSpace
+    #[account(
+        mut,
+        seeds = [b"project", expected_project_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub project: UncheckedAccount<'info>,
+
+    /// Creator cooldown account - created on the creator's first project
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorCooldown::SPACE,
+        seeds = [b"creator_cooldown", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_cooldown: Account<'info, CreatorCooldown>,
+    
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = creator_token_account.owner == creator.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User global burn statistics tracking account (now required)
+    #[account(
+        mut,
+        seeds = [b"user_global_burn_stats", creator.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-burn program
+    pub memo_burn_program: Program<'info, MemoBurn>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// CHECK: Operation switches; verified in ProgramConfig::load_flags (all enabled until created)
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
+    /// CHECK: Minimum burns and tag limits; verified in EconomyConfig::load (constants until created)
+    #[account(
+        seeds = [b"economy_config"],
+        bump
+    )]
+    pub economy_config: UncheckedAccount<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Admin-granted rate-limit exemption for the creator (optional; skips the creation cooldown)
+    #[account(
+        seeds = [b"exempt", creator.key().as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+
+    /// Idempotency marker (required exactly when idempotency_key is set; created on first use)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = IdempotencyMarker::SPACE,
+        seeds = [b"idem", creator.key().as_ref(), idempotency_key.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub idempotency_marker: Option<Account<'info, IdempotencyMarker>>,
+
+    /// Parent project (required exactly when the memo sets parent_project_id; its child_count grows)
+    #[account(
+        mut,
+        constraint = Project::is_migrated(parent_project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub parent_project: Option<Account<'info, Project>>,
+}
+
+/// Account structure for forking a project
+#[derive(Accounts)]
+#[instruction(source_project_id: u64, expected_project_id: u64, burn_amount: u64)]
+pub struct ForkProject<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// Project whose metadata is copied (read-only)
+    #[account(
+        seeds = [b"project", source_project_id.to_le_bytes().as_ref()],
+        bump = source_project.bump,
+        constraint = Project::is_migrated(source_project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub source_project: Account<'info, Project>,
+    
+    #[account(
+        mut,
+        seeds = [b"global_counter"],
+        bump
+    )]
+    pub global_counter: Account<'info, GlobalProjectCounter>,
+    
+    /// CHECK: New project PDA, created by the handler (see create_project_account) instead of
+    /// `init` so an already-used id fails with ProjectIdAlreadyUsed rather than an opaque
+    /// system program error, and an older, differently sized account never hits ConstraintSpace
+    #[account(
+        mut,
+        seeds = [b"project", expected_project_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub project: UncheckedAccount<'info>,
+
+    /// Creator cooldown account - created on the creator's first project
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = CreatorCooldown::SPACE,
+        seeds = [b"creator_cooldown", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_cooldown: Account<'info, CreatorCooldown>,
+    
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = creator_token_account.owner == creator.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User global burn statistics tracking account
+    #[account(
+        mut,
+        seeds = [b"user_global_burn_stats", creator.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-burn program
+    pub memo_burn_program: Program<'info, MemoBurn>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// CHECK: Operation switches; verified in ProgramConfig::load_flags (all enabled until created)
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
+    /// CHECK: Minimum burns and tag limits; verified in EconomyConfig::load (constants until created)
+    #[account(
+        seeds = [b"economy_config"],
+        bump
+    )]
+    pub economy_config: UncheckedAccount<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Admin-granted rate-limit exemption for the creator (optional; skips the creation cooldown)
+    #[account(
+        seeds = [b"exempt", creator.key().as_ref()],
+        bump = rate_limit_exemption.bump
+    )]
+    pub rate_limit_exemption: Option<Account<'info, RateLimitExemption>>,
+}
+
+/// Account structure for updating a project
+#[derive(Accounts)]
+#[instruction(project_id: u64, burn_amount: u64)]
+pub struct UpdateProject<'info> {
+    #[account(
+        mut,
+        constraint = project.can_update(updater.key()) @ ErrorCode::UnauthorizedProjectAccess
+    )]
+    pub updater: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = updater_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = updater_token_account.owner == updater.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub updater_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User global burn statistics tracking account (now required)
+    #[account(
+        mut,
+        seeds = [b"user_global_burn_stats", updater.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-burn program
+    pub memo_burn_program: Program<'info, MemoBurn>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    /// CHECK: Operation switches; verified in ProgramConfig::load_flags (all enabled until created)
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
+    /// Creates the TagStats / TagProjectList accounts of tags the update adds
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Minimum burns and tag limits; verified in EconomyConfig::load (constants until created)
+    #[account(
+        seeds = [b"economy_config"],
+        bump
+    )]
+    pub economy_config: UncheckedAccount<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for scheduling a project update (same signers as update_project)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct ScheduleProjectUpdate<'info> {
+    #[account(
+        mut,
+        constraint = project.can_update(updater.key()) @ ErrorCode::UnauthorizedProjectAccess
+    )]
+    pub updater: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+    
+    /// init_if_needed so an update that is still pending reaches the handler and fails
+    /// with UpdateAlreadyScheduled (see PendingUpdate::schedule)
+    #[account(
+        init_if_needed,
+        payer = updater,
+        space = PendingUpdate::SPACE,
+        seeds = [b"pending_update", project_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_update: Account<'info, PendingUpdate>,
+    
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = updater_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = updater_token_account.owner == updater.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub updater_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User global burn statistics tracking account
+    #[account(
+        mut,
+        seeds = [b"user_global_burn_stats", updater.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-burn program
+    pub memo_burn_program: Program<'info, MemoBurn>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Operation switches; verified in ProgramConfig::load_flags (all enabled until created)
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
+    /// CHECK: Minimum burns and tag limits; verified in EconomyConfig::load (constants until created)
+    #[account(
+        seeds = [b"economy_config"],
+        bump
+    )]
+    pub economy_config: UncheckedAccount<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for applying a due scheduled update (anyone can call)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct ApplyScheduledUpdate<'info> {
+    /// Pays for the tag accounts of tags the update adds
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(
+        mut,
+        close = scheduler,
+        seeds = [b"pending_update", project_id.to_le_bytes().as_ref()],
+        bump = pending_update.bump
+    )]
+    pub pending_update: Account<'info, PendingUpdate>,
+    
+    /// CHECK: Receives the PendingUpdate rent; must be the wallet that scheduled it
+    #[account(
+        mut,
+        constraint = scheduler.key() == pending_update.scheduler @ ErrorCode::InvalidSchedulerAccount
+    )]
+    pub scheduler: UncheckedAccount<'info>,
+
+    /// CHECK: Operation switches; verified in ProgramConfig::load_flags (all enabled until created)
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Aggregate counts returned by get_global_stats (Borsh-encoded return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GlobalStats {
+    pub total_projects: u64,
+    pub leaderboard_size: u32,
+    pub leaderboard_min: u64,   // Smallest burned amount on the leaderboard (0 if empty)
+}
+
+impl GlobalStats {
+    pub fn new(counter: &GlobalProjectCounter, leaderboard: &BurnLeaderboard) -> Self {
+        Self {
+            total_projects: counter.total_projects,
+            leaderboard_size: leaderboard.entries.len() as u32,
+            leaderboard_min: leaderboard.entries.iter().map(|e| e.burned_amount).min().unwrap_or(0),
+        }
+    }
+}
+
+/// Project metadata plus leaderboard rank returned by get_project_with_rank (Borsh-encoded
+/// return data; the fields are chosen so a maximum-size project stays within MAX_RETURN_DATA)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProjectWithRank {
+    pub project_id: u64,
+    pub creator: Pubkey,
+    pub name: String,
+    pub description: String,
+    pub image: String,
+    pub website: String,
+    pub tags: Vec<String>,
+    pub created_at: i64,
+    pub last_updated: i64,
+    pub memo_count: u64,
+    pub burned_amount: u64,
+    pub verified: bool,
+    pub burns_frozen: bool,     // Burns blocked by the admin (metadata stays readable)
+    pub trending_eligible: bool, // Burned on at least MIN_ACTIVE_DAYS distinct days
+    pub rank: Option<u32>,      // 1-based leaderboard rank (None if not on the leaderboard)
+    pub total_entries: u32,     // Number of leaderboard entries
+    pub trend_score: u64,       // Project::trend_score at call time (trending lists are ordered by it)
+}
+
+impl ProjectWithRank {
+    pub fn new(project: &Project, leaderboard: &BurnLeaderboard, now: i64) -> Self {
+        Self {
+            project_id: project.project_id,
+            creator: project.creator,
+            name: project.name.clone(),
+            description: project.description.clone(),
+            image: project.image.clone(),
+            website: project.website.clone(),
+            tags: project.tags.clone(),
+            created_at: project.created_at,
+            last_updated: project.last_updated,
+            memo_count: project.memo_count,
+            burned_amount: project.burned_amount,
+            verified: project.verified,
+            burns_frozen: project.burns_frozen,
+            trending_eligible: project.is_trending_eligible(),
+            rank: leaderboard.rank_of(project.project_id),
+            total_entries: leaderboard.entries.len() as u32,
+            trend_score: project.trend_score(now),
+        }
+    }
+}
+
+/// Leaderboard entry bar returned by get_leaderboard_min (Borsh-encoded return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LeaderboardMin {
+    pub min_burned_amount: u64, // Amount a newcomer must exceed (0 while the board has free slots)
+    pub is_full: bool,
+}
+
+impl LeaderboardMin {
+    pub fn new(leaderboard: &BurnLeaderboard) -> Self {
+        if leaderboard.entries.len() < leaderboard.capacity() {
+            return Self { min_burned_amount: 0, is_full: false };
+        }
+        Self {
+            min_burned_amount: leaderboard.entries.iter().map(|e| e.burned_amount).min().unwrap_or(0),
+            is_full: true,
+        }
+    }
+}
+
+/// Result of calculate_required_space (Borsh-encoded return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequiredSpace {
+    pub space: u64,
+    pub rent_lamports: u64,     // Rent-exempt minimum balance for `space` bytes
+}
+
+impl RequiredSpace {
+    pub fn new(space: usize, rent: &Rent) -> Self {
+        Self {
+            space: space as u64,
+            rent_lamports: rent.minimum_balance(space),
+        }
     }
+}
+
+/// Account structure for the dry-run space calculator (no accounts needed)
+#[derive(Accounts)]
+pub struct CalculateRequiredSpace {}
+
+/// Account structure for migrating a legacy-sized project (permissionless, payer covers rent)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct MigrateProject<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: legacy projects are not decoded as Project; owner, discriminator and size are
+    /// checked in migrate_project
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub project: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for reading global stats (read-only)
+#[derive(Accounts)]
+pub struct GetGlobalStats<'info> {
+    #[account(
+        seeds = [b"global_counter"],
+        bump
+    )]
+    pub global_counter: Account<'info, GlobalProjectCounter>,
     
-    msg!("Borsh+Base64 update memo validation passed: version {}, {} units, payload: {} bytes", 
-         burn_memo.version, expected_amount, burn_memo.payload.len());
+    #[account(
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+}
+
+/// Account structure for reading the leaderboard entry bar (read-only)
+#[derive(Accounts)]
+pub struct GetLeaderboardMin<'info> {
+    #[account(
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+}
+
+/// Account structure for reading a project with its leaderboard rank (read-only)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct GetProjectWithRank<'info> {
+    #[account(
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
     
-    // Deserialize ProjectUpdateData from payload
-    let update_data = ProjectUpdateData::try_from_slice(&burn_memo.payload)
-        .map_err(|_| {
-            msg!("Invalid project update data format in payload");
-            ErrorCode::InvalidProjectDataFormat
-        })?;
+    #[account(
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+}
+
+/// Account structure for initializing burn leaderboard (admin only)
+#[derive(Accounts)]
+pub struct InitializeBurnLeaderboard<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = BurnLeaderboard::SPACE,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for resizing the burn leaderboard (admin only).
+/// Shared by resize_leaderboard and shrink_leaderboard: realloc charges the admin when
+/// growing and refunds the excess rent to the admin when shrinking.
+#[derive(Accounts)]
+#[instruction(new_capacity: u16)]
+pub struct ResizeLeaderboard<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
     
-    // Validate the project update data
-    update_data.validate(expected_project_id)?;
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump,
+        realloc = BurnLeaderboard::space_for(new_capacity as usize),
+        realloc::payer = admin,
+        realloc::zero = false
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
     
-    msg!("Project update data parsed successfully: project_id={}, has updates: name={}, description={}, image={}, website={}, tag={}", 
-         update_data.project_id, 
-         update_data.name.is_some(),
-         update_data.description.is_some(),
-         update_data.image.is_some(),
-         update_data.website.is_some(),
-         update_data.tags.is_some());
-
-    Ok(update_data)
+    pub system_program: Program<'info, System>,
 }
 
-/// Parse and validate Borsh-formatted memo data for project burn (with Base64 decoding)
-fn parse_project_burn_borsh_memo(memo_data: &[u8], expected_project_id: u64, expected_amount: u64, expected_burner: Pubkey) -> Result<()> {
-    // First, decode the Base64-encoded memo data
-    let base64_str = std::str::from_utf8(memo_data)
-        .map_err(|_| {
-            msg!("Invalid UTF-8 in memo data");
-            ErrorCode::InvalidMemoFormat
-        })?;
+/// Account structure for force-removing a leaderboard entry (admin only)
+#[derive(Accounts)]
+pub struct AdminRemoveLeaderboardEntry<'info> {
+    /// One of the admin signers (the rest are passed as signer remaining accounts)
+    pub admin: Signer<'info>,
     
-    let decoded_data = general_purpose::STANDARD.decode(base64_str)
-        .map_err(|_| {
-            msg!("Invalid Base64 encoding in memo");
-            ErrorCode::InvalidMemoFormat
-        })?;
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
 
-    // Check decoded borsh data size
-    if decoded_data.len() > MAX_BORSH_DATA_SIZE {
-        msg!("Decoded data too large: {} bytes (max: {})", decoded_data.len(), MAX_BORSH_DATA_SIZE);
-        return Err(ErrorCode::InvalidMemoFormat.into());
-    }
-    
-    msg!("Base64 decoded: {} bytes -> {} bytes", memo_data.len(), decoded_data.len());
-    
-    // Deserialize Borsh data from decoded bytes (following memo-burn pattern)
-    let burn_memo = BurnMemo::try_from_slice(&decoded_data)
-        .map_err(|_| {
-            msg!("Invalid Borsh format after Base64 decoding");
-            ErrorCode::InvalidMemoFormat
-        })?;
-    
-    // Validate version compatibility
-    if burn_memo.version != BURN_MEMO_VERSION {
-        msg!("Unsupported memo version: {} (expected: {})", 
-             burn_memo.version, BURN_MEMO_VERSION);
-        return Err(ErrorCode::UnsupportedMemoVersion.into());
-    }
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for pinning a project on the burn leaderboard (admin only)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct PinProject<'info> {
+    #[account(
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
     
-    // Validate burn amount matches
-    if burn_memo.burn_amount != expected_amount {
-        msg!("Burn amount mismatch: memo {} vs expected {}", 
-             burn_memo.burn_amount, expected_amount);
-        return Err(ErrorCode::BurnAmountMismatch.into());
-    }
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+
+    #[account(
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for creating the admin multisig (hardcoded admin only, once)
+#[derive(Accounts)]
+pub struct InitializeAdminConfig<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
     
-    // Validate payload length does not exceed maximum allowed value
-    if burn_memo.payload.len() > MAX_PAYLOAD_LENGTH {
-        msg!("Payload too long: {} bytes (max: {})", 
-             burn_memo.payload.len(), MAX_PAYLOAD_LENGTH);
-        return Err(ErrorCode::PayloadTooLong.into());
-    }
+    #[account(
+        init,
+        payer = admin,
+        space = AdminConfig::SPACE,
+        seeds = [b"admin_config"],
+        bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
     
-    msg!("Borsh+Base64 burn memo validation passed: version {}, {} units, payload: {} bytes", 
-         burn_memo.version, expected_amount, burn_memo.payload.len());
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for replacing the admin signer set (admin multisig)
+#[derive(Accounts)]
+pub struct SetAdminSigners<'info> {
+    /// One of the admin signers (the rest are passed as signer remaining accounts)
+    pub admin: Signer<'info>,
     
-    // Deserialize project burn data from payload
-    let burn_data = ProjectBurnData::try_from_slice(&burn_memo.payload)
-        .map_err(|_| {
-            msg!("Invalid project burn data format in payload");
-            ErrorCode::InvalidProjectBurnDataFormat
-        })?;
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for setting the operation flags (admin multisig)
+#[derive(Accounts)]
+pub struct SetOperationFlags<'info> {
+    /// One of the admin signers (pays for the config; the rest are passed as signer remaining accounts)
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
     
-    // Validate project burn data
-    burn_data.validate(expected_project_id, expected_burner)?;
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ProgramConfig::SPACE,
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
     
-    Ok(())
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-/// Check for memo instruction at REQUIRED index 0
-/// 
-/// IMPORTANT: This contract enforces memo at index 0:
-/// - Index 0: SPL Memo instruction (REQUIRED)
-/// - Index 1+: memo-project instructions (create_project, update_project, etc.)
-/// 
-/// Compute budget instructions can be placed anywhere in the transaction
-/// as they are processed by Solana runtime before instruction execution.
-fn check_memo_instruction(instructions: &AccountInfo) -> Result<(bool, Vec<u8>)> {
-    // Get current instruction index
-    let current_index = anchor_lang::solana_program::sysvar::instructions::load_current_index_checked(instructions)?;
+/// Account structure for setting the economy config (admin only)
+#[derive(Accounts)]
+pub struct SetEconomyConfig<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
     
-    // Current instruction must be at index 1 or later
-    // to leave index 0 available for memo
-    if current_index < 1 {
-        msg!("memo-project instruction must be at index 1 or later, but current instruction is at index {}", current_index);
-        return Ok((false, vec![]));
-    }
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = EconomyConfig::SPACE,
+        seeds = [b"economy_config"],
+        bump
+    )]
+    pub economy_config: Account<'info, EconomyConfig>,
     
-    // Check that index 0 contains the memo instruction
-    match anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked(0, instructions) {
-        Ok(ix) => {
-            if ix.program_id == MEMO_PROGRAM_ID {
-                msg!("Found memo instruction at required index 0");
-                validate_memo_length(&ix.data, MEMO_MIN_LENGTH, MEMO_MAX_LENGTH)
-            } else {
-                msg!("Instruction at index 0 is not a memo (program_id: {})", ix.program_id);
-                Ok((false, vec![]))
-            }
-        },
-        Err(e) => {
-            msg!("Failed to load instruction at required index 0: {:?}", e);
-            Ok((false, vec![]))
-        }
-    }
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-/// Validate memo data length and return result
-fn validate_memo_length(memo_data: &[u8], min_length: usize, max_length: usize) -> Result<(bool, Vec<u8>)> {
-    let memo_length = memo_data.len();
-    
-    // Ensure data is not empty
-    if memo_data.is_empty() {
-        msg!("Memo data is empty");
-        return Err(ErrorCode::MemoTooShort.into());
-    }
+/// Account structure for capturing a leaderboard snapshot (admin only)
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct SnapshotLeaderboard<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
     
-    // Check minimum length requirement
-    if memo_length < min_length {
-        msg!("Memo too short: {} bytes (minimum: {})", memo_length, min_length);
-        return Err(ErrorCode::MemoTooShort.into());
-    }
+    #[account(
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
     
-    // Check maximum length requirement
-    if memo_length > max_length {
-        msg!("Memo too long: {} bytes (maximum: {})", memo_length, max_length);
-        return Err(ErrorCode::MemoTooLong.into());
-    }
+    /// init_if_needed so a repeated epoch reaches the handler and fails with
+    /// SnapshotAlreadyExists (see LeaderboardSnapshot::check_unused)
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = LeaderboardSnapshot::SPACE,
+        seeds = [b"snapshot", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, LeaderboardSnapshot>,
     
-    // Length is valid, return memo data
-    msg!("Memo length validation passed: {} bytes (range: {}-{})", memo_length, min_length, max_length);
-    Ok((true, memo_data.to_vec()))
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-/// Burn leaderboard entry
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
-pub struct LeaderboardEntry {
-    pub project_id: u64,
-    pub burned_amount: u64,
+/// Account structure for setting the verified flag of a project (admin only)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct SetProjectVerified<'info> {
+    #[account(
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-/// Burn leaderboard account (stores top 100 projects by burn amount)
-#[account]
-pub struct BurnLeaderboard {
-    /// Array of leaderboard entries (unsorted for performance - sort off-chain for display)
-    /// Maximum 100 entries
-    pub entries: Vec<LeaderboardEntry>,
+/// Account structure for freezing burns on a project (admin only)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct AdminFreezeProjectBurns<'info> {
+    #[account(
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-impl BurnLeaderboard {
-    pub const SPACE: usize = 8 + // discriminator
-        4 + // Vec length prefix
-        100 * 16 + // max entries (100 * (8 + 8) bytes each)
-        64; // safety buffer
-    
-    /// Initialize with empty entries
-    pub fn initialize(&mut self) {
-        self.entries = Vec::with_capacity(100);
-    }
+/// Account structure for correcting a project's burned_amount (admin only)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct RecomputeProjectBurns<'info> {
+    #[account(
+        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+    )]
+    pub admin: Signer<'info>,
     
-    /// find project position and min burned_amount position (core optimization)
-    pub fn find_project_position_and_min(&self, project_id: u64) -> (Option<usize>, Option<usize>) {
-        if self.entries.is_empty() {
-            return (None, None);
-        }
-        
-        let mut min_pos = None;
-        let mut min_amount = u64::MAX;
-        let mut found_project_pos = None;
-        
-        // loop all elements
-        for (i, entry) in self.entries.iter().enumerate() {
-            // record target project position
-            if entry.project_id == project_id {
-                found_project_pos = Some(i);
-            }
-            
-            // always record min position
-            if entry.burned_amount < min_amount {
-                min_amount = entry.burned_amount;
-                min_pos = Some(i);
-            }
-        }
-        
-        (found_project_pos, min_pos)
-    }
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
     
-    /// update leaderboard - zero array move version
-    pub fn update_leaderboard(&mut self, project_id: u64, new_burned_amount: u64) -> Result<bool> {
-        // 1. one loop to get project position and min position
-        let (existing_pos, min_pos) = self.find_project_position_and_min(project_id);
-        
-        // 2. if project exists, update burned_amount (zero move)
-        if let Some(pos) = existing_pos {
-            self.entries[pos].burned_amount = new_burned_amount;
-            return Ok(true);
-        }
-        
-        // 3. new project and leaderboard not full, add directly (no sort)
-        if self.entries.len() < 100 {
-            let new_entry = LeaderboardEntry {
-                project_id,
-                burned_amount: new_burned_amount,
-            };
-            self.entries.push(new_entry);
-            return Ok(true);
-        }
-        
-        // 4. new project and leaderboard full, check if can replace min value
-        if let Some(min_position) = min_pos {
-            let min_amount = self.entries[min_position].burned_amount;
-            if new_burned_amount > min_amount {
-                // replace min value entry (zero move)
-                self.entries[min_position] = LeaderboardEntry {
-                    project_id,
-                    burned_amount: new_burned_amount,
-                };
-                return Ok(true);
-            } else {
-                // new value not big enough, cannot enter leaderboard
-                return Ok(false);
-            }
-        }
-        
-        Ok(false)
-    }
+    #[account(
+        mut,
+        seeds = [b"burn_leaderboard"],
+        bump
+    )]
+    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-/// Global project counter account
-#[account]
-pub struct GlobalProjectCounter {
-    pub total_projects: u64,          // Total number of projects created (starts at 0)
+/// Account structure for setting the delegate of a project (creator only)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct SetProjectDelegate<'info> {
+    #[account(
+        constraint = creator.key() == project.creator @ ErrorCode::UnauthorizedProjectAccess
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-impl GlobalProjectCounter {
-    pub const SPACE: usize = 8 + // discriminator
-        8; // total_projects (u64)
+/// Account structure for setting the co-owners of a project (creator only)
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct SetCoOwners<'info> {
+    #[account(
+        constraint = creator.key() == project.creator @ ErrorCode::UnauthorizedProjectAccess
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-/// Account structure for initializing global counter (admin only)
+/// Account structure for claiming a project slug (creator only)
 #[derive(Accounts)]
-pub struct InitializeGlobalCounter<'info> {
+#[instruction(project_id: u64, slug: String)]
+pub struct SetProjectSlug<'info> {
     #[account(
         mut,
-        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+        constraint = creator.key() == project.creator @ ErrorCode::UnauthorizedProjectAccess
     )]
-    pub admin: Signer<'info>,
+    pub creator: Signer<'info>,
     
     #[account(
-        init,
-        payer = admin,
-        space = GlobalProjectCounter::SPACE,
-        seeds = [b"global_counter"],
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+    
+    /// init_if_needed so a taken slug reaches the handler and fails with
+    /// ProjectSlugTaken (see ProjectSlug::claim)
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = ProjectSlug::SPACE,
+        seeds = [b"slug", slug.as_bytes()],
         bump
     )]
-    pub global_counter: Account<'info, GlobalProjectCounter>,
+    pub project_slug: Account<'info, ProjectSlug>,
     
     pub system_program: Program<'info, System>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
 }
 
-/// Account structure for creating a project
+/// Account structure for endorsing a project (creators of an existing project only)
 #[derive(Accounts)]
-#[instruction(expected_project_id: u64, burn_amount: u64)]
-pub struct CreateProject<'info> {
+#[instruction(target_project_id: u64, endorser_project_id: u64)]
+pub struct EndorseProject<'info> {
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub endorser: Signer<'info>,
+    
+    /// A project owned by the endorser, as proof they are an established creator
+    #[account(
+        seeds = [b"project", endorser_project_id.to_le_bytes().as_ref()],
+        bump = endorser_project.bump,
+        constraint = endorser_project.creator == endorser.key() @ ErrorCode::EndorserNotProjectCreator,
+        constraint = Project::is_migrated(endorser_project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub endorser_project: Account<'info, Project>,
     
     #[account(
         mut,
-        seeds = [b"global_counter"],
-        bump
+        seeds = [b"project", target_project_id.to_le_bytes().as_ref()],
+        bump = target_project.bump,
+        constraint = Project::is_migrated(target_project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
     )]
-    pub global_counter: Account<'info, GlobalProjectCounter>,
+    pub target_project: Account<'info, Project>,
     
+    /// init_if_needed so a repeated endorsement reaches the handler and fails with
+    /// AlreadyEndorsed (see Endorsement::claim)
     #[account(
-        init,
-        payer = creator,
-        space = Project::calculate_space_max(),
-        seeds = [b"project", expected_project_id.to_le_bytes().as_ref()],
+        init_if_needed,
+        payer = endorser,
+        space = Endorsement::SPACE,
+        seeds = [b"endorse", target_project_id.to_le_bytes().as_ref(), endorser.key().as_ref()],
         bump
     )]
+    pub endorsement: Account<'info, Endorsement>,
+    
+    pub system_program: Program<'info, System>,
+
+    /// Program-wide stats (advances the event sequence)
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+/// Account structure for burning tokens for a project
+#[derive(Accounts)]
+#[instruction(project_id: u64, amount: u64, receipt_nonce: Option<u64>)]
+pub struct BurnForProject<'info> {
+    #[account(
+        mut,
+        constraint = burner.key() == project.creator @ ErrorCode::UnauthorizedProjectAccess
+    )]
+    pub burner: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
     pub project: Account<'info, Project>,
     
     #[account(
@@ -1224,7 +5477,109 @@ pub struct CreateProject<'info> {
     
     #[account(
         mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = burner_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = burner_token_account.owner == burner.key() @ ErrorCode::UnauthorizedTokenAccount
+    )]
+    pub burner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// User global burn statistics tracking account (now required)
+    #[account(
+        mut,
+        seeds = [b"user_global_burn_stats", burner.key().as_ref()],
+        bump,
+        seeds::program = memo_burn_program.key()
+    )]
+    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
+    
+    pub token_program: Program<'info, Token2022>,
+    
+    /// The memo-burn program
+    pub memo_burn_program: Program<'info, MemoBurn>,
+    
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: Operation switches; verified in ProgramConfig::load_flags (all enabled until created)
+    #[account(
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
+    /// CHECK: Minimum burns and tag limits; verified in EconomyConfig::load (constants until created)
+    #[account(
+        seeds = [b"economy_config"],
+        bump
+    )]
+    pub economy_config: UncheckedAccount<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
+    #[account(
+        mut,
+        seeds = [b"stats"],
+        bump = program_stats.bump
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+
+    /// Donor message board (required when the burn memo carries a message, which is appended)
+    #[account(
+        mut,
+        seeds = [b"board", project_id.to_le_bytes().as_ref()],
+        bump = message_board.bump
+    )]
+    pub message_board: Option<Account<'info, ProjectMessageBoard>>,
+
+    /// Burn receipt (required exactly when receipt_nonce is set; created on first use)
+    #[account(
+        init_if_needed,
+        payer = burner,
+        space = BurnReceipt::SPACE,
+        seeds = [b"receipt", burner.key().as_ref(), receipt_nonce.unwrap_or_default().to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_receipt: Option<Account<'info, BurnReceipt>>,
+
+    /// Recent burners ring buffer (required once initialized for the project; records the burn)
+    #[account(
+        mut,
+        seeds = [b"recent", project_id.to_le_bytes().as_ref()],
+        bump = recent_burners.bump
+    )]
+    pub recent_burners: Option<Account<'info, RecentBurners>>,
+}
+
+/// Account structure for boosting a project (creator only)
+#[derive(Accounts)]
+#[instruction(project_id: u64, burn_amount: u64)]
+pub struct BoostProject<'info> {
+    #[account(
+        mut,
+        constraint = creator.key() == project.creator @ ErrorCode::UnauthorizedProjectAccess
+    )]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
+    )]
+    pub project: Account<'info, Project>,
+    
+    #[account(
+        mut,
+        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint,
+        constraint = mint_decimals_valid(mint.decimals) @ ErrorCode::UnexpectedMintDecimals
     )]
     pub mint: InterfaceAccount<'info, Mint>,
     
@@ -1235,7 +5590,7 @@ pub struct CreateProject<'info> {
     )]
     pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    /// User global burn statistics tracking account (now required)
+    /// User global burn statistics tracking account
     #[account(
         mut,
         seeds = [b"user_global_burn_stats", creator.key().as_ref()],
@@ -1249,182 +5604,739 @@ pub struct CreateProject<'info> {
     /// The memo-burn program
     pub memo_burn_program: Program<'info, MemoBurn>,
     
-    pub system_program: Program<'info, System>,
-    
     /// CHECK: Instructions sysvar
     #[account(address = INSTRUCTIONS_ID)]
     pub instructions: AccountInfo<'info>,
-}
 
-/// Account structure for updating a project
-#[derive(Accounts)]
-#[instruction(project_id: u64, burn_amount: u64)]
-pub struct UpdateProject<'info> {
-    #[account(
-        mut,
-        constraint = updater.key() == project.creator @ ErrorCode::UnauthorizedProjectAccess
-    )]
-    pub updater: Signer<'info>,
-    
-    #[account(
-        mut,
-        seeds = [b"project", project_id.to_le_bytes().as_ref()],
-        bump = project.bump
-    )]
-    pub project: Account<'info, Project>,
-    
+    /// CHECK: Operation switches; verified in ProgramConfig::load_flags (all enabled until created)
     #[account(
-        mut,
-        seeds = [b"burn_leaderboard"],
+        seeds = [b"program_config"],
         bump
     )]
-    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
-    
+    pub program_config: UncheckedAccount<'info>,
+
+    /// CHECK: Minimum burns and tag limits; verified in EconomyConfig::load (constants until created)
     #[account(
-        mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
+        seeds = [b"economy_config"],
+        bump
     )]
-    pub mint: InterfaceAccount<'info, Mint>,
-    
+    pub economy_config: UncheckedAccount<'info>,
+
+    /// Program-wide burn/mint totals and event sequence
     #[account(
         mut,
-        constraint = updater_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
-        constraint = updater_token_account.owner == updater.key() @ ErrorCode::UnauthorizedTokenAccount
+        seeds = [b"stats"],
+        bump = program_stats.bump
     )]
-    pub updater_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub program_stats: Account<'info, ProgramStats>,
 
-    /// User global burn statistics tracking account (now required)
+    /// Recent burners ring buffer (required once initialized for the project; records the boost)
     #[account(
         mut,
-        seeds = [b"user_global_burn_stats", updater.key().as_ref()],
-        bump,
-        seeds::program = memo_burn_program.key()
+        seeds = [b"recent", project_id.to_le_bytes().as_ref()],
+        bump = recent_burners.bump
     )]
-    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
-    
-    pub token_program: Program<'info, Token2022>,
-    
-    /// The memo-burn program
-    pub memo_burn_program: Program<'info, MemoBurn>,
-    
-    /// CHECK: Instructions sysvar
-    #[account(address = INSTRUCTIONS_ID)]
-    pub instructions: AccountInfo<'info>,
+    pub recent_burners: Option<Account<'info, RecentBurners>>,
 }
 
-/// Account structure for initializing burn leaderboard (admin only)
+/// Account structure for creating a project's donor message board
 #[derive(Accounts)]
-pub struct InitializeBurnLeaderboard<'info> {
+#[instruction(project_id: u64)]
+pub struct InitializeMessageBoard<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
     #[account(
-        mut,
-        constraint = admin.key() == AUTHORIZED_ADMIN_PUBKEY @ ErrorCode::UnauthorizedAdmin
+        seeds = [b"project", project_id.to_le_bytes().as_ref()],
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
     )]
-    pub admin: Signer<'info>,
+    pub project: Account<'info, Project>,
     
     #[account(
         init,
-        payer = admin,
-        space = BurnLeaderboard::SPACE,
-        seeds = [b"burn_leaderboard"],
+        payer = payer,
+        space = ProjectMessageBoard::INITIAL_SPACE,
+        seeds = [b"board", project_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+    pub message_board: Account<'info, ProjectMessageBoard>,
     
     pub system_program: Program<'info, System>,
 }
 
-/// Account structure for burning tokens for a project
+/// Account structure for creating a project's recent-burners ring buffer
 #[derive(Accounts)]
-#[instruction(project_id: u64, amount: u64)]
-pub struct BurnForProject<'info> {
-    #[account(
-        mut,
-        constraint = burner.key() == project.creator @ ErrorCode::UnauthorizedProjectAccess
-    )]
-    pub burner: Signer<'info>,
+#[instruction(project_id: u64)]
+pub struct InitializeRecentBurners<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     
     #[account(
         mut,
         seeds = [b"project", project_id.to_le_bytes().as_ref()],
-        bump = project.bump
+        bump = project.bump,
+        constraint = Project::is_migrated(project.to_account_info().data_len()) @ ErrorCode::ProjectNotMigrated
     )]
     pub project: Account<'info, Project>,
     
     #[account(
-        mut,
-        seeds = [b"burn_leaderboard"],
+        init,
+        payer = payer,
+        space = RecentBurners::SPACE,
+        seeds = [b"recent", project_id.to_le_bytes().as_ref()],
         bump
     )]
-    pub burn_leaderboard: Account<'info, BurnLeaderboard>,
+    pub recent_burners: Account<'info, RecentBurners>,
     
-    #[account(
-        mut,
-        constraint = mint.key() == AUTHORIZED_MINT_PUBKEY @ ErrorCode::UnauthorizedMint
-    )]
-    pub mint: InterfaceAccount<'info, Mint>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for growing a donor message board to its full size
+#[derive(Accounts)]
+#[instruction(project_id: u64)]
+pub struct ExtendMessageBoard<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
     
     #[account(
         mut,
-        constraint = burner_token_account.mint == mint.key() @ ErrorCode::InvalidTokenAccount,
-        constraint = burner_token_account.owner == burner.key() @ ErrorCode::UnauthorizedTokenAccount
+        seeds = [b"board", project_id.to_le_bytes().as_ref()],
+        bump = message_board.bump,
+        realloc = ProjectMessageBoard::SPACE,
+        realloc::payer = payer,
+        realloc::zero = false
     )]
-    pub burner_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub message_board: Account<'info, ProjectMessageBoard>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for simulating memo validation (read-only)
+#[derive(Accounts)]
+pub struct ValidateMemo<'info> {
+    /// CHECK: Instructions sysvar
+    #[account(address = INSTRUCTIONS_ID)]
+    pub instructions: AccountInfo<'info>,
+}
+
+/// Project data structure
+#[account]
+pub struct Project {
+    pub project_id: u64,              // Sequential project ID (0, 1, 2, ...)
+    pub creator: Pubkey,              // Creator
+    pub created_at: i64,              // Creation timestamp
+    pub last_updated: i64,            // Last updated timestamp (updated on project updates)
+    pub name: String,                 // Project name
+    pub description: String,          // Project description
+    pub image: String,                // Project image info (max 256 chars)
+    pub website: String,              // Project website URL (max 128 chars)
+    pub tags: Vec<String>,            // Tags (max 4 tags, each max 32 chars)
+    pub memo_count: u64,              // Number of burn_for_project operations (not create/update)
+    pub burned_amount: u64,           // Total burned tokens for this project
+    pub last_memo_time: i64,          // Last burn_for_project operation timestamp (0 if never burned)
+    pub bump: u8,                     // PDA bump
+    // Fields below were appended after the original layout; projects created before
+    // them are converted by migrate_project (see Project::LEGACY_SPACE)
+    pub burn_cap: u64,                // Maximum total burn counted for this project (0 = unlimited)
+    pub verified: bool,               // Set by admin only (trust signal for explorers)
+    pub delegate: Option<Pubkey>,     // Optional account allowed to update metadata (set by creator)
+    pub boost_until: i64,             // Boost window end (trending multiplier applies while now < boost_until)
+    pub boost_multiplier: u8,         // Trending multiplier bought by boost_project (0/1 = none)
+    pub goal: u64,                    // Donation goal in token units (0 = none)
+    pub goal_reached: bool,           // Set once burned_amount first reaches goal
+    pub co_owners: Vec<Pubkey>,       // Co-owners of a shared project (max 3, set by creator)
+    pub require_quorum: bool,         // Updates need a majority of co_owners as signers
+    pub name_lower: String,           // Lowercased name for case-insensitive search (kept in sync with name)
+    pub forked_from: Option<u64>,     // Source project id when created by fork_project
+    pub burns_last_24h: u64,          // Approximate burn_for_project total of the last 24h (reset when idle > 24h)
+    pub last_activity: i64,           // Last create/update/burn/boost/owner change (unlike burn-only last_memo_time)
+    pub parent_project_id: Option<u64>, // Parent project when created as a sub-project
+    pub child_count: u64,             // Sub-projects created under this project
+    pub burn_histogram: [u32; 4],     // Burn/boost counts per BURN_HISTOGRAM_BOUNDS_TOKENS bucket
+    pub slug: String,                 // Unique URL slug claimed via set_project_slug (empty = none)
+    pub burns_frozen: bool,           // Set by admin only while a dispute is handled (blocks burns and boosts)
+    pub distinct_active_days: u16,    // Distinct UTC days with a burn or boost (trending needs MIN_ACTIVE_DAYS)
+    pub last_active_day: i64,         // UTC day index (unix time / 86400) of the latest burn or boost
+    pub endorsement_count: u64,       // Endorsements by creators of other projects
+    pub recent_burners_enabled: bool, // Set by initialize_recent_burners; burns and boosts must then record to it
+}
+
+/// Project layout written by program versions before burn_cap (the prefix of Project)
+#[derive(AnchorDeserialize)]
+struct LegacyProject {
+    project_id: u64,
+    creator: Pubkey,
+    created_at: i64,
+    last_updated: i64,
+    name: String,
+    description: String,
+    image: String,
+    website: String,
+    tags: Vec<String>,
+    memo_count: u64,
+    burned_amount: u64,
+    last_memo_time: i64,
+    bump: u8,
+}
+
+impl Project {
+    /// A newly created project with no metadata, burns or optional settings yet
+    pub fn new(project_id: u64, creator: Pubkey, timestamp: i64, bump: u8) -> Self {
+        Self {
+            project_id,
+            creator,
+            created_at: timestamp,
+            last_updated: timestamp,
+            name: String::new(),
+            description: String::new(),
+            image: String::new(),
+            website: String::new(),
+            tags: Vec::new(),
+            memo_count: 0, // Only tracks burn_for_project operations
+            burned_amount: 0,
+            last_memo_time: 0, // 0 until the first burn_for_project memo
+            bump,
+            burn_cap: 0,
+            verified: false, // Only the admin can mark a project as verified
+            delegate: None, // Only the creator can set a delegate
+            boost_until: 0, // No boost until the creator calls boost_project
+            boost_multiplier: 0,
+            goal: 0,
+            goal_reached: false,
+            co_owners: Vec::new(), // Shared ownership is opted into with set_co_owners
+            require_quorum: false,
+            name_lower: String::new(),
+            forked_from: None,
+            burns_last_24h: 0,
+            last_activity: timestamp,
+            parent_project_id: None,
+            child_count: 0,
+            burn_histogram: [0; 4],
+            slug: String::new(),
+            burns_frozen: false,
+            distinct_active_days: 0,
+            last_active_day: 0,
+            endorsement_count: 0,
+            recent_burners_enabled: false, // Until initialize_recent_burners runs
+        }
+    }
+
+    /// Calculate maximum space for the account (conservative estimate)
+    pub fn calculate_space_max() -> usize {
+        8 + // discriminator
+        8 + // project_id (u64)
+        32 + // creator
+        8 + // created_at
+        8 + // last_updated
+        8 + // memo_count
+        8 + // burned_amount
+        8 + // last_memo_time
+        1 + // bump
+        8 + // burn_cap
+        1 + // verified
+        1 + 32 + // delegate (Option<Pubkey>)
+        8 + // boost_until
+        1 + // boost_multiplier
+        8 + // goal
+        1 + // goal_reached
+        4 + 32 * MAX_CO_OWNERS + // co_owners (max 3)
+        1 + // require_quorum
+        4 + 64 + // name_lower (never longer than name)
+        1 + 8 + // forked_from (Option<u64>)
+        8 + // burns_last_24h
+        8 + // last_activity
+        1 + 8 + // parent_project_id (Option<u64>)
+        8 + // child_count
+        4 * 4 + // burn_histogram
+        4 + MAX_PROJECT_SLUG_LENGTH + // slug (max 32 chars)
+        1 + // burns_frozen
+        2 + // distinct_active_days
+        8 + // last_active_day
+        8 + // endorsement_count
+        1 + // recent_burners_enabled
+        4 + 64 + // name (max 64 chars)
+        4 + 256 + // description (max 256 chars)
+        4 + 256 + // image (max 256 chars)
+        4 + 128 + // website (max 128 chars)
+        4 + (4 + MAX_TAG_LENGTH_LIMIT) * MAX_TAGS_COUNT_LIMIT + // tags (hard ceiling, whatever the economy config allows)
+        128 // safety buffer
+    }
+
+    /// Size of projects created before burn_cap and the later fields were appended
+    /// (the calculate_space_max of that layout, which every such project was allocated with)
+    pub const LEGACY_SPACE: usize = 8 + // discriminator
+        8 + // project_id
+        32 + // creator
+        8 + // created_at
+        8 + // last_updated
+        8 + // memo_count
+        8 + // burned_amount
+        8 + // last_memo_time
+        1 + // bump
+        4 + 64 + // name
+        4 + 256 + // description
+        4 + 256 + // image
+        4 + 128 + // website
+        4 + (4 + 32) * 4 + // tags (4 tags of 32 chars)
+        128; // safety buffer
+
+    /// Whether a project account of `data_len` bytes has the current layout. A legacy-sized
+    /// project may decode, but its appended fields come from whatever bytes followed its
+    /// old data (zeros or stale string bytes), so instructions reject it until migrated
+    pub fn is_migrated(data_len: usize) -> bool {
+        data_len != Self::LEGACY_SPACE
+    }
+
+    /// Whether a project account needs migrate_project: true for a legacy-sized project,
+    /// false if it is already current, error for anything else
+    pub fn needs_migration(data: &[u8]) -> Result<bool> {
+        if !data.starts_with(Project::DISCRIMINATOR) {
+            return Err(ErrorCode::InvalidProjectAccount.into());
+        }
+        match data.len() {
+            Self::LEGACY_SPACE => Ok(true),
+            len if len == Self::calculate_space_max() => Ok(false),
+            _ => Err(ErrorCode::InvalidProjectAccount.into()),
+        }
+    }
+
+    /// Decode a legacy-sized project (discriminator included). The appended fields start
+    /// empty, except name_lower (derived from name) and last_activity (latest of
+    /// last_updated and last_memo_time)
+    pub fn from_legacy(data: &[u8]) -> Result<Self> {
+        if !Self::needs_migration(data)? {
+            return Err(ErrorCode::InvalidProjectAccount.into());
+        }
+        let legacy = LegacyProject::deserialize(&mut &data[8..])
+            .map_err(|_| ErrorCode::InvalidProjectAccount)?;
+
+        let mut project = Self::new(legacy.project_id, legacy.creator, legacy.created_at, legacy.bump);
+        project.last_updated = legacy.last_updated;
+        project.name_lower = lowercase_name(&legacy.name);
+        project.name = legacy.name;
+        project.description = legacy.description;
+        project.image = legacy.image;
+        project.website = legacy.website;
+        project.tags = legacy.tags;
+        project.memo_count = legacy.memo_count;
+        project.burned_amount = legacy.burned_amount;
+        project.last_memo_time = legacy.last_memo_time;
+        project.last_activity = legacy.last_updated.max(legacy.last_memo_time);
+        Ok(project)
+    }
+
+    /// Calculate the exact space for a project with the given field lengths
+    /// (each tag is reserved at MAX_TAG_LENGTH_LIMIT since only the count is known)
+    pub fn calculate_space(
+        name_len: usize,
+        description_len: usize,
+        image_len: usize,
+        website_len: usize,
+        tag_count: usize,
+    ) -> Result<usize> {
+        if name_len == 0 {
+            return Err(ErrorCode::EmptyProjectName.into());
+        }
+        if name_len > MAX_PROJECT_NAME_LENGTH {
+            return Err(ErrorCode::ProjectNameTooLong.into());
+        }
+        if description_len > MAX_PROJECT_DESCRIPTION_LENGTH {
+            return Err(ErrorCode::ProjectDescriptionTooLong.into());
+        }
+        if image_len > MAX_PROJECT_IMAGE_LENGTH {
+            return Err(ErrorCode::ProjectImageTooLong.into());
+        }
+        if website_len > MAX_PROJECT_WEBSITE_LENGTH {
+            return Err(ErrorCode::ProjectWebsiteTooLong.into());
+        }
+        if tag_count > MAX_TAGS_COUNT_LIMIT {
+            return Err(ErrorCode::TooManyTags.into());
+        }
+
+        Ok(8 + // discriminator
+            8 + // project_id (u64)
+            32 + // creator
+            8 + // created_at
+            8 + // last_updated
+            8 + // memo_count
+            8 + // burned_amount
+            8 + // last_memo_time
+            1 + // bump
+            8 + // burn_cap
+            1 + // verified
+            1 + 32 + // delegate (Option<Pubkey>)
+            8 + // boost_until
+            1 + // boost_multiplier
+            8 + // goal
+            1 + // goal_reached
+            4 + 32 * MAX_CO_OWNERS + // co_owners (reserved at max, set after creation)
+            1 + // require_quorum
+            4 + name_len + // name_lower (never longer than name)
+            1 + 8 + // forked_from (Option<u64>)
+            8 + // burns_last_24h
+            8 + // last_activity
+            1 + 8 + // parent_project_id (reserved as Some)
+            8 + // child_count
+            4 * 4 + // burn_histogram
+            4 + MAX_PROJECT_SLUG_LENGTH + // slug (reserved at max, set after creation)
+            1 + // burns_frozen
+            2 + // distinct_active_days
+            8 + // last_active_day
+            8 + // endorsement_count
+            1 + // recent_burners_enabled
+            4 + name_len + // name
+            4 + description_len + // description
+            4 + image_len + // image
+            4 + website_len + // website
+            4 + (4 + MAX_TAG_LENGTH_LIMIT) * tag_count) // tags
+    }
+
+    /// Space create_project/fork_project actually allocate for a project with these field
+    /// lengths: the lengths are validated, but the account is always sized at the maximum
+    /// so later updates never need a realloc
+    pub fn allocated_space(
+        name_len: usize,
+        description_len: usize,
+        image_len: usize,
+        website_len: usize,
+        tag_count: usize,
+    ) -> Result<usize> {
+        Self::calculate_space(name_len, description_len, image_len, website_len, tag_count)?;
+        Ok(Self::calculate_space_max())
+    }
+
+    /// Copy a source project's metadata into this (new) project for fork_project:
+    /// name, image, website and tags verbatim, the description with a "(fork of #N)" note
+    pub fn copy_metadata_from(&mut self, source: &Project) {
+        self.name = source.name.clone();
+        self.name_lower = lowercase_name(&source.name);
+        self.description = fork_description(&source.description, source.project_id);
+        self.image = source.image.clone();
+        self.website = source.website.clone();
+        self.tags = source.tags.clone();
+        self.forked_from = Some(source.project_id);
+    }
+
+    /// Record a burn_for_project burn: add to burned_amount and count it as a memo.
+    /// burns_last_24h restarts at `amount` when the previous burn is older than the window
+    /// (a cheap decay that needs no per-burn timestamps), otherwise it accumulates
+    pub fn record_burn(&mut self, amount: u64, timestamp: i64) {
+        if timestamp.saturating_sub(self.last_memo_time) > BURN_RATE_WINDOW_SECONDS {
+            self.burns_last_24h = amount;
+        } else {
+            self.burns_last_24h = self.burns_last_24h.saturating_add(amount);
+        }
+        self.burned_amount = self.burned_amount.saturating_add(amount);
+        self.memo_count = self.memo_count.saturating_add(1);
+        self.last_memo_time = timestamp;
+        self.last_activity = timestamp;
+        self.record_histogram(amount);
+        self.record_active_day(timestamp);
+    }
+
+    /// Count the UTC day of `timestamp` as active the first time a burn lands on it
+    pub fn record_active_day(&mut self, timestamp: i64) {
+        let day = timestamp.div_euclid(SECONDS_PER_DAY);
+        if self.distinct_active_days == 0 || day != self.last_active_day {
+            self.distinct_active_days = self.distinct_active_days.saturating_add(1);
+            self.last_active_day = day;
+        }
+    }
+
+    /// Whether the project has been active on enough distinct days to appear in trending,
+    /// so a single-day pump cannot dominate it
+    pub fn is_trending_eligible(&self) -> bool {
+        self.distinct_active_days >= MIN_ACTIVE_DAYS
+    }
+
+    /// Count a burn in its burn_histogram bucket and return the bucket index
+    pub fn record_histogram(&mut self, amount: u64) -> u8 {
+        let bucket = burn_histogram_bucket(amount);
+        let count = &mut self.burn_histogram[bucket as usize];
+        *count = count.saturating_add(1);
+        bucket
+    }
+
+    /// Count an endorsement by `endorser`, who must not be this project's creator;
+    /// returns the new endorsement_count
+    pub fn add_endorsement(&mut self, endorser: Pubkey) -> Result<u64> {
+        if endorser == self.creator {
+            msg!("Creator {} cannot endorse their own project {}", endorser, self.project_id);
+            return Err(ErrorCode::SelfEndorsement.into());
+        }
+        self.endorsement_count = self.endorsement_count.checked_add(1)
+            .ok_or(ErrorCode::ProjectCounterOverflow)?;
+        Ok(self.endorsement_count)
+    }
+
+    /// Count a sub-project created under this project, which must be the one the memo names
+    pub fn add_child(&mut self, expected_parent_id: u64) -> Result<u64> {
+        if self.project_id != expected_parent_id {
+            msg!("Parent project mismatch: account holds {}, memo names {}", self.project_id, expected_parent_id);
+            return Err(ErrorCode::ParentProjectMismatch.into());
+        }
+        self.child_count = self.child_count.checked_add(1)
+            .ok_or(ErrorCode::ProjectCounterOverflow)?;
+        Ok(self.child_count)
+    }
+
+    /// Mark the goal as reached the first time burned_amount meets it; returns true only on
+    /// that crossing, so GoalReachedEvent fires exactly once per project
+    pub fn check_goal_reached(&mut self) -> bool {
+        if self.goal == 0 || self.goal_reached || self.burned_amount < self.goal {
+            return false;
+        }
+        self.goal_reached = true;
+        true
+    }
+
+    /// Check that the project has not reached its burn cap (0 = unlimited)
+    pub fn check_burn_cap(&self) -> Result<()> {
+        if self.burn_cap > 0 && self.burned_amount >= self.burn_cap {
+            msg!("Project {} reached its burn cap: {} tokens", 
+                 self.project_id, self.burn_cap / DECIMAL_FACTOR);
+            return Err(ErrorCode::ProjectBurnCapReached.into());
+        }
+        Ok(())
+    }
+
+    /// Apply a boost bought with `amount` units at `now`: the window grows by BOOST_SECONDS_PER_TOKEN
+    /// per token (never past MAX_BOOST_DURATION_SECONDS from now), and boosting while a boost is
+    /// still active stacks the multiplier by one, up to MAX_BOOST_MULTIPLIER
+    pub fn apply_boost(&mut self, amount: u64, now: i64) {
+        let duration = ((amount / DECIMAL_FACTOR) as i64).saturating_mul(BOOST_SECONDS_PER_TOKEN);
+        let start = if self.is_boost_active(now) {
+            self.boost_multiplier = self.boost_multiplier.saturating_add(1).min(MAX_BOOST_MULTIPLIER);
+            self.boost_until
+        } else {
+            self.boost_multiplier = BASE_BOOST_MULTIPLIER;
+            now
+        };
+        self.boost_until = start
+            .saturating_add(duration)
+            .min(now.saturating_add(MAX_BOOST_DURATION_SECONDS));
+        self.last_activity = now;
+    }
+
+    /// Whether a boost window is running at `now`
+    pub fn is_boost_active(&self, now: i64) -> bool {
+        now < self.boost_until
+    }
+
+    /// Trending score multiplier at `now` (1 when no boost is active)
+    pub fn active_boost_multiplier(&self, now: i64) -> u8 {
+        if self.is_boost_active(now) {
+            self.boost_multiplier.clamp(1, MAX_BOOST_MULTIPLIER)
+        } else {
+            1
+        }
+    }
+
+    /// Apply the active boost to a trending score
+    pub fn boosted_trend_score(&self, trend_score: u64, now: i64) -> u64 {
+        trend_score.saturating_mul(self.active_boost_multiplier(now) as u64)
+    }
+
+    /// Trending score at `now`: the rolling 24h burn total (0 once the window has lapsed
+    /// without a burn, or while the project is not trending-eligible) multiplied by the active boost
+    pub fn trend_score(&self, now: i64) -> u64 {
+        if !self.is_trending_eligible() || now.saturating_sub(self.last_memo_time) > BURN_RATE_WINDOW_SECONDS {
+            return 0;
+        }
+        self.boosted_trend_score(self.burns_last_24h, now)
+    }
+
+    /// Burned amount counted on the leaderboard (never exceeds burn_cap when set)
+    pub fn leaderboard_amount(&self) -> u64 {
+        if self.burn_cap > 0 {
+            self.burned_amount.min(self.burn_cap)
+        } else {
+            self.burned_amount
+        }
+    }
+
+    /// Check that a project PDA has never been created: it must still be an empty,
+    /// system-owned address (lamports sent to it beforehand are fine)
+    pub fn check_account_unused(account: &AccountInfo, project_id: u64) -> Result<()> {
+        if !account.data_is_empty() || *account.owner != anchor_lang::system_program::ID {
+            msg!("Project ID {} is already used ({} bytes, owner {})", project_id, account.data_len(), account.owner);
+            return Err(ErrorCode::ProjectIdAlreadyUsed.into());
+        }
+        Ok(())
+    }
+
+    /// Tags in an update's new tag list that the project does not have yet
+    pub fn added_tags(&self, new_tags: Option<&[String]>) -> Vec<String> {
+        new_tags
+            .unwrap_or_default()
+            .iter()
+            .filter(|tag| !self.tags.contains(tag))
+            .cloned()
+            .collect()
+    }
+
+    /// Apply the fields present in an update memo (metadata only; verified is never touched)
+    pub fn apply_update(&mut self, update_data: ProjectUpdateData) {
+        if let Some(new_name) = update_data.name {
+            self.name_lower = lowercase_name(&new_name);
+            self.name = new_name;
+        }
+        
+        if let Some(new_description) = update_data.description {
+            self.description = new_description;
+        }
+        
+        if let Some(new_image) = update_data.image {
+            self.image = new_image;
+        }
+        
+        if let Some(new_website) = update_data.website {
+            self.website = new_website;
+        }
+        
+        if let Some(new_tags) = update_data.tags {
+            self.tags = new_tags;
+        }
+    }
+
+    /// Record an update_project call: apply the memo's fields and add its burn, advancing
+    /// last_updated and last_activity (last_memo_time only tracks burn_for_project)
+    pub fn record_update(&mut self, update_data: ProjectUpdateData, burn_amount: u64, timestamp: i64) {
+        self.apply_update(update_data);
+        self.record_update_burn(burn_amount, timestamp);
+        self.last_updated = timestamp;
+    }
+
+    /// Add an update's burn (update_project or schedule_project_update) and advance last_activity
+    pub fn record_update_burn(&mut self, burn_amount: u64, timestamp: i64) {
+        self.burned_amount = self.burned_amount.saturating_add(burn_amount);
+        self.last_activity = timestamp;
+    }
+
+    /// Set the verified flag (only AUTHORIZED_ADMIN_PUBKEY may change it)
+    pub fn set_verified(&mut self, admin: Pubkey, verified: bool) -> Result<()> {
+        if admin != AUTHORIZED_ADMIN_PUBKEY {
+            return Err(ErrorCode::UnauthorizedAdmin.into());
+        }
+        self.verified = verified;
+        Ok(())
+    }
+
+    /// Freeze or unfreeze burns (only AUTHORIZED_ADMIN_PUBKEY)
+    pub fn set_burns_frozen(&mut self, admin: Pubkey, frozen: bool) -> Result<()> {
+        if admin != AUTHORIZED_ADMIN_PUBKEY {
+            return Err(ErrorCode::UnauthorizedAdmin.into());
+        }
+        self.burns_frozen = frozen;
+        Ok(())
+    }
+
+    /// Check that a burn or boost passes the recent-burners ring buffer once the project
+    /// has one, so no burn is left out of it
+    pub fn check_recent_burners(&self, provided: bool) -> Result<()> {
+        if self.recent_burners_enabled && !provided {
+            msg!("Project {} records recent burners: pass its recent_burners account", self.project_id);
+            return Err(ErrorCode::RecentBurnersRequired.into());
+        }
+        Ok(())
+    }
+
+    /// Check that the admin has not frozen burns on this project
+    pub fn check_burns_not_frozen(&self) -> Result<()> {
+        if self.burns_frozen {
+            msg!("Burns on project {} are frozen by the admin", self.project_id);
+            return Err(ErrorCode::ProjectBurnsFrozen.into());
+        }
+        Ok(())
+    }
 
-    /// User global burn statistics tracking account (now required)
-    #[account(
-        mut,
-        seeds = [b"user_global_burn_stats", burner.key().as_ref()],
-        bump,
-        seeds::program = memo_burn_program.key()
-    )]
-    pub user_global_burn_stats: Account<'info, memo_burn::UserGlobalBurnStats>,
-    
-    pub token_program: Program<'info, Token2022>,
-    
-    /// The memo-burn program
-    pub memo_burn_program: Program<'info, MemoBurn>,
-    
-    /// CHECK: Instructions sysvar
-    #[account(address = INSTRUCTIONS_ID)]
-    pub instructions: AccountInfo<'info>,
-}
+    /// Overwrite burned_amount with an audited value (only AUTHORIZED_ADMIN_PUBKEY);
+    /// returns the previous amount
+    pub fn correct_burned_amount(&mut self, admin: Pubkey, correct_value: u64) -> Result<u64> {
+        if admin != AUTHORIZED_ADMIN_PUBKEY {
+            return Err(ErrorCode::UnauthorizedAdmin.into());
+        }
+        let old_amount = self.burned_amount;
+        self.burned_amount = correct_value;
+        Ok(old_amount)
+    }
 
-/// Project data structure
-#[account]
-pub struct Project {
-    pub project_id: u64,              // Sequential project ID (0, 1, 2, ...)
-    pub creator: Pubkey,              // Creator
-    pub created_at: i64,              // Creation timestamp
-    pub last_updated: i64,            // Last updated timestamp (updated on project updates)
-    pub name: String,                 // Project name
-    pub description: String,          // Project description
-    pub image: String,                // Project image info (max 256 chars)
-    pub website: String,              // Project website URL (max 128 chars)
-    pub tags: Vec<String>,            // Tags (max 4 tags, each max 32 chars)
-    pub memo_count: u64,              // Number of burn_for_project operations (not create/update)
-    pub burned_amount: u64,           // Total burned tokens for this project
-    pub last_memo_time: i64,          // Last burn_for_project operation timestamp (0 if never burned)
-    pub bump: u8,                     // PDA bump
-}
+    /// Whether `signer` may update metadata (the creator or the delegate)
+    pub fn can_update(&self, signer: Pubkey) -> bool {
+        signer == self.creator || self.delegate == Some(signer)
+    }
 
-impl Project {
-    /// Calculate maximum space for the account (conservative estimate)
-    pub fn calculate_space_max() -> usize {
-        8 + // discriminator
-        8 + // project_id (u64)
-        32 + // creator
-        8 + // created_at
-        8 + // last_updated
-        8 + // memo_count
-        8 + // burned_amount
-        8 + // last_memo_time
-        1 + // bump
-        4 + 64 + // name (max 64 chars)
-        4 + 256 + // description (max 256 chars)
-        4 + 256 + // image (max 256 chars)
-        4 + 128 + // website (max 128 chars)
-        4 + (4 + 32) * 4 + // tags (max 4 tags, 32 chars each)
-        128 // safety buffer
+    /// Replace the co-owner list and quorum mode (creator only)
+    pub fn set_co_owners(&mut self, caller: Pubkey, co_owners: Vec<Pubkey>, require_quorum: bool) -> Result<()> {
+        if caller != self.creator {
+            return Err(ErrorCode::UnauthorizedProjectAccess.into());
+        }
+        if co_owners.len() > MAX_CO_OWNERS {
+            return Err(ErrorCode::TooManyCoOwners.into());
+        }
+        for (i, co_owner) in co_owners.iter().enumerate() {
+            if *co_owner == Pubkey::default() || co_owners[..i].contains(co_owner) {
+                msg!("Invalid co-owner {}: {}", i, co_owner);
+                return Err(ErrorCode::InvalidCoOwners.into());
+            }
+        }
+        if require_quorum && co_owners.is_empty() {
+            return Err(ErrorCode::InvalidCoOwners.into());
+        }
+        self.co_owners = co_owners;
+        self.require_quorum = require_quorum;
+        Ok(())
+    }
+
+    /// Number of co-owner signatures an update needs (a strict majority of co_owners)
+    pub fn quorum_threshold(&self) -> usize {
+        self.co_owners.len() / 2 + 1
+    }
+
+    /// Reject scheduling an update while quorum is required
+    pub fn check_schedulable(&self) -> Result<()> {
+        if self.require_quorum {
+            msg!("Project {} requires quorum; use update_project with the co-owners' signatures", self.project_id);
+            return Err(ErrorCode::QuorumUpdateNotSchedulable.into());
+        }
+        Ok(())
+    }
+
+    /// When quorum is required, check that a majority of co_owners are among `signers`
+    pub fn check_quorum(&self, signers: &[Pubkey]) -> Result<()> {
+        if !self.require_quorum {
+            return Ok(());
+        }
+        let approvals = self.co_owners.iter().filter(|co_owner| signers.contains(co_owner)).count();
+        if approvals < self.quorum_threshold() {
+            msg!("Quorum not met for project {}: {} of {} co-owners signed (need {})", 
+                 self.project_id, approvals, self.co_owners.len(), self.quorum_threshold());
+            return Err(ErrorCode::QuorumNotMet.into());
+        }
+        Ok(())
+    }
+
+    /// Set or clear the delegate (creator only); returns the previous delegate
+    pub fn set_delegate(&mut self, caller: Pubkey, delegate: Option<Pubkey>) -> Result<Option<Pubkey>> {
+        if caller != self.creator {
+            return Err(ErrorCode::UnauthorizedProjectAccess.into());
+        }
+        Ok(std::mem::replace(&mut self.delegate, delegate))
+    }
+
+    /// Set the project's slug (creator only, once); the caller claims the ProjectSlug PDA
+    pub fn set_slug(&mut self, caller: Pubkey, slug: String) -> Result<()> {
+        if caller != self.creator {
+            return Err(ErrorCode::UnauthorizedProjectAccess.into());
+        }
+        validate_project_slug(&slug)?;
+        if !self.slug.is_empty() {
+            msg!("Project {} already has slug '{}'", self.project_id, self.slug);
+            return Err(ErrorCode::ProjectSlugAlreadySet.into());
+        }
+        self.slug = slug;
+        Ok(())
     }
 }
 
@@ -1434,12 +6346,55 @@ pub struct ProjectCreatedEvent {
     pub project_id: u64,
     pub creator: Pubkey,
     pub name: String,
+    pub name_lower: String,
+    pub description: String,
+    pub image: String,
+    pub website: String,
+    pub tags: Vec<String>,
+    pub burn_amount: u64,
+    pub burn_cap: u64,
+    pub goal: u64,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a project is created as a sub-project of another project
+#[event]
+pub struct SubProjectCreatedEvent {
+    pub parent_project_id: u64,
+    pub project_id: u64,
+    pub child_count: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when a project is created by forking another project
+#[event]
+pub struct ProjectForkedEvent {
+    pub project_id: u64,
+    pub source_project_id: u64,
+    pub creator: Pubkey,
+    pub name: String,
+    pub name_lower: String,
     pub description: String,
     pub image: String,
     pub website: String,
     pub tags: Vec<String>,
     pub burn_amount: u64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted once, by the burn that first brings burned_amount to the project's goal
+#[event]
+pub struct GoalReachedEvent {
+    pub project_id: u64,
+    pub goal: u64,
+    pub total: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
 }
 
 /// Event emitted when a project is updated
@@ -1448,13 +6403,30 @@ pub struct ProjectUpdatedEvent {
     pub project_id: u64,
     pub updater: Pubkey,
     pub name: String,
+    pub name_lower: String,
     pub description: String,
     pub image: String,
     pub website: String,
     pub tags: Vec<String>,
     pub burn_amount: u64,
     pub total_burned: u64,
+    pub last_activity: i64,
     pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a project update is scheduled (ProjectUpdatedEvent follows when it is applied)
+#[event]
+pub struct ProjectUpdateScheduledEvent {
+    pub project_id: u64,
+    pub scheduler: Pubkey,
+    pub apply_at: i64,
+    pub burn_amount: u64,
+    pub total_burned: u64,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
 }
 
 /// Event emitted when tokens are burned for a project
@@ -1464,7 +6436,164 @@ pub struct TokensBurnedForProjectEvent {
     pub burner: Pubkey,
     pub amount: u64,
     pub total_burned: u64,
+    pub burns_last_24h: u64,
+    pub last_activity: i64,
+    pub histogram_bucket: u8,       // burn_histogram bucket this burn was counted in
+    pub ref_post_id: Option<u64>,
+    pub ref_sig: Option<String>,
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when a creator boosts a project
+#[event]
+pub struct ProjectBoostedEvent {
+    pub project_id: u64,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub boost_multiplier: u8,
+    pub boost_until: i64,
+    pub last_activity: i64,
+    pub histogram_bucket: u8,       // burn_histogram bucket this boost was counted in
+    pub timestamp: i64,
+    pub memo_hash: [u8; 32],
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin sets the economy config
+#[event]
+pub struct EconomyConfigUpdatedEvent {
+    pub admin: Pubkey,
+    pub min_create_burn: u64,
+    pub min_update_burn: u64,
+    pub min_burn: u64,
+    pub max_tags: u8,
+    pub max_tag_length: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin signer set or threshold changes
+#[event]
+pub struct AdminConfigUpdatedEvent {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin sets the operation flags
+#[event]
+pub struct OperationFlagsUpdatedEvent {
+    pub admin: Pubkey,
+    pub operation_flags: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin changes a project's verified flag
+#[event]
+pub struct VerificationChangedEvent {
+    pub project_id: u64,
+    pub admin: Pubkey,
+    pub verified: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin freezes or unfreezes burns on a project
+#[event]
+pub struct ProjectBurnsFrozenEvent {
+    pub project_id: u64,
+    pub admin: Pubkey,
+    pub frozen: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin corrects a project's burned_amount
+#[event]
+pub struct BurnAmountCorrectedEvent {
+    pub project_id: u64,
+    pub admin: Pubkey,
+    pub old_amount: u64,
+    pub new_amount: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the creator changes a project's delegate
+#[event]
+pub struct DelegateChangedEvent {
+    pub project_id: u64,
+    pub creator: Pubkey,
+    pub old_delegate: Option<Pubkey>,
+    pub new_delegate: Option<Pubkey>,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the creator changes a project's co-owners or quorum mode
+#[event]
+pub struct CoOwnersChangedEvent {
+    pub project_id: u64,
+    pub creator: Pubkey,
+    pub co_owners: Vec<Pubkey>,
+    pub require_quorum: bool,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the creator claims a slug for a project
+#[event]
+pub struct ProjectSlugSetEvent {
+    pub project_id: u64,
+    pub creator: Pubkey,
+    pub slug: String,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when a creator endorses another creator's project
+#[event]
+pub struct ProjectEndorsedEvent {
+    pub target_project_id: u64,
+    pub endorser: Pubkey,
+    pub endorser_project_id: u64,
+    pub endorsement_count: u64,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin captures a leaderboard snapshot
+#[event]
+pub struct LeaderboardSnapshotEvent {
+    pub epoch: u64,
+    pub admin: Pubkey,
+    pub entry_count: u8,
+    pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin force-removes a leaderboard entry
+#[event]
+pub struct LeaderboardEntryRemovedEvent {
+    pub project_id: u64,
+    pub admin: Pubkey,
+    pub burned_amount: u64, // Amount the removed entry held
     pub timestamp: i64,
+    pub event_seq: u64,
+}
+
+/// Event emitted when the admin pins a project on the leaderboard
+#[event]
+pub struct ProjectPinnedEvent {
+    pub project_id: u64,
+    pub admin: Pubkey,
+    pub burned_amount: u64, // Amount the pinned entry holds
+    pub timestamp: i64,
+    pub event_seq: u64,
 }
 
 /// Error code definitions
@@ -1598,4 +6727,229 @@ pub enum ErrorCode {
     
     #[msg("Burn message too long: Message must be at most 696 characters.")]
     BurnMessageTooLong,
+
+    #[msg("Project creation too frequent: Please wait for the creation cooldown to expire.")]
+    CreateTooFrequent,
+
+    #[msg("Project burn cap reached: This project does not accept further burns.")]
+    ProjectBurnCapReached,
+
+    #[msg("Project ID already used: The project account for this ID already exists (global counter out of sync).")]
+    ProjectIdAlreadyUsed,
+
+    #[msg("Project ID exceeds counter: The expected project ID is ahead of the next available ID.")]
+    ProjectIdExceedsCounter,
+
+    #[msg("Leaderboard corrupted: Entry count exceeds the leaderboard capacity.")]
+    LeaderboardCorrupted,
+
+    #[msg("Invalid economy config: Minimum burns must be whole tokens between 1 and 1,000,000,000,000.")]
+    InvalidEconomyConfig,
+
+    #[msg("Memo at wrong index: The SPL Memo instruction must be placed at index 0 of the transaction.")]
+    MemoWrongIndex,
+
+    #[msg("Invalid image reference: Image must be empty, an ipfs://, ar:// or https:// reference, or a CIDv0 hash.")]
+    InvalidImageReference,
+
+    #[msg("Invalid create_project memo: Memo could not be decoded as a project creation memo.")]
+    ProjectCreateMemoInvalid,
+
+    #[msg("Invalid update_project memo: Memo could not be decoded as a project update memo.")]
+    ProjectUpdateMemoInvalid,
+
+    #[msg("Invalid burn_for_project memo: Memo could not be decoded as a project burn memo.")]
+    ProjectBurnMemoInvalid,
+
+    #[msg("Leaderboard snapshot already exists: A snapshot has already been captured for this epoch.")]
+    SnapshotAlreadyExists,
+
+    #[msg("Entity limit reached: No more projects can be created.")]
+    EntityLimitReached,
+
+    #[msg("Message board not extended: Call extend_message_board before burning with a message board.")]
+    MessageBoardNotExtended,
+
+    #[msg("Invalid TagStats account: Pass one TagStats PDA per project tag, in tag order.")]
+    InvalidTagStatsAccount,
+
+    #[msg("Invalid leaderboard capacity: The leaderboard can only grow, up to 500 entries.")]
+    InvalidLeaderboardCapacity,
+
+    #[msg("Burn not reflected: The token balance did not drop by the burned amount.")]
+    BurnAmountNotReflected,
+
+    #[msg("Invalid ref signature format: Must be a base58 encoded 64-byte transaction signature.")]
+    InvalidRefSignatureFormat,
+
+    #[msg("Invalid boost_project memo: Memo could not be decoded as a project boost memo.")]
+    ProjectBoostMemoInvalid,
+
+    #[msg("Unsupported project boost data version. Please use the correct structure version.")]
+    UnsupportedProjectBoostDataVersion,
+
+    #[msg("Invalid tag at index 0: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex0,
+
+    #[msg("Invalid tag at index 1: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex1,
+
+    #[msg("Invalid tag at index 2: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex2,
+
+    #[msg("Invalid tag at index 3: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex3,
+
+    #[msg("Too many co-owners: A project can have at most 3 co-owners.")]
+    TooManyCoOwners,
+
+    #[msg("Invalid co-owners: Co-owners must be distinct, and quorum needs at least one co-owner.")]
+    InvalidCoOwners,
+
+    #[msg("Quorum not met: A majority of the project's co-owners must sign this update.")]
+    QuorumNotMet,
+
+    #[msg("Invalid burn stats account: user_global_burn_stats must be a memo-burn UserGlobalBurnStats account.")]
+    InvalidBurnStatsAccount,
+
+    #[msg("Invalid fork_project memo: Memo could not be decoded as a project fork memo.")]
+    ProjectForkMemoInvalid,
+
+    #[msg("Unsupported project fork data version. Please use the correct structure version.")]
+    UnsupportedProjectForkDataVersion,
+
+    #[msg("Source project ID mismatch: The memo's source_project_id does not match the forked project.")]
+    SourceProjectIdMismatch,
+
+    #[msg("Duplicate request: This idempotency key was already used for a create request.")]
+    DuplicateRequest,
+
+    #[msg("Idempotency marker mismatch: idempotency_marker must be provided exactly when idempotency_key is set.")]
+    IdempotencyMarkerMismatch,
+
+    #[msg("Idempotency marker not expired: Markers can be closed after 1 hour.")]
+    IdempotencyMarkerNotExpired,
+
+    #[msg("Unexpected mint decimals: The authorized mint must have 6 decimals.")]
+    UnexpectedMintDecimals,
+
+    #[msg("Invalid TagProjectList account: Pass the last page and the next page PDA for each project tag, in tag order.")]
+    InvalidTagProjectListAccount,
+
+    #[msg("TagProjectList page is full: Roll over to the continuation page.")]
+    TagProjectListFull,
+
+    #[msg("Too many pinned entries: At most 5 leaderboard entries can be pinned.")]
+    TooManyPinnedEntries,
+
+    #[msg("Leaderboard has too many entries: Cannot shrink below the number of entries in use.")]
+    LeaderboardTooManyEntries,
+
+    #[msg("Duplicate receipt nonce: A burn receipt already exists for this nonce.")]
+    DuplicateReceiptNonce,
+
+    #[msg("Burn receipt account mismatch: Pass the receipt account exactly when receipt_nonce is set.")]
+    BurnReceiptAccountMismatch,
+
+    #[msg("Invalid parent project: A project cannot be its own parent.")]
+    InvalidParentProject,
+
+    #[msg("Parent project not found: Pass the parent's Project account when setting parent_project_id.")]
+    ParentProjectNotFound,
+
+    #[msg("Parent project mismatch: The parent account must match the memo's parent_project_id.")]
+    ParentProjectMismatch,
+
+    #[msg("Invalid tag content: Tags cannot be whitespace-only or contain control characters.")]
+    InvalidTagContent,
+
+    #[msg("Invalid project slug: Use 1-32 lowercase letters, digits and hyphens, not starting or ending with a hyphen.")]
+    InvalidProjectSlug,
+
+    #[msg("Project slug taken: Another project already claimed this slug.")]
+    ProjectSlugTaken,
+
+    #[msg("Project slug already set: A project's slug can only be claimed once.")]
+    ProjectSlugAlreadySet,
+
+    #[msg("Project burns frozen: The admin has frozen burns on this project.")]
+    ProjectBurnsFrozen,
+
+    #[msg("Non-canonical Base64: The memo must use canonical padding with zero trailing bits.")]
+    NonCanonicalBase64,
+
+    #[msg("Endorser is not a project creator: Pass a project you created as proof.")]
+    EndorserNotProjectCreator,
+
+    #[msg("Self-endorsement: Creators cannot endorse their own project.")]
+    SelfEndorsement,
+
+    #[msg("Already endorsed: Each creator can endorse a project only once.")]
+    AlreadyEndorsed,
+
+    #[msg("Update already scheduled: Apply the pending update before scheduling another.")]
+    UpdateAlreadyScheduled,
+
+    #[msg("Invalid apply_at: A scheduled update must apply in the future.")]
+    InvalidApplyAt,
+
+    #[msg("Scheduled update not due: The update cannot be applied before its apply_at.")]
+    ScheduledUpdateNotDue,
+
+    #[msg("Invalid scheduler account: Pass the wallet that scheduled the update.")]
+    InvalidSchedulerAccount,
+
+    #[msg("Wrong program category: This memo was built for another program (memo-project expects category \"project\").")]
+    WrongProgramCategory,
+
+    #[msg("Operation disabled: The admin has turned this operation off in the program config.")]
+    OperationDisabled,
+
+    #[msg("Invalid operation flags: Only OPERATION_FLAG_* bits may be set.")]
+    InvalidOperationFlags,
+
+    #[msg("Invalid program config account: The program_config account is not owned by this program.")]
+    InvalidProgramConfigAccount,
+
+    #[msg("Invalid admin config: Use 1-5 distinct signers and a threshold between 1 and the signer count.")]
+    InvalidAdminConfig,
+
+    #[msg("Admin threshold not met: Not enough admin signers signed this instruction.")]
+    AdminThresholdNotMet,
+
+    #[msg("Missing TagStats accounts: Pass one TagStats PDA per project tag as remaining accounts.")]
+    MissingTagStatsAccounts,
+
+    #[msg("Missing TagProjectList accounts: Pass a (tail page, next page) TagProjectList pair per project tag after the TagStats accounts.")]
+    MissingTagProjectListAccounts,
+
+    #[msg("Invalid tag at index 4: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex4,
+
+    #[msg("Invalid tag at index 5: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex5,
+
+    #[msg("Invalid tag at index 6: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex6,
+
+    #[msg("Invalid tag at index 7: Tag must be 1-32 characters.")]
+    InvalidTagAtIndex7,
+
+    #[msg("Invalid economy config account: Pass the program's economy_config PDA.")]
+    InvalidEconomyConfigAccount,
+
+    #[msg("Quorum update not schedulable: Projects that require quorum must use update_project.")]
+    QuorumUpdateNotSchedulable,
+
+    #[msg("Invalid project account: Not a current or legacy-sized project.")]
+    InvalidProjectAccount,
+
+    #[msg("Project not migrated: Call migrate_project to convert this project to the current layout.")]
+    ProjectNotMigrated,
+
+    #[msg("Recent burners required: This project records recent burners; pass its recent_burners account.")]
+    RecentBurnersRequired,
+
+    #[msg("Message board required: Burns with a message must pass the project's message board.")]
+    MessageBoardRequired,
 }
@@ -0,0 +1,99 @@
+//! Typed builders for memo-project memos (enabled with the `client` feature).
+//!
+//! Each builder wraps a current-version payload in a `BurnMemo` and returns the
+//! Base64 string to put in the memo instruction, so clients and tests never
+//! hand-roll the Borsh layout.
+
+use crate::*;
+
+/// Encode a payload as a Base64 Borsh `BurnMemo` carrying `burn_amount`
+pub fn encode_burn_memo(burn_amount: u64, payload: Vec<u8>) -> String {
+    let burn_memo = BurnMemo {
+        version: BURN_MEMO_VERSION,
+        burn_amount,
+        payload,
+    };
+    general_purpose::STANDARD.encode(burn_memo.try_to_vec().expect("serializing into a Vec cannot fail"))
+}
+
+/// Current-version creation payload with only the required fields set
+/// (adjust description, image, website, tags, goal or parent before building)
+pub fn project_creation_data(project_id: u64, name: &str) -> ProjectCreationData {
+    ProjectCreationData {
+        version: PROJECT_CREATION_DATA_VERSION,
+        category: EXPECTED_CATEGORY.to_string(),
+        operation: EXPECTED_OPERATION.to_string(),
+        project_id,
+        name: name.to_string(),
+        description: String::new(),
+        image: String::new(),
+        website: String::new(),
+        tags: Vec::new(),
+        goal: 0,
+        parent_project_id: None,
+    }
+}
+
+/// Current-version update payload that changes nothing yet (set the fields to update before building)
+pub fn project_update_data(project_id: u64) -> ProjectUpdateData {
+    ProjectUpdateData {
+        version: PROJECT_UPDATE_DATA_VERSION,
+        category: EXPECTED_CATEGORY.to_string(),
+        operation: EXPECTED_UPDATE_OPERATION.to_string(),
+        project_id,
+        name: None,
+        description: None,
+        image: None,
+        website: None,
+        tags: None,
+    }
+}
+
+/// Memo for create_project / create_and_burn
+pub fn build_project_creation_memo(burn_amount: u64, data: &ProjectCreationData) -> String {
+    encode_burn_memo(burn_amount, data.try_to_vec().expect("serializing into a Vec cannot fail"))
+}
+
+/// Memo for update_project
+pub fn build_project_update_memo(burn_amount: u64, data: &ProjectUpdateData) -> String {
+    encode_burn_memo(burn_amount, data.try_to_vec().expect("serializing into a Vec cannot fail"))
+}
+
+/// Memo for burn_for_project (current version, without ref_post_id or ref_sig)
+pub fn build_project_burn_memo(burn_amount: u64, project_id: u64, burner: &Pubkey, message: &str) -> String {
+    let data = ProjectBurnData {
+        version: PROJECT_BURN_DATA_VERSION,
+        category: EXPECTED_CATEGORY.to_string(),
+        operation: EXPECTED_BURN_FOR_PROJECT_OPERATION.to_string(),
+        project_id,
+        burner: burner.to_string(),
+        message: message.to_string(),
+        ref_post_id: None,
+        ref_sig: None,
+    };
+    encode_burn_memo(burn_amount, data.try_to_vec().expect("serializing into a Vec cannot fail"))
+}
+
+/// Memo for boost_project
+pub fn build_project_boost_memo(burn_amount: u64, project_id: u64, burner: &Pubkey) -> String {
+    let data = ProjectBoostData {
+        version: PROJECT_BOOST_DATA_VERSION,
+        category: EXPECTED_CATEGORY.to_string(),
+        operation: EXPECTED_BOOST_PROJECT_OPERATION.to_string(),
+        project_id,
+        burner: burner.to_string(),
+    };
+    encode_burn_memo(burn_amount, data.try_to_vec().expect("serializing into a Vec cannot fail"))
+}
+
+/// Memo for fork_project
+pub fn build_project_fork_memo(burn_amount: u64, project_id: u64, source_project_id: u64) -> String {
+    let data = ProjectForkData {
+        version: PROJECT_FORK_DATA_VERSION,
+        category: EXPECTED_CATEGORY.to_string(),
+        operation: EXPECTED_FORK_PROJECT_OPERATION.to_string(),
+        project_id,
+        source_project_id,
+    };
+    encode_burn_memo(burn_amount, data.try_to_vec().expect("serializing into a Vec cannot fail"))
+}